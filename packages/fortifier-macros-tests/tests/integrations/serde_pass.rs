@@ -1,4 +1,6 @@
-use fortifier::Validate;
+use std::collections::HashMap;
+
+use fortifier::{Validate, ValidationErrors};
 use pretty_assertions::assert_eq;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -8,7 +10,13 @@ struct CreateUser {
     #[validate(length(min = 1, max = 256))]
     name: String,
 
+    #[validate(email_address)]
+    #[validate(length(max = 8))]
+    email_address: String,
+
     email_addresses: Vec<CreateEmailAddress>,
+
+    labeled_email_addresses: HashMap<String, CreateEmailAddress>,
 }
 
 #[derive(Deserialize, Serialize, Validate)]
@@ -20,14 +28,23 @@ struct CreateEmailAddress {
 fn main() {
     let data = CreateUser {
         name: "".to_owned(),
+        email_address: "john.doe".to_owned(),
         email_addresses: vec![CreateEmailAddress {
             email_address: "invalid".to_owned(),
         }],
+        labeled_email_addresses: HashMap::from([(
+            "work".to_owned(),
+            CreateEmailAddress {
+                email_address: "invalid".to_owned(),
+            },
+        )]),
     };
 
+    let errors = data.validate_sync().expect_err("validation error");
+    let serialized = serde_json::to_value(&errors).expect("serializable value");
+
     assert_eq!(
-        serde_json::to_value(data.validate_sync().expect_err("validation error"))
-            .expect("serializable value"),
+        serialized,
         json!([
             {
                 "path": "name",
@@ -36,6 +53,11 @@ fn main() {
                 "min": 1,
                 "value": 0
             },
+            {
+                "path": "emailAddress",
+                "code": "emailAddress",
+                "subcode": "missingSeparator"
+            },
             {
                 "path": "emailAddresses",
                 "code": "nested",
@@ -47,7 +69,25 @@ fn main() {
                         "subcode": "missingSeparator"
                     }
                 ]
+            },
+            {
+                "path": "labeledEmailAddresses",
+                "code": "nested",
+                "errors": [
+                    {
+                        "key": "work",
+                        "path": "emailAddress",
+                        "code": "emailAddress",
+                        "subcode": "missingSeparator"
+                    }
+                ]
             }
         ]),
     );
+
+    assert_eq!(
+        serde_json::from_value::<ValidationErrors<CreateUserValidationError>>(serialized)
+            .expect("deserializable value"),
+        errors
+    );
 }
@@ -0,0 +1,8 @@
+use trybuild::TestCases;
+
+#[test]
+fn sanitize() {
+    let t = TestCases::new();
+    t.pass("tests/sanitize/*_pass.rs");
+    t.compile_fail("tests/sanitize/*_fail.rs");
+}
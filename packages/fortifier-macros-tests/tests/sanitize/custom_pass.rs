@@ -0,0 +1,39 @@
+use fortifier::Sanitize;
+
+#[derive(Debug, PartialEq, Sanitize)]
+#[sanitizer(custom = sanitize_create_user)]
+struct CreateUser {
+    #[sanitizer(custom(function = sanitize_phone_number))]
+    phone_number: String,
+    display_name: String,
+    name: String,
+}
+
+fn sanitize_create_user(value: &mut CreateUser) {
+    if value.display_name.is_empty() {
+        value.display_name = value.name.clone();
+    }
+}
+
+fn sanitize_phone_number(value: &mut String) {
+    value.retain(|c| !c.is_whitespace());
+}
+
+fn main() {
+    let mut data = CreateUser {
+        phone_number: "+44 20 7946 0000".to_owned(),
+        display_name: String::new(),
+        name: "John Doe".to_owned(),
+    };
+
+    data.sanitize();
+
+    assert_eq!(
+        data,
+        CreateUser {
+            phone_number: "+442079460000".to_owned(),
+            display_name: "John Doe".to_owned(),
+            name: "John Doe".to_owned(),
+        }
+    );
+}
@@ -0,0 +1,44 @@
+use fortifier::Sanitize;
+
+#[derive(Debug, PartialEq, Sanitize)]
+enum Contact {
+    None,
+    EmailAddress {
+        #[sanitizer(trim, lowercase)]
+        email_address: String,
+        primary: bool,
+    },
+    PhoneNumber(#[sanitizer(trim)] String, bool),
+}
+
+fn main() {
+    let mut data = Contact::None;
+
+    data.sanitize();
+
+    assert_eq!(data, Contact::None);
+
+    let mut data = Contact::EmailAddress {
+        email_address: " John@Example.COM ".to_owned(),
+        primary: true,
+    };
+
+    data.sanitize();
+
+    assert_eq!(
+        data,
+        Contact::EmailAddress {
+            email_address: "john@example.com".to_owned(),
+            primary: true,
+        }
+    );
+
+    let mut data = Contact::PhoneNumber(" +44 20 7946 0000 ".to_owned(), false);
+
+    data.sanitize();
+
+    assert_eq!(
+        data,
+        Contact::PhoneNumber("+44 20 7946 0000".to_owned(), false)
+    );
+}
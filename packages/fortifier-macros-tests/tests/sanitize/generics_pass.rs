@@ -0,0 +1,42 @@
+use fortifier::Sanitize;
+
+#[derive(Debug, PartialEq, Sanitize)]
+struct Page<T> {
+    #[sanitizer(trim)]
+    title: String,
+    items: Vec<T>,
+    item: T,
+}
+
+#[derive(Debug, PartialEq, Sanitize)]
+struct Item {
+    #[sanitizer(trim)]
+    name: String,
+}
+
+fn main() {
+    let mut data = Page {
+        title: " Items ".to_owned(),
+        items: vec![Item {
+            name: " First ".to_owned(),
+        }],
+        item: Item {
+            name: " Second ".to_owned(),
+        },
+    };
+
+    data.sanitize();
+
+    assert_eq!(
+        data,
+        Page {
+            title: "Items".to_owned(),
+            items: vec![Item {
+                name: "First".to_owned(),
+            }],
+            item: Item {
+                name: "Second".to_owned(),
+            },
+        }
+    );
+}
@@ -0,0 +1,66 @@
+use fortifier::{EmailAddressError, IndexedValidationError, Sanitize, Validate, ValidationErrors};
+
+#[derive(Debug, PartialEq, Sanitize, Validate)]
+struct CreateUser {
+    #[sanitizer(trim)]
+    #[validate(length(min = 1))]
+    name: String,
+    email_addresses: Vec<CreateEmailAddress>,
+    #[sanitizer(skip)]
+    #[validate(skip)]
+    note: Note,
+}
+
+#[derive(Debug, PartialEq, Sanitize, Validate)]
+struct CreateEmailAddress {
+    #[sanitizer(trim, lowercase)]
+    #[validate(email_address)]
+    email_address: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Note(String);
+
+fn main() {
+    let mut data = CreateUser {
+        name: " John Doe ".to_owned(),
+        email_addresses: vec![CreateEmailAddress {
+            email_address: " John@Example.COM ".to_owned(),
+        }],
+        note: Note(" note ".to_owned()),
+    };
+
+    assert_eq!(data.sanitize_and_validate_sync(), Ok(()));
+    assert_eq!(
+        data,
+        CreateUser {
+            name: "John Doe".to_owned(),
+            email_addresses: vec![CreateEmailAddress {
+                email_address: "john@example.com".to_owned(),
+            }],
+            note: Note(" note ".to_owned()),
+        }
+    );
+
+    let mut data = CreateUser {
+        name: "John Doe".to_owned(),
+        email_addresses: vec![CreateEmailAddress {
+            email_address: " invalid ".to_owned(),
+        }],
+        note: Note("note".to_owned()),
+    };
+
+    assert_eq!(
+        data.sanitize_and_validate_sync(),
+        Err(ValidationErrors::from_iter([
+            CreateUserValidationError::EmailAddresses(ValidationErrors::from_iter([
+                IndexedValidationError::new(
+                    0,
+                    CreateEmailAddressValidationError::EmailAddress(EmailAddressError::from(
+                        email_address::Error::MissingSeparator
+                    ))
+                )
+            ]))
+        ]))
+    );
+}
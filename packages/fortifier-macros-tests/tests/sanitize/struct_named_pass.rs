@@ -0,0 +1,33 @@
+use fortifier::Sanitize;
+
+#[derive(Debug, PartialEq, Sanitize)]
+struct CreateUser {
+    #[sanitizer(trim, collapse_whitespace)]
+    name: String,
+    #[sanitizer(trim, lowercase)]
+    email_address: Option<String>,
+    #[sanitizer(trim)]
+    tags: Vec<String>,
+    age: u8,
+}
+
+fn main() {
+    let mut data = CreateUser {
+        name: "  John   Doe ".to_owned(),
+        email_address: Some(" John@Example.COM".to_owned()),
+        tags: vec![" admin ".to_owned()],
+        age: 42,
+    };
+
+    data.sanitize();
+
+    assert_eq!(
+        data,
+        CreateUser {
+            name: "John Doe".to_owned(),
+            email_address: Some("john@example.com".to_owned()),
+            tags: vec!["admin".to_owned()],
+            age: 42,
+        }
+    );
+}
@@ -0,0 +1,12 @@
+use fortifier::Sanitize;
+
+#[derive(Debug, PartialEq, Sanitize)]
+struct Name(#[sanitizer(trim)] String, u8);
+
+fn main() {
+    let mut data = Name(" John Doe ".to_owned(), 42);
+
+    data.sanitize();
+
+    assert_eq!(data, Name("John Doe".to_owned(), 42));
+}
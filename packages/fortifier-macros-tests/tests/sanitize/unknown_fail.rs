@@ -0,0 +1,9 @@
+use fortifier::Sanitize;
+
+#[derive(Sanitize)]
+struct CreateUser {
+    #[sanitizer(uppercase)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+enum Role {
+    #[validate(allow = true)]
+    Admin,
+    Member,
+}
+
+fn main() {}
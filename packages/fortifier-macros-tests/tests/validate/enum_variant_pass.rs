@@ -0,0 +1,82 @@
+use fortifier::{
+    DisallowError, LengthError, LengthErrorCode, Validate, ValidateWithContext, ValidationErrors,
+};
+use serde::{Deserialize, Serialize};
+
+struct Context {
+    admin: bool,
+    strict: bool,
+}
+
+#[derive(Validate)]
+#[validate(context = Context)]
+enum Role {
+    #[validate(disallow = !context.admin)]
+    Admin,
+    #[validate(custom(function = validate_moderator, error = ModeratorError))]
+    Moderator { team: String },
+    #[validate(when = context.strict)]
+    Member(#[validate(length(min = 1))] String),
+    #[validate(skip)]
+    Guest(#[validate(length(min = 1))] String),
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct ModeratorError;
+
+fn validate_moderator(value: &Role) -> Result<(), ModeratorError> {
+    match value {
+        Role::Moderator { team } if team.is_empty() => Err(ModeratorError),
+        _ => Ok(()),
+    }
+}
+
+fn main() {
+    let admin = Context {
+        admin: true,
+        strict: true,
+    };
+    let user = Context {
+        admin: false,
+        strict: false,
+    };
+
+    assert_eq!(Role::Admin.validate_sync_with_context(&admin), Ok(()));
+    assert_eq!(
+        Role::Admin.validate_sync_with_context(&user),
+        Err(ValidationErrors::from_iter([RoleValidationError::Admin(
+            RoleAdminValidationError::Disallow(DisallowError::default())
+        )]))
+    );
+
+    assert_eq!(
+        Role::Moderator {
+            team: String::new()
+        }
+        .validate_sync_with_context(&user),
+        Err(ValidationErrors::from_iter([RoleValidationError::Moderator(
+            RoleModeratorValidationError::Custom(ModeratorError)
+        )]))
+    );
+
+    assert_eq!(
+        Role::Member(String::new()).validate_sync_with_context(&user),
+        Ok(())
+    );
+    assert_eq!(
+        Role::Member(String::new()).validate_sync_with_context(&admin),
+        Err(ValidationErrors::from_iter([RoleValidationError::Member(
+            RoleMemberValidationError::F0(LengthError::Min {
+                unit: None,
+                code: LengthErrorCode,
+                min: 1,
+                value: 0,
+            })
+        )]))
+    );
+
+    assert_eq!(
+        Role::Guest(String::new()).validate_sync_with_context(&admin),
+        Ok(())
+    );
+}
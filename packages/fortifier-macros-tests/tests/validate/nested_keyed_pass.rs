@@ -0,0 +1,74 @@
+use std::collections::{BTreeMap, HashMap};
+
+use fortifier::{EmailAddressError, KeyedValidationError, Validate, ValidationErrors};
+use indexmap::IndexMap;
+
+#[derive(Validate)]
+struct CreateUser {
+    name: String,
+    btree_email_addresses: BTreeMap<String, CreateEmailAddress>,
+    hash_email_addresses: HashMap<String, CreateEmailAddress>,
+    index_email_addresses: IndexMap<String, CreateEmailAddress>,
+    tags: HashMap<String, String>,
+}
+
+#[derive(Validate)]
+struct CreateEmailAddress {
+    #[validate(email_address)]
+    email_address: String,
+}
+
+fn main() {
+    let data = CreateUser {
+        name: "John Doe".to_owned(),
+        btree_email_addresses: BTreeMap::from([(
+            "work".to_owned(),
+            CreateEmailAddress {
+                email_address: "invalid".to_owned(),
+            },
+        )]),
+        hash_email_addresses: HashMap::from([(
+            "work".to_owned(),
+            CreateEmailAddress {
+                email_address: "invalid".to_owned(),
+            },
+        )]),
+        index_email_addresses: IndexMap::from([(
+            "work".to_owned(),
+            CreateEmailAddress {
+                email_address: "invalid".to_owned(),
+            },
+        )]),
+        tags: HashMap::from([("role".to_owned(), "admin".to_owned())]),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            CreateUserValidationError::BtreeEmailAddresses(ValidationErrors::from_iter([
+                KeyedValidationError::new(
+                    "work".to_owned(),
+                    CreateEmailAddressValidationError::EmailAddress(EmailAddressError::from(
+                        email_address::Error::MissingSeparator
+                    ))
+                )
+            ])),
+            CreateUserValidationError::HashEmailAddresses(ValidationErrors::from_iter([
+                KeyedValidationError::new(
+                    "work".to_owned(),
+                    CreateEmailAddressValidationError::EmailAddress(EmailAddressError::from(
+                        email_address::Error::MissingSeparator
+                    ))
+                )
+            ])),
+            CreateUserValidationError::IndexEmailAddresses(ValidationErrors::from_iter([
+                KeyedValidationError::new(
+                    "work".to_owned(),
+                    CreateEmailAddressValidationError::EmailAddress(EmailAddressError::from(
+                        email_address::Error::MissingSeparator
+                    ))
+                )
+            ]))
+        ]))
+    );
+}
@@ -0,0 +1,41 @@
+use fortifier::{Url, Validate, ValidateOutputWithContext, ValidationErrors};
+use serde::{Deserialize, Serialize};
+
+struct Context {
+    max: usize,
+}
+
+#[derive(Validate)]
+#[validate(
+    context = Context,
+    output(ident = ParsedLink),
+    custom(function = validate_custom, error = CustomError, context, async),
+)]
+struct Link(
+    #[validate(url, length(max = context.max))] String,
+    #[validate(length(min = 1))] String,
+);
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct CustomError;
+
+async fn validate_custom(_value: &Link, _context: &Context) -> Result<(), CustomError> {
+    Ok(())
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), ValidationErrors<LinkValidationError>> {
+    let data = Link("http://localhost".to_owned(), "Home".to_owned());
+
+    let output: ParsedLink = data
+        .validate_into_output_with_context(&Context { max: 256 })
+        .await?;
+
+    assert_eq!(
+        output.0,
+        Url::parse("http://localhost").expect("valid URL")
+    );
+    assert_eq!(output.1, "Home");
+
+    Ok(())
+}
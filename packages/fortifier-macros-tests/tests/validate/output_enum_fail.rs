@@ -0,0 +1,10 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+#[validate(output)]
+enum Contact {
+    EmailAddress(#[validate(email_address)] String),
+    PhoneNumber(#[validate(phone_number)] String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+#[validate(output)]
+struct Contact {
+    #[validate(email_address)]
+    #[validate(url)]
+    value: String,
+}
+
+fn main() {}
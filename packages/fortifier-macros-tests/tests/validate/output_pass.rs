@@ -0,0 +1,60 @@
+use fortifier::{
+    EmailAddress, EmailAddressError, PhoneNumber, PhoneNumberCountry, Url, Validate,
+    ValidateOutput, ValidationErrors,
+};
+
+#[derive(Validate)]
+#[validate(output(derive(Debug, PartialEq)))]
+struct CreateUser {
+    #[validate(length(min = 1, max = 256))]
+    name: String,
+    #[validate(email_address)]
+    email_address: String,
+    #[validate(phone_number(default_country = PhoneNumberCountry::GB))]
+    phone_number: Option<String>,
+    #[validate(url)]
+    website: Option<String>,
+    #[validate(country_code(alpha3))]
+    country: String,
+}
+
+fn main() {
+    let data = CreateUser {
+        name: "John Doe".to_owned(),
+        email_address: "john@example.com".to_owned(),
+        phone_number: Some("020 7946 0000".to_owned()),
+        website: None,
+        country: "GBR".to_owned(),
+    };
+
+    let output: CreateUserOutput = data.validate_into_output_sync().expect("valid data");
+
+    assert_eq!(output.name, "John Doe");
+    assert_eq!(
+        output.email_address,
+        EmailAddress::new_unchecked("john@example.com")
+    );
+    assert_eq!(
+        output.phone_number.as_ref().map(PhoneNumber::to_string),
+        Some("+442079460000".to_owned())
+    );
+    assert_eq!(output.website, None::<Url>);
+    assert_eq!(output.country, PhoneNumberCountry::GB);
+
+    let data = CreateUser {
+        name: "John Doe".to_owned(),
+        email_address: "invalid".to_owned(),
+        phone_number: None,
+        website: None,
+        country: "GBR".to_owned(),
+    };
+
+    assert_eq!(
+        data.validate_into_output_sync(),
+        Err(ValidationErrors::from_iter([
+            CreateUserValidationError::EmailAddress(EmailAddressError::from(
+                email_address::Error::MissingSeparator
+            ))
+        ]))
+    );
+}
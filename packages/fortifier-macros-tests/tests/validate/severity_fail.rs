@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct CreateUser {
+    #[validate(length(max = 8), severity = info)]
+    nickname: String,
+}
+
+fn main() {}
@@ -0,0 +1,152 @@
+use fortifier::{
+    EmailAddressError, IndexedValidationError, LengthError, LengthErrorCode, Validate,
+    ValidationErrors, ValidationReport,
+};
+use serde_json::json;
+
+#[derive(Validate)]
+struct CreateUser {
+    #[validate(length(min = 1, max = 256))]
+    name: String,
+    #[validate(length(max = 8), severity = warning)]
+    nickname: String,
+    email_addresses: Vec<CreateEmailAddress>,
+}
+
+#[derive(Validate)]
+struct CreateEmailAddress {
+    #[validate(email_address)]
+    #[validate(length(max = 16), severity = warning)]
+    email_address: String,
+}
+
+fn main() {
+    let data = CreateUser {
+        name: "John Doe".to_owned(),
+        nickname: "Johnny Doe".to_owned(),
+        email_addresses: vec![CreateEmailAddress {
+            email_address: "john.doe@example.com".to_owned(),
+        }],
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let warnings = || ValidationErrors::from_iter([
+        CreateUserValidationError::Nickname(LengthError::Max {
+            unit: None,
+            code: LengthErrorCode,
+            max: 8,
+            value: 10,
+        }),
+        CreateUserValidationError::EmailAddresses(ValidationErrors::from_iter([
+            IndexedValidationError::new(
+                0,
+                CreateEmailAddressValidationError::EmailAddress(
+                    CreateEmailAddressEmailAddressValidationError::Length(LengthError::Max {
+                        unit: None,
+                        code: LengthErrorCode,
+                        max: 16,
+                        value: 20,
+                    }),
+                ),
+            ),
+        ])),
+    ]);
+
+    assert_eq!(
+        data.validate_report_sync(),
+        ValidationReport {
+            errors: ValidationErrors::default(),
+            warnings: warnings(),
+        }
+    );
+    assert_eq!(data.validate_report_sync().into_result(), Ok(warnings()));
+
+    let data = CreateUser {
+        name: "".to_owned(),
+        nickname: "Johnny Doe".to_owned(),
+        email_addresses: vec![CreateEmailAddress {
+            email_address: "invalid".to_owned(),
+        }],
+    };
+
+    let report = data.validate_report_sync();
+
+    assert!(!report.is_valid());
+    assert_eq!(
+        report.errors,
+        ValidationErrors::from_iter([
+            CreateUserValidationError::Name(LengthError::Min {
+                unit: None,
+                code: LengthErrorCode,
+                min: 1,
+                value: 0,
+            }),
+            CreateUserValidationError::EmailAddresses(ValidationErrors::from_iter([
+                IndexedValidationError::new(
+                    0,
+                    CreateEmailAddressValidationError::EmailAddress(
+                        CreateEmailAddressEmailAddressValidationError::EmailAddress(
+                            EmailAddressError::from(email_address::Error::MissingSeparator)
+                        ),
+                    ),
+                ),
+            ])),
+        ])
+    );
+    assert_eq!(
+        report.warnings,
+        ValidationErrors::from_iter([CreateUserValidationError::Nickname(LengthError::Max {
+            unit: None,
+            code: LengthErrorCode,
+            max: 8,
+            value: 10,
+        })])
+    );
+
+    let report = report.into_result().expect_err("invalid report");
+
+    let serialized = serde_json::to_value(&report).expect("serializable report");
+
+    assert_eq!(
+        serialized,
+        json!({
+            "errors": [
+                {
+                    "path": "name",
+                    "code": "length",
+                    "subcode": "min",
+                    "min": 1,
+                    "value": 0,
+                },
+                {
+                    "path": "emailAddresses",
+                    "code": "nested",
+                    "errors": [
+                        {
+                            "index": 0,
+                            "path": "emailAddress",
+                            "code": "emailAddress",
+                            "subcode": "missingSeparator",
+                        },
+                    ],
+                },
+            ],
+            "warnings": [
+                {
+                    "path": "nickname",
+                    "code": "length",
+                    "subcode": "max",
+                    "max": 8,
+                    "value": 10,
+                },
+            ],
+        })
+    );
+
+    assert_eq!(
+        serde_json::from_value::<ValidationReport<CreateUserValidationError>>(serialized)
+            .expect("deserializable report"),
+        report
+    );
+}
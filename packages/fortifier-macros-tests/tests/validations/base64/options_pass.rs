@@ -0,0 +1,65 @@
+use fortifier::{Base64Error, Base64ErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct Attachment<'a> {
+    #[validate(base64)]
+    content: &'a str,
+    #[validate(base64(padding))]
+    signature: String,
+    #[validate(base64(url_safe, padding = false))]
+    token: Option<String>,
+}
+
+fn main() {
+    let data = Attachment {
+        content: "Zm9vYg",
+        signature: "Zm9vYg==".to_owned(),
+        token: Some("-_-_Zm9vYg".to_owned()),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = Attachment {
+        content: "Zm9vY",
+        signature: "Zm9vYg".to_owned(),
+        token: Some("Zm9vYg==".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            AttachmentValidationError::Content(Base64Error::InvalidLength {
+                code: Base64ErrorCode,
+                value: 5,
+            }),
+            AttachmentValidationError::Signature(Base64Error::MissingPadding {
+                code: Base64ErrorCode,
+            }),
+            AttachmentValidationError::Token(Base64Error::UnexpectedPadding {
+                code: Base64ErrorCode,
+            }),
+        ]))
+    );
+
+    let data = Attachment {
+        content: "Zm9v+/=",
+        signature: "Zg==Zg==".to_owned(),
+        token: Some("Zm9v+/".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            AttachmentValidationError::Content(Base64Error::InvalidPadding {
+                code: Base64ErrorCode,
+            }),
+            AttachmentValidationError::Signature(Base64Error::InvalidPadding {
+                code: Base64ErrorCode,
+            }),
+            AttachmentValidationError::Token(Base64Error::InvalidCharacter {
+                code: Base64ErrorCode,
+                character: '+',
+            }),
+        ]))
+    );
+}
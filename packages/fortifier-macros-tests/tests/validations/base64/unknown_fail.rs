@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct Attachment<'a> {
+    #[validate(base64(url_safe, unknown = true))]
+    content: &'a str,
+}
+
+fn main() {}
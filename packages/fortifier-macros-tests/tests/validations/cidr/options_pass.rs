@@ -0,0 +1,43 @@
+use fortifier::{CidrError, CidrErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct CidrData<'a> {
+    #[validate(cidr)]
+    any: &'a str,
+    #[validate(cidr(v4, allow_private = false))]
+    public_v4: String,
+    #[validate(cidr)]
+    host_bits: Option<String>,
+}
+
+fn main() {
+    let data = CidrData {
+        any: "2001:db8::/32",
+        public_v4: "203.0.113.0/24".to_owned(),
+        host_bits: Some("10.0.0.0/8".to_owned()),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = CidrData {
+        any: "10.0.0.0/33",
+        public_v4: "192.168.0.0/16".to_owned(),
+        host_bits: Some("10.0.0.1/8".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            CidrDataValidationError::Any(CidrError::InvalidPrefixLength {
+                code: CidrErrorCode,
+                max: 32,
+            }),
+            CidrDataValidationError::PublicV4(CidrError::Private {
+                code: CidrErrorCode,
+            }),
+            CidrDataValidationError::HostBits(CidrError::HostBitsSet {
+                code: CidrErrorCode,
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct ContentData {
+    #[validate(charset = "z-a")]
+    slug: String,
+}
+
+fn main() {}
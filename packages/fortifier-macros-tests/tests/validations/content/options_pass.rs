@@ -0,0 +1,107 @@
+use std::{borrow::Cow, sync::Arc};
+
+use fortifier::{ContentError, ContentErrorCode, Validate, ValidationErrors};
+use serde_json::json;
+
+#[derive(Validate)]
+struct ContentData<'a> {
+    #[validate(starts_with = "sk_", alphanumeric, charset = "a-z0-9_")]
+    api_key: String,
+    #[validate(not_contains = "<", ascii)]
+    comment: &'a str,
+    #[validate(ends_with(suffix = ".com"))]
+    domain: Cow<'a, str>,
+    #[validate(contains(pattern = "@"))]
+    handle: Option<Arc<str>>,
+    #[validate(charset = "a-z0-9-")]
+    slug: Box<str>,
+}
+
+fn main() {
+    let data = ContentData {
+        api_key: "sk_live".to_owned(),
+        comment: "Hello",
+        domain: Cow::Borrowed("example.com"),
+        handle: None,
+        slug: "my-slug".into(),
+    };
+
+    assert_eq!(data.validate_sync(), Err(ValidationErrors::from_iter([
+        ContentDataValidationError::ApiKey(ContentDataApiKeyValidationError::Alphanumeric(
+            ContentError::Alphanumeric {
+                code: ContentErrorCode,
+                character: '_',
+            }
+        )),
+    ])));
+
+    let data = ContentData {
+        api_key: "pk_Live".to_owned(),
+        comment: "<café>",
+        domain: Cow::Owned("example.org".to_owned()),
+        handle: Some("john".into()),
+        slug: "My slug".into(),
+    };
+
+    let errors = data.validate_sync().expect_err("invalid data");
+
+    assert_eq!(
+        errors,
+        ValidationErrors::from_iter([
+            ContentDataValidationError::ApiKey(ContentDataApiKeyValidationError::StartsWith(
+                ContentError::StartsWith {
+                    code: ContentErrorCode,
+                    prefix: "sk_".to_owned(),
+                }
+            )),
+            ContentDataValidationError::ApiKey(ContentDataApiKeyValidationError::Alphanumeric(
+                ContentError::Alphanumeric {
+                    code: ContentErrorCode,
+                    character: '_',
+                }
+            )),
+            ContentDataValidationError::ApiKey(ContentDataApiKeyValidationError::Charset(
+                ContentError::Charset {
+                    code: ContentErrorCode,
+                    charset: "a-z0-9_".to_owned(),
+                    character: 'L',
+                }
+            )),
+            ContentDataValidationError::Comment(ContentDataCommentValidationError::NotContains(
+                ContentError::NotContains {
+                    code: ContentErrorCode,
+                    pattern: "<".to_owned(),
+                }
+            )),
+            ContentDataValidationError::Comment(ContentDataCommentValidationError::Ascii(
+                ContentError::Ascii {
+                    code: ContentErrorCode,
+                    character: 'é',
+                }
+            )),
+            ContentDataValidationError::Domain(ContentError::EndsWith {
+                code: ContentErrorCode,
+                suffix: ".com".to_owned(),
+            }),
+            ContentDataValidationError::Handle(ContentError::Contains {
+                code: ContentErrorCode,
+                pattern: "@".to_owned(),
+            }),
+            ContentDataValidationError::Slug(ContentError::Charset {
+                code: ContentErrorCode,
+                charset: "a-z0-9-".to_owned(),
+                character: 'M',
+            }),
+        ])
+    );
+
+    assert_eq!(
+        serde_json::to_value(&errors).expect("serializable errors")[3],
+        json!({
+            "path": "comment",
+            "code": "content",
+            "subcode": "notContains",
+            "pattern": "<",
+        })
+    );
+}
@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct ContentData {
+    #[validate(starts_with(unknown = "sk_"))]
+    api_key: String,
+}
+
+fn main() {}
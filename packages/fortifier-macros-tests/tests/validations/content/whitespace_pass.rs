@@ -0,0 +1,60 @@
+use fortifier::{ContentError, ContentErrorCode, Validate, ValidationErrors};
+use serde_json::json;
+
+#[derive(Validate)]
+struct CommentData {
+    #[validate(not_blank, no_control_characters)]
+    name: String,
+    #[validate(not_blank, no_control_characters(allow_whitespace = true))]
+    body: Option<String>,
+}
+
+fn main() {
+    let data = CommentData {
+        name: "John".to_owned(),
+        body: Some("Hello,\r\n\tworld!".to_owned()),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = CommentData {
+        name: "John\u{202E}".to_owned(),
+        body: Some(" \n ".to_owned()),
+    };
+
+    let errors = data.validate_sync().expect_err("invalid data");
+
+    assert_eq!(
+        errors,
+        ValidationErrors::from_iter([
+            CommentDataValidationError::Name(CommentDataNameValidationError::NoControlCharacters(
+                ContentError::NoControlCharacters {
+                    code: ContentErrorCode,
+                    character: '\u{202E}',
+                }
+            )),
+            CommentDataValidationError::Body(CommentDataBodyValidationError::NotBlank(
+                ContentError::NotBlank {
+                    code: ContentErrorCode,
+                }
+            )),
+        ])
+    );
+
+    assert_eq!(
+        serde_json::to_value(&errors).expect("serializable errors"),
+        json!([
+            {
+                "path": "name",
+                "code": "content",
+                "subcode": "noControlCharacters",
+                "character": "\u{202E}",
+            },
+            {
+                "path": "body",
+                "code": "content",
+                "subcode": "notBlank",
+            },
+        ])
+    );
+}
@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use fortifier::{CountryCodeError, CountryCodeErrorCode, PhoneNumberCountry, Validate, ValidationErrors};
+
+const ALLOWED_COUNTRIES: [PhoneNumberCountry; 2] = [PhoneNumberCountry::BE, PhoneNumberCountry::NL];
+
+#[derive(Validate)]
+struct CountryCodeData<'a> {
+    #[validate(country_code)]
+    r#str: &'a str,
+    #[validate(country_code(alpha3))]
+    string: String,
+    #[validate(country_code(alpha2, allowed_countries = ALLOWED_COUNTRIES))]
+    cow: Cow<'a, str>,
+    #[validate(country_code(alpha3, allowed_countries = [PhoneNumberCountry::NL]))]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = CountryCodeData {
+        r#str: "US",
+        string: "NLD".to_owned(),
+        cow: Cow::Borrowed("BE"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = CountryCodeData {
+        r#str: "us",
+        string: "NL".to_owned(),
+        cow: Cow::Borrowed("DE"),
+        option: Some("BEL".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            CountryCodeDataValidationError::Str(CountryCodeError::Unknown {
+                code: CountryCodeErrorCode,
+                value: "us".to_owned(),
+            }),
+            CountryCodeDataValidationError::String(CountryCodeError::Unknown {
+                code: CountryCodeErrorCode,
+                value: "NL".to_owned(),
+            }),
+            CountryCodeDataValidationError::Cow(CountryCodeError::Disallowed {
+                code: CountryCodeErrorCode,
+                allowed: vec![PhoneNumberCountry::BE, PhoneNumberCountry::NL],
+                value: PhoneNumberCountry::DE,
+            }),
+            CountryCodeDataValidationError::Option(CountryCodeError::Disallowed {
+                code: CountryCodeErrorCode,
+                allowed: vec![PhoneNumberCountry::NL],
+                value: PhoneNumberCountry::BE,
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct Address<'a> {
+    #[validate(country_code(alpha3, numeric))]
+    country: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use fortifier::{
+    CreditCardBrand, CreditCardError, CreditCardErrorCode, Validate, ValidationErrors,
+};
+
+#[derive(Validate)]
+struct CreditCardData<'a> {
+    #[validate(credit_card)]
+    any_brand: &'a str,
+    #[validate(credit_card(allowed_brands = vec![CreditCardBrand::Mastercard, CreditCardBrand::Visa]))]
+    allowed_brands: String,
+    #[validate(credit_card)]
+    checksum: Option<String>,
+    #[validate(credit_card, length(max = 23))]
+    combined: &'a str,
+}
+
+fn main() {
+    let data = CreditCardData {
+        any_brand: "3782 822463 10005",
+        allowed_brands: "4111-1111-1111-1111".to_owned(),
+        checksum: None,
+        combined: "4111 1111 1111 1111",
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = CreditCardData {
+        any_brand: "1234 5678 9012 3456",
+        allowed_brands: "3782 822463 10005".to_owned(),
+        checksum: Some("4111 1111 1111 1112".to_owned()),
+        combined: "4111 1111 1111 1111",
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            CreditCardDataValidationError::AnyBrand(CreditCardError::UnknownBrand {
+                code: CreditCardErrorCode,
+            }),
+            CreditCardDataValidationError::AllowedBrands(CreditCardError::DisallowedBrand {
+                code: CreditCardErrorCode,
+                allowed: vec![CreditCardBrand::Mastercard, CreditCardBrand::Visa],
+                value: CreditCardBrand::AmericanExpress,
+            }),
+            CreditCardDataValidationError::Checksum(CreditCardError::Checksum {
+                code: CreditCardErrorCode,
+                brand: CreditCardBrand::Visa,
+            }),
+        ]))
+    );
+}
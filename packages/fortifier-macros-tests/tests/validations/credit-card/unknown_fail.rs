@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct CreditCardData<'a> {
+    #[validate(credit_card(unknown = true))]
+    value: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use fortifier::{CurrencyCodeError, CurrencyCodeErrorCode, Validate, ValidationErrors};
+
+const ALLOWED_CURRENCIES: [&str; 2] = ["EUR", "USD"];
+
+#[derive(Validate)]
+struct CurrencyCodeData<'a> {
+    #[validate(currency_code)]
+    r#str: &'a str,
+    #[validate(currency_code(allowed_currencies = ["EUR"]))]
+    string: String,
+    #[validate(currency_code(allowed_currencies = ALLOWED_CURRENCIES))]
+    cow: Cow<'a, str>,
+    #[validate(currency_code)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = CurrencyCodeData {
+        r#str: "JPY",
+        string: "EUR".to_owned(),
+        cow: Cow::Borrowed("USD"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = CurrencyCodeData {
+        r#str: "eur",
+        string: "USD".to_owned(),
+        cow: Cow::Borrowed("GBP"),
+        option: Some("EURO".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            CurrencyCodeDataValidationError::Str(CurrencyCodeError::Unknown {
+                code: CurrencyCodeErrorCode,
+                value: "eur".to_owned(),
+            }),
+            CurrencyCodeDataValidationError::String(CurrencyCodeError::Disallowed {
+                code: CurrencyCodeErrorCode,
+                allowed: vec!["EUR".to_owned()],
+                value: "USD".to_owned(),
+            }),
+            CurrencyCodeDataValidationError::Cow(CurrencyCodeError::Disallowed {
+                code: CurrencyCodeErrorCode,
+                allowed: vec!["EUR".to_owned(), "USD".to_owned()],
+                value: "GBP".to_owned(),
+            }),
+            CurrencyCodeDataValidationError::Option(CurrencyCodeError::Unknown {
+                code: CurrencyCodeErrorCode,
+                value: "EURO".to_owned(),
+            }),
+        ]))
+    );
+}
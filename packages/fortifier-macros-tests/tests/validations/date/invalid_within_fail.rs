@@ -0,0 +1,10 @@
+use chrono::NaiveDate;
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct CreateAppointment {
+    #[validate(within = "30 days")]
+    date: NaiveDate,
+}
+
+fn main() {}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use fortifier::{
+    Clock, DateError, DateErrorCode, FixedClock, Validate, ValidateWithContext, ValidationErrors,
+};
+use serde_json::json;
+
+struct Context {
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+#[derive(Validate)]
+#[validate(context = Context)]
+struct CreateUser {
+    #[validate(past(clock = context.clock), min_age(years = 18, clock = context.clock))]
+    birth_date: NaiveDate,
+    #[validate(future(clock = context.clock))]
+    expires_at: Option<DateTime<Utc>>,
+    #[validate(past_or_present(clock = context.clock))]
+    verified_at: Option<NaiveDateTime>,
+    #[validate(within(duration = "30d", clock = context.clock))]
+    appointment: NaiveDate,
+}
+
+#[derive(Validate)]
+struct CreateEvent {
+    #[validate(past_or_present, within = "1h30m")]
+    created_at: DateTime<Utc>,
+}
+
+fn date(value: &str) -> NaiveDate {
+    value.parse().expect("valid date")
+}
+
+fn main() {
+    let context = Context {
+        clock: Arc::new(FixedClock(
+            "2025-06-15T12:00:00Z"
+                .parse::<DateTime<Utc>>()
+                .expect("valid date time")
+                .into(),
+        )),
+    };
+
+    let data = CreateUser {
+        birth_date: date("2007-06-15"),
+        expires_at: Some("2025-06-15T12:00:01Z".parse().expect("valid date time")),
+        verified_at: Some("2025-06-15T12:00:00".parse().expect("valid date time")),
+        appointment: date("2025-07-15"),
+    };
+
+    assert_eq!(data.validate_sync_with_context(&context), Ok(()));
+
+    let data = CreateUser {
+        birth_date: date("2007-06-16"),
+        expires_at: None,
+        verified_at: Some("2025-06-15T12:00:01".parse().expect("valid date time")),
+        appointment: date("2025-07-16"),
+    };
+
+    let errors = data
+        .validate_sync_with_context(&context)
+        .expect_err("invalid data");
+
+    assert_eq!(
+        errors,
+        ValidationErrors::from_iter([
+            CreateUserValidationError::BirthDate(CreateUserBirthDateValidationError::MinAge(
+                DateError::MinAge {
+                    min_age: 18,
+                    value: date("2007-06-16"),
+                    code: DateErrorCode,
+                }
+            )),
+            CreateUserValidationError::VerifiedAt(DateError::PastOrPresent {
+                value: "2025-06-15T12:00:01".parse().expect("valid date time"),
+                code: DateErrorCode,
+            }),
+            CreateUserValidationError::Appointment(DateError::Within {
+                within: 2_592_000,
+                value: date("2025-07-16"),
+                code: DateErrorCode,
+            }),
+        ])
+    );
+
+    assert_eq!(
+        serde_json::to_value(&errors).expect("serializable errors")[0],
+        json!({
+            "path": "birthDate",
+            "code": "date",
+            "subcode": "minAge",
+            "minAge": 18,
+            "value": "2007-06-16",
+        })
+    );
+
+    let data = CreateEvent {
+        created_at: Utc::now(),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+}
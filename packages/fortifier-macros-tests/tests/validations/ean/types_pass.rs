@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+
+use fortifier::{EanError, EanErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct EanData<'a> {
+    #[validate(ean)]
+    r#str: &'a str,
+    #[validate(ean)]
+    string: String,
+    #[validate(ean)]
+    cow: Cow<'a, str>,
+    #[validate(ean)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = EanData {
+        r#str: "4006381333931",
+        string: "96385074".to_owned(),
+        cow: Cow::Borrowed("5901234123457"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = EanData {
+        r#str: "4006381333932",
+        string: "963850741".to_owned(),
+        cow: Cow::Borrowed("590123412345X"),
+        option: Some("4006381333931".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            EanDataValidationError::Str(EanError::Checksum { code: EanErrorCode }),
+            EanDataValidationError::String(EanError::InvalidLength {
+                code: EanErrorCode,
+                value: 9,
+            }),
+            EanDataValidationError::Cow(EanError::InvalidCharacter {
+                code: EanErrorCode,
+                character: 'X',
+            }),
+        ]))
+    );
+}
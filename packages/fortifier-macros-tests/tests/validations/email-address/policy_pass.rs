@@ -0,0 +1,68 @@
+use fortifier::{
+    EmailAddressError, EmailAddressErrorCode, LengthError, LengthErrorCode, Validate,
+    ValidateWithContext, ValidationErrors,
+};
+
+struct Context {
+    company_domains: Vec<String>,
+}
+
+#[derive(Validate)]
+#[validate(context = Context)]
+struct SignUp<'a> {
+    #[validate(email_address(allowed_domains = context.company_domains))]
+    work_email: &'a str,
+    #[validate(email_address(denied_domains = ["example.org"], deny_disposable = true))]
+    personal_email: String,
+    #[validate(email_address(deny_subaddressing = true))]
+    recovery_email: Option<String>,
+    #[validate(email_address, length(max = 255))]
+    contact_email: String,
+}
+
+fn main() {
+    let context = Context {
+        company_domains: vec!["example.com".to_owned()],
+    };
+
+    let data = SignUp {
+        work_email: "admin@example.com",
+        personal_email: "admin@example.net".to_owned(),
+        recovery_email: Some("admin@example.net".to_owned()),
+        contact_email: "admin@example.net".to_owned(),
+    };
+
+    assert_eq!(data.validate_sync_with_context(&context), Ok(()));
+
+    let data = SignUp {
+        work_email: "admin@example.net",
+        personal_email: "admin@mail.example.org".to_owned(),
+        recovery_email: Some("admin+recovery@example.net".to_owned()),
+        contact_email: format!("{}@{}example.net", "a".repeat(64), "a.".repeat(95)),
+    };
+
+    assert_eq!(
+        data.validate_sync_with_context(&context),
+        Err(ValidationErrors::from_iter([
+            SignUpValidationError::WorkEmail(EmailAddressError::DisallowedDomain {
+                code: EmailAddressErrorCode,
+                value: "example.net".to_owned(),
+            }),
+            SignUpValidationError::PersonalEmail(EmailAddressError::DeniedDomain {
+                code: EmailAddressErrorCode,
+                value: "mail.example.org".to_owned(),
+            }),
+            SignUpValidationError::RecoveryEmail(EmailAddressError::Subaddressing {
+                code: EmailAddressErrorCode,
+            }),
+            SignUpValidationError::ContactEmail(SignUpContactEmailValidationError::Length(
+                LengthError::Max {
+                    max: 255,
+                    value: 266,
+                    unit: None,
+                    code: LengthErrorCode,
+                }
+            )),
+        ]))
+    );
+}
@@ -0,0 +1,105 @@
+use std::{collections::HashSet, pin::Pin, sync::Arc};
+
+use fortifier::{
+    Deliverability, DomainResolver, EmailAddressError, EmailAddressErrorCode, Validate,
+    ValidateWithContext, ValidationErrors,
+};
+
+struct MemoryResolver {
+    domains: HashSet<String>,
+    unknown_domains: HashSet<String>,
+}
+
+impl DomainResolver for MemoryResolver {
+    fn is_deliverable<'a>(
+        &'a self,
+        domain: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Deliverability> + Send + 'a>> {
+        Box::pin(async move {
+            if self.domains.contains(domain) {
+                Deliverability::Deliverable
+            } else if self.unknown_domains.contains(domain) {
+                Deliverability::Unknown
+            } else {
+                Deliverability::Undeliverable
+            }
+        })
+    }
+}
+
+struct Context {
+    resolver: Arc<dyn DomainResolver>,
+}
+
+#[derive(Validate)]
+#[validate(context = Context)]
+struct SignUp<'a> {
+    #[validate(email_address(resolver = context.resolver))]
+    email_address: &'a str,
+    #[validate(email_address(
+        denied_domains = ["gmial.com"],
+        deny_unknown_deliverability = true,
+        resolver = context.resolver
+    ))]
+    recovery_email_address: Option<String>,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let context = Context {
+        resolver: Arc::new(MemoryResolver {
+            domains: HashSet::from(["gmail.com".to_owned()]),
+            unknown_domains: HashSet::from(["example.com".to_owned()]),
+        }),
+    };
+
+    let data = SignUp {
+        email_address: "admin@gmail.com",
+        recovery_email_address: None,
+    };
+
+    assert_eq!(data.validate_with_context(&context).await, Ok(()));
+
+    let data = SignUp {
+        email_address: "admin@gmial.com",
+        recovery_email_address: Some("admin@gmail.com".to_owned()),
+    };
+
+    assert_eq!(data.validate_sync_with_context(&context), Ok(()));
+    assert_eq!(
+        data.validate_with_context(&context).await,
+        Err(ValidationErrors::from_iter([
+            SignUpValidationError::EmailAddress(EmailAddressError::UndeliverableDomain {
+                code: EmailAddressErrorCode,
+                value: "gmial.com".to_owned(),
+            })
+        ]))
+    );
+
+    let data = SignUp {
+        email_address: "admin@example.com",
+        recovery_email_address: Some("admin@example.com".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_with_context(&context).await,
+        Err(ValidationErrors::from_iter([
+            SignUpValidationError::RecoveryEmailAddress(
+                EmailAddressError::UnknownDeliverability {
+                    code: EmailAddressErrorCode,
+                    value: "example.com".to_owned(),
+                }
+            )
+        ]))
+    );
+
+    let data = SignUp {
+        email_address: "invalid",
+        recovery_email_address: Some("admin@gmial.com".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_async_with_context(&context).await,
+        Ok(())
+    );
+}
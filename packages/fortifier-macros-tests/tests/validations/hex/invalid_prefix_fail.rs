@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct Theme<'a> {
+    #[validate(hex(prefix = '#'))]
+    color: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,63 @@
+use fortifier::{HexError, HexErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct Theme<'a> {
+    #[validate(hex)]
+    checksum: &'a str,
+    #[validate(hex(prefix = "#", lengths = [3, 6]))]
+    color: String,
+    #[validate(hex(prefix = "0x", even_length))]
+    bytes: Option<String>,
+}
+
+fn main() {
+    let data = Theme {
+        checksum: "9e107d9d372bb6826bd81d3542a419d6",
+        color: "#ff8800".to_owned(),
+        bytes: Some("0XdeadBEEF".to_owned()),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = Theme {
+        checksum: "9e107d9d372bb682z",
+        color: "#ff88".to_owned(),
+        bytes: None,
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            ThemeValidationError::Checksum(HexError::InvalidCharacter {
+                code: HexErrorCode,
+                character: 'z',
+            }),
+            ThemeValidationError::Color(HexError::DisallowedLength {
+                code: HexErrorCode,
+                allowed: vec![3, 6],
+                value: 4,
+            }),
+        ]))
+    );
+
+    let data = Theme {
+        checksum: "",
+        color: "ff8800".to_owned(),
+        bytes: Some("0xabc".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            ThemeValidationError::Checksum(HexError::Empty { code: HexErrorCode }),
+            ThemeValidationError::Color(HexError::MissingPrefix {
+                code: HexErrorCode,
+                prefix: "#".to_owned(),
+            }),
+            ThemeValidationError::Bytes(HexError::OddLength {
+                code: HexErrorCode,
+                value: 3,
+            }),
+        ]))
+    );
+}
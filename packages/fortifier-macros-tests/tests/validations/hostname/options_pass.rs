@@ -0,0 +1,47 @@
+use fortifier::{HostnameError, HostnameErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct HostnameData<'a> {
+    #[validate(hostname)]
+    ascii: &'a str,
+    #[validate(hostname(allow_idn = true))]
+    idn: String,
+    #[validate(hostname)]
+    label: Option<String>,
+    #[validate(hostname, length(max = 253))]
+    combined: &'a str,
+}
+
+fn main() {
+    let data = HostnameData {
+        ascii: "api.example.com",
+        idn: "bücher.example".to_owned(),
+        label: Some("localhost".to_owned()),
+        combined: "example.com",
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = HostnameData {
+        ascii: "bücher.example",
+        idn: "example..com".to_owned(),
+        label: Some("-example.com".to_owned()),
+        combined: "example.com",
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            HostnameDataValidationError::Ascii(HostnameError::InvalidCharacter {
+                code: HostnameErrorCode,
+                character: 'ü',
+            }),
+            HostnameDataValidationError::Idn(HostnameError::EmptyLabel {
+                code: HostnameErrorCode,
+            }),
+            HostnameDataValidationError::Label(HostnameError::InvalidHyphen {
+                code: HostnameErrorCode,
+            }),
+        ]))
+    );
+}
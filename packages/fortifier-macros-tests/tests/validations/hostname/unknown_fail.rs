@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct HostnameData<'a> {
+    #[validate(hostname(unknown = true))]
+    value: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use fortifier::{IbanError, IbanErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct IbanData<'a> {
+    #[validate(iban)]
+    r#str: &'a str,
+    #[validate(iban)]
+    string: String,
+    #[validate(iban)]
+    cow: Cow<'a, str>,
+    #[validate(iban)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = IbanData {
+        r#str: "NL91 ABNA 0417 1643 00",
+        string: "DE89370400440532013000".to_owned(),
+        cow: Cow::Borrowed("gb29 nwbk 6016 1331 9268 19"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = IbanData {
+        r#str: "NL91 ABNA 0417 1643 01",
+        string: "DE8937040044053201300".to_owned(),
+        cow: Cow::Borrowed("ZZ29 NWBK 6016 1331 9268 19"),
+        option: Some("GB29/NWBK".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            IbanDataValidationError::Str(IbanError::Checksum {
+                code: IbanErrorCode,
+            }),
+            IbanDataValidationError::String(IbanError::InvalidLength {
+                code: IbanErrorCode,
+                country: "DE".to_owned(),
+                length: 22,
+                value: 21,
+            }),
+            IbanDataValidationError::Cow(IbanError::InvalidCountryCode {
+                code: IbanErrorCode,
+            }),
+            IbanDataValidationError::Option(IbanError::InvalidCharacter {
+                code: IbanErrorCode,
+                character: '/',
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,58 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use fortifier::{IpError, IpErrorCode, IpVersion, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct IpData<'a> {
+    #[validate(ip)]
+    any: &'a str,
+    #[validate(ip(v4))]
+    v4: String,
+    #[validate(ip(v6))]
+    v6: Option<String>,
+    #[validate(ip(allow_private = false, allow_loopback = false, allow_link_local = false, allow_multicast = false))]
+    public: IpAddr,
+    #[validate(ip(allow_unspecified = false, allow_broadcast = false))]
+    bind: &'a str,
+    #[validate(ip, length(max = 39))]
+    combined: &'a str,
+}
+
+fn main() {
+    let data = IpData {
+        any: "::1",
+        v4: "127.0.0.1".to_owned(),
+        v6: Some("fe80::1".to_owned()),
+        public: IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)),
+        bind: "192.0.2.1",
+        combined: "192.0.2.1",
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = IpData {
+        any: "localhost",
+        v4: "::1".to_owned(),
+        v6: Some("127.0.0.1".to_owned()),
+        public: IpAddr::V4(Ipv4Addr::new(169, 254, 169, 254)),
+        bind: "0.0.0.0",
+        combined: "2001:db8::1",
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            IpDataValidationError::Any(IpError::InvalidAddress { code: IpErrorCode }),
+            IpDataValidationError::V4(IpError::DisallowedVersion {
+                code: IpErrorCode,
+                value: IpVersion::V6,
+            }),
+            IpDataValidationError::V6(IpError::DisallowedVersion {
+                code: IpErrorCode,
+                value: IpVersion::V4,
+            }),
+            IpDataValidationError::Public(IpError::LinkLocal { code: IpErrorCode }),
+            IpDataValidationError::Bind(IpError::Unspecified { code: IpErrorCode }),
+        ]))
+    );
+}
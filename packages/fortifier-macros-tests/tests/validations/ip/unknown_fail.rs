@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct IpData<'a> {
+    #[validate(ip(v5))]
+    value: &'a str,
+}
+
+fn main() {}
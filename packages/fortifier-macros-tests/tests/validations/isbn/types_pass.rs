@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use fortifier::{IsbnError, IsbnErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct IsbnData<'a> {
+    #[validate(isbn)]
+    r#str: &'a str,
+    #[validate(isbn)]
+    string: String,
+    #[validate(isbn)]
+    cow: Cow<'a, str>,
+    #[validate(isbn)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = IsbnData {
+        r#str: "978-0-306-40615-7",
+        string: "0-8044-2957-X".to_owned(),
+        cow: Cow::Borrowed("0306406152"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = IsbnData {
+        r#str: "978-0-306-40615-8",
+        string: "0-8044-2957".to_owned(),
+        cow: Cow::Borrowed("9770306406157"),
+        option: Some("ISBN 0306406152".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            IsbnDataValidationError::Str(IsbnError::Checksum {
+                code: IsbnErrorCode,
+            }),
+            IsbnDataValidationError::String(IsbnError::InvalidLength {
+                code: IsbnErrorCode,
+                value: 9,
+            }),
+            IsbnDataValidationError::Cow(IsbnError::InvalidPrefix {
+                code: IsbnErrorCode,
+            }),
+            IsbnDataValidationError::Option(IsbnError::InvalidCharacter {
+                code: IsbnErrorCode,
+                character: 'I',
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use fortifier::{JsonError, JsonErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct JsonData<'a> {
+    #[validate(json)]
+    r#str: &'a str,
+    #[validate(json)]
+    string: String,
+    #[validate(json)]
+    cow: Cow<'a, str>,
+    #[validate(json)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = JsonData {
+        r#str: r#"{"enabled": true}"#,
+        string: "[1, 2, 3]".to_owned(),
+        cow: Cow::Borrowed("null"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = JsonData {
+        r#str: r#"{"enabled": true,}"#,
+        string: "[1, 2".to_owned(),
+        cow: Cow::Borrowed("\"text\""),
+        option: Some("{}".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            JsonDataValidationError::Str(JsonError::Syntax {
+                code: JsonErrorCode,
+                line: 1,
+                column: 18,
+            }),
+            JsonDataValidationError::String(JsonError::Eof {
+                code: JsonErrorCode,
+                line: 1,
+                column: 5,
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+
+use fortifier::{LanguageTagError, LanguageTagErrorCode, Validate, ValidationErrors};
+
+const ALLOWED_LANGUAGES: [&str; 2] = ["en", "nl"];
+
+#[derive(Validate)]
+struct LanguageTagData<'a> {
+    #[validate(language_tag)]
+    r#str: &'a str,
+    #[validate(language_tag(allowed_languages = ["en"]))]
+    string: String,
+    #[validate(language_tag(allowed_languages = ALLOWED_LANGUAGES))]
+    cow: Cow<'a, str>,
+    #[validate(language_tag)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = LanguageTagData {
+        r#str: "zh-Hant-TW",
+        string: "en-GB".to_owned(),
+        cow: Cow::Borrowed("nl-BE"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = LanguageTagData {
+        r#str: "en_US",
+        string: "nl".to_owned(),
+        cow: Cow::Borrowed("en-Abcd"),
+        option: Some("en-YY".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            LanguageTagDataValidationError::Str(LanguageTagError::Malformed {
+                code: LanguageTagErrorCode,
+                value: "en_US".to_owned(),
+            }),
+            LanguageTagDataValidationError::String(LanguageTagError::Disallowed {
+                code: LanguageTagErrorCode,
+                allowed: vec!["en".to_owned()],
+                value: "nl".to_owned(),
+            }),
+            LanguageTagDataValidationError::Cow(LanguageTagError::UnknownScript {
+                code: LanguageTagErrorCode,
+                subtag: "Abcd".to_owned(),
+            }),
+            LanguageTagDataValidationError::Option(LanguageTagError::UnknownRegion {
+                code: LanguageTagErrorCode,
+                subtag: "YY".to_owned(),
+            }),
+        ]))
+    );
+}
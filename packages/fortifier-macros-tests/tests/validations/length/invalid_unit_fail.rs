@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct LengthData {
+    #[validate(length(max = 255, unit = words))]
+    description: String,
+}
+
+fn main() {}
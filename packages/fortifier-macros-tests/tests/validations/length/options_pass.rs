@@ -24,21 +24,25 @@ fn main() {
         data.validate_sync(),
         Err(ValidationErrors::from_iter([
             LengthDataValidationError::Equal(LengthError::Equal {
+                unit: None,
                 code: LengthErrorCode,
                 equal: 2,
                 value: 1
             }),
             LengthDataValidationError::Min(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::Max(LengthError::Max {
+                unit: None,
                 code: LengthErrorCode,
                 max: 4,
                 value: 5
             }),
             LengthDataValidationError::MinMax(LengthError::Max {
+                unit: None,
                 code: LengthErrorCode,
                 max: 4,
                 value: 6
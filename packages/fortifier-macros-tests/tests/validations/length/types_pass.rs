@@ -54,66 +54,79 @@ fn main() {
         data.validate_sync(),
         Err(ValidationErrors::from_iter([
             LengthDataValidationError::Str(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::String(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::Array(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::Slice(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::BTreeMap(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::BTreeSet(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::HashMap(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::HashSet(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::IndexMap(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::IndexSet(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::LinkedList(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::Vec(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
             }),
             LengthDataValidationError::VecDeque(LengthError::Min {
+                unit: None,
                 code: LengthErrorCode,
                 min: 1,
                 value: 0
@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use fortifier::{LengthError, LengthErrorCode, LengthUnit, Validate, ValidationErrors};
+use serde_json::json;
+
+#[derive(Validate)]
+struct UnitData<'a> {
+    #[validate(length(max = 8, unit = bytes))]
+    bytes: &'a str,
+    #[validate(length(max = 2, unit = chars))]
+    chars: String,
+    #[validate(length(max = 2, unit = utf16))]
+    utf16: Cow<'a, str>,
+    #[validate(length(min = 1, max = 2, unit = graphemes))]
+    graphemes: Option<Box<str>>,
+}
+
+fn main() {
+    let data = UnitData {
+        bytes: "👍👍",
+        chars: "e\u{301}".to_owned(),
+        utf16: Cow::Borrowed("👍"),
+        graphemes: Some("e\u{301}👍".into()),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = UnitData {
+        bytes: "👍👍👍",
+        chars: "e\u{301}👍".to_owned(),
+        utf16: Cow::Borrowed("👍👍"),
+        graphemes: Some("".into()),
+    };
+
+    let errors = data.validate_sync().expect_err("invalid data");
+
+    assert_eq!(
+        errors,
+        ValidationErrors::from_iter([
+            UnitDataValidationError::Bytes(LengthError::Max {
+                max: 8,
+                value: 12,
+                unit: Some(LengthUnit::Bytes),
+                code: LengthErrorCode,
+            }),
+            UnitDataValidationError::Chars(LengthError::Max {
+                max: 2,
+                value: 3,
+                unit: Some(LengthUnit::Chars),
+                code: LengthErrorCode,
+            }),
+            UnitDataValidationError::Utf16(LengthError::Max {
+                max: 2,
+                value: 4,
+                unit: Some(LengthUnit::Utf16),
+                code: LengthErrorCode,
+            }),
+            UnitDataValidationError::Graphemes(LengthError::Min {
+                min: 1,
+                value: 0,
+                unit: Some(LengthUnit::Graphemes),
+                code: LengthErrorCode,
+            }),
+        ])
+    );
+
+    assert_eq!(
+        serde_json::to_value(&errors).expect("serializable errors")[2],
+        json!({
+            "path": "utf16",
+            "code": "length",
+            "subcode": "max",
+            "max": 2,
+            "value": 4,
+            "unit": "utf16",
+        })
+    );
+}
@@ -0,0 +1,61 @@
+use fortifier::{NoneOfError, NoneOfErrorCode, Validate, ValidationErrors};
+use serde_json::json;
+
+const RESERVED: &[&str] = &["admin", "root"];
+
+#[derive(Validate)]
+struct NoneOfData {
+    #[validate(none_of = RESERVED)]
+    username: String,
+    #[validate(none_of(values = [0, 13]))]
+    number: Option<i32>,
+}
+
+fn main() {
+    let data = NoneOfData {
+        username: "john".to_owned(),
+        number: Some(7),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = NoneOfData {
+        username: "root".to_owned(),
+        number: Some(13),
+    };
+
+    let errors = data.validate_sync().expect_err("reserved values");
+
+    assert_eq!(
+        errors,
+        ValidationErrors::from_iter([
+            NoneOfDataValidationError::Username(NoneOfError {
+                code: NoneOfErrorCode,
+                disallowed: vec!["admin".to_owned(), "root".to_owned()],
+                value: "root".to_owned(),
+            }),
+            NoneOfDataValidationError::Number(NoneOfError {
+                code: NoneOfErrorCode,
+                disallowed: vec![0, 13],
+                value: 13,
+            }),
+        ])
+    );
+    assert_eq!(
+        serde_json::to_value(&errors).expect("serializable errors"),
+        json!([
+            {
+                "path": "username",
+                "code": "noneOf",
+                "disallowed": ["admin", "root"],
+                "value": "root",
+            },
+            {
+                "path": "number",
+                "code": "noneOf",
+                "disallowed": [0, 13],
+                "value": 13,
+            },
+        ])
+    );
+}
@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct NoneOfData {
+    #[validate(none_of(unknown = ["admin", "root"]))]
+    username: String,
+}
+
+fn main() {}
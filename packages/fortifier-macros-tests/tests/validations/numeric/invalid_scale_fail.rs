@@ -0,0 +1,10 @@
+use fortifier::Validate;
+use rust_decimal::Decimal;
+
+#[derive(Validate)]
+struct OrderData {
+    #[validate(decimal(precision = 2, scale = 4))]
+    price: Decimal,
+}
+
+fn main() {}
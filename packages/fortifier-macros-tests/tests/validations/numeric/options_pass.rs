@@ -0,0 +1,98 @@
+use fortifier::{NumericError, NumericErrorCode, Validate, ValidationErrors};
+use rust_decimal::Decimal;
+use serde_json::json;
+
+#[derive(Validate)]
+struct OrderData {
+    #[validate(multiple_of = 5)]
+    quantity: u32,
+    #[validate(finite, multiple_of = 0.25)]
+    weight: f64,
+    #[validate(finite)]
+    ratio: Option<f32>,
+    #[validate(decimal(precision = 10, scale = 2), multiple_of = Decimal::new(5, 2))]
+    price: Decimal,
+}
+
+fn main() {
+    let data = OrderData {
+        quantity: 15,
+        weight: 1.75,
+        ratio: None,
+        price: Decimal::new(1234567895, 2),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = OrderData {
+        quantity: 7,
+        weight: f64::INFINITY,
+        ratio: Some(f32::NEG_INFINITY),
+        price: Decimal::new(12345678910, 2),
+    };
+
+    let errors = data.validate_sync().expect_err("invalid data");
+
+    assert_eq!(
+        errors,
+        ValidationErrors::from_iter([
+            OrderDataValidationError::Quantity(NumericError::MultipleOf {
+                multiple_of: 5,
+                value: 7,
+                code: NumericErrorCode,
+            }),
+            OrderDataValidationError::Weight(OrderDataWeightValidationError::Finite(
+                NumericError::Finite {
+                    value: f64::INFINITY,
+                    code: NumericErrorCode,
+                }
+            )),
+            OrderDataValidationError::Weight(OrderDataWeightValidationError::MultipleOf(
+                NumericError::MultipleOf {
+                    multiple_of: 0.25,
+                    value: f64::INFINITY,
+                    code: NumericErrorCode,
+                }
+            )),
+            OrderDataValidationError::Ratio(NumericError::Finite {
+                value: f32::NEG_INFINITY,
+                code: NumericErrorCode,
+            }),
+            OrderDataValidationError::Price(OrderDataPriceValidationError::Decimal(
+                NumericError::Precision {
+                    precision: 10,
+                    value: 11,
+                    code: NumericErrorCode,
+                }
+            )),
+        ])
+    );
+
+    let data = OrderData {
+        quantity: 5,
+        weight: 0.5,
+        ratio: Some(0.5),
+        price: Decimal::new(1234, 3),
+    };
+
+    assert_eq!(
+        serde_json::to_value(data.validate_sync().expect_err("invalid data"))
+            .expect("serializable errors"),
+        json!([
+            {
+                "path": "price",
+                "code": "numeric",
+                "subcode": "scale",
+                "scale": 2,
+                "value": 3,
+            },
+            {
+                "path": "price",
+                "code": "numeric",
+                "subcode": "multipleOf",
+                "multipleOf": "0.05",
+                "value": "1.234",
+            },
+        ])
+    );
+}
@@ -0,0 +1,10 @@
+use fortifier::Validate;
+use rust_decimal::Decimal;
+
+#[derive(Validate)]
+struct OrderData {
+    #[validate(decimal(digits = 10))]
+    price: Decimal,
+}
+
+fn main() {}
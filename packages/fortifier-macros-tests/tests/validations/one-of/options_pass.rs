@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use fortifier::{OneOfError, OneOfErrorCode, Validate, ValidateWithContext, ValidationErrors};
+
+const CURRENCIES: &[&str] = &["EUR", "GBP", "USD"];
+
+struct Context {
+    features: HashSet<String>,
+}
+
+#[derive(Validate)]
+#[validate(context = Context)]
+struct OneOfData {
+    #[validate(one_of = ["basic", "pro"])]
+    plan: String,
+    #[validate(one_of = CURRENCIES)]
+    currency: Option<String>,
+    #[validate(one_of(values = context.features))]
+    feature: String,
+    #[validate(one_of = [1, 2, 4, 8])]
+    size: u8,
+}
+
+fn main() {
+    let context = Context {
+        features: HashSet::from(["beta".to_owned()]),
+    };
+
+    let data = OneOfData {
+        plan: "pro".to_owned(),
+        currency: None,
+        feature: "beta".to_owned(),
+        size: 4,
+    };
+
+    assert_eq!(data.validate_sync_with_context(&context), Ok(()));
+
+    let data = OneOfData {
+        plan: "free".to_owned(),
+        currency: Some("JPY".to_owned()),
+        feature: "alpha".to_owned(),
+        size: 3,
+    };
+
+    assert_eq!(
+        data.validate_sync_with_context(&context),
+        Err(ValidationErrors::from_iter([
+            OneOfDataValidationError::Plan(OneOfError {
+                code: OneOfErrorCode,
+                allowed: vec!["basic".to_owned(), "pro".to_owned()],
+                value: "free".to_owned(),
+            }),
+            OneOfDataValidationError::Currency(OneOfError {
+                code: OneOfErrorCode,
+                allowed: vec!["EUR".to_owned(), "GBP".to_owned(), "USD".to_owned()],
+                value: "JPY".to_owned(),
+            }),
+            OneOfDataValidationError::Feature(OneOfError {
+                code: OneOfErrorCode,
+                allowed: vec!["beta".to_owned()],
+                value: "alpha".to_owned(),
+            }),
+            OneOfDataValidationError::Size(OneOfError {
+                code: OneOfErrorCode,
+                allowed: vec![1, 2, 4, 8],
+                value: 3,
+            }),
+        ]))
+    );
+}
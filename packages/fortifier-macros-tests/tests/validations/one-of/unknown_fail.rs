@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct OneOfData {
+    #[validate(one_of(unknown = ["basic", "pro"]))]
+    plan: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct SignUp<'a> {
+    #[validate(password(min_score = 5))]
+    password: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,97 @@
+use fortifier::{
+    PasswordError, PasswordErrorCode, PasswordRequirement, Validate, ValidationErrors,
+};
+
+#[derive(Validate)]
+struct SignUp<'a> {
+    username: &'a str,
+    email_address: Option<String>,
+    #[validate(password(
+        min_character_classes = 3,
+        min_score = 3,
+        deny_common,
+        user_inputs = [username, email_address]
+    ))]
+    password: &'a str,
+}
+
+#[derive(Validate)]
+enum Credentials {
+    Password {
+        username: String,
+        #[validate(password(deny_common = true, user_inputs = [username]))]
+        password: String,
+    },
+}
+
+fn main() {
+    let data = SignUp {
+        username: "simon",
+        email_address: Some("simon.doe@example.com".to_owned()),
+        password: "Correct-Horse-42",
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = SignUp {
+        username: "simon",
+        email_address: Some("simon.doe@example.com".to_owned()),
+        password: "password",
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([SignUpValidationError::Password(
+            PasswordError {
+                code: PasswordErrorCode,
+                requirements: vec![
+                    PasswordRequirement::CharacterClasses { min: 3, value: 1 },
+                    PasswordRequirement::Score { min: 3, value: 0 },
+                    PasswordRequirement::Common {},
+                ],
+            }
+        )]))
+    );
+
+    let data = SignUp {
+        username: "simon",
+        email_address: Some("simon.doe@example.com".to_owned()),
+        password: "Simon.Doe-2024!",
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([SignUpValidationError::Password(
+            PasswordError {
+                code: PasswordErrorCode,
+                requirements: vec![
+                    PasswordRequirement::ContainsUserInput {
+                        field: "username".to_owned(),
+                    },
+                    PasswordRequirement::ContainsUserInput {
+                        field: "email_address".to_owned(),
+                    },
+                ],
+            }
+        )]))
+    );
+
+    let data = Credentials::Password {
+        username: "simon".to_owned(),
+        password: "simon123".to_owned(),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            CredentialsValidationError::Password(CredentialsPasswordValidationError::Password(
+                PasswordError {
+                    code: PasswordErrorCode,
+                    requirements: vec![PasswordRequirement::ContainsUserInput {
+                        field: "username".to_owned(),
+                    }],
+                }
+            ))
+        ]))
+    );
+}
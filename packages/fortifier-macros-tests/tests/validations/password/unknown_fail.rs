@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct SignUp<'a> {
+    #[validate(password(unknown = true))]
+    password: &'a str,
+}
+
+fn main() {}
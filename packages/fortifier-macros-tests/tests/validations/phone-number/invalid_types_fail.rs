@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct PhoneNumberData<'a> {
+    #[validate(phone_number(types = [mobile, landline]))]
+    value: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+use fortifier::{
+    PhoneNumberCountry, PhoneNumberError, PhoneNumberErrorCode, PhoneNumberType, Validate,
+    ValidationErrors,
+};
+
+#[derive(Validate)]
+struct PhoneNumberData<'a> {
+    #[validate(phone_number(types = [mobile, fixed_line_or_mobile]), length(max = 32))]
+    types: &'a str,
+    #[validate(phone_number(strict_validity))]
+    strict_validity: &'a str,
+    #[validate(phone_number(
+        default_country = PhoneNumberCountry::GB,
+        require_international_format = true
+    ))]
+    require_international_format: &'a str,
+}
+
+fn main() {
+    let data = PhoneNumberData {
+        types: "+44 7400 123456",
+        strict_validity: "+1 650 253 0000",
+        require_international_format: "+44 20 7946 0000",
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = PhoneNumberData {
+        types: "+44 800 123 4567",
+        strict_validity: "+44 7700 900123",
+        require_international_format: "020 7946 0000",
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            PhoneNumberDataValidationError::Types(
+                PhoneNumberDataTypesValidationError::PhoneNumber(
+                    PhoneNumberError::DisallowedType {
+                        code: PhoneNumberErrorCode,
+                        allowed: vec![PhoneNumberType::Mobile, PhoneNumberType::FixedLineOrMobile],
+                        value: PhoneNumberType::TollFree,
+                    }
+                )
+            ),
+            PhoneNumberDataValidationError::StrictValidity(PhoneNumberError::InvalidNumber {
+                code: PhoneNumberErrorCode,
+            }),
+            PhoneNumberDataValidationError::RequireInternationalFormat(
+                PhoneNumberError::MissingInternationalPrefix {
+                    code: PhoneNumberErrorCode,
+                }
+            ),
+        ]))
+    );
+}
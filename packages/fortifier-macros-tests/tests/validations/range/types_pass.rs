@@ -0,0 +1,149 @@
+use fortifier::{RangeError, RangeErrorCode, Validate, ValidationErrors};
+use jiff::{SignedDuration, Timestamp, Zoned, civil};
+use serde_json::json;
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime};
+
+fn time_date(day: u8) -> Date {
+    Date::from_calendar_date(2025, Month::January, day).expect("valid date")
+}
+
+fn time_date_time(day: u8) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(time_date(day), Time::MIDNIGHT)
+}
+
+fn timestamp(second: i64) -> Timestamp {
+    Timestamp::from_second(second).expect("valid timestamp")
+}
+
+#[derive(Validate)]
+struct JiffData {
+    #[validate(range(min = civil::date(2025, 1, 1)))]
+    date: civil::Date,
+    #[validate(range(max = civil::datetime(2025, 12, 31, 23, 59, 59, 0)))]
+    date_time: civil::DateTime,
+    #[validate(range(min = civil::time(9, 0, 0, 0), max = civil::time(17, 0, 0, 0)))]
+    time: civil::Time,
+    #[validate(range(exclusive_min = timestamp(0)))]
+    timestamp: Timestamp,
+    #[validate(range(min = timestamp(0).in_tz("UTC").expect("valid time zone")))]
+    zoned: Option<Zoned>,
+    #[validate(range(max = SignedDuration::from_hours(1)))]
+    duration: SignedDuration,
+}
+
+#[derive(Validate)]
+struct TimeData {
+    #[validate(range(min = time_date(1)))]
+    date: Date,
+    #[validate(range(max = time_date_time(31)))]
+    date_time: PrimitiveDateTime,
+    #[validate(range(min = time_date_time(1).assume_utc()))]
+    offset_date_time: OffsetDateTime,
+    #[validate(range(min = time_date_time(1).as_utc()))]
+    utc_date_time: Option<UtcDateTime>,
+    #[validate(range(min = Time::MIDNIGHT, exclusive_max = Time::from_hms(12, 0, 0).expect("valid time")))]
+    time: Time,
+    #[validate(range(max = Duration::hours(1)))]
+    duration: Duration,
+}
+
+fn main() {
+    let data = JiffData {
+        date: civil::date(2025, 4, 3),
+        date_time: civil::datetime(2025, 4, 3, 12, 34, 56, 0),
+        time: civil::time(12, 34, 56, 0),
+        timestamp: timestamp(1),
+        zoned: None,
+        duration: SignedDuration::from_mins(30),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = JiffData {
+        date: civil::date(2024, 12, 31),
+        date_time: civil::datetime(2026, 1, 1, 0, 0, 0, 0),
+        time: civil::time(8, 0, 0, 0),
+        timestamp: timestamp(0),
+        zoned: Some(timestamp(-1).in_tz("UTC").expect("valid time zone")),
+        duration: SignedDuration::from_hours(2),
+    };
+
+    let errors = data.validate_sync().expect_err("invalid data");
+
+    assert_eq!(errors.len(), 6);
+    assert_eq!(
+        errors.first(),
+        Some(&JiffDataValidationError::Date(RangeError::Min {
+            min: civil::date(2025, 1, 1),
+            value: civil::date(2024, 12, 31),
+            code: RangeErrorCode,
+        }))
+    );
+    assert_eq!(
+        serde_json::to_value(&errors).expect("serializable errors")[0],
+        json!({
+            "path": "date",
+            "code": "range",
+            "subcode": "min",
+            "min": "2025-01-01",
+            "value": "2024-12-31",
+        })
+    );
+
+    let data = TimeData {
+        date: time_date(3),
+        date_time: time_date_time(3),
+        offset_date_time: time_date_time(3).assume_utc(),
+        utc_date_time: Some(time_date_time(3).as_utc()),
+        time: Time::from_hms(11, 59, 59).expect("valid time"),
+        duration: Duration::minutes(30),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = TimeData {
+        date: Date::from_calendar_date(2024, Month::December, 31).expect("valid date"),
+        date_time: PrimitiveDateTime::new(
+            Date::from_calendar_date(2025, Month::February, 1).expect("valid date"),
+            Time::MIDNIGHT,
+        ),
+        offset_date_time: time_date_time(1).assume_utc() - Duration::SECOND,
+        utc_date_time: None,
+        time: Time::from_hms(12, 0, 0).expect("valid time"),
+        duration: Duration::hours(2),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            TimeDataValidationError::Date(RangeError::Min {
+                min: time_date(1),
+                value: Date::from_calendar_date(2024, Month::December, 31).expect("valid date"),
+                code: RangeErrorCode,
+            }),
+            TimeDataValidationError::DateTime(RangeError::Max {
+                max: time_date_time(31),
+                value: PrimitiveDateTime::new(
+                    Date::from_calendar_date(2025, Month::February, 1).expect("valid date"),
+                    Time::MIDNIGHT,
+                ),
+                code: RangeErrorCode,
+            }),
+            TimeDataValidationError::OffsetDateTime(RangeError::Min {
+                min: time_date_time(1).assume_utc(),
+                value: time_date_time(1).assume_utc() - Duration::SECOND,
+                code: RangeErrorCode,
+            }),
+            TimeDataValidationError::Time(RangeError::ExclusiveMax {
+                exclusive_max: Time::from_hms(12, 0, 0).expect("valid time"),
+                value: Time::from_hms(12, 0, 0).expect("valid time"),
+                code: RangeErrorCode,
+            }),
+            TimeDataValidationError::Duration(RangeError::Max {
+                max: Duration::hours(1),
+                value: Duration::hours(2),
+                code: RangeErrorCode,
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use fortifier::{SemverError, SemverErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct SemverData<'a> {
+    #[validate(semver)]
+    r#str: &'a str,
+    #[validate(semver)]
+    string: String,
+    #[validate(semver)]
+    cow: Cow<'a, str>,
+    #[validate(semver)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = SemverData {
+        r#str: "1.0.0",
+        string: "2.1.0-beta.1".to_owned(),
+        cow: Cow::Borrowed("0.3.7+build.5"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = SemverData {
+        r#str: "1.0",
+        string: "1.0.0-beta.01".to_owned(),
+        cow: Cow::Borrowed("1.0.0+build_5"),
+        option: Some("1.0.0".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            SemverDataValidationError::Str(SemverError::InvalidCore {
+                code: SemverErrorCode
+            }),
+            SemverDataValidationError::String(SemverError::LeadingZero {
+                code: SemverErrorCode,
+                identifier: "01".to_owned(),
+            }),
+            SemverDataValidationError::Cow(SemverError::InvalidCharacter {
+                code: SemverErrorCode,
+                character: '_',
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use fortifier::{SlugError, SlugErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct SlugData<'a> {
+    #[validate(slug)]
+    r#str: &'a str,
+    #[validate(slug)]
+    string: String,
+    #[validate(slug)]
+    cow: Cow<'a, str>,
+    #[validate(slug)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = SlugData {
+        r#str: "hello-world",
+        string: "release-2".to_owned(),
+        cow: Cow::Borrowed("docs"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = SlugData {
+        r#str: "",
+        string: "Hello-world".to_owned(),
+        cow: Cow::Borrowed("hello--world"),
+        option: Some("hello-world".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            SlugDataValidationError::Str(SlugError::Empty {
+                code: SlugErrorCode
+            }),
+            SlugDataValidationError::String(SlugError::InvalidCharacter {
+                code: SlugErrorCode,
+                character: 'H',
+            }),
+            SlugDataValidationError::Cow(SlugError::InvalidHyphen {
+                code: SlugErrorCode
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,45 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use fortifier::{SocketAddrError, SocketAddrErrorCode, Validate, ValidationErrors};
+
+#[derive(Validate)]
+struct SocketAddrData<'a> {
+    #[validate(socket_addr)]
+    any: &'a str,
+    #[validate(socket_addr(allow_loopback = false))]
+    webhook: String,
+    #[validate(socket_addr(v6))]
+    parsed: Option<SocketAddr>,
+}
+
+fn main() {
+    let data = SocketAddrData {
+        any: "[::1]:8080",
+        webhook: "203.0.113.1:443".to_owned(),
+        parsed: Some("[2001:db8::1]:443".parse().expect("valid socket address")),
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = SocketAddrData {
+        any: "127.0.0.1",
+        webhook: "127.0.0.1:443".to_owned(),
+        parsed: Some(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80))),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            SocketAddrDataValidationError::Any(SocketAddrError::MissingPort {
+                code: SocketAddrErrorCode,
+            }),
+            SocketAddrDataValidationError::Webhook(SocketAddrError::Loopback {
+                code: SocketAddrErrorCode,
+            }),
+            SocketAddrDataValidationError::Parsed(SocketAddrError::DisallowedVersion {
+                code: SocketAddrErrorCode,
+                value: fortifier::IpVersion::V4,
+            }),
+        ]))
+    );
+}
@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use fortifier::{TimeZoneError, TimeZoneErrorCode, Validate, ValidationErrors};
+
+const ALLOWED_TIME_ZONES: [&str; 2] = ["Europe/Amsterdam", "Europe/Brussels"];
+
+#[derive(Validate)]
+struct TimeZoneData<'a> {
+    #[validate(time_zone)]
+    r#str: &'a str,
+    #[validate(time_zone(allowed_time_zones = ["UTC"]))]
+    string: String,
+    #[validate(time_zone(allowed_time_zones = ALLOWED_TIME_ZONES))]
+    cow: Cow<'a, str>,
+    #[validate(time_zone)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = TimeZoneData {
+        r#str: "America/New_York",
+        string: "UTC".to_owned(),
+        cow: Cow::Borrowed("Europe/Amsterdam"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = TimeZoneData {
+        r#str: "europe/amsterdam",
+        string: "Etc/UTC".to_owned(),
+        cow: Cow::Borrowed("Europe/London"),
+        option: Some("Europe/Rotterdam".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            TimeZoneDataValidationError::Str(TimeZoneError::Unknown {
+                code: TimeZoneErrorCode,
+                value: "europe/amsterdam".to_owned(),
+            }),
+            TimeZoneDataValidationError::String(TimeZoneError::Disallowed {
+                code: TimeZoneErrorCode,
+                allowed: vec!["UTC".to_owned()],
+                value: "Etc/UTC".to_owned(),
+            }),
+            TimeZoneDataValidationError::Cow(TimeZoneError::Disallowed {
+                code: TimeZoneErrorCode,
+                allowed: vec!["Europe/Amsterdam".to_owned(), "Europe/Brussels".to_owned()],
+                value: "Europe/London".to_owned(),
+            }),
+            TimeZoneDataValidationError::Option(TimeZoneError::Unknown {
+                code: TimeZoneErrorCode,
+                value: "Europe/Rotterdam".to_owned(),
+            }),
+        ]))
+    );
+}
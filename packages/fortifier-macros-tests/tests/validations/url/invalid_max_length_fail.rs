@@ -0,0 +1,9 @@
+use fortifier::Validate;
+
+#[derive(Validate)]
+struct UrlData<'a> {
+    #[validate(url(max_length = "2048"))]
+    value: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use fortifier::{UuidError, UuidErrorCode, Validate, ValidationErrors};
+
+const VERSIONS: [u8; 2] = [4, 7];
+
+#[derive(Validate)]
+struct UuidData<'a> {
+    #[validate(uuid)]
+    r#str: &'a str,
+    #[validate(uuid(versions = [4]))]
+    string: String,
+    #[validate(uuid(versions = VERSIONS))]
+    cow: Cow<'a, str>,
+    #[validate(uuid)]
+    option: Option<String>,
+}
+
+fn main() {
+    let data = UuidData {
+        r#str: "00000000-0000-0000-0000-000000000000",
+        string: "67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned(),
+        cow: Cow::Borrowed("01890a5d-ac96-774b-bcce-b302099a8057"),
+        option: None,
+    };
+
+    assert_eq!(data.validate_sync(), Ok(()));
+
+    let data = UuidData {
+        r#str: "67e5504410b1426f9247bb680e5fe0c8",
+        string: "01890a5d-ac96-774b-bcce-b302099a8057".to_owned(),
+        cow: Cow::Borrowed("67e55044-10b1-426f-9247-bb680e5fe0cx"),
+        option: Some("67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned()),
+    };
+
+    assert_eq!(
+        data.validate_sync(),
+        Err(ValidationErrors::from_iter([
+            UuidDataValidationError::Str(UuidError::InvalidLength {
+                code: UuidErrorCode,
+                value: 32,
+            }),
+            UuidDataValidationError::String(UuidError::DisallowedVersion {
+                code: UuidErrorCode,
+                allowed: vec![4],
+                value: 7,
+            }),
+            UuidDataValidationError::Cow(UuidError::InvalidCharacter {
+                code: UuidErrorCode,
+                character: 'x',
+                index: 35,
+            }),
+        ]))
+    );
+}
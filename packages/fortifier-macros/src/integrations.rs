@@ -1,5 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::TypePath;
 
 pub fn enum_attributes() -> TokenStream {
     #[allow(unused_mut)]
@@ -37,6 +38,38 @@ pub fn enum_attributes() -> TokenStream {
     }
 }
 
+pub fn untagged_enum_attributes() -> TokenStream {
+    #[allow(unused_mut)]
+    let mut attributes: Vec<TokenStream> = vec![];
+
+    #[cfg(feature = "serde")]
+    {
+        use proc_macro_crate::crate_name;
+
+        if crate_name("serde").is_ok() {
+            attributes.push(quote! {
+                #[derive(serde::Deserialize, serde::Serialize)]
+                #[serde(untagged)]
+            });
+        }
+    }
+
+    #[cfg(feature = "utoipa")]
+    {
+        use proc_macro_crate::crate_name;
+
+        if crate_name("utoipa").is_ok() {
+            attributes.push(quote! {
+                #[derive(utoipa::ToSchema)]
+            });
+        }
+    }
+
+    quote! {
+        #( #attributes )*
+    }
+}
+
 pub fn enum_field_attributes() -> TokenStream {
     #[allow(unused_mut)]
     let mut attributes: Vec<TokenStream> = vec![];
@@ -57,6 +90,27 @@ pub fn enum_field_attributes() -> TokenStream {
     }
 }
 
+#[cfg_attr(not(feature = "utoipa"), allow(unused_variables))]
+pub fn nested_enum_variant_attributes(error_type: &TypePath) -> TokenStream {
+    #[allow(unused_mut)]
+    let mut attributes: Vec<TokenStream> = vec![];
+
+    #[cfg(feature = "utoipa")]
+    {
+        use proc_macro_crate::crate_name;
+
+        if crate_name("utoipa").is_ok() {
+            attributes.push(quote! {
+                #[schema(value_type = ::fortifier::utoipa::NestedErrors<#error_type>)]
+            });
+        }
+    }
+
+    quote! {
+        #( #attributes )*
+    }
+}
+
 pub fn where_predicate(error_type: TokenStream) -> TokenStream {
     #[allow(unused_mut)]
     let mut lifetime = TokenStream::new();
@@ -0,0 +1,717 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::PathBuf,
+};
+
+use convert_case::{Case, Casing};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+use serde_json::{Map, Value};
+use syn::{
+    Error, Ident, LitStr, Result,
+    parse::{Parse, ParseStream},
+};
+
+pub struct ImportJsonSchema {
+    path: LitStr,
+}
+
+impl Parse for ImportJsonSchema {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(ImportJsonSchema {
+            path: input.parse()?,
+        })
+    }
+}
+
+impl ImportJsonSchema {
+    pub fn expand(&self) -> Result<TokenStream> {
+        let span = self.path.span();
+
+        let manifest_dir = env::var("CARGO_MANIFEST_DIR")
+            .map_err(|_| Error::new(span, "`CARGO_MANIFEST_DIR` is not set"))?;
+        let path = PathBuf::from(manifest_dir).join(self.path.value());
+
+        let contents = fs::read_to_string(&path).map_err(|error| {
+            Error::new(
+                span,
+                format!("failed to read `{}`: {error}", path.display()),
+            )
+        })?;
+        let schema: Value = serde_json::from_str(&contents).map_err(|error| {
+            Error::new(
+                span,
+                format!("failed to parse `{}`: {error}", path.display()),
+            )
+        })?;
+
+        let name = schema
+            .get("title")
+            .and_then(Value::as_str)
+            .map(str::to_owned)
+            .or_else(|| {
+                path.file_stem()
+                    .map(|stem| stem.to_string_lossy().replace(".schema", ""))
+            })
+            .unwrap_or_else(|| "Schema".to_owned());
+
+        let mut generator = Generator::new(&schema, span);
+        generator.generate(&name)?;
+
+        let items = generator.items;
+        let path = path.to_string_lossy();
+
+        Ok(quote! {
+            const _: &str = include_str!(#path);
+
+            #( #items )*
+        })
+    }
+}
+
+/// Nested validation of a generated type.
+#[derive(Clone)]
+enum Nested {
+    /// The type is not validated.
+    None,
+    /// The type is a generated type without validations, which has to be skipped.
+    Skip,
+    /// The type is validated with the error type.
+    ErrorType(TokenStream),
+}
+
+impl Nested {
+    fn map(self, f: impl FnOnce(TokenStream) -> TokenStream) -> Nested {
+        match self {
+            Nested::ErrorType(error_type) => Nested::ErrorType(f(error_type)),
+            nested => nested,
+        }
+    }
+}
+
+/// Generated named type.
+#[derive(Clone)]
+struct Definition {
+    ident: Ident,
+    validate: bool,
+}
+
+impl Definition {
+    fn nested(&self) -> Nested {
+        if self.validate {
+            let error_ident = format_ident!("{}ValidationError", self.ident);
+
+            Nested::ErrorType(error_ident.to_token_stream())
+        } else {
+            Nested::Skip
+        }
+    }
+}
+
+/// Type of a field, with its validations.
+struct Field {
+    r#type: TokenStream,
+    optional: bool,
+    nested: Nested,
+    validations: Vec<TokenStream>,
+}
+
+impl Field {
+    fn new(r#type: TokenStream, nested: Nested) -> Self {
+        Field {
+            r#type,
+            optional: false,
+            nested,
+            validations: vec![],
+        }
+    }
+
+    fn validates(&self) -> bool {
+        !self.validations.is_empty() || matches!(self.nested, Nested::ErrorType(_))
+    }
+
+    fn attributes(&self) -> TokenStream {
+        let validations = &self.validations;
+        let nested = match &self.nested {
+            Nested::None => None,
+            Nested::Skip => Some(quote!(#[validate(skip)])),
+            Nested::ErrorType(error_type) => {
+                Some(quote!(#[validate(nested(error_type = #error_type))]))
+            }
+        };
+
+        quote! {
+            #( #[validate(#validations)] )*
+            #nested
+        }
+    }
+}
+
+struct Generator<'a> {
+    root: &'a Value,
+    span: Span,
+    names: HashSet<String>,
+    definitions: HashMap<String, Definition>,
+    items: Vec<TokenStream>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(root: &'a Value, span: Span) -> Self {
+        Generator {
+            root,
+            span,
+            names: HashSet::new(),
+            definitions: HashMap::new(),
+            items: vec![],
+        }
+    }
+
+    fn generate(&mut self, name: &str) -> Result<()> {
+        if let Some(schema) = self.root.as_object()
+            && is_named(schema)
+        {
+            self.named_type(name, schema)?;
+        }
+
+        for key in ["$defs", "definitions"] {
+            if let Some(definitions) = self.root.get(key).and_then(Value::as_object) {
+                // Other definitions are inlined where they are referenced.
+                for (name, definition) in definitions {
+                    if definition.as_object().is_some_and(is_named) {
+                        self.reference(&format!("#/{key}/{}", escape_pointer(name)))?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn error(&self, message: impl Into<String>) -> Error {
+        Error::new(self.span, message.into())
+    }
+
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut unique_name = name.to_owned();
+        let mut index = 1;
+
+        while !self.names.insert(unique_name.clone()) {
+            index += 1;
+            unique_name = format!("{name}{index}");
+        }
+
+        unique_name
+    }
+
+    fn reference(&mut self, reference: &str) -> Result<Field> {
+        if let Some(definition) = self.definitions.get(reference) {
+            let ident = &definition.ident;
+
+            return Ok(Field::new(ident.to_token_stream(), definition.nested()));
+        }
+
+        let Some(pointer) = reference.strip_prefix('#') else {
+            return Err(self.error(format!(
+                "unsupported reference `{reference}`, only local references are supported"
+            )));
+        };
+        let root = self.root;
+        let Some(schema) = root.pointer(pointer) else {
+            return Err(self.error(format!("unknown reference `{reference}`")));
+        };
+        let name = pointer
+            .rsplit('/')
+            .next()
+            .map(unescape_pointer)
+            .unwrap_or_default();
+
+        match schema.as_object() {
+            Some(schema) if is_named(schema) => {
+                let ident = type_ident(&self.unique_name(&pascal_case(&name)));
+
+                // Insert the definition before generating it, so recursive references resolve.
+                self.definitions.insert(
+                    reference.to_owned(),
+                    Definition {
+                        ident: ident.clone(),
+                        validate: true,
+                    },
+                );
+
+                let validate = self.named_type_with_ident(ident.clone(), schema)?;
+                let definition = Definition { ident, validate };
+                let field = Field::new(definition.ident.to_token_stream(), definition.nested());
+
+                self.definitions.insert(reference.to_owned(), definition);
+
+                Ok(field)
+            }
+            _ => self.field(schema, &pascal_case(&name)),
+        }
+    }
+
+    fn named_type(&mut self, name: &str, schema: &Map<String, Value>) -> Result<Definition> {
+        let ident = type_ident(&self.unique_name(&pascal_case(name)));
+        let validate = self.named_type_with_ident(ident.clone(), schema)?;
+
+        Ok(Definition { ident, validate })
+    }
+
+    /// Generate a struct or enum, returning whether it has validations.
+    fn named_type_with_ident(&mut self, ident: Ident, schema: &Map<String, Value>) -> Result<bool> {
+        if let Some(variants) = schema.get("oneOf").and_then(Value::as_array) {
+            self.one_of(ident, schema, variants)
+        } else if let Some(values) = string_enum(schema) {
+            self.string_enum(ident, schema, &values);
+
+            Ok(false)
+        } else {
+            self.object(ident, schema)
+        }
+    }
+
+    fn field(&mut self, schema: &Value, name: &str) -> Result<Field> {
+        let Some(schema) = schema.as_object() else {
+            return Ok(Field::new(quote!(::serde_json::Value), Nested::Skip));
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return self.reference(reference);
+        }
+
+        if is_named(schema) {
+            let definition = self.named_type(name, schema)?;
+            let ident = &definition.ident;
+
+            return Ok(Field::new(ident.to_token_stream(), definition.nested()));
+        }
+
+        let (types, nullable) = match schema.get("type") {
+            Some(Value::String(r#type)) => (vec![r#type.as_str()], false),
+            Some(Value::Array(types)) => {
+                let types = types.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+                let nullable = types.contains(&"null");
+
+                (
+                    types
+                        .into_iter()
+                        .filter(|r#type| *r#type != "null")
+                        .collect(),
+                    nullable,
+                )
+            }
+            _ => (vec![], false),
+        };
+
+        let mut field = match types.as_slice() {
+            ["string"] => {
+                let mut field = Field::new(quote!(String), Nested::None);
+                field.validations = self.string_validations(schema, name)?;
+                field
+            }
+            ["integer"] => {
+                let mut field = Field::new(quote!(i64), Nested::None);
+                field.validations = range_validations(schema, true);
+                field
+            }
+            ["number"] => {
+                let mut field = Field::new(quote!(f64), Nested::None);
+                field.validations = range_validations(schema, false);
+                field
+            }
+            ["boolean"] => Field::new(quote!(bool), Nested::None),
+            ["array"] => {
+                let item = match schema.get("items") {
+                    Some(items) => self.item(items, &format!("{name}Item"))?,
+                    None => Field::new(quote!(::serde_json::Value), Nested::Skip),
+                };
+                let item_type = &item.r#type;
+
+                let mut field = Field::new(
+                    quote!(Vec<#item_type>),
+                    item.nested
+                        .map(|error_type| quote!(::fortifier::IndexedValidationError<#error_type>)),
+                );
+                field.validations = length_validations(schema, "minItems", "maxItems");
+                field
+            }
+            ["object"] => match schema.get("additionalProperties") {
+                Some(value @ Value::Object(_)) => {
+                    let value = self.item(value, &format!("{name}Value"))?;
+                    let value_type = &value.r#type;
+
+                    let mut field = Field::new(
+                        quote!(::std::collections::BTreeMap<String, #value_type>),
+                        value.nested.map(
+                            |error_type| quote!(::fortifier::KeyedValidationError<String, #error_type>),
+                        ),
+                    );
+                    field.validations =
+                        length_validations(schema, "minProperties", "maxProperties");
+                    field
+                }
+                _ => Field::new(quote!(::serde_json::Value), Nested::Skip),
+            },
+            ["null"] => Field::new(quote!(()), Nested::None),
+            _ => Field::new(quote!(::serde_json::Value), Nested::Skip),
+        };
+
+        if nullable {
+            let r#type = &field.r#type;
+            field.r#type = quote!(Option<#r#type>);
+            field.optional = true;
+        }
+
+        Ok(field)
+    }
+
+    /// Generate the type of an array item or map value.
+    ///
+    /// Validations can't be applied to items directly, so items with validations are wrapped in a newtype.
+    fn item(&mut self, schema: &Value, name: &str) -> Result<Field> {
+        let item = self.field(schema, name)?;
+
+        if item.validations.is_empty() {
+            return Ok(item);
+        }
+
+        let ident = type_ident(&self.unique_name(name));
+        let r#type = &item.r#type;
+        let attributes = item.attributes();
+        let doc = doc(schema.as_object());
+
+        self.items.push(quote! {
+            #doc
+            #[derive(Clone, Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize, ::fortifier::Validate)]
+            #[serde(transparent)]
+            pub struct #ident(#attributes pub #r#type);
+        });
+
+        let definition = Definition {
+            ident,
+            validate: true,
+        };
+        let ident = &definition.ident;
+
+        Ok(Field::new(ident.to_token_stream(), definition.nested()))
+    }
+
+    fn object(&mut self, ident: Ident, schema: &Map<String, Value>) -> Result<bool> {
+        let required = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| {
+                required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut validate = false;
+        let mut fields = vec![];
+
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                let field_ident = field_ident(name);
+                let mut field = self.field(property, &format!("{ident}{}", pascal_case(name)))?;
+
+                let mut serde_arguments = vec![];
+                let unraw_ident = field_ident.to_string();
+                if unraw_ident.trim_start_matches("r#") != name {
+                    serde_arguments.push(quote!(rename = #name));
+                }
+                if !required.contains(&name.as_str()) {
+                    if !field.optional {
+                        let r#type = &field.r#type;
+                        field.r#type = quote!(Option<#r#type>);
+                    }
+
+                    serde_arguments.push(quote!(default, skip_serializing_if = "Option::is_none"));
+                }
+                let serde_attribute = (!serde_arguments.is_empty())
+                    .then(|| quote!(#[serde(#( #serde_arguments ),*)]));
+
+                validate |= field.validates();
+
+                let doc = doc(property.as_object());
+                let attributes = field.attributes();
+                let r#type = &field.r#type;
+
+                fields.push(quote! {
+                    #doc
+                    #serde_attribute
+                    #attributes
+                    pub #field_ident: #r#type
+                });
+            }
+        }
+
+        let doc = doc(Some(schema));
+
+        self.items.push(quote! {
+            #doc
+            #[derive(Clone, Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize, ::fortifier::Validate)]
+            pub struct #ident {
+                #( #fields ),*
+            }
+        });
+
+        Ok(validate)
+    }
+
+    fn one_of(
+        &mut self,
+        ident: Ident,
+        schema: &Map<String, Value>,
+        variants: &[Value],
+    ) -> Result<bool> {
+        if let Some(values) = variants
+            .iter()
+            .map(|variant| variant.get("const").and_then(Value::as_str))
+            .collect::<Option<Vec<_>>>()
+        {
+            self.string_enum(ident, schema, &values);
+
+            return Ok(false);
+        }
+
+        let mut validate = false;
+        let mut names = HashSet::new();
+        let mut variant_tokens = vec![];
+
+        for (index, variant) in variants.iter().enumerate() {
+            let name = variant_name(variant).unwrap_or_else(|| format!("Variant{index}"));
+            let name = if names.insert(name.clone()) {
+                name
+            } else {
+                format!("{name}{index}")
+            };
+
+            let variant_ident = type_ident(&name);
+            let field = self.field(variant, &format!("{ident}{name}"))?;
+
+            validate |= field.validates();
+
+            let doc = doc(variant.as_object());
+            let attributes = field.attributes();
+            let r#type = &field.r#type;
+
+            variant_tokens.push(quote! {
+                #doc
+                #variant_ident(#attributes #r#type)
+            });
+        }
+
+        let doc = doc(Some(schema));
+
+        self.items.push(quote! {
+            #doc
+            #[derive(Clone, Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize, ::fortifier::Validate)]
+            #[serde(untagged)]
+            pub enum #ident {
+                #( #variant_tokens ),*
+            }
+        });
+
+        Ok(validate)
+    }
+
+    fn string_enum(&mut self, ident: Ident, schema: &Map<String, Value>, values: &[&str]) {
+        let mut names = HashSet::new();
+        let variants = values.iter().enumerate().map(|(index, value)| {
+            let name = pascal_case(value);
+            let name = if !name.is_empty() && names.insert(name.clone()) {
+                name
+            } else {
+                format!("Variant{index}")
+            };
+            let variant_ident = type_ident(&name);
+
+            quote! {
+                #[serde(rename = #value)]
+                #variant_ident
+            }
+        });
+
+        let doc = doc(Some(schema));
+
+        self.items.push(quote! {
+            #doc
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+            pub enum #ident {
+                #( #variants ),*
+            }
+        });
+    }
+
+    fn string_validations(
+        &mut self,
+        schema: &Map<String, Value>,
+        name: &str,
+    ) -> Result<Vec<TokenStream>> {
+        let mut validations = length_validations(schema, "minLength", "maxLength");
+
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            regex::Regex::new(pattern)
+                .map_err(|error| self.error(format!("invalid pattern `{pattern}`: {error}")))?;
+
+            let static_ident = format_ident!(
+                "{}",
+                self.unique_name(&format!("{}_PATTERN", name.to_case(Case::UpperSnake)))
+            );
+
+            self.items.push(quote! {
+                static #static_ident: ::std::sync::LazyLock<::regex::Regex> =
+                    ::std::sync::LazyLock::new(|| {
+                        ::regex::Regex::new(#pattern).expect("valid regular expression")
+                    });
+            });
+
+            validations.push(quote!(regex(expression = &#static_ident)));
+        }
+
+        match schema.get("format").and_then(Value::as_str) {
+            Some("email" | "idn-email") => validations.push(quote!(email_address)),
+            Some("uri" | "iri" | "url") => validations.push(quote!(url)),
+            _ => {}
+        }
+
+        Ok(validations)
+    }
+}
+
+/// Whether the schema is generated as a named type.
+fn is_named(schema: &Map<String, Value>) -> bool {
+    schema.contains_key("oneOf")
+        || string_enum(schema).is_some()
+        || (schema.get("type").and_then(Value::as_str) == Some("object")
+            && schema.contains_key("properties"))
+        || (!schema.contains_key("type") && schema.contains_key("properties"))
+}
+
+fn string_enum(schema: &Map<String, Value>) -> Option<Vec<&str>> {
+    schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|values| values.iter().map(Value::as_str).collect())
+}
+
+fn variant_name(schema: &Value) -> Option<String> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference
+            .rsplit('/')
+            .next()
+            .map(|name| pascal_case(&unescape_pointer(name)));
+    }
+
+    if let Some(title) = schema.get("title").and_then(Value::as_str) {
+        return Some(pascal_case(title));
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") => Some("String".to_owned()),
+        Some("integer") => Some("Integer".to_owned()),
+        Some("number") => Some("Number".to_owned()),
+        Some("boolean") => Some("Boolean".to_owned()),
+        Some("array") => Some("Array".to_owned()),
+        Some("object") => Some("Object".to_owned()),
+        _ => None,
+    }
+}
+
+fn length_validations(schema: &Map<String, Value>, min: &str, max: &str) -> Vec<TokenStream> {
+    let arguments = [("min", min), ("max", max)]
+        .into_iter()
+        .filter_map(|(argument, keyword)| {
+            let value = schema.get(keyword).and_then(Value::as_u64)?;
+            let argument = format_ident!("{argument}");
+            let value = Literal::u64_unsuffixed(value);
+
+            Some(quote!(#argument = #value))
+        })
+        .collect::<Vec<_>>();
+
+    if arguments.is_empty() {
+        vec![]
+    } else {
+        vec![quote!(length(#( #arguments ),*))]
+    }
+}
+
+fn range_validations(schema: &Map<String, Value>, integer: bool) -> Vec<TokenStream> {
+    let arguments = [
+        ("min", "minimum"),
+        ("max", "maximum"),
+        ("exclusive_min", "exclusiveMinimum"),
+        ("exclusive_max", "exclusiveMaximum"),
+    ]
+    .into_iter()
+    .filter_map(|(argument, keyword)| {
+        let value = schema.get(keyword)?;
+        let value = match value.as_i64() {
+            Some(value) if integer => Literal::i64_unsuffixed(value),
+            _ => Literal::f64_unsuffixed(value.as_f64()?),
+        };
+        let argument = format_ident!("{argument}");
+
+        Some(quote!(#argument = #value))
+    })
+    .collect::<Vec<_>>();
+
+    if arguments.is_empty() {
+        vec![]
+    } else {
+        vec![quote!(range(#( #arguments ),*))]
+    }
+}
+
+fn doc(schema: Option<&Map<String, Value>>) -> Option<TokenStream> {
+    let description = schema?.get("description")?.as_str()?;
+
+    Some(quote!(#[doc = #description]))
+}
+
+fn pascal_case(name: &str) -> String {
+    sanitize(&name.to_case(Case::Pascal))
+}
+
+fn type_ident(name: &str) -> Ident {
+    ident(if name.is_empty() { "Type" } else { name })
+}
+
+fn field_ident(name: &str) -> Ident {
+    let name = sanitize(&name.to_case(Case::Snake));
+
+    ident(if name.is_empty() { "field" } else { &name })
+}
+
+fn sanitize(name: &str) -> String {
+    let name = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_')
+        .collect::<String>();
+
+    if name.starts_with(|c: char| c.is_numeric()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+fn ident(name: &str) -> Ident {
+    match name {
+        "crate" | "self" | "Self" | "super" => format_ident!("{name}_"),
+        _ => syn::parse_str::<Ident>(name)
+            .unwrap_or_else(|_| Ident::new_raw(name, Span::call_site())),
+    }
+}
+
+fn escape_pointer(name: &str) -> String {
+    name.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape_pointer(name: &str) -> String {
+    name.replace("~1", "/").replace("~0", "~")
+}
@@ -4,16 +4,25 @@
 
 mod generics;
 mod integrations;
+#[cfg(feature = "json-schema")]
+mod json_schema;
+mod sanitize;
 mod util;
 mod validate;
 mod validation;
 mod validations;
+#[cfg(feature = "zod")]
+mod zod;
 
 use proc_macro::TokenStream;
 use quote::ToTokens;
 use syn::{DeriveInput, Error, parse_macro_input};
 
-use crate::validate::Validate;
+#[cfg(feature = "json-schema")]
+use crate::json_schema::ImportJsonSchema;
+#[cfg(feature = "zod")]
+use crate::zod::ToZod;
+use crate::{sanitize::Sanitize, validate::Validate};
 
 /// Validate derive macro.
 #[proc_macro_derive(Validate, attributes(validate))]
@@ -25,3 +34,46 @@ pub fn derive(input: TokenStream) -> TokenStream {
         .unwrap_or_else(Error::into_compile_error)
         .into()
 }
+
+/// Sanitize derive macro.
+///
+/// The helper attribute is named `sanitizer`, as `sanitize` conflicts with a built-in attribute.
+#[proc_macro_derive(Sanitize, attributes(sanitizer))]
+pub fn derive_sanitize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    Sanitize::parse(&input)
+        .map(|sanitize| sanitize.to_token_stream())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Zod derive macro.
+#[cfg(feature = "zod")]
+#[proc_macro_derive(ToZod, attributes(serde, validate))]
+pub fn derive_to_zod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    ToZod::parse(&input)
+        .map(|to_zod| to_zod.to_token_stream())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Import a JSON Schema, generating structs and enums with validations.
+///
+/// The path is relative to the `CARGO_MANIFEST_DIR` of the crate.
+///
+/// ```ignore
+/// fortifier::import_json_schema!("schemas/user.json");
+/// ```
+#[cfg(feature = "json-schema")]
+#[proc_macro]
+pub fn import_json_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ImportJsonSchema);
+
+    input
+        .expand()
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
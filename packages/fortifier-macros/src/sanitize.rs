@@ -0,0 +1,312 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Error, Field, Fields, GenericArgument, Generics, Index, Path,
+    PathArguments, Result, Type, meta::ParseNestedMeta,
+};
+
+use crate::validate::r#type::should_validate_type;
+
+pub struct Sanitize<'a> {
+    input: &'a DeriveInput,
+    sanitizers: Vec<Sanitizer>,
+    where_predicates: Vec<TokenStream>,
+    body: TokenStream,
+}
+
+impl<'a> Sanitize<'a> {
+    pub fn parse(input: &'a DeriveInput) -> Result<Self> {
+        let mut result = Sanitize {
+            input,
+            sanitizers: vec![],
+            where_predicates: vec![],
+            body: TokenStream::new(),
+        };
+
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("sanitizer") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("custom") {
+                    result.sanitizers.push(Sanitizer::parse_custom(&meta)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        result.body = match &input.data {
+            Data::Struct(data) => {
+                let statements = data
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        let expr = match &field.ident {
+                            Some(ident) => quote!(&mut self.#ident),
+                            None => {
+                                let index = Index::from(index);
+                                quote!(&mut self.#index)
+                            }
+                        };
+
+                        SanitizeField::parse(&input.generics, field)
+                            .map(|field| field.statements(&expr, &mut result.where_predicates))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                quote!(#( #statements )*)
+            }
+            Data::Enum(data) => {
+                let arms = data
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let variant_ident = &variant.ident;
+                        let mut bindings = vec![];
+                        let mut statements = vec![];
+
+                        for (index, field) in variant.fields.iter().enumerate() {
+                            let binding = field
+                                .ident
+                                .clone()
+                                .unwrap_or_else(|| format_ident!("f{index}"));
+                            let field_statements = SanitizeField::parse(&input.generics, field)?
+                                .statements(
+                                    &binding.to_token_stream(),
+                                    &mut result.where_predicates,
+                                );
+
+                            // Ignore fields without statements, to prevent unused variable warnings.
+                            bindings.push(match (&field.ident, field_statements.is_empty()) {
+                                (Some(ident), true) => quote!(#ident: _),
+                                (None, true) => quote!(_),
+                                (_, false) => binding.to_token_stream(),
+                            });
+                            statements.push(field_statements);
+                        }
+
+                        let pattern = match &variant.fields {
+                            Fields::Named(_) => quote!(Self::#variant_ident { #( #bindings ),* }),
+                            Fields::Unnamed(_) => quote!(Self::#variant_ident( #( #bindings ),* )),
+                            Fields::Unit => quote!(Self::#variant_ident),
+                        };
+
+                        Ok(quote! {
+                            #pattern => {
+                                #( #statements )*
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                if arms.is_empty() {
+                    TokenStream::new()
+                } else {
+                    quote! {
+                        match self {
+                            #( #arms )*
+                        }
+                    }
+                }
+            }
+            Data::Union(_) => {
+                return Err(Error::new_spanned(input, "union is not supported"));
+            }
+        };
+
+        Ok(result)
+    }
+}
+
+impl ToTokens for Sanitize<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.input.ident;
+        let (impl_generics, type_generics, where_clause) = self.input.generics.split_for_impl();
+
+        let where_predicates = where_clause
+            .map(|where_clause| where_clause.predicates.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let additional_where_predicates = &self.where_predicates;
+        let where_clause =
+            (!where_predicates.is_empty() || !additional_where_predicates.is_empty()).then(|| {
+                quote! {
+                    where
+                        #( #where_predicates, )*
+                        #( #additional_where_predicates, )*
+                }
+            });
+
+        let body = &self.body;
+        let sanitizers = self
+            .sanitizers
+            .iter()
+            .map(|sanitizer| sanitizer.statement(&quote!(self)));
+
+        tokens.append_all(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::fortifier::Sanitize for #ident #type_generics #where_clause {
+                fn sanitize(&mut self) {
+                    #body
+                    #( #sanitizers )*
+                }
+            }
+        });
+    }
+}
+
+struct SanitizeField<'a> {
+    r#type: &'a Type,
+    nested: bool,
+    generic: bool,
+    sanitizers: Vec<Sanitizer>,
+}
+
+impl<'a> SanitizeField<'a> {
+    fn parse(generics: &Generics, field: &'a Field) -> Result<Self> {
+        let mut sanitizers = vec![];
+        let mut skip = false;
+
+        for attribute in sanitize_attributes(&field.attrs) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("collapse_whitespace") {
+                    sanitizers.push(Sanitizer::String(quote!(
+                        ::fortifier::sanitize_collapse_whitespace
+                    )));
+                } else if meta.path.is_ident("custom") {
+                    sanitizers.push(Sanitizer::parse_custom(&meta)?);
+                } else if meta.path.is_ident("lowercase") {
+                    sanitizers.push(Sanitizer::String(quote!(::fortifier::sanitize_lowercase)));
+                } else if meta.path.is_ident("nfc") {
+                    sanitizers.push(Sanitizer::String(quote!(::fortifier::sanitize_nfc)));
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("trim") {
+                    sanitizers.push(Sanitizer::String(quote!(::fortifier::sanitize_trim)));
+                } else {
+                    return Err(meta.error("unknown parameter"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        let generic = generics.type_params().next().is_some();
+        let nested = !skip
+            && (should_validate_type(generics, &field.ty).is_some()
+                || contains_type_param(generics, &field.ty));
+
+        Ok(SanitizeField {
+            r#type: &field.ty,
+            nested,
+            generic,
+            sanitizers,
+        })
+    }
+
+    fn statements(
+        &self,
+        expr: &TokenStream,
+        where_predicates: &mut Vec<TokenStream>,
+    ) -> TokenStream {
+        let r#type = self.r#type;
+
+        let nested = self.nested.then(|| {
+            if self.generic {
+                where_predicates.push(quote!(#r#type: ::fortifier::Sanitize));
+            }
+
+            quote! {
+                ::fortifier::Sanitize::sanitize(#expr);
+            }
+        });
+        let sanitizers = self
+            .sanitizers
+            .iter()
+            .map(|sanitizer| sanitizer.statement(expr));
+
+        quote! {
+            #nested
+            #( #sanitizers )*
+        }
+    }
+}
+
+enum Sanitizer {
+    /// String sanitizer function.
+    String(TokenStream),
+    /// Custom sanitizer function.
+    Custom(Path),
+}
+
+impl Sanitizer {
+    fn parse_custom(meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut function_path: Option<Path> = None;
+
+        if let Ok(value) = meta.value() {
+            function_path = Some(value.parse()?);
+        } else {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("function") {
+                    function_path = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        let Some(function_path) = function_path else {
+            return Err(meta.error("missing function parameter"));
+        };
+
+        Ok(Sanitizer::Custom(function_path))
+    }
+
+    fn statement(&self, expr: &TokenStream) -> TokenStream {
+        match self {
+            Sanitizer::String(function) => quote! {
+                ::fortifier::SanitizeString::sanitize_string(#expr, #function);
+            },
+            Sanitizer::Custom(function_path) => quote! {
+                #function_path(#expr);
+            },
+        }
+    }
+}
+
+fn sanitize_attributes(attributes: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("sanitizer"))
+}
+
+/// Whether the type contains a type parameter, in which case it is sanitized with an additional where predicate.
+fn contains_type_param(generics: &Generics, r#type: &Type) -> bool {
+    match r#type {
+        Type::Array(r#type) => contains_type_param(generics, &r#type.elem),
+        Type::Group(r#type) => contains_type_param(generics, &r#type.elem),
+        Type::Paren(r#type) => contains_type_param(generics, &r#type.elem),
+        Type::Slice(r#type) => contains_type_param(generics, &r#type.elem),
+        Type::Path(r#type) => {
+            if let Some(ident) = r#type.path.get_ident() {
+                return generics.type_params().any(|param| param.ident == *ident);
+            }
+
+            let Some(segment) = r#type.path.segments.last() else {
+                return false;
+            };
+
+            segment.ident != "PhantomData"
+                && matches!(&segment.arguments, PathArguments::AngleBracketed(arguments) if arguments.args.iter().any(|argument| {
+                    matches!(argument, GenericArgument::Type(r#type) if contains_type_param(generics, r#type))
+                }))
+        }
+        _ => false,
+    }
+}
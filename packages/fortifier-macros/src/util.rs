@@ -1,6 +1,12 @@
 use convert_case::{Case, Casing};
-use quote::format_ident;
-use syn::{GenericArgument, Ident, Path, PathArguments, Type};
+use proc_macro2::TokenStream;
+#[cfg(feature = "zod")]
+use proc_macro2::TokenTree;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Expr, GenericArgument, Ident, LitBool, Path, PathArguments, Result, Token, Type, bracketed,
+    meta::ParseNestedMeta, parse::ParseStream, punctuated::Punctuated, token,
+};
 
 pub fn upper_camel_ident(ident: &Ident) -> Ident {
     let s = ident.to_string();
@@ -12,6 +18,18 @@ pub fn upper_camel_ident(ident: &Ident) -> Ident {
     }
 }
 
+/// Convert an upper camel case identifier to camel case, matching `#[serde(rename_all = "camelCase")]`.
+#[cfg(feature = "zod")]
+pub fn camel_case(ident: &Ident) -> String {
+    let s = ident.to_string();
+    let mut chars = s.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
 pub fn path_to_string(path: &Path) -> String {
     path.segments
         .iter()
@@ -54,3 +72,43 @@ pub fn strip_options(r#type: &Type) -> &Type {
         r#type
     }
 }
+
+/// Parse a flag, which is either a bare path or a boolean literal.
+pub fn parse_flag(meta: &ParseNestedMeta<'_>) -> Result<bool> {
+    if let Ok(value) = meta.value() {
+        let lit: LitBool = value.parse()?;
+
+        Ok(lit.value)
+    } else {
+        Ok(true)
+    }
+}
+
+/// Parse an expression or an array of expressions.
+///
+/// Array expressions require the `full` feature of `syn`, so they are parsed separately.
+pub fn parse_expr_or_array(input: ParseStream<'_>) -> Result<TokenStream> {
+    if input.peek(token::Bracket) {
+        let content;
+        bracketed!(content in input);
+        let exprs = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+
+        Ok(quote!([#exprs]))
+    } else {
+        Ok(input.parse::<Expr>()?.into_token_stream())
+    }
+}
+
+/// Whether the expression uses the validation context, which is not available outside of validation.
+#[cfg(feature = "zod")]
+pub fn uses_context(expr: &Expr) -> bool {
+    fn contains_context(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Group(group) => contains_context(group.stream()),
+            TokenTree::Ident(ident) => ident == "context",
+            _ => false,
+        })
+    }
+
+    contains_context(expr.to_token_stream())
+}
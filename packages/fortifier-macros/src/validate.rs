@@ -1,16 +1,17 @@
 mod data;
 mod r#enum;
 mod error;
-mod field;
+pub mod field;
 mod fields;
+mod output;
 mod r#struct;
-mod r#type;
+pub mod r#type;
 mod r#union;
 
 use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, format_ident, quote};
 use syn::{
-    DeriveInput, Generics, Ident, Result, Type, TypeNever, TypeTuple, Visibility,
+    DeriveInput, Error, Generics, Ident, Result, Type, TypeNever, TypeTuple, Visibility,
     punctuated::Punctuated,
 };
 
@@ -18,8 +19,9 @@ use crate::{
     validate::{
         data::ValidateData,
         error::{ErrorType, error_type, format_error_ident},
+        output::ValidateOutput,
     },
-    validation::{Execution, Validation},
+    validation::{Execution, Severity, Validation},
     validations::Custom,
 };
 
@@ -30,6 +32,7 @@ pub struct Validate<'a> {
     root_error_ident: Ident,
     context_type: Option<Type>,
     data: ValidateData<'a>,
+    output: Option<ValidateOutput>,
     validations: Vec<Box<dyn Validation>>,
 }
 
@@ -43,6 +46,7 @@ impl<'a> Validate<'a> {
             root_error_ident: format_ident!("Root"),
             context_type: None,
             data: ValidateData::parse(input)?,
+            output: None,
             validations: vec![],
         };
 
@@ -51,6 +55,9 @@ impl<'a> Validate<'a> {
                 continue;
             }
 
+            let start = result.validations.len();
+            let mut severity = Severity::Error;
+
             attribute.parse_nested_meta(|meta| {
                 if meta.path.is_ident("context") {
                     result.context_type = Some(meta.value()?.parse()?);
@@ -66,11 +73,26 @@ impl<'a> Validate<'a> {
                         &meta,
                     )?));
 
+                    Ok(())
+                } else if meta.path.is_ident("output") {
+                    if !matches!(result.data, ValidateData::Struct(_)) {
+                        return Err(meta.error("output is only supported for structs"));
+                    }
+
+                    result.output = Some(ValidateOutput::parse(&input.ident, &meta)?);
+
+                    Ok(())
+                } else if meta.path.is_ident("severity") {
+                    severity = Severity::parse(&meta)?;
+
                     Ok(())
                 } else {
                     Err(meta.error("unknown parameter"))
                 }
             })?;
+
+            let validations = result.validations.split_off(start);
+            result.validations.extend(severity.apply(validations));
         }
 
         Ok(result)
@@ -87,9 +109,16 @@ impl<'a> Validate<'a> {
         self.data.error_type(root_error_type.as_ref())
     }
 
-    fn validations(&self, execution: Execution) -> TokenStream {
+    #[cfg(feature = "zod")]
+    pub fn typescript_definition(&self) -> Option<TokenStream> {
+        self.error_type()
+            .and_then(|error_type| error_type.typescript_definition)
+    }
+
+    fn validations(&self, execution: Execution, severity: Severity) -> TokenStream {
         self.data.validations(
             execution,
+            severity,
             &format_error_ident(self.ident),
             &self.root_error_ident,
             &self.validations,
@@ -148,8 +177,10 @@ impl<'a> ToTokens for Validate<'a> {
             }
         };
 
-        let sync_validations = self.validations(Execution::Sync);
-        let async_validations = self.validations(Execution::Async);
+        let sync_validations = self.validations(Execution::Sync, Severity::Error);
+        let async_validations = self.validations(Execution::Async, Severity::Error);
+        let sync_warnings = self.validations(Execution::Sync, Severity::Warning);
+        let async_warnings = self.validations(Execution::Async, Severity::Warning);
 
         let no_context_impl = self.context_type.is_none().then(|| {
             quote! {
@@ -158,6 +189,27 @@ impl<'a> ToTokens for Validate<'a> {
             }
         });
 
+        let output = if let Some(output) = &self.output
+            && let ValidateData::Struct(r#struct) = &self.data
+        {
+            output
+                .to_tokens(
+                    self.visibility,
+                    ident,
+                    self.generics,
+                    r#struct.data(),
+                    r#struct.fields(),
+                    &where_clause,
+                    self.context_type.is_some(),
+                    &format_error_ident(self.ident),
+                    &self.root_error_ident,
+                    &self.validations,
+                )
+                .unwrap_or_else(Error::into_compile_error)
+        } else {
+            TokenStream::new()
+        };
+
         tokens.append_all(quote! {
             #error_definition
 
@@ -175,9 +227,21 @@ impl<'a> ToTokens for Validate<'a> {
                         #async_validations
                     })
                 }
+
+                fn validate_warnings_sync_with_context(&self, context: &Self::Context) -> Result<(), ::fortifier::ValidationErrors<Self::Error>> {
+                    #sync_warnings
+                }
+
+                fn validate_warnings_async_with_context(&self, context: &Self::Context) -> ::std::pin::Pin<Box<impl Future<Output = Result<(), ::fortifier::ValidationErrors<Self::Error>>>>> {
+                    Box::pin(async move {
+                        #async_warnings
+                    })
+                }
             }
 
             #no_context_impl
+
+            #output
         })
     }
 }
@@ -5,7 +5,7 @@ use crate::{
     validate::{
         r#enum::ValidateEnum, error::ErrorType, r#struct::ValidateStruct, union::ValidateUnion,
     },
-    validation::{Execution, Validation},
+    validation::{Execution, Severity, Validation},
 };
 
 pub enum ValidateData<'a> {
@@ -34,6 +34,7 @@ impl<'a> ValidateData<'a> {
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         root_type_prefix: &Ident,
         root_error_ident: &Ident,
         root_validations: &[Box<dyn Validation>],
@@ -41,18 +42,21 @@ impl<'a> ValidateData<'a> {
         match self {
             ValidateData::Struct(r#struct) => r#struct.validations(
                 execution,
+                severity,
                 root_type_prefix,
                 root_error_ident,
                 root_validations,
             ),
             ValidateData::Enum(r#enum) => r#enum.validations(
                 execution,
+                severity,
                 root_type_prefix,
                 root_error_ident,
                 root_validations,
             ),
             ValidateData::Union(r#union) => r#union.validations(
                 execution,
+                severity,
                 root_type_prefix,
                 root_error_ident,
                 root_validations,
@@ -1,14 +1,22 @@
+use std::slice;
+
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{DataEnum, DeriveInput, Generics, Ident, Result, Variant, Visibility};
+use syn::{
+    DataEnum, DeriveInput, Expr, Fields, Generics, Ident, Result, Type, TypeNever, Variant,
+    Visibility,
+};
 
 use crate::{
     validate::{
-        error::{ErrorType, combined_error_type, format_error_ident},
+        error::{ErrorType, combined_error_type, error_type, format_error_ident},
         field::{LiteralOrIdent, ValidateFieldPrefix},
-        fields::ValidateFields,
+        fields::{ValidateFields, field_statements, root_statements},
+    },
+    validation::{Execution, Severity, Validation},
+    validations::{
+        Custom, Disallow, combine_validations, combine_wrapped_validations, wrap_validations,
     },
-    validation::{Execution, Validation},
 };
 
 pub struct ValidateEnum<'a> {
@@ -62,6 +70,7 @@ impl<'a> ValidateEnum<'a> {
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         root_type_prefix: &Ident,
         root_error_ident: &Ident,
         root_validations: &[Box<dyn Validation>],
@@ -69,6 +78,7 @@ impl<'a> ValidateEnum<'a> {
         let variant_match_arms = self.variants.iter().map(|variant| {
             variant.match_arm(
                 execution,
+                severity,
                 root_type_prefix,
                 root_error_ident,
                 root_validations,
@@ -84,10 +94,15 @@ impl<'a> ValidateEnum<'a> {
 }
 
 pub struct ValidateEnumVariant<'a> {
+    visibility: &'a Visibility,
+    generics: &'a Generics,
     enum_ident: &'a Ident,
     enum_error_ident: Ident,
     ident: &'a Ident,
     fields: ValidateFields<'a>,
+    validations: Vec<Box<dyn Validation>>,
+    when: Option<Expr>,
+    skip: bool,
 }
 
 impl<'a> ValidateEnumVariant<'a> {
@@ -98,7 +113,9 @@ impl<'a> ValidateEnumVariant<'a> {
         enum_error_ident: Ident,
         variant: &'a Variant,
     ) -> Result<Self> {
-        let result = ValidateEnumVariant {
+        let mut result = ValidateEnumVariant {
+            visibility,
+            generics,
             enum_ident,
             enum_error_ident,
             ident: &variant.ident,
@@ -106,20 +123,99 @@ impl<'a> ValidateEnumVariant<'a> {
                 visibility,
                 generics,
                 format_ident!("{}{}", enum_ident, variant.ident),
+                match variant.fields {
+                    Fields::Unnamed(_) => ValidateFieldPrefix::F,
+                    Fields::Named(_) | Fields::Unit => ValidateFieldPrefix::None,
+                },
                 &variant.fields,
             )?,
+            validations: vec![],
+            when: None,
+            skip: false,
         };
 
+        for attribute in &variant.attrs {
+            if !attribute.path().is_ident("validate") {
+                continue;
+            }
+
+            let start = result.validations.len();
+            let mut severity = Severity::Error;
+
+            attribute.parse_nested_meta(|meta| {
+                // Type is never used in the variant validations, so pass an arbitrary value.
+                let r#type = Type::Never(TypeNever {
+                    attrs: Default::default(),
+                    bang_token: Default::default(),
+                });
+
+                if meta.path.is_ident("custom") {
+                    result
+                        .validations
+                        .push(Box::new(Custom::parse(&r#type, &meta)?));
+
+                    Ok(())
+                } else if meta.path.is_ident("disallow") {
+                    result
+                        .validations
+                        .push(Box::new(Disallow::parse(&r#type, &meta)?));
+
+                    Ok(())
+                } else if meta.path.is_ident("severity") {
+                    severity = Severity::parse(&meta)?;
+
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    result.skip = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("when") {
+                    result.when = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+
+            let validations = result.validations.split_off(start);
+            result.validations.extend(severity.apply(validations));
+        }
+
         Ok(result)
     }
 
     fn error_type(&self, root_error_type: Option<&ErrorType>) -> Option<ErrorType> {
-        self.fields.error_type(Some(self.ident), root_error_type)
+        let type_prefix = self.fields.ident();
+
+        let fields = if self.skip { &[] } else { self.fields.fields() };
+
+        let field_error_types = fields
+            .iter()
+            .flat_map(|field| field.error_type(type_prefix));
+        let variant_error_types = self.validations.iter().flat_map(|validation| {
+            error_type(
+                self.visibility,
+                type_prefix,
+                &validation.ident(),
+                slice::from_ref(validation),
+            )
+        });
+
+        combined_error_type(
+            self.visibility,
+            self.generics,
+            self.ident,
+            self.fields.error_ident(),
+            field_error_types.chain(variant_error_types).collect(),
+            root_error_type,
+        )
     }
 
     fn match_arm(
         &self,
-        exeuction: Execution,
+        execution: Execution,
+        severity: Severity,
         root_type_prefix: &Ident,
         root_error_ident: &Ident,
         root_validations: &[Box<dyn Validation>],
@@ -127,68 +223,102 @@ impl<'a> ValidateEnumVariant<'a> {
         let enum_ident = &self.enum_ident;
         let enum_error_ident = &self.enum_error_ident;
         let ident = &self.ident;
+        let error_ident = self.fields.error_ident();
 
         let error_wrapper = |tokens| quote!(#enum_error_ident::#ident(#tokens));
 
-        match &self.fields {
+        let (pattern, field_prefix) = match &self.fields {
+            _ if self.skip => (
+                quote!(#enum_ident::#ident { .. }),
+                ValidateFieldPrefix::None,
+            ),
             ValidateFields::Named(fields) => {
                 let field_idents = fields.idents();
-                let validations = fields.validations(
-                    exeuction,
-                    ValidateFieldPrefix::None,
-                    &error_wrapper,
-                    root_type_prefix,
-                    root_error_ident,
-                    root_validations,
-                );
 
                 // TODO: Only destructure fields required for validation.
-                quote! {
-                    #[allow(unused_variables)]
-                    #enum_ident::#ident {
-                        #( #field_idents ),*
-                    } => {
-                        #validations
-                    }
-                }
+                (
+                    quote! {
+                        #[allow(unused_variables)]
+                        #enum_ident::#ident {
+                            #( #field_idents ),*
+                        }
+                    },
+                    ValidateFieldPrefix::None,
+                )
             }
             ValidateFields::Unnamed(fields) => {
                 let field_idents = fields.idents().map(|ident| match ident {
                     LiteralOrIdent::Literal(literal) => format_ident!("f{literal}"),
                     LiteralOrIdent::Ident(ident) => ident.clone(),
                 });
-                let validations = fields.validations(
-                    exeuction,
+
+                (
+                    quote! {
+                        #enum_ident::#ident(
+                            #( #field_idents ),*
+                        )
+                    },
                     ValidateFieldPrefix::F,
-                    &error_wrapper,
-                    root_type_prefix,
-                    root_error_ident,
-                    root_validations,
-                );
-
-                quote! {
-                    #enum_ident::#ident(
-                        #( #field_idents ),*
-                    ) => {
-                        #validations
-                    }
-                }
+                )
             }
-            ValidateFields::Unit(fields) => {
-                let validations = fields.validations(
-                    exeuction,
-                    ValidateFieldPrefix::None,
+            ValidateFields::Unit(_) => (quote!(#enum_ident::#ident), ValidateFieldPrefix::None),
+        };
+
+        let mut statements = self
+            .validations
+            .iter()
+            .flat_map(|validation| {
+                wrap_validations(
+                    error_ident,
+                    &validation.ident(),
                     &error_wrapper,
-                    root_type_prefix,
-                    root_error_ident,
-                    root_validations,
-                );
-
-                quote! {
-                    #enum_ident::#ident => {
-                        #validations
-                    }
+                    combine_validations(
+                        execution,
+                        severity,
+                        error_ident,
+                        &quote!(self),
+                        slice::from_ref(validation),
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if !self.skip {
+            statements.extend(field_statements(
+                execution,
+                severity,
+                field_prefix,
+                error_ident,
+                &error_wrapper,
+                self.fields.fields().iter(),
+            ));
+        }
+
+        if let Some(when) = &self.when
+            && !statements.is_empty()
+        {
+            statements = vec![quote! {
+                if #when {
+                    #( #statements )*
                 }
+            }];
+        }
+
+        statements.extend(root_statements(
+            execution,
+            severity,
+            error_ident,
+            &error_wrapper,
+            root_type_prefix,
+            root_error_ident,
+            root_validations,
+        ));
+
+        let validations = combine_wrapped_validations(statements);
+
+        quote! {
+            #pattern => {
+                #validations
             }
         }
     }
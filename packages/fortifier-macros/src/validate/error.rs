@@ -2,9 +2,11 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, format_ident, quote};
 use syn::{Generics, Ident, Visibility};
 
+#[cfg(feature = "zod")]
+use crate::util::camel_case;
 use crate::{
     generics::{Generic, filter_generics},
-    integrations::enum_attributes,
+    integrations::{enum_attributes, untagged_enum_attributes},
     validation::Validation,
 };
 
@@ -18,10 +20,17 @@ pub fn format_error_ident_with_prefix(prefix: &Ident, ident: &Ident) -> Ident {
 
 pub struct ErrorType {
     pub variant_ident: Ident,
+    pub variant_attributes: TokenStream,
     pub r#type: TokenStream,
     pub generics: Vec<Generic>,
     pub where_predicates: Vec<TokenStream>,
     pub definition: Option<TokenStream>,
+    #[cfg(feature = "zod")]
+    pub typescript: TokenStream,
+    #[cfg(feature = "zod")]
+    pub typescript_where_predicates: Vec<TokenStream>,
+    #[cfg(feature = "zod")]
+    pub typescript_definition: Option<TokenStream>,
 }
 
 pub fn error_type(
@@ -31,8 +40,11 @@ pub fn error_type(
     validations: &[Box<dyn Validation>],
 ) -> Option<ErrorType> {
     if validations.len() > 1 {
-        let attributes = enum_attributes();
+        let attributes = untagged_enum_attributes();
         let ident = format_error_ident_with_prefix(prefix, error_ident);
+        let variant_attributes = validations
+            .iter()
+            .map(|validation| validation.error_attributes());
         let variant_ident = validations.iter().map(|validation| validation.ident());
         let variant_type = validations.iter().map(|validation| validation.error_type());
         let generics = validations
@@ -44,8 +56,14 @@ pub fn error_type(
             .flat_map(|validation| validation.error_where_predicates())
             .collect();
 
+        #[cfg(feature = "zod")]
+        let variant_typescript = validations
+            .iter()
+            .map(|validation| validation.error_typescript());
+
         Some(ErrorType {
             variant_ident: error_ident.clone(),
+            variant_attributes: TokenStream::new(),
             r#type: ident.to_token_stream(),
             generics,
             where_predicates,
@@ -53,17 +71,33 @@ pub fn error_type(
                 #[derive(Debug, PartialEq)]
                 #attributes
                 #visibility enum #ident {
-                    #( #variant_ident(#variant_type) ),*
+                    #( #variant_attributes #variant_ident(#variant_type) ),*
                 }
             }),
+            #[cfg(feature = "zod")]
+            typescript: quote!([#( #variant_typescript ),*].join(" | ")),
+            #[cfg(feature = "zod")]
+            typescript_where_predicates: validations
+                .iter()
+                .flat_map(|validation| validation.error_typescript_where_predicates())
+                .collect(),
+            #[cfg(feature = "zod")]
+            typescript_definition: None,
         })
     } else {
         validations.first().map(|validation| ErrorType {
             variant_ident: error_ident.clone(),
+            variant_attributes: validation.error_attributes(),
             r#type: validation.error_type(),
             generics: validation.error_generics(),
             where_predicates: validation.error_where_predicates(),
             definition: None,
+            #[cfg(feature = "zod")]
+            typescript: validation.error_typescript(),
+            #[cfg(feature = "zod")]
+            typescript_where_predicates: validation.error_typescript_where_predicates(),
+            #[cfg(feature = "zod")]
+            typescript_definition: None,
         })
     }
 }
@@ -91,6 +125,15 @@ pub fn combined_error_type(
                 .map(|error_type| &error_type.variant_ident),
         );
 
+    let variant_attributes = root_error_type
+        .into_iter()
+        .map(|error_type| &error_type.variant_attributes)
+        .chain(
+            error_types
+                .iter()
+                .map(|error_type| &error_type.variant_attributes),
+        );
+
     let variant_types = root_error_type
         .into_iter()
         .map(|error_type| &error_type.r#type)
@@ -146,8 +189,87 @@ pub fn combined_error_type(
         }
     };
 
+    #[cfg(feature = "zod")]
+    let (typescript_where_predicates, typescript_definition) = {
+        let typescript_variants =
+            root_error_type
+                .into_iter()
+                .chain(error_types.iter())
+                .map(|error_type| {
+                    let path = camel_case(&error_type.variant_ident);
+                    let typescript = &error_type.typescript;
+
+                    quote!(format!("{{ path: \"{}\" }} & ({})", #path, #typescript))
+                });
+
+        let typescript_where_predicates = root_error_type
+            .into_iter()
+            .flat_map(|error_type| &error_type.typescript_where_predicates)
+            .chain(
+                error_types
+                    .iter()
+                    .flat_map(|error_type| &error_type.typescript_where_predicates),
+            )
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let typescript_definitions = root_error_type
+            .into_iter()
+            .flat_map(|error_type| &error_type.typescript_definition)
+            .chain(
+                error_types
+                    .iter()
+                    .flat_map(|error_type| &error_type.typescript_definition),
+            );
+
+        let typescript_where_clause = if typescript_where_predicates.is_empty() {
+            where_clause.clone()
+        } else if where_predicates.is_empty()
+            && generics
+                .where_clause
+                .as_ref()
+                .is_none_or(|where_clause| where_clause.predicates.is_empty())
+        {
+            quote! {
+                where #( #typescript_where_predicates ),*
+            }
+        } else {
+            quote! {
+                #where_clause, #( #typescript_where_predicates ),*
+            }
+        };
+
+        let typescript = quote!([#( #typescript_variants ),*].join(" | "));
+        let typescript = if generics.params.is_empty() {
+            let name = ident.to_string();
+
+            quote! {
+                generator.define_type(#name, |generator| #typescript);
+
+                #name.to_owned()
+            }
+        } else {
+            typescript
+        };
+
+        (
+            typescript_where_predicates,
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::fortifier::zod::ToTypeScript for #ident #type_generics #typescript_where_clause {
+                    fn typescript(generator: &mut ::fortifier::zod::ZodGenerator) -> String {
+                        #typescript
+                    }
+                }
+
+                #( #typescript_definitions )*
+            },
+        )
+    };
+
     Some(ErrorType {
         variant_ident: variant_ident.clone(),
+        variant_attributes: TokenStream::new(),
         r#type: quote!(#ident #type_generics),
         generics: generic_arguments_or_params,
         where_predicates,
@@ -156,7 +278,7 @@ pub fn combined_error_type(
             #[derive(Debug, PartialEq)]
             #attributes
             #visibility enum #ident #type_generics #where_clause {
-                #( #variant_idents(#variant_types) ),*
+                #( #variant_attributes #variant_idents(#variant_types) ),*
             }
 
             #[automatically_derived]
@@ -171,5 +293,11 @@ pub fn combined_error_type(
 
             #( #definitions )*
         }),
+        #[cfg(feature = "zod")]
+        typescript: quote!(<#ident #type_generics as ::fortifier::zod::ToTypeScript>::typescript(generator)),
+        #[cfg(feature = "zod")]
+        typescript_where_predicates,
+        #[cfg(feature = "zod")]
+        typescript_definition: Some(typescript_definition),
     })
 }
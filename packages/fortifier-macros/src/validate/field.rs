@@ -8,9 +8,14 @@ use crate::{
         error::{ErrorType, error_type, format_error_ident_with_prefix},
         r#type::{KnownOrUnknown, should_validate_type},
     },
-    validation::{Execution, Validation},
+    validation::{Execution, Severity, Validation},
     validations::{
-        Custom, EmailAddress, Length, Nested, PhoneNumber, Range, Regex, Url, combine_validations,
+        Alphanumeric, Ascii, Base64, Charset, Cidr, Contains, CountryCode, CreditCard,
+        CurrencyCode, Custom, Decimal, Ean, EmailAddress, EndsWith, Finite, Future, Hex, Hostname,
+        Iban, Ip, Isbn, Json, LanguageTag, Length, MinAge, MultipleOf, Nested, NoControlCharacters,
+        NoneOf, NotBlank, NotContains, OneOf, OutputValidation, Password, Past, PastOrPresent,
+        PhoneNumber, Range, Regex, Semver, Slug, SocketAddr, StartsWith, TimeZone, Url, Uuid,
+        Within, combine_output_validations, combine_validations,
     },
 };
 
@@ -28,13 +33,28 @@ impl ToTokens for LiteralOrIdent {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub enum ValidateFieldPrefix {
+    #[default]
     None,
     SelfKeyword,
     F,
 }
 
+impl ValidateFieldPrefix {
+    /// Expression for a field, also used to access sibling fields.
+    pub fn expr(self, ident: &LiteralOrIdent) -> TokenStream {
+        match self {
+            ValidateFieldPrefix::None => ident.to_token_stream(),
+            ValidateFieldPrefix::SelfKeyword => quote!(self.#ident),
+            ValidateFieldPrefix::F => match ident {
+                LiteralOrIdent::Literal(literal) => format_ident!("f{literal}").to_token_stream(),
+                LiteralOrIdent::Ident(ident) => ident.to_token_stream(),
+            },
+        }
+    }
+}
+
 pub struct ValidateField<'a> {
     visibility: &'a Visibility,
     ident: LiteralOrIdent,
@@ -49,6 +69,7 @@ impl<'a> ValidateField<'a> {
         generics: &'a Generics,
         type_prefix: &Ident,
         ident: LiteralOrIdent,
+        field_prefix: ValidateFieldPrefix,
         field: &Field,
     ) -> Result<Self> {
         let error_ident = match &ident {
@@ -68,24 +89,165 @@ impl<'a> ValidateField<'a> {
 
         for attr in &field.attrs {
             if attr.path().is_ident("validate") {
+                let start = result.validations.len();
+                let mut severity = Severity::Error;
+
                 attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("custom") {
+                    if meta.path.is_ident("alphanumeric") {
+                        result
+                            .validations
+                            .push(Box::new(Alphanumeric::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("ascii") {
+                        result
+                            .validations
+                            .push(Box::new(Ascii::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("base64") {
+                        result
+                            .validations
+                            .push(Box::new(Base64::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("charset") {
+                        result
+                            .validations
+                            .push(Box::new(Charset::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("cidr") {
+                        result
+                            .validations
+                            .push(Box::new(Cidr::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("contains") {
+                        result
+                            .validations
+                            .push(Box::new(Contains::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("country_code") {
+                        result
+                            .validations
+                            .push(Box::new(CountryCode::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("credit_card") {
+                        result
+                            .validations
+                            .push(Box::new(CreditCard::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("currency_code") {
+                        result
+                            .validations
+                            .push(Box::new(CurrencyCode::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("custom") {
                         result
                             .validations
                             .push(Box::new(Custom::parse(&field.ty, &meta)?));
 
+                        Ok(())
+                    } else if meta.path.is_ident("decimal") {
+                        result
+                            .validations
+                            .push(Box::new(Decimal::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("ean") {
+                        result
+                            .validations
+                            .push(Box::new(Ean::parse(&field.ty, &meta)?));
+
                         Ok(())
                     } else if meta.path.is_ident("email_address") {
                         result
                             .validations
                             .push(Box::new(EmailAddress::parse(&field.ty, &meta)?));
 
+                        Ok(())
+                    } else if meta.path.is_ident("ends_with") {
+                        result
+                            .validations
+                            .push(Box::new(EndsWith::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("finite") {
+                        result
+                            .validations
+                            .push(Box::new(Finite::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("future") {
+                        result
+                            .validations
+                            .push(Box::new(Future::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("hex") {
+                        result
+                            .validations
+                            .push(Box::new(Hex::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("hostname") {
+                        result
+                            .validations
+                            .push(Box::new(Hostname::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("iban") {
+                        result
+                            .validations
+                            .push(Box::new(Iban::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("ip") {
+                        result
+                            .validations
+                            .push(Box::new(Ip::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("isbn") {
+                        result
+                            .validations
+                            .push(Box::new(Isbn::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("json") {
+                        result
+                            .validations
+                            .push(Box::new(Json::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("language_tag") {
+                        result
+                            .validations
+                            .push(Box::new(LanguageTag::parse(&field.ty, &meta)?));
+
                         Ok(())
                     } else if meta.path.is_ident("length") {
                         result
                             .validations
                             .push(Box::new(Length::parse(&field.ty, &meta)?));
 
+                        Ok(())
+                    } else if meta.path.is_ident("min_age") {
+                        result
+                            .validations
+                            .push(Box::new(MinAge::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("multiple_of") {
+                        result
+                            .validations
+                            .push(Box::new(MultipleOf::parse(&field.ty, &meta)?));
+
                         Ok(())
                     } else if meta.path.is_ident("nested") {
                         result
@@ -93,6 +255,54 @@ impl<'a> ValidateField<'a> {
                             .push(Box::new(Nested::parse(&field.ty, &meta)?));
                         skip_nested = true;
 
+                        Ok(())
+                    } else if meta.path.is_ident("no_control_characters") {
+                        result
+                            .validations
+                            .push(Box::new(NoControlCharacters::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("none_of") {
+                        result
+                            .validations
+                            .push(Box::new(NoneOf::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("not_blank") {
+                        result
+                            .validations
+                            .push(Box::new(NotBlank::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("not_contains") {
+                        result
+                            .validations
+                            .push(Box::new(NotContains::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("one_of") {
+                        result
+                            .validations
+                            .push(Box::new(OneOf::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("password") {
+                        result.validations.push(Box::new(
+                            Password::parse(&field.ty, &meta)?.with_field_prefix(field_prefix),
+                        ));
+
+                        Ok(())
+                    } else if meta.path.is_ident("past") {
+                        result
+                            .validations
+                            .push(Box::new(Past::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("past_or_present") {
+                        result
+                            .validations
+                            .push(Box::new(PastOrPresent::parse(&field.ty, &meta)?));
+
                         Ok(())
                     } else if meta.path.is_ident("phone_number") {
                         result
@@ -111,12 +321,58 @@ impl<'a> ValidateField<'a> {
                             .validations
                             .push(Box::new(Regex::parse(&field.ty, &meta)?));
 
+                        Ok(())
+                    } else if meta.path.is_ident("semver") {
+                        result
+                            .validations
+                            .push(Box::new(Semver::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("slug") {
+                        result
+                            .validations
+                            .push(Box::new(Slug::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("socket_addr") {
+                        result
+                            .validations
+                            .push(Box::new(SocketAddr::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("starts_with") {
+                        result
+                            .validations
+                            .push(Box::new(StartsWith::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("time_zone") {
+                        result
+                            .validations
+                            .push(Box::new(TimeZone::parse(&field.ty, &meta)?));
+
                         Ok(())
                     } else if meta.path.is_ident("url") {
                         result
                             .validations
                             .push(Box::new(Url::parse(&field.ty, &meta)?));
 
+                        Ok(())
+                    } else if meta.path.is_ident("uuid") {
+                        result
+                            .validations
+                            .push(Box::new(Uuid::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("within") {
+                        result
+                            .validations
+                            .push(Box::new(Within::parse(&field.ty, &meta)?));
+
+                        Ok(())
+                    } else if meta.path.is_ident("severity") {
+                        severity = Severity::parse(&meta)?;
+
                         Ok(())
                     } else if meta.path.is_ident("skip") {
                         skip_nested = true;
@@ -126,6 +382,9 @@ impl<'a> ValidateField<'a> {
                         Err(meta.error("unknown parameter"))
                     }
                 })?;
+
+                let validations = result.validations.split_off(start);
+                result.validations.extend(severity.apply(validations));
             }
         }
 
@@ -158,6 +417,14 @@ impl<'a> ValidateField<'a> {
         &self.error_ident
     }
 
+    #[cfg(feature = "zod")]
+    pub fn zod_checks(&self) -> Vec<TokenStream> {
+        self.validations
+            .iter()
+            .flat_map(|validation| validation.zod_checks())
+            .collect()
+    }
+
     pub fn error_type(&self, ident: &Ident) -> Option<ErrorType> {
         error_type(self.visibility, ident, &self.error_ident, &self.validations)
     }
@@ -165,19 +432,30 @@ impl<'a> ValidateField<'a> {
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         field_prefix: ValidateFieldPrefix,
     ) -> Vec<TokenStream> {
-        let ident = &self.ident;
+        let expr = self.expr(field_prefix);
 
-        let expr = match field_prefix {
-            ValidateFieldPrefix::None => self.ident.to_token_stream(),
-            ValidateFieldPrefix::SelfKeyword => quote!(self.#ident),
-            ValidateFieldPrefix::F => match &self.ident {
-                LiteralOrIdent::Literal(literal) => format_ident!("f{literal}").to_token_stream(),
-                LiteralOrIdent::Ident(ident) => ident.to_token_stream(),
-            },
-        };
+        combine_validations(
+            execution,
+            severity,
+            &self.error_type_ident,
+            &expr,
+            &self.validations,
+        )
+    }
+
+    pub fn output_validations(
+        &self,
+        field_prefix: ValidateFieldPrefix,
+    ) -> Result<(Vec<TokenStream>, Option<OutputValidation>)> {
+        let expr = self.expr(field_prefix);
+
+        combine_output_validations(&self.error_type_ident, &expr, &self.validations)
+    }
 
-        combine_validations(execution, &self.error_type_ident, &expr, &self.validations)
+    fn expr(&self, field_prefix: ValidateFieldPrefix) -> TokenStream {
+        field_prefix.expr(&self.ident)
     }
 }
@@ -11,7 +11,7 @@ use crate::{
         },
         field::{LiteralOrIdent, ValidateField, ValidateFieldPrefix},
     },
-    validation::{Execution, Validation},
+    validation::{Execution, Severity, Validation},
     validations::{combine_validations, combine_wrapped_validations, wrap_validations},
 };
 
@@ -26,14 +26,23 @@ impl<'a> ValidateFields<'a> {
         visibility: &'a Visibility,
         generics: &'a Generics,
         ident: Ident,
+        field_prefix: ValidateFieldPrefix,
         fields: &'a Fields,
     ) -> Result<Self> {
         Ok(match fields {
             Fields::Named(fields) => Self::Named(ValidateNamedFields::parse(
-                visibility, generics, ident, fields,
+                visibility,
+                generics,
+                ident,
+                field_prefix,
+                fields,
             )?),
             Fields::Unnamed(fields) => Self::Unnamed(ValidateUnnamedFields::parse(
-                visibility, generics, ident, fields,
+                visibility,
+                generics,
+                ident,
+                field_prefix,
+                fields,
             )?),
             Fields::Unit => Self::Unit(ValidateUnitFields::parse(visibility, generics, ident)?),
         })
@@ -53,9 +62,35 @@ impl<'a> ValidateFields<'a> {
         }
     }
 
+    pub fn fields(&self) -> &[ValidateField<'a>] {
+        match self {
+            ValidateFields::Named(named) => &named.fields,
+            ValidateFields::Unnamed(unnamed) => &unnamed.fields,
+            ValidateFields::Unit(_) => &[],
+        }
+    }
+
+    pub fn ident(&self) -> &Ident {
+        match self {
+            ValidateFields::Named(named) => &named.ident,
+            ValidateFields::Unnamed(unnamed) => &unnamed.ident,
+            ValidateFields::Unit(unit) => &unit.ident,
+        }
+    }
+
+    pub fn error_ident(&self) -> &Ident {
+        match self {
+            ValidateFields::Named(named) => &named.error_ident,
+            ValidateFields::Unnamed(unnamed) => &unnamed.error_ident,
+            ValidateFields::Unit(unit) => &unit.error_ident,
+        }
+    }
+
+    #[expect(clippy::too_many_arguments)]
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         field_prefix: ValidateFieldPrefix,
         error_wrapper: &impl Fn(TokenStream) -> TokenStream,
         root_type_prefix: &Ident,
@@ -65,6 +100,7 @@ impl<'a> ValidateFields<'a> {
         match self {
             ValidateFields::Named(named) => named.validations(
                 execution,
+                severity,
                 field_prefix,
                 error_wrapper,
                 root_type_prefix,
@@ -73,6 +109,7 @@ impl<'a> ValidateFields<'a> {
             ),
             ValidateFields::Unnamed(unnamed) => unnamed.validations(
                 execution,
+                severity,
                 field_prefix,
                 error_wrapper,
                 root_type_prefix,
@@ -81,6 +118,7 @@ impl<'a> ValidateFields<'a> {
             ),
             ValidateFields::Unit(unit) => unit.validations(
                 execution,
+                severity,
                 field_prefix,
                 error_wrapper,
                 root_type_prefix,
@@ -104,6 +142,7 @@ impl<'a> ValidateNamedFields<'a> {
         visibility: &'a Visibility,
         generics: &'a Generics,
         ident: Ident,
+        field_prefix: ValidateFieldPrefix,
         fields: &'a FieldsNamed,
     ) -> Result<Self> {
         let error_ident = format_error_ident(&ident);
@@ -126,6 +165,7 @@ impl<'a> ValidateNamedFields<'a> {
                 generics,
                 &result.ident,
                 LiteralOrIdent::Ident(field_ident.clone()),
+                field_prefix,
                 field,
             )?);
         }
@@ -153,9 +193,11 @@ impl<'a> ValidateNamedFields<'a> {
         )
     }
 
+    #[expect(clippy::too_many_arguments)]
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         field_prefix: ValidateFieldPrefix,
         error_wrapper: &impl Fn(TokenStream) -> TokenStream,
         root_type_prefix: &Ident,
@@ -164,6 +206,7 @@ impl<'a> ValidateNamedFields<'a> {
     ) -> TokenStream {
         validations(
             execution,
+            severity,
             field_prefix,
             &self.error_ident,
             error_wrapper,
@@ -188,6 +231,7 @@ impl<'a> ValidateUnnamedFields<'a> {
         visibility: &'a Visibility,
         generics: &'a Generics,
         ident: Ident,
+        field_prefix: ValidateFieldPrefix,
         fields: &'a FieldsUnnamed,
     ) -> Result<Self> {
         let error_ident = format_error_ident(&ident);
@@ -206,6 +250,7 @@ impl<'a> ValidateUnnamedFields<'a> {
                 generics,
                 &result.ident,
                 LiteralOrIdent::Literal(Literal::usize_unsuffixed(index)),
+                field_prefix,
                 field,
             )?);
         }
@@ -233,9 +278,11 @@ impl<'a> ValidateUnnamedFields<'a> {
         )
     }
 
+    #[expect(clippy::too_many_arguments)]
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         field_prefix: ValidateFieldPrefix,
         error_wrapper: &impl Fn(TokenStream) -> TokenStream,
         root_type_prefix: &Ident,
@@ -244,6 +291,7 @@ impl<'a> ValidateUnnamedFields<'a> {
     ) -> TokenStream {
         validations(
             execution,
+            severity,
             field_prefix,
             &self.error_ident,
             error_wrapper,
@@ -290,9 +338,11 @@ impl<'a> ValidateUnitFields<'a> {
         )
     }
 
+    #[expect(clippy::too_many_arguments)]
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         field_prefix: ValidateFieldPrefix,
         error_wrapper: &impl Fn(TokenStream) -> TokenStream,
         root_type_prefix: &Ident,
@@ -304,6 +354,7 @@ impl<'a> ValidateUnitFields<'a> {
         } else {
             validations(
                 execution,
+                severity,
                 field_prefix,
                 &self.error_ident,
                 error_wrapper,
@@ -340,6 +391,7 @@ fn error_type<'a>(
 #[expect(clippy::too_many_arguments)]
 fn validations<'a>(
     execution: Execution,
+    severity: Severity,
     field_prefix: ValidateFieldPrefix,
     error_ident: &Ident,
     error_wrapper: &impl Fn(TokenStream) -> TokenStream,
@@ -348,24 +400,64 @@ fn validations<'a>(
     root_error_ident: &Ident,
     root_validations: &[Box<dyn Validation>],
 ) -> TokenStream {
-    let root_validations = wrap_validations(
+    let validations = field_statements(
+        execution,
+        severity,
+        field_prefix,
+        error_ident,
+        error_wrapper,
+        fields,
+    );
+    let root_validations = root_statements(
+        execution,
+        severity,
+        error_ident,
+        error_wrapper,
+        root_type_prefix,
+        root_error_ident,
+        root_validations,
+    );
+
+    combine_wrapped_validations(validations.into_iter().chain(root_validations).collect())
+}
+
+pub fn field_statements<'a>(
+    execution: Execution,
+    severity: Severity,
+    field_prefix: ValidateFieldPrefix,
+    error_ident: &Ident,
+    error_wrapper: &impl Fn(TokenStream) -> TokenStream,
+    fields: impl Iterator<Item = &'a ValidateField<'a>>,
+) -> Vec<TokenStream> {
+    fields
+        .flat_map(|field| {
+            let field_error_ident = field.error_ident();
+            let validations = field.validations(execution, severity, field_prefix);
+
+            wrap_validations(error_ident, field_error_ident, error_wrapper, validations)
+        })
+        .collect()
+}
+
+pub fn root_statements(
+    execution: Execution,
+    severity: Severity,
+    error_ident: &Ident,
+    error_wrapper: &impl Fn(TokenStream) -> TokenStream,
+    root_type_prefix: &Ident,
+    root_error_ident: &Ident,
+    root_validations: &[Box<dyn Validation>],
+) -> Vec<TokenStream> {
+    wrap_validations(
         error_ident,
         root_error_ident,
         error_wrapper,
         combine_validations(
             execution,
+            severity,
             &format_error_ident_with_prefix(root_type_prefix, root_error_ident),
             &quote!(self),
             root_validations,
         ),
-    );
-
-    let validations = fields.flat_map(|field| {
-        let field_error_ident = field.error_ident();
-        let validations = field.validations(execution, field_prefix);
-
-        wrap_validations(error_ident, field_error_ident, error_wrapper, validations)
-    });
-
-    combine_wrapped_validations(validations.chain(root_validations).collect::<Vec<_>>())
+    )
 }
@@ -0,0 +1,228 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    DataStruct, Error, Fields, Generics, Ident, Path, Result, Visibility, meta::ParseNestedMeta,
+    token,
+};
+
+use crate::{
+    util::count_options,
+    validate::{
+        error::format_error_ident_with_prefix, field::ValidateFieldPrefix, fields::ValidateFields,
+    },
+    validation::{Execution, Severity, Validation},
+    validations::{combine_validations, wrap_validations},
+};
+
+pub struct ValidateOutput {
+    ident: Ident,
+    derives: Vec<Path>,
+}
+
+impl ValidateOutput {
+    pub fn parse(ident: &Ident, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = ValidateOutput {
+            ident: format_ident!("{ident}Output"),
+            derives: vec![],
+        };
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("derive") {
+                    meta.parse_nested_meta(|meta| {
+                        result.derives.push(meta.path);
+
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("ident") {
+                    result.ident = meta.value()?.parse()?;
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    pub fn to_tokens(
+        &self,
+        visibility: &Visibility,
+        ident: &Ident,
+        generics: &Generics,
+        data: &DataStruct,
+        fields: &ValidateFields,
+        where_clause: &TokenStream,
+        context: bool,
+        root_type_prefix: &Ident,
+        root_error_ident: &Ident,
+        root_validations: &[Box<dyn Validation>],
+    ) -> Result<TokenStream> {
+        let error_ident = fields.error_ident();
+        let error_wrapper = |tokens| tokens;
+
+        let mut statements = vec![];
+        let mut output_fields = vec![];
+        let mut output_values = vec![];
+
+        for (index, (field, validate_field)) in data.fields.iter().zip(fields.fields()).enumerate()
+        {
+            let member = validate_field.ident();
+            let field_visibility = &field.vis;
+            let (validations, output) = validate_field
+                .output_validations(ValidateFieldPrefix::SelfKeyword)
+                .map_err(|error| Error::new_spanned(field, error))?;
+
+            let mut validations = wrap_validations(
+                error_ident,
+                validate_field.error_ident(),
+                &error_wrapper,
+                validations,
+            );
+
+            let (r#type, value) = if let Some(output) = output {
+                let variable = format_ident!("output_{index}");
+                let field_error_ident = validate_field.error_ident();
+                let expr = output.expr;
+                let error = error_wrapper(quote!(#error_ident::#field_error_ident(err)));
+
+                validations.insert(
+                    output.index,
+                    quote! {
+                        let #variable = match #expr {
+                            Ok(value) => value,
+                            Err(err) => {
+                                errors.push(#error);
+                                None
+                            }
+                        };
+                    },
+                );
+
+                let output_type = output.r#type;
+
+                if count_options(&field.ty) > 0 {
+                    (quote!(Option<#output_type>), quote!(#variable))
+                } else {
+                    (
+                        output_type,
+                        quote!(#variable.expect("value should be present")),
+                    )
+                }
+            } else {
+                (field.ty.to_token_stream(), quote!(self.#member))
+            };
+
+            statements.extend(validations);
+
+            match &field.ident {
+                Some(field_ident) => {
+                    output_fields.push(quote!(#field_visibility #field_ident: #r#type));
+                    output_values.push(quote!(#field_ident: #value));
+                }
+                None => {
+                    output_fields.push(quote!(#field_visibility #r#type));
+                    output_values.push(value);
+                }
+            }
+        }
+
+        statements.extend(wrap_validations(
+            error_ident,
+            root_error_ident,
+            &error_wrapper,
+            combine_validations(
+                Execution::Sync,
+                Severity::Error,
+                &format_error_ident_with_prefix(root_type_prefix, root_error_ident),
+                &quote!(self),
+                root_validations,
+            ),
+        ));
+
+        let output_ident = &self.ident;
+        let (impl_generics, type_generics, _) = generics.split_for_impl();
+        let definition_where_clause = &generics.where_clause;
+
+        let derives = (!self.derives.is_empty()).then(|| {
+            let derives = &self.derives;
+
+            quote!(#[derive( #( #derives ),* )])
+        });
+        let (definition, value) = match &data.fields {
+            Fields::Named(_) => (
+                quote! {
+                    #visibility struct #output_ident #generics #definition_where_clause {
+                        #( #output_fields, )*
+                    }
+                },
+                quote!(#output_ident { #( #output_values, )* }),
+            ),
+            Fields::Unnamed(_) => (
+                quote! {
+                    #visibility struct #output_ident #generics ( #( #output_fields, )* ) #definition_where_clause;
+                },
+                quote!(#output_ident( #( #output_values, )* )),
+            ),
+            Fields::Unit => (
+                quote! {
+                    #visibility struct #output_ident #generics #definition_where_clause;
+                },
+                quote!(#output_ident),
+            ),
+        };
+
+        let body = (!statements.is_empty()).then(|| {
+            quote! {
+                let mut errors = vec![];
+
+                #( #statements )*
+
+                if !errors.is_empty() {
+                    return Err(errors.into());
+                }
+            }
+        });
+
+        let no_context_impl = (!context).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::fortifier::ValidateOutput for #ident #type_generics #where_clause {}
+            }
+        });
+
+        Ok(quote! {
+            #derives
+            #definition
+
+            #[automatically_derived]
+            impl #impl_generics ::fortifier::ValidateOutputWithContext for #ident #type_generics #where_clause {
+                type Output = #output_ident #type_generics;
+
+                fn validate_into_output_with_context(self, context: &Self::Context) -> ::std::pin::Pin<Box<impl Future<Output = Result<Self::Output, ::fortifier::ValidationErrors<Self::Error>>> + Send>>
+                where
+                    Self: Send + Sync,
+                {
+                    Box::pin(async move {
+                        #body
+
+                        ::fortifier::ValidateWithContext::validate_async_with_context(&self, context).await?;
+
+                        Ok(#value)
+                    })
+                }
+
+                fn validate_into_output_sync_with_context(self, context: &Self::Context) -> Result<Self::Output, ::fortifier::ValidationErrors<Self::Error>> {
+                    #body
+
+                    Ok(#value)
+                }
+            }
+
+            #no_context_impl
+        })
+    }
+}
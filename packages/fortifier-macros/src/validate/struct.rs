@@ -3,25 +3,36 @@ use syn::{DataStruct, DeriveInput, Ident, Result};
 
 use crate::{
     validate::{error::ErrorType, field::ValidateFieldPrefix, fields::ValidateFields},
-    validation::{Execution, Validation},
+    validation::{Execution, Severity, Validation},
 };
 
 pub struct ValidateStruct<'a> {
+    data: &'a DataStruct,
     fields: ValidateFields<'a>,
 }
 
 impl<'a> ValidateStruct<'a> {
     pub fn parse(input: &'a DeriveInput, data: &'a DataStruct) -> Result<Self> {
         Ok(ValidateStruct {
+            data,
             fields: ValidateFields::parse(
                 &input.vis,
                 &input.generics,
                 input.ident.clone(),
+                ValidateFieldPrefix::SelfKeyword,
                 &data.fields,
             )?,
         })
     }
 
+    pub fn data(&self) -> &'a DataStruct {
+        self.data
+    }
+
+    pub fn fields(&self) -> &ValidateFields<'a> {
+        &self.fields
+    }
+
     pub fn error_type(&self, root_error_type: Option<&ErrorType>) -> Option<ErrorType> {
         self.fields.error_type(None, root_error_type)
     }
@@ -29,6 +40,7 @@ impl<'a> ValidateStruct<'a> {
     pub fn validations(
         &self,
         execution: Execution,
+        severity: Severity,
         root_type_prefix: &Ident,
         root_error_ident: &Ident,
         root_validations: &[Box<dyn Validation>],
@@ -37,6 +49,7 @@ impl<'a> ValidateStruct<'a> {
 
         self.fields.validations(
             execution,
+            severity,
             ValidateFieldPrefix::SelfKeyword,
             &error_wrapper,
             root_type_prefix,
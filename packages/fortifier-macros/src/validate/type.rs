@@ -118,13 +118,15 @@ const BUILT_IN_TYPES: [&str; 40] = [
 ];
 
 /// Container types.
-const CONTAINER_TYPES: [&str; 12] = [
+const CONTAINER_TYPES: [&str; 14] = [
     "Arc",
     "NonZero",
     "Option",
     "Rc",
     "Saturating",
+    "Validated",
     "Wrapping",
+    "fortifier::Validated",
     "std::num::NonZero",
     "std::num::Saturating",
     "std::num::Wrapping",
@@ -162,7 +164,7 @@ const KEYED_CONTAINER_TYPES: [&str; 6] = [
 /// Ecosystem types.
 ///
 /// De facto standard types.
-const ECOSYSTEM_TYPES: [&str; 65] = [
+const ECOSYSTEM_TYPES: [&str; 76] = [
     "Date",
     "DateTime",
     "Days",
@@ -187,12 +189,16 @@ const ECOSYSTEM_TYPES: [&str; 65] = [
     "OrderedFloat",
     "PrimitiveDateTime",
     "Regex",
+    "SignedDuration",
+    "Span",
     "Time",
     "TimeDelta",
+    "Timestamp",
     "UtcDateTime",
     "Uuid",
     "Weekday",
     "WeekdaySet",
+    "Zoned",
     "chrono::Date",
     "chrono::DateTime",
     "chrono::Days",
@@ -216,6 +222,13 @@ const ECOSYSTEM_TYPES: [&str; 65] = [
     "fixed::FixedU32",
     "fixed::FixedU64",
     "fixed::FixedU128",
+    "jiff::SignedDuration",
+    "jiff::Span",
+    "jiff::Timestamp",
+    "jiff::Zoned",
+    "jiff::civil::Date",
+    "jiff::civil::DateTime",
+    "jiff::civil::Time",
     "ordered_float::OrderedFloat",
     "regex::Regex",
     "rust_decimal::Decimal",
@@ -353,15 +366,20 @@ fn should_validate_path(generics: &Generics, path: &Path) -> Option<ValidateResu
         });
     }
 
-    // TODO: Determine error type.
-    if KEYED_CONTAINER_TYPES.contains(&path_string)
-        && let Some(segment) = path.segments.last()
-        && let PathArguments::AngleBracketed(arguments) = &segment.arguments
-        && !arguments
-            .args
-            .iter()
-            .all(|arg| should_validate_generic_argument(generics, arg).is_some())
-    {
+    if KEYED_CONTAINER_TYPES.contains(&path_string) {
+        if let Some(segment) = path.segments.last()
+            && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+            && let Some(GenericArgument::Type(key_type)) = arguments.args.first()
+            && let Some(argument) = arguments.args.get(1)
+        {
+            return should_validate_generic_argument(generics, argument).map(|mut result| {
+                result.error_type = result.error_type.map(
+                    |error_type| quote!(::fortifier::KeyedValidationError<#key_type, #error_type>),
+                );
+                result
+            });
+        }
+
         return None;
     }
 
@@ -482,6 +500,7 @@ mod tests {
                     .replace(" <", "<")
                     .replace("> ", ">")
                     .replace(" >", ">")
+                    .replace(" ,", ",")
                     .trim()
                     .to_string()
             })
@@ -490,8 +509,6 @@ mod tests {
 
     #[test]
     fn should_validate() {
-        // TODO: Keyed error types.
-
         assert_eq!(
             validate(quote!(&T)),
             Some(KnownOrUnknown::Known("TValidationError".to_owned()))
@@ -508,6 +525,14 @@ mod tests {
             validate(quote!(T<u8, u8>)),
             Some(KnownOrUnknown::Known("TValidationError".to_owned()))
         );
+        assert_eq!(
+            validate(quote!(Validated<T>)),
+            Some(KnownOrUnknown::Known("TValidationError".to_owned()))
+        );
+        assert_eq!(
+            validate(quote!(fortifier::Validated<T>)),
+            Some(KnownOrUnknown::Known("TValidationError".to_owned()))
+        );
 
         assert_eq!(validate(quote!((T, T))), Some(KnownOrUnknown::Unknown));
         assert_eq!(validate(quote!((A, B, C))), Some(KnownOrUnknown::Unknown));
@@ -559,36 +584,36 @@ mod tests {
                 "::fortifier::IndexedValidationError<TValidationError>".to_owned()
             ))
         );
-        // assert_eq!(
-        //     validate(quote!(BTreeMap<K, V>)),
-        //     Some(KnownOrUnknown::Known(
-        //         "::fortifier::KeyedValidationError<K, TValidationError>".to_owned()
-        //     ))
-        // );
+        assert_eq!(
+            validate(quote!(BTreeMap<K, T>)),
+            Some(KnownOrUnknown::Known(
+                "::fortifier::KeyedValidationError<K, TValidationError>".to_owned()
+            ))
+        );
         assert_eq!(
             validate(quote!(IndexSet<T>)),
             Some(KnownOrUnknown::Known(
                 "::fortifier::IndexedValidationError<TValidationError>".to_owned()
             ))
         );
-        // assert_eq!(
-        //     validate(quote!(IndexMap<K, V>)),
-        //     Some(KnownOrUnknown::Known(
-        //         "::fortifier::KeyedValidationError<K, TValidationError>".to_owned()
-        //     ))
-        // );
+        assert_eq!(
+            validate(quote!(IndexMap<K, T>)),
+            Some(KnownOrUnknown::Known(
+                "::fortifier::KeyedValidationError<K, TValidationError>".to_owned()
+            ))
+        );
         assert_eq!(
             validate(quote!(HashSet<T>)),
             Some(KnownOrUnknown::Known(
                 "::fortifier::IndexedValidationError<TValidationError>".to_owned()
             ))
         );
-        // assert_eq!(
-        //     validate(quote!(HashMap<K, V>)),
-        //     Some(KnownOrUnknown::Known(
-        //         "::fortifier::KeyedValidationError<K, TValidationError>".to_owned()
-        //     ))
-        // );
+        assert_eq!(
+            validate(quote!(HashMap<K, T>)),
+            Some(KnownOrUnknown::Known(
+                "::fortifier::KeyedValidationError<K, TValidationError>".to_owned()
+            ))
+        );
         assert_eq!(
             validate(quote!(LinkedList<T>)),
             Some(KnownOrUnknown::Known(
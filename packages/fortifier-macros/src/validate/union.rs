@@ -3,7 +3,7 @@ use syn::{DataUnion, DeriveInput, Ident, Result};
 
 use crate::{
     validate::error::ErrorType,
-    validation::{Execution, Validation},
+    validation::{Execution, Severity, Validation},
 };
 
 pub struct ValidateUnion {}
@@ -20,6 +20,7 @@ impl ValidateUnion {
     pub fn validations(
         &self,
         _execution: Execution,
+        _severity: Severity,
         _root_type_prefix: &Ident,
         _root_error_ident: &Ident,
         _root_validations: &[Box<dyn Validation>],
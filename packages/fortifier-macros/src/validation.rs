@@ -1,7 +1,9 @@
 use proc_macro2::TokenStream;
-use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+#[cfg(feature = "zod")]
+use quote::quote;
+use syn::{Error, Ident, Result, Type, meta::ParseNestedMeta};
 
-use crate::generics::Generic;
+use crate::{generics::Generic, validations::Warning};
 
 #[derive(Clone, Copy)]
 pub enum Execution {
@@ -9,6 +11,37 @@ pub enum Execution {
     Async,
 }
 
+#[derive(Clone, Copy)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn parse(meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let ident: Ident = meta.value()?.parse()?;
+
+        if ident == "error" {
+            Ok(Severity::Error)
+        } else if ident == "warning" {
+            Ok(Severity::Warning)
+        } else {
+            Err(Error::new_spanned(ident, "expected `error` or `warning`"))
+        }
+    }
+
+    /// Apply the severity to validations.
+    pub fn apply(self, validations: Vec<Box<dyn Validation>>) -> Vec<Box<dyn Validation>> {
+        match self {
+            Severity::Error => validations,
+            Severity::Warning => validations
+                .into_iter()
+                .map(|validation| Box::new(Warning::new(validation)) as Box<dyn Validation>)
+                .collect(),
+        }
+    }
+}
+
 pub trait Validation {
     fn parse(_type: &Type, _meta: &ParseNestedMeta<'_>) -> Result<Self>
     where
@@ -18,9 +51,45 @@ pub trait Validation {
 
     fn error_type(&self) -> TokenStream;
 
+    fn error_attributes(&self) -> TokenStream {
+        TokenStream::new()
+    }
+
     fn error_generics(&self) -> Vec<Generic>;
 
     fn error_where_predicates(&self) -> Vec<TokenStream>;
 
+    #[cfg(feature = "zod")]
+    fn error_typescript(&self) -> TokenStream {
+        let error_type = self.error_type();
+
+        quote!(<#error_type as ::fortifier::zod::ToTypeScript>::typescript(generator))
+    }
+
+    #[cfg(feature = "zod")]
+    fn error_typescript_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
     fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream>;
+
+    /// Expression which reports warnings, e.g. for validations with warning severity.
+    fn warning_expr(&self, _execution: Execution, _expr: &TokenStream) -> Option<TokenStream> {
+        None
+    }
+
+    /// Parsed output type, for validations which parse the value.
+    fn output_type(&self) -> Option<TokenStream> {
+        None
+    }
+
+    /// Expression which validates and parses the value into `Result<Option<Output>, Error>`.
+    fn output_expr(&self, _expr: &TokenStream) -> Option<TokenStream> {
+        None
+    }
 }
@@ -1,29 +1,82 @@
+mod base64;
+mod cidr;
+mod content;
+mod country_code;
+mod credit_card;
+mod currency_code;
 mod custom;
+mod date;
+mod disallow;
+mod ean;
 mod email_address;
+mod hex;
+mod hostname;
+mod iban;
+mod ip;
+mod isbn;
+mod json;
+mod language_tag;
 mod length;
 mod nested;
+mod none_of;
+mod numeric;
+mod one_of;
+mod password;
 mod phone_number;
 mod range;
 mod regex;
+mod semver;
+mod slug;
+mod socket_addr;
+mod time_zone;
 mod url;
+mod uuid;
+mod warning;
 
+pub use base64::*;
+pub use cidr::*;
+pub use content::*;
+pub use country_code::*;
+pub use credit_card::*;
+pub use currency_code::*;
 pub use custom::*;
+pub use date::*;
+pub use disallow::*;
+pub use ean::*;
 pub use email_address::*;
+pub use hex::*;
+pub use hostname::*;
+pub use iban::*;
+pub use ip::*;
+pub use isbn::*;
+pub use json::*;
+pub use language_tag::*;
 pub use length::*;
 pub use nested::*;
+pub use none_of::*;
+pub use numeric::*;
+pub use one_of::*;
+pub use password::*;
 pub use phone_number::*;
 pub use range::*;
 pub use regex::*;
+pub use semver::*;
+pub use slug::*;
+pub use socket_addr::*;
+pub use time_zone::*;
 pub use url::*;
+pub use uuid::*;
+pub use warning::*;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::Ident;
+use syn::{Error, Ident, Result};
 
-use crate::validation::{Execution, Validation};
+use crate::validation::{Execution, Severity, Validation};
 
 pub fn combine_validations(
     execution: Execution,
+    severity: Severity,
     error_type_ident: &Ident,
     expr: &TokenStream,
     validations: &[Box<dyn Validation>],
@@ -32,7 +85,10 @@ pub fn combine_validations(
         .iter()
         .flat_map(|validation| {
             let validation_ident = validation.ident();
-            let expr = validation.expr(execution, expr);
+            let expr = match severity {
+                Severity::Error => validation.expr(execution, expr),
+                Severity::Warning => validation.warning_expr(execution, expr),
+            };
 
             expr.map(|expr| {
                 if validations.len() > 1 {
@@ -47,6 +103,58 @@ pub fn combine_validations(
         .collect()
 }
 
+pub struct OutputValidation {
+    /// Number of preceding validations, to preserve the order of errors.
+    pub index: usize,
+    pub r#type: TokenStream,
+    pub expr: TokenStream,
+}
+
+/// Combine synchronous validations, where the validation which parses the value provides the output.
+pub fn combine_output_validations(
+    error_type_ident: &Ident,
+    expr: &TokenStream,
+    validations: &[Box<dyn Validation>],
+) -> Result<(Vec<TokenStream>, Option<OutputValidation>)> {
+    let wrap = |validation: &dyn Validation, expr: TokenStream| {
+        let validation_ident = validation.ident();
+
+        if validations.len() > 1 {
+            quote! {
+                #expr.map_err(#error_type_ident::#validation_ident)
+            }
+        } else {
+            expr
+        }
+    };
+
+    let mut exprs = vec![];
+    let mut output: Option<OutputValidation> = None;
+
+    for validation in validations {
+        if let (Some(r#type), Some(output_expr)) =
+            (validation.output_type(), validation.output_expr(expr))
+        {
+            if output.is_some() {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "output supports only one parsing validation per field",
+                ));
+            }
+
+            output = Some(OutputValidation {
+                index: exprs.len(),
+                r#type,
+                expr: wrap(validation.as_ref(), output_expr),
+            });
+        } else if let Some(expr) = validation.expr(Execution::Sync, expr) {
+            exprs.push(wrap(validation.as_ref(), expr));
+        }
+    }
+
+    Ok((exprs, output))
+}
+
 pub fn wrap_validations(
     error_ident: &Ident,
     error_field_ident: &Ident,
@@ -0,0 +1,93 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::parse_flag,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Base64 {
+    url_safe: bool,
+    padding: Option<bool>,
+}
+
+impl Validation for Base64 {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = Base64::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("url_safe") {
+                    result.url_safe = parse_flag(&meta)?;
+
+                    Ok(())
+                } else if meta.path.is_ident("padding") {
+                    result.padding = Some(parse_flag(&meta)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Base64")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::Base64Error)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        let alphabet = if self.url_safe {
+            "[A-Za-z0-9_-]"
+        } else {
+            "[A-Za-z0-9+/]"
+        };
+        let last = match self.padding {
+            Some(true) => format!("{alphabet}{{2}}==|{alphabet}{{3}}="),
+            Some(false) => format!("{alphabet}{{2,3}}"),
+            None => format!("{alphabet}{{2}}(==)?|{alphabet}{{3}}=?"),
+        };
+        let pattern = format!("^({alphabet}{{4}})*({last})?$");
+
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(#pattern.to_owned()))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let url_safe = self.url_safe;
+        let padding = match self.padding {
+            Some(padding) => quote!(Some(#padding)),
+            None => quote!(None),
+        };
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateBase64::validate_base64(
+                    &#expr,
+                    ::fortifier::Base64Options {
+                        url_safe: #url_safe,
+                        padding: #padding,
+                    },
+                )
+            }),
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+    validations::IpOptions,
+};
+
+pub struct Cidr {
+    options: IpOptions,
+}
+
+impl Validation for Cidr {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Ok(Cidr {
+            options: IpOptions::parse(meta)?,
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Cidr")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::CidrError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        let pattern = format!(r"^{}/\d{{1,3}}$", self.options.pattern());
+
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(#pattern.to_owned()))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let options = self.options.to_expr();
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateCidr::validate_cidr(&#expr, #options)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Expr, Ident, LitBool, LitStr, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+fn parse_parameter<T: syn::parse::Parse>(meta: &ParseNestedMeta<'_>, name: &str) -> Result<T> {
+    let mut parameter = None;
+
+    if let Ok(value) = meta.value() {
+        parameter = Some(value.parse()?);
+    } else {
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                parameter = Some(meta.value()?.parse()?);
+
+                Ok(())
+            } else {
+                Err(meta.error("unknown parameter"))
+            }
+        })?;
+    }
+
+    parameter.ok_or_else(|| meta.error(format!("missing {name} parameter")))
+}
+
+fn parse_empty(meta: &ParseNestedMeta<'_>) -> Result<()> {
+    if meta.input.peek(token::Paren) {
+        meta.parse_nested_meta(|meta| Err(meta.error("unknown parameter")))?;
+    }
+
+    Ok(())
+}
+
+macro_rules! content_validation {
+    ($ident:ident, $parameter:literal, $method:ident) => {
+        pub struct $ident {
+            expression: Expr,
+        }
+
+        impl Validation for $ident {
+            fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+                Ok($ident {
+                    expression: parse_parameter(meta, $parameter)?,
+                })
+            }
+
+            fn ident(&self) -> Ident {
+                format_ident!(stringify!($ident))
+            }
+
+            fn error_type(&self) -> TokenStream {
+                quote!(::fortifier::ContentError)
+            }
+
+            fn error_generics(&self) -> Vec<Generic> {
+                vec![]
+            }
+
+            fn error_where_predicates(&self) -> Vec<TokenStream> {
+                vec![]
+            }
+
+            fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+                match execution {
+                    Execution::Sync => {
+                        let expression = &self.expression;
+
+                        Some(quote! {
+                            ::fortifier::ValidateContent::$method(&#expr, &#expression)
+                        })
+                    }
+                    Execution::Async => None,
+                }
+            }
+        }
+    };
+    ($ident:ident, $method:ident) => {
+        pub struct $ident;
+
+        impl Validation for $ident {
+            fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+                parse_empty(meta)?;
+
+                Ok($ident)
+            }
+
+            fn ident(&self) -> Ident {
+                format_ident!(stringify!($ident))
+            }
+
+            fn error_type(&self) -> TokenStream {
+                quote!(::fortifier::ContentError)
+            }
+
+            fn error_generics(&self) -> Vec<Generic> {
+                vec![]
+            }
+
+            fn error_where_predicates(&self) -> Vec<TokenStream> {
+                vec![]
+            }
+
+            fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+                match execution {
+                    Execution::Sync => Some(quote! {
+                        ::fortifier::ValidateContent::$method(&#expr)
+                    }),
+                    Execution::Async => None,
+                }
+            }
+        }
+    };
+}
+
+content_validation!(Contains, "pattern", validate_contains);
+content_validation!(NotContains, "pattern", validate_not_contains);
+content_validation!(StartsWith, "prefix", validate_starts_with);
+content_validation!(EndsWith, "suffix", validate_ends_with);
+content_validation!(Ascii, validate_ascii);
+content_validation!(Alphanumeric, validate_alphanumeric);
+content_validation!(NotBlank, validate_not_blank);
+
+#[derive(Default)]
+pub struct NoControlCharacters {
+    allow_whitespace: bool,
+}
+
+impl Validation for NoControlCharacters {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = NoControlCharacters::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("allow_whitespace") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_whitespace = lit.value;
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("NoControlCharacters")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::ContentError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let allow_whitespace = self.allow_whitespace;
+
+                Some(quote! {
+                    ::fortifier::ValidateContent::validate_no_control_characters(&#expr, #allow_whitespace)
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
+
+pub struct Charset {
+    charset: LitStr,
+}
+
+impl Validation for Charset {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let charset: LitStr = parse_parameter(meta, "charset")?;
+        let value = charset.value();
+
+        if value.is_empty() {
+            return Err(Error::new_spanned(&charset, "empty character set"));
+        }
+
+        let chars = value.chars().collect::<Vec<_>>();
+        let mut index = 0;
+        while index < chars.len() {
+            if index + 2 < chars.len() && chars[index + 1] == '-' {
+                if chars[index] > chars[index + 2] {
+                    return Err(Error::new_spanned(
+                        &charset,
+                        format!(
+                            "invalid character range `{}-{}`",
+                            chars[index],
+                            chars[index + 2]
+                        ),
+                    ));
+                }
+
+                index += 3;
+            } else {
+                index += 1;
+            }
+        }
+
+        Ok(Charset { charset })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Charset")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::ContentError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let charset = &self.charset;
+
+                Some(quote! {
+                    ::fortifier::ValidateContent::validate_charset(&#expr, #charset)
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::parse_expr_or_array,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct CountryCode {
+    alpha3: bool,
+    allowed_countries: Option<TokenStream>,
+}
+
+impl Validation for CountryCode {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = CountryCode::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("alpha2") {
+                    result.alpha3 = false;
+
+                    Ok(())
+                } else if meta.path.is_ident("alpha3") {
+                    result.alpha3 = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("allowed_countries") {
+                    result.allowed_countries = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("CountryCode")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::CountryCodeError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        let pattern = if self.alpha3 {
+            "^[A-Z]{3}$"
+        } else {
+            "^[A-Z]{2}$"
+        };
+
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(#pattern.to_owned()))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(self.call(format_ident!("validate_country_code"), expr)),
+            Execution::Async => None,
+        }
+    }
+
+    fn output_type(&self) -> Option<TokenStream> {
+        Some(quote!(::fortifier::PhoneNumberCountry))
+    }
+
+    fn output_expr(&self, expr: &TokenStream) -> Option<TokenStream> {
+        Some(self.call(format_ident!("parse_country_code"), expr))
+    }
+}
+
+impl CountryCode {
+    fn call(&self, method: Ident, expr: &TokenStream) -> TokenStream {
+        let format = if self.alpha3 {
+            quote!(::fortifier::CountryCodeFormat::Alpha3)
+        } else {
+            quote!(::fortifier::CountryCodeFormat::Alpha2)
+        };
+        let allowed_countries = match &self.allowed_countries {
+            Some(allowed_countries) => quote!(Some(
+                ::std::convert::AsRef::<[::fortifier::PhoneNumberCountry]>::as_ref(
+                    &#allowed_countries
+                )
+            )),
+            None => quote!(None),
+        };
+
+        quote! {
+            ::fortifier::ValidateCountryCode::#method(&#expr, #format, #allowed_countries)
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, Ident, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct CreditCard {
+    allowed_brands: Option<Expr>,
+}
+
+impl Validation for CreditCard {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = CreditCard::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("allowed_brands") {
+                    let expr: Expr = meta.value()?.parse()?;
+                    result.allowed_brands = Some(expr);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("CreditCard")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::CreditCardError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(
+            "^[0-9 -]+$".to_owned()
+        ))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(self.call(format_ident!("validate_credit_card"), expr)),
+            Execution::Async => None,
+        }
+    }
+
+    fn output_type(&self) -> Option<TokenStream> {
+        Some(quote!(::fortifier::CreditCardBrand))
+    }
+
+    fn output_expr(&self, expr: &TokenStream) -> Option<TokenStream> {
+        Some(self.call(format_ident!("parse_credit_card"), expr))
+    }
+}
+
+impl CreditCard {
+    fn call(&self, method: Ident, expr: &TokenStream) -> TokenStream {
+        let allowed_brands = match &self.allowed_brands {
+            Some(allowed_brands) => quote!(Some(#allowed_brands)),
+            None => quote!(None),
+        };
+
+        quote! {
+            ::fortifier::ValidateCreditCard::#method(&#expr, #allowed_brands)
+        }
+    }
+}
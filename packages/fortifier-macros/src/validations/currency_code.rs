@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::parse_expr_or_array,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct CurrencyCode {
+    allowed_currencies: Option<TokenStream>,
+}
+
+impl Validation for CurrencyCode {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = CurrencyCode::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("allowed_currencies") {
+                    result.allowed_currencies = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("CurrencyCode")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::CurrencyCodeError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(
+            "^[A-Z]{3}$".to_owned()
+        ))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let allowed_currencies = match &self.allowed_currencies {
+            Some(allowed_currencies) => {
+                quote!(Some(::std::convert::AsRef::<[&str]>::as_ref(&#allowed_currencies)))
+            }
+            None => quote!(None),
+        };
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateCurrencyCode::validate_currency_code(&#expr, #allowed_currencies)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
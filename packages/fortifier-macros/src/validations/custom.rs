@@ -116,6 +116,17 @@ impl Validation for Custom {
         vec![]
     }
 
+    #[cfg(feature = "zod")]
+    fn error_typescript_where_predicates(&self) -> Vec<TokenStream> {
+        let error_type = &self.error_type;
+
+        if self.error_generics().is_empty() {
+            vec![]
+        } else {
+            vec![quote!(#error_type: ::fortifier::zod::ToTypeScript)]
+        }
+    }
+
     fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match (execution, self.execution) {
             (Execution::Sync, Execution::Sync) => Some(wrapper(
@@ -0,0 +1,283 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Expr, Ident, LitStr, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::strip_options,
+    validation::{Execution, Validation},
+};
+
+fn clock_tokens(clock: &Option<Expr>) -> TokenStream {
+    if let Some(clock) = clock {
+        quote!(&#clock)
+    } else {
+        quote!(&::fortifier::SystemClock)
+    }
+}
+
+/// Parse a duration, e.g. `30d` or `1h30m`, into seconds.
+fn parse_duration(lit: &LitStr) -> Result<u64> {
+    let value = lit.value();
+    let mut seconds: u64 = 0;
+    let mut number = String::new();
+
+    for character in value.chars() {
+        if character.is_ascii_digit() {
+            number.push(character);
+            continue;
+        }
+
+        let unit = match character {
+            'w' => 7 * 24 * 60 * 60,
+            'd' => 24 * 60 * 60,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => {
+                return Err(Error::new_spanned(
+                    lit,
+                    format!(
+                        "unknown duration unit `{character}`, expected `w`, `d`, `h`, `m` or `s`"
+                    ),
+                ));
+            }
+        };
+
+        let amount: u64 = number
+            .parse()
+            .map_err(|_| Error::new_spanned(lit, "expected a number before duration unit"))?;
+        number.clear();
+
+        seconds = amount
+            .checked_mul(unit)
+            .and_then(|amount| seconds.checked_add(amount))
+            .ok_or_else(|| Error::new_spanned(lit, "duration is too large"))?;
+    }
+
+    if !number.is_empty() || value.is_empty() {
+        return Err(Error::new_spanned(
+            lit,
+            "expected a duration such as `30d` or `1h30m`",
+        ));
+    }
+
+    Ok(seconds)
+}
+
+macro_rules! relative_validation {
+    ($ident:ident, $method:ident) => {
+        pub struct $ident {
+            r#type: Type,
+            clock: Option<Expr>,
+        }
+
+        impl Validation for $ident {
+            fn parse(r#type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+                let mut result = $ident {
+                    r#type: r#type.clone(),
+                    clock: None,
+                };
+
+                if meta.input.peek(token::Paren) {
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("clock") {
+                            result.clock = Some(meta.value()?.parse()?);
+
+                            Ok(())
+                        } else {
+                            Err(meta.error("unknown parameter"))
+                        }
+                    })?;
+                }
+
+                Ok(result)
+            }
+
+            fn ident(&self) -> Ident {
+                format_ident!(stringify!($ident))
+            }
+
+            fn error_type(&self) -> TokenStream {
+                let r#type = strip_options(&self.r#type);
+
+                quote!(::fortifier::DateError<#r#type>)
+            }
+
+            fn error_generics(&self) -> Vec<Generic> {
+                vec![]
+            }
+
+            fn error_where_predicates(&self) -> Vec<TokenStream> {
+                vec![]
+            }
+
+            fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+                match execution {
+                    Execution::Sync => {
+                        let clock = clock_tokens(&self.clock);
+
+                        Some(quote! {
+                            ::fortifier::ValidateDate::$method(&#expr, #clock)
+                        })
+                    }
+                    Execution::Async => None,
+                }
+            }
+        }
+    };
+}
+
+relative_validation!(Past, validate_past);
+relative_validation!(Future, validate_future);
+relative_validation!(PastOrPresent, validate_past_or_present);
+
+pub struct MinAge {
+    r#type: Type,
+    years: Expr,
+    clock: Option<Expr>,
+}
+
+impl Validation for MinAge {
+    fn parse(r#type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut years = None;
+        let mut clock = None;
+
+        if let Ok(value) = meta.value() {
+            years = Some(value.parse()?);
+        } else {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("years") {
+                    years = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else if meta.path.is_ident("clock") {
+                    clock = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        let Some(years) = years else {
+            return Err(meta.error("missing years parameter"));
+        };
+
+        Ok(MinAge {
+            r#type: r#type.clone(),
+            years,
+            clock,
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("MinAge")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        let r#type = strip_options(&self.r#type);
+
+        quote!(::fortifier::DateError<#r#type>)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let years = &self.years;
+                let clock = clock_tokens(&self.clock);
+
+                Some(quote! {
+                    ::fortifier::ValidateDate::validate_min_age(&#expr, #clock, #years)
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
+
+pub struct Within {
+    r#type: Type,
+    seconds: u64,
+    clock: Option<Expr>,
+}
+
+impl Validation for Within {
+    fn parse(r#type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut duration: Option<LitStr> = None;
+        let mut clock = None;
+
+        if let Ok(value) = meta.value() {
+            duration = Some(value.parse()?);
+        } else {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("duration") {
+                    duration = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else if meta.path.is_ident("clock") {
+                    clock = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        let Some(duration) = duration else {
+            return Err(meta.error("missing duration parameter"));
+        };
+
+        Ok(Within {
+            r#type: r#type.clone(),
+            seconds: parse_duration(&duration)?,
+            clock,
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Within")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        let r#type = strip_options(&self.r#type);
+
+        quote!(::fortifier::DateError<#r#type>)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let seconds = self.seconds;
+                let clock = clock_tokens(&self.clock);
+
+                Some(quote! {
+                    ::fortifier::ValidateDate::validate_within(
+                        &#expr,
+                        #clock,
+                        ::std::time::Duration::from_secs(#seconds),
+                    )
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
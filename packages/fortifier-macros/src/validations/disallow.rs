@@ -0,0 +1,67 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+pub struct Disallow {
+    expression: Expr,
+}
+
+impl Validation for Disallow {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut expression: Option<Expr> = None;
+
+        if let Ok(value) = meta.value() {
+            expression = Some(value.parse()?);
+        } else {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("expression") {
+                    expression = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        let Some(expression) = expression else {
+            return Err(meta.error("missing expression parameter"));
+        };
+
+        Ok(Disallow { expression })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Disallow")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::DisallowError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, _expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let expression = &self.expression;
+
+                Some(quote! {
+                    ::fortifier::validate_disallow(#expression)
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Ean {}
+
+impl Validation for Ean {
+    fn parse(_type: &Type, _meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Ok(Ean::default())
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Ean")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::EanError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(
+            "^([0-9]{8}|[0-9]{13})$".to_owned()
+        ))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateEan::validate_ean(&#expr)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
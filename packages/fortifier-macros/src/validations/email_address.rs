@@ -1,9 +1,10 @@
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
-use syn::{Ident, LitBool, LitInt, Result, Type, meta::ParseNestedMeta};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Expr, Ident, LitBool, LitInt, Result, Type, meta::ParseNestedMeta, token};
 
 use crate::{
     generics::Generic,
+    util::parse_expr_or_array,
     validation::{Execution, Validation},
 };
 
@@ -11,6 +12,12 @@ pub struct EmailAddress {
     allow_display_text: bool,
     allow_domain_literal: bool,
     minimum_sub_domains: usize,
+    allowed_domains: Option<TokenStream>,
+    denied_domains: Option<TokenStream>,
+    deny_disposable: bool,
+    deny_subaddressing: bool,
+    deny_unknown_deliverability: bool,
+    resolver: Option<TokenStream>,
 }
 
 impl Default for EmailAddress {
@@ -19,6 +26,12 @@ impl Default for EmailAddress {
             allow_display_text: false,
             allow_domain_literal: true,
             minimum_sub_domains: 0,
+            allowed_domains: None,
+            denied_domains: None,
+            deny_disposable: false,
+            deny_subaddressing: false,
+            deny_unknown_deliverability: false,
+            resolver: None,
         }
     }
 }
@@ -27,7 +40,7 @@ impl Validation for EmailAddress {
     fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
         let mut result = EmailAddress::default();
 
-        if !meta.input.is_empty() {
+        if meta.input.peek(token::Paren) {
             meta.parse_nested_meta(|meta| {
                 if meta.path.is_ident("allow_display_text") {
                     let lit: LitBool = meta.value()?.parse()?;
@@ -43,6 +56,34 @@ impl Validation for EmailAddress {
                     let lit: LitInt = meta.value()?.parse()?;
                     result.minimum_sub_domains = lit.base10_parse()?;
 
+                    Ok(())
+                } else if meta.path.is_ident("allowed_domains") {
+                    result.allowed_domains = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else if meta.path.is_ident("denied_domains") {
+                    result.denied_domains = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else if meta.path.is_ident("deny_disposable") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.deny_disposable = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("deny_subaddressing") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.deny_subaddressing = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("deny_unknown_deliverability") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.deny_unknown_deliverability = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("resolver") {
+                    let expr: Expr = meta.value()?.parse()?;
+                    result.resolver = Some(expr.into_token_stream());
+
                     Ok(())
                 } else {
                     Err(meta.error("unknown parameter"))
@@ -68,26 +109,97 @@ impl Validation for EmailAddress {
         vec![]
     }
 
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::EmailAddress)]
+    }
+
     fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match execution {
-            Execution::Sync => {
-                let allow_display_text = self.allow_display_text;
-                let allow_domain_literal = self.allow_domain_literal;
-                let minimum_sub_domains = self.minimum_sub_domains;
+            Execution::Sync => Some(self.call(format_ident!("validate_email_address"), expr)),
+            Execution::Async => self.resolver.as_ref().map(|resolver| {
+                let options = self.options();
+                let policy = self.policy();
 
-                Some(quote! {
+                quote! {
                     {
-                        const EMAIL_ADDRESS_OPTIONS: ::fortifier::EmailAddressOptions = ::fortifier::EmailAddressOptions {
-                            allow_display_text: #allow_display_text,
-                            allow_domain_literal: #allow_domain_literal,
-                            minimum_sub_domains: #minimum_sub_domains,
-                        };
+                        #options
 
-                        ::fortifier::ValidateEmailAddress::validate_email_address(&#expr, EMAIL_ADDRESS_OPTIONS)
+                        ::fortifier::ValidateEmailAddress::validate_email_address_deliverability(
+                            &#expr,
+                            EMAIL_ADDRESS_OPTIONS,
+                            #policy,
+                            &#resolver,
+                        )
+                        .await
                     }
-                })
+                }
+            }),
+        }
+    }
+
+    fn output_type(&self) -> Option<TokenStream> {
+        Some(quote!(::fortifier::EmailAddress))
+    }
+
+    fn output_expr(&self, expr: &TokenStream) -> Option<TokenStream> {
+        Some(self.call(format_ident!("parse_email_address"), expr))
+    }
+}
+
+impl EmailAddress {
+    fn options(&self) -> TokenStream {
+        let allow_display_text = self.allow_display_text;
+        let allow_domain_literal = self.allow_domain_literal;
+        let minimum_sub_domains = self.minimum_sub_domains;
+
+        quote! {
+            const EMAIL_ADDRESS_OPTIONS: ::fortifier::EmailAddressOptions = ::fortifier::EmailAddressOptions {
+                allow_display_text: #allow_display_text,
+                allow_domain_literal: #allow_domain_literal,
+                minimum_sub_domains: #minimum_sub_domains,
+            };
+        }
+    }
+
+    fn policy(&self) -> TokenStream {
+        let allowed_domains = match &self.allowed_domains {
+            Some(allowed_domains) => quote!(Some(&#allowed_domains)),
+            None => quote!(None),
+        };
+        let denied_domains = match &self.denied_domains {
+            Some(denied_domains) => quote!(Some(&#denied_domains)),
+            None => quote!(None),
+        };
+        let deny_disposable = self.deny_disposable;
+        let deny_subaddressing = self.deny_subaddressing;
+        let deny_unknown_deliverability = self.deny_unknown_deliverability;
+
+        quote! {
+            ::fortifier::EmailAddressPolicy {
+                allowed_domains: #allowed_domains,
+                denied_domains: #denied_domains,
+                deny_disposable: #deny_disposable,
+                deny_subaddressing: #deny_subaddressing,
+                deny_unknown_deliverability: #deny_unknown_deliverability,
+            }
+        }
+    }
+
+    fn call(&self, method: Ident, expr: &TokenStream) -> TokenStream {
+        let options = self.options();
+        let policy = self.policy();
+
+        quote! {
+            {
+                #options
+
+                ::fortifier::ValidateEmailAddress::#method(
+                    &#expr,
+                    EMAIL_ADDRESS_OPTIONS,
+                    #policy,
+                )
             }
-            Execution::Async => None,
         }
     }
 }
@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, LitStr, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::{parse_expr_or_array, parse_flag},
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Hex {
+    prefix: Option<LitStr>,
+    lengths: Option<TokenStream>,
+    even_length: bool,
+}
+
+impl Validation for Hex {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = Hex::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("prefix") {
+                    result.prefix = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else if meta.path.is_ident("lengths") {
+                    result.lengths = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else if meta.path.is_ident("even_length") {
+                    result.even_length = parse_flag(&meta)?;
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Hex")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::HexError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        // The prefix is compared case-insensitively.
+        let prefix = self
+            .prefix
+            .as_ref()
+            .map(|prefix| {
+                prefix
+                    .value()
+                    .chars()
+                    .map(|character| {
+                        if character.is_ascii_alphabetic() {
+                            format!(
+                                "[{}{}]",
+                                character.to_ascii_lowercase(),
+                                character.to_ascii_uppercase()
+                            )
+                        } else if "\\^$.|?*+()[]{}/".contains(character) {
+                            format!("\\{character}")
+                        } else {
+                            character.to_string()
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .unwrap_or_default();
+        let digits = if self.even_length {
+            "([0-9a-fA-F]{2})+"
+        } else {
+            "[0-9a-fA-F]+"
+        };
+        let pattern = format!("^{prefix}{digits}$");
+
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(#pattern.to_owned()))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let prefix = match &self.prefix {
+            Some(prefix) => quote!(Some(#prefix)),
+            None => quote!(None),
+        };
+        let lengths = match &self.lengths {
+            Some(lengths) => quote!(Some(::std::convert::AsRef::<[usize]>::as_ref(&#lengths))),
+            None => quote!(None),
+        };
+        let even_length = self.even_length;
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateHex::validate_hex(
+                    &#expr,
+                    ::fortifier::HexOptions {
+                        prefix: #prefix,
+                        lengths: #lengths,
+                        even_length: #even_length,
+                    },
+                )
+            }),
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, LitBool, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Hostname {
+    allow_idn: bool,
+}
+
+impl Validation for Hostname {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = Hostname::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("allow_idn") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_idn = lit.value;
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Hostname")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::HostnameError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        if self.allow_idn {
+            vec![]
+        } else {
+            vec![quote!(::fortifier::zod::ZodCheck::Regex(
+                "^[A-Za-z0-9.-]+$".to_owned()
+            ))]
+        }
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let allow_idn = self.allow_idn;
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                {
+                    const HOSTNAME_OPTIONS: ::fortifier::HostnameOptions = ::fortifier::HostnameOptions {
+                        allow_idn: #allow_idn,
+                    };
+
+                    ::fortifier::ValidateHostname::validate_hostname(&#expr, HOSTNAME_OPTIONS)
+                }
+            }),
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Iban {}
+
+impl Validation for Iban {
+    fn parse(_type: &Type, _meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Ok(Iban::default())
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Iban")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::IbanError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(
+            "^[A-Za-z]{2}[0-9]{2}[A-Za-z0-9 ]+$".to_owned()
+        ))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateIban::validate_iban(&#expr)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, LitBool, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+#[cfg(feature = "zod")]
+const IPV4_PATTERN: &str = r"(\d{1,3}\.){3}\d{1,3}";
+#[cfg(feature = "zod")]
+const IPV6_PATTERN: &str = r"[0-9A-Fa-f:.]*:[0-9A-Fa-f:.]*";
+
+/// IP address options, shared by the IP address, CIDR and socket address validations.
+pub struct IpOptions {
+    v4: bool,
+    v6: bool,
+    allow_private: bool,
+    allow_loopback: bool,
+    allow_link_local: bool,
+    allow_multicast: bool,
+    allow_unspecified: bool,
+    allow_broadcast: bool,
+}
+
+impl Default for IpOptions {
+    fn default() -> Self {
+        Self {
+            v4: true,
+            v6: true,
+            allow_private: true,
+            allow_loopback: true,
+            allow_link_local: true,
+            allow_multicast: true,
+            allow_unspecified: true,
+            allow_broadcast: true,
+        }
+    }
+}
+
+impl IpOptions {
+    pub fn parse(meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = IpOptions::default();
+        let mut v4 = false;
+        let mut v6 = false;
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("v4") {
+                    v4 = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("v6") {
+                    v6 = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("allow_private") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_private = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("allow_loopback") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_loopback = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("allow_link_local") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_link_local = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("allow_multicast") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_multicast = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("allow_unspecified") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_unspecified = lit.value;
+
+                    Ok(())
+                } else if meta.path.is_ident("allow_broadcast") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    result.allow_broadcast = lit.value;
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        // Without a version, both versions are allowed.
+        if v4 || v6 {
+            result.v4 = v4;
+            result.v6 = v6;
+        }
+
+        Ok(result)
+    }
+
+    /// Regular expression pattern for the allowed IP address versions, without anchors.
+    #[cfg(feature = "zod")]
+    pub fn pattern(&self) -> String {
+        self.pattern_with(IPV4_PATTERN, IPV6_PATTERN)
+    }
+
+    /// Regular expression pattern for the allowed IP address versions, with IPv6 addresses enclosed in brackets.
+    #[cfg(feature = "zod")]
+    pub fn bracketed_pattern(&self) -> String {
+        self.pattern_with(IPV4_PATTERN, &format!(r"\[{IPV6_PATTERN}\]"))
+    }
+
+    #[cfg(feature = "zod")]
+    fn pattern_with(&self, v4_pattern: &str, v6_pattern: &str) -> String {
+        match (self.v4, self.v6) {
+            (true, false) => v4_pattern.to_owned(),
+            (false, true) => v6_pattern.to_owned(),
+            _ => format!("({v4_pattern}|{v6_pattern})"),
+        }
+    }
+
+    pub fn to_expr(&self) -> TokenStream {
+        let v4 = self.v4;
+        let v6 = self.v6;
+        let allow_private = self.allow_private;
+        let allow_loopback = self.allow_loopback;
+        let allow_link_local = self.allow_link_local;
+        let allow_multicast = self.allow_multicast;
+        let allow_unspecified = self.allow_unspecified;
+        let allow_broadcast = self.allow_broadcast;
+
+        quote! {
+            {
+                const IP_OPTIONS: ::fortifier::IpOptions = ::fortifier::IpOptions {
+                    v4: #v4,
+                    v6: #v6,
+                    allow_private: #allow_private,
+                    allow_loopback: #allow_loopback,
+                    allow_link_local: #allow_link_local,
+                    allow_multicast: #allow_multicast,
+                    allow_unspecified: #allow_unspecified,
+                    allow_broadcast: #allow_broadcast,
+                };
+
+                IP_OPTIONS
+            }
+        }
+    }
+}
+
+pub struct Ip {
+    options: IpOptions,
+}
+
+impl Validation for Ip {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Ok(Ip {
+            options: IpOptions::parse(meta)?,
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Ip")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::IpError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        let pattern = format!("^{}$", self.options.pattern());
+
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(#pattern.to_owned()))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(self.call(format_ident!("validate_ip"), expr)),
+            Execution::Async => None,
+        }
+    }
+
+    fn output_type(&self) -> Option<TokenStream> {
+        Some(quote!(::std::net::IpAddr))
+    }
+
+    fn output_expr(&self, expr: &TokenStream) -> Option<TokenStream> {
+        Some(self.call(format_ident!("parse_ip"), expr))
+    }
+}
+
+impl Ip {
+    fn call(&self, method: Ident, expr: &TokenStream) -> TokenStream {
+        let options = self.options.to_expr();
+
+        quote! {
+            ::fortifier::ValidateIp::#method(&#expr, #options)
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Json {}
+
+impl Validation for Json {
+    fn parse(_type: &Type, _meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Ok(Json::default())
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Json")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::JsonError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateJson::validate_json(&#expr)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
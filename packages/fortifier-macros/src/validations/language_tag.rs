@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::parse_expr_or_array,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct LanguageTag {
+    allowed_languages: Option<TokenStream>,
+}
+
+impl Validation for LanguageTag {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = LanguageTag::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("allowed_languages") {
+                    result.allowed_languages = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("LanguageTag")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::LanguageTagError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(
+            "^[a-zA-Z0-9]{1,8}(-[a-zA-Z0-9]{1,8})*$".to_owned()
+        ))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let allowed_languages = match &self.allowed_languages {
+            Some(allowed_languages) => {
+                quote!(Some(::std::convert::AsRef::<[&str]>::as_ref(&#allowed_languages)))
+            }
+            None => quote!(None),
+        };
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateLanguageTag::validate_language_tag(&#expr, #allowed_languages)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Expr, Ident, Result, Type, meta::ParseNestedMeta};
+use syn::{Error, Expr, Ident, Result, Type, meta::ParseNestedMeta};
 
+#[cfg(feature = "zod")]
+use crate::util::uses_context;
 use crate::{
     generics::Generic,
     validation::{Execution, Validation},
@@ -12,6 +14,7 @@ pub struct Length {
     equal: Option<Expr>,
     min: Option<Expr>,
     max: Option<Expr>,
+    unit: Option<Ident>,
 }
 
 impl Validation for Length {
@@ -33,6 +36,23 @@ impl Validation for Length {
                 let expr: Expr = meta.value()?.parse()?;
                 result.max = Some(expr);
 
+                Ok(())
+            } else if meta.path.is_ident("unit") {
+                let ident: Ident = meta.value()?.parse()?;
+
+                result.unit = Some(match ident.to_string().as_str() {
+                    "bytes" => format_ident!("Bytes", span = ident.span()),
+                    "chars" => format_ident!("Chars", span = ident.span()),
+                    "utf16" => format_ident!("Utf16", span = ident.span()),
+                    "graphemes" => format_ident!("Graphemes", span = ident.span()),
+                    _ => {
+                        return Err(Error::new_spanned(
+                            ident,
+                            "expected `bytes`, `chars`, `utf16` or `graphemes`",
+                        ));
+                    }
+                });
+
                 Ok(())
             } else {
                 Err(meta.error("unknown parameter"))
@@ -66,6 +86,36 @@ impl Validation for Length {
         vec![]
     }
 
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        // Zod measures string length in UTF-16 code units, byte and grapheme lengths can not be checked.
+        if self
+            .unit
+            .as_ref()
+            .is_some_and(|unit| unit != "Chars" && unit != "Utf16")
+        {
+            return vec![];
+        }
+
+        let [equal, min, max] = [&self.equal, &self.min, &self.max].map(|expr| {
+            if let Some(expr) = expr
+                && !uses_context(expr)
+            {
+                quote!(Some(#expr))
+            } else {
+                quote!(None)
+            }
+        });
+
+        vec![quote! {
+            ::fortifier::zod::ZodCheck::Length {
+                equal: #equal,
+                min: #min,
+                max: #max,
+            }
+        }]
+    }
+
     fn expr(&self, exeuction: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match exeuction {
             Execution::Sync => {
@@ -85,8 +135,20 @@ impl Validation for Length {
                     quote!(None)
                 };
 
-                Some(quote! {
-                    ::fortifier::ValidateLength::validate_length(&#expr, #equal, #min, #max)
+                Some(if let Some(unit) = &self.unit {
+                    quote! {
+                        ::fortifier::ValidateLengthUnit::validate_length_in(
+                            &#expr,
+                            #equal,
+                            #min,
+                            #max,
+                            ::fortifier::LengthUnit::#unit,
+                        )
+                    }
+                } else {
+                    quote! {
+                        ::fortifier::ValidateLength::validate_length(&#expr, #equal, #min, #max)
+                    }
                 })
             }
             Execution::Async => None,
@@ -4,7 +4,7 @@ use syn::{GenericParam, Ident, Result, Type, TypePath, meta::ParseNestedMeta};
 
 use crate::{
     generics::{Generic, generic_arguments},
-    integrations::enum_field_attributes,
+    integrations::{enum_field_attributes, nested_enum_variant_attributes},
     validation::{Execution, Validation},
 };
 
@@ -64,6 +64,10 @@ impl Validation for Nested {
         quote!(#attributes ::fortifier::ValidationErrors<#error_type>)
     }
 
+    fn error_attributes(&self) -> TokenStream {
+        nested_enum_variant_attributes(&self.error_type)
+    }
+
     fn error_generics(&self) -> Vec<Generic> {
         generic_arguments(&self.error_type)
             .into_iter()
@@ -75,6 +79,24 @@ impl Validation for Nested {
         self.where_predicates.clone()
     }
 
+    #[cfg(feature = "zod")]
+    fn error_typescript(&self) -> TokenStream {
+        let error_type = &self.error_type;
+
+        quote!(::fortifier::zod::nested_errors::<#error_type>(generator))
+    }
+
+    #[cfg(feature = "zod")]
+    fn error_typescript_where_predicates(&self) -> Vec<TokenStream> {
+        let error_type = &self.error_type;
+
+        if self.where_predicates.is_empty() {
+            vec![]
+        } else {
+            vec![quote!(#error_type: ::fortifier::zod::ToTypeScript)]
+        }
+    }
+
     fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match execution {
             Execution::Sync => Some(quote! {
@@ -85,4 +107,15 @@ impl Validation for Nested {
             }),
         }
     }
+
+    fn warning_expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateWithContext::validate_warnings_sync_with_context(&#expr, context)
+            }),
+            Execution::Async => Some(quote! {
+                ::fortifier::ValidateWithContext::validate_warnings_async_with_context(&#expr, context).await
+            }),
+        }
+    }
 }
@@ -0,0 +1,74 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    util::{parse_expr_or_array, strip_options},
+    validation::{Execution, Validation},
+};
+
+pub struct NoneOf {
+    r#type: Type,
+    values: TokenStream,
+}
+
+impl Validation for NoneOf {
+    fn parse(r#type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut values = None;
+
+        if let Ok(value) = meta.value() {
+            values = Some(parse_expr_or_array(value)?);
+        } else {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("values") {
+                    values = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        let Some(values) = values else {
+            return Err(meta.error("missing values parameter"));
+        };
+
+        Ok(NoneOf {
+            r#type: r#type.clone(),
+            values,
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("NoneOf")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        let r#type = strip_options(&self.r#type);
+
+        quote!(::fortifier::NoneOfError<#r#type>)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let values = &self.values;
+
+                Some(quote! {
+                    ::fortifier::ValidateMembership::validate_none_of(&#expr, &#values)
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, Ident, LitInt, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::strip_options,
+    validation::{Execution, Validation},
+};
+
+pub struct MultipleOf {
+    r#type: Type,
+    expression: Expr,
+}
+
+impl Validation for MultipleOf {
+    fn parse(r#type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Ok(MultipleOf {
+            r#type: r#type.clone(),
+            expression: meta.value()?.parse()?,
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("MultipleOf")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        let r#type = strip_options(&self.r#type);
+
+        quote!(::fortifier::NumericError<#r#type>)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let expression = &self.expression;
+
+                Some(quote! {
+                    ::fortifier::ValidateNumeric::validate_multiple_of(&#expr, #expression)
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
+
+pub struct Finite {
+    r#type: Type,
+}
+
+impl Validation for Finite {
+    fn parse(r#type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| Err(meta.error("unknown parameter")))?;
+        }
+
+        Ok(Finite {
+            r#type: r#type.clone(),
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Finite")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        let r#type = strip_options(&self.r#type);
+
+        quote!(::fortifier::NumericError<#r#type>)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateNumeric::validate_finite(&#expr)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
+
+pub struct Decimal {
+    r#type: Type,
+    precision: Option<u32>,
+    scale: Option<u32>,
+}
+
+impl Validation for Decimal {
+    fn parse(r#type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = Decimal {
+            r#type: r#type.clone(),
+            precision: None,
+            scale: None,
+        };
+
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("precision") {
+                let lit: LitInt = meta.value()?.parse()?;
+                result.precision = Some(lit.base10_parse()?);
+
+                Ok(())
+            } else if meta.path.is_ident("scale") {
+                let lit: LitInt = meta.value()?.parse()?;
+                result.scale = Some(lit.base10_parse()?);
+
+                Ok(())
+            } else {
+                Err(meta.error("unknown parameter"))
+            }
+        })?;
+
+        match (result.precision, result.scale) {
+            (None, None) => Err(meta.error("missing precision or scale parameter")),
+            (Some(precision), Some(scale)) if scale > precision => {
+                Err(meta.error("`scale` must not be greater than `precision`"))
+            }
+            _ => Ok(result),
+        }
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Decimal")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        let r#type = strip_options(&self.r#type);
+
+        quote!(::fortifier::NumericError<#r#type>)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let precision = if let Some(precision) = self.precision {
+                    quote!(Some(#precision))
+                } else {
+                    quote!(None)
+                };
+                let scale = if let Some(scale) = self.scale {
+                    quote!(Some(#scale))
+                } else {
+                    quote!(None)
+                };
+
+                Some(quote! {
+                    ::fortifier::ValidateDecimal::validate_decimal(&#expr, #precision, #scale)
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Error, Ident, LitInt, Result, Token, Type, bracketed, meta::ParseNestedMeta,
+    punctuated::Punctuated, token,
+};
+
+use crate::{
+    generics::Generic,
+    util::parse_flag,
+    validate::field::{LiteralOrIdent, ValidateFieldPrefix},
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Password {
+    min_character_classes: Option<usize>,
+    min_score: Option<u8>,
+    deny_common: bool,
+    user_inputs: Vec<Ident>,
+    field_prefix: ValidateFieldPrefix,
+}
+
+impl Password {
+    /// Set the prefix used to access the sibling fields of the user inputs.
+    pub fn with_field_prefix(mut self, field_prefix: ValidateFieldPrefix) -> Self {
+        self.field_prefix = field_prefix;
+        self
+    }
+}
+
+impl Validation for Password {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = Password::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("min_character_classes") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    let min_character_classes = lit.base10_parse()?;
+
+                    if !(1..=4).contains(&min_character_classes) {
+                        return Err(Error::new_spanned(lit, "expected a value from 1 to 4"));
+                    }
+
+                    result.min_character_classes = Some(min_character_classes);
+
+                    Ok(())
+                } else if meta.path.is_ident("min_score") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    let min_score = lit.base10_parse()?;
+
+                    if min_score > 4 {
+                        return Err(Error::new_spanned(lit, "expected a value from 0 to 4"));
+                    }
+
+                    result.min_score = Some(min_score);
+
+                    Ok(())
+                } else if meta.path.is_ident("deny_common") {
+                    result.deny_common = parse_flag(&meta)?;
+
+                    Ok(())
+                } else if meta.path.is_ident("user_inputs") {
+                    let value = meta.value()?;
+                    let content;
+                    bracketed!(content in value);
+                    let user_inputs = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+
+                    result.user_inputs = user_inputs.into_iter().collect();
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Password")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::PasswordError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => {
+                let min_character_classes = match self.min_character_classes {
+                    Some(min_character_classes) => quote!(Some(#min_character_classes)),
+                    None => quote!(None),
+                };
+                let min_score = match self.min_score {
+                    Some(min_score) => quote!(Some(#min_score)),
+                    None => quote!(None),
+                };
+                let deny_common = self.deny_common;
+                let user_inputs = self.user_inputs.iter().map(|ident| {
+                    let field = ident.to_string();
+                    let sibling_expr = self
+                        .field_prefix
+                        .expr(&LiteralOrIdent::Ident(ident.clone()));
+
+                    quote! {
+                        (#field, ::fortifier::PasswordUserInput::user_input(&#sibling_expr))
+                    }
+                });
+
+                Some(quote! {
+                    ::fortifier::ValidatePassword::validate_password(
+                        &#expr,
+                        ::fortifier::PasswordOptions {
+                            min_character_classes: #min_character_classes,
+                            min_score: #min_score,
+                            deny_common: #deny_common,
+                            user_inputs: &[#( #user_inputs ),*],
+                        },
+                    )
+                })
+            }
+            Execution::Async => None,
+        }
+    }
+}
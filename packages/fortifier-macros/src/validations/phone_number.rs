@@ -1,23 +1,50 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Expr, Ident, Result, Type, meta::ParseNestedMeta};
+use syn::{
+    Error, Expr, Ident, Result, Token, Type, bracketed, meta::ParseNestedMeta,
+    punctuated::Punctuated, token,
+};
 
 use crate::{
     generics::Generic,
+    util::{parse_flag, upper_camel_ident},
     validation::{Execution, Validation},
 };
 
+const TYPES: [&str; 17] = [
+    "carrier",
+    "emergency",
+    "fixed_line",
+    "fixed_line_or_mobile",
+    "mobile",
+    "no_international",
+    "pager",
+    "personal_number",
+    "premium_rate",
+    "shared_cost",
+    "short_code",
+    "standard_rate",
+    "toll_free",
+    "uan",
+    "unknown",
+    "voicemail",
+    "voip",
+];
+
 #[derive(Default)]
 pub struct PhoneNumber {
     allowed_countries: Option<Expr>,
     default_country: Option<Expr>,
+    types: Option<Vec<Ident>>,
+    strict_validity: bool,
+    require_international_format: bool,
 }
 
 impl Validation for PhoneNumber {
     fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
         let mut result = PhoneNumber::default();
 
-        if !meta.input.is_empty() {
+        if meta.input.peek(token::Paren) {
             meta.parse_nested_meta(|meta| {
                 if meta.path.is_ident("allowed_countries") {
                     let expr: Expr = meta.value()?.parse()?;
@@ -28,6 +55,35 @@ impl Validation for PhoneNumber {
                     let expr: Expr = meta.value()?.parse()?;
                     result.default_country = Some(expr);
 
+                    Ok(())
+                } else if meta.path.is_ident("types") {
+                    let value = meta.value()?;
+                    let content;
+                    bracketed!(content in value);
+                    let types = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+
+                    for r#type in &types {
+                        if !TYPES.contains(&r#type.to_string().as_str()) {
+                            return Err(Error::new_spanned(
+                                r#type,
+                                format!(
+                                    "unknown phone number type, expected one of `{}`",
+                                    TYPES.join("`, `")
+                                ),
+                            ));
+                        }
+                    }
+
+                    result.types = Some(types.into_iter().collect());
+
+                    Ok(())
+                } else if meta.path.is_ident("strict_validity") {
+                    result.strict_validity = parse_flag(&meta)?;
+
+                    Ok(())
+                } else if meta.path.is_ident("require_international_format") {
+                    result.require_international_format = parse_flag(&meta)?;
+
                     Ok(())
                 } else {
                     Err(meta.error("unknown parameter"))
@@ -53,23 +109,60 @@ impl Validation for PhoneNumber {
         vec![]
     }
 
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::PhoneNumber)]
+    }
+
     fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match execution {
-            Execution::Sync => {
-                let allowed_countries = match &self.allowed_countries {
-                    Some(allowed_countries) => quote!(Some(#allowed_countries)),
-                    None => quote!(None),
-                };
-                let default_country = match &self.default_country {
-                    Some(default_country) => quote!(Some(#default_country)),
-                    None => quote!(None),
-                };
-
-                Some(quote! {
-                    ::fortifier::ValidatePhoneNumber::validate_phone_number(&#expr, #default_country, #allowed_countries)
-                })
-            }
+            Execution::Sync => Some(self.call(format_ident!("validate_phone_number"), expr)),
             Execution::Async => None,
         }
     }
+
+    fn output_type(&self) -> Option<TokenStream> {
+        Some(quote!(::fortifier::PhoneNumber))
+    }
+
+    fn output_expr(&self, expr: &TokenStream) -> Option<TokenStream> {
+        Some(self.call(format_ident!("parse_phone_number"), expr))
+    }
+}
+
+impl PhoneNumber {
+    fn call(&self, method: Ident, expr: &TokenStream) -> TokenStream {
+        let allowed_countries = match &self.allowed_countries {
+            Some(allowed_countries) => quote!(Some(#allowed_countries)),
+            None => quote!(None),
+        };
+        let default_country = match &self.default_country {
+            Some(default_country) => quote!(Some(#default_country)),
+            None => quote!(None),
+        };
+
+        let types = match &self.types {
+            Some(types) => {
+                let types = types.iter().map(upper_camel_ident);
+
+                quote!(Some(&[#(::fortifier::PhoneNumberType::#types),*]))
+            }
+            None => quote!(None),
+        };
+        let strict_validity = self.strict_validity;
+        let require_international_format = self.require_international_format;
+
+        quote! {
+            ::fortifier::ValidatePhoneNumber::#method(
+                &#expr,
+                #default_country,
+                #allowed_countries,
+                ::fortifier::PhoneNumberOptions {
+                    types: #types,
+                    strict_validity: #strict_validity,
+                    require_international_format: #require_international_format,
+                },
+            )
+        }
+    }
 }
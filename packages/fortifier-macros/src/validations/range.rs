@@ -2,6 +2,8 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{Expr, Ident, Result, Type, meta::ParseNestedMeta};
 
+#[cfg(feature = "zod")]
+use crate::util::uses_context;
 use crate::{
     generics::Generic,
     util::strip_options,
@@ -80,6 +82,36 @@ impl Validation for Range {
         vec![]
     }
 
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        let r#type = strip_options(&self.r#type);
+
+        let [min, max, exclusive_min, exclusive_max] = [
+            &self.min,
+            &self.max,
+            &self.exclusive_min,
+            &self.exclusive_max,
+        ]
+        .map(|expr| {
+            if let Some(expr) = expr
+                && !uses_context(expr)
+            {
+                quote!(Some(::fortifier::zod::literal::<#r#type>(&(#expr))))
+            } else {
+                quote!(None)
+            }
+        });
+
+        vec![quote! {
+            ::fortifier::zod::ZodCheck::Range {
+                min: #min,
+                max: #max,
+                exclusive_min: #exclusive_min,
+                exclusive_max: #exclusive_max,
+            }
+        }]
+    }
+
     fn expr(&self, exeuction: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match exeuction {
             Execution::Sync => {
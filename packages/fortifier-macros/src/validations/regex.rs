@@ -2,6 +2,8 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{Expr, Ident, Result, Type, meta::ParseNestedMeta};
 
+#[cfg(feature = "zod")]
+use crate::util::uses_context;
 use crate::{
     generics::Generic,
     validation::{Execution, Validation},
@@ -52,6 +54,21 @@ impl Validation for Regex {
         vec![]
     }
 
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        if uses_context(&self.expression) {
+            return vec![];
+        }
+
+        let expression = &self.expression;
+
+        vec![quote! {
+            ::fortifier::zod::ZodCheck::Regex(
+                ::fortifier::AsRegex::as_regex(&#expression).as_str().to_owned()
+            )
+        }]
+    }
+
     fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match execution {
             Execution::Sync => {
@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+    validations::IpOptions,
+};
+
+pub struct SocketAddr {
+    options: IpOptions,
+}
+
+impl Validation for SocketAddr {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Ok(SocketAddr {
+            options: IpOptions::parse(meta)?,
+        })
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("SocketAddr")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::SocketAddrError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        let pattern = format!(r"^{}:\d{{1,5}}$", self.options.bracketed_pattern());
+
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(#pattern.to_owned()))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        match execution {
+            Execution::Sync => Some(self.call(format_ident!("validate_socket_addr"), expr)),
+            Execution::Async => None,
+        }
+    }
+
+    fn output_type(&self) -> Option<TokenStream> {
+        Some(quote!(::std::net::SocketAddr))
+    }
+
+    fn output_expr(&self, expr: &TokenStream) -> Option<TokenStream> {
+        Some(self.call(format_ident!("parse_socket_addr"), expr))
+    }
+}
+
+impl SocketAddr {
+    fn call(&self, method: Ident, expr: &TokenStream) -> TokenStream {
+        let options = self.options.to_expr();
+
+        quote! {
+            ::fortifier::ValidateSocketAddr::#method(&#expr, #options)
+        }
+    }
+}
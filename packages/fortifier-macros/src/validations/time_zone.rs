@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::parse_expr_or_array,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct TimeZone {
+    allowed_time_zones: Option<TokenStream>,
+}
+
+impl Validation for TimeZone {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = TimeZone::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("allowed_time_zones") {
+                    result.allowed_time_zones = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("TimeZone")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::TimeZoneError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(
+            "^[A-Za-z0-9_+-]+(/[A-Za-z0-9_+-]+)*$".to_owned()
+        ))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let allowed_time_zones = match &self.allowed_time_zones {
+            Some(allowed_time_zones) => {
+                quote!(Some(::std::convert::AsRef::<[&str]>::as_ref(&#allowed_time_zones)))
+            }
+            None => quote!(None),
+        };
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateTimeZone::validate_time_zone(&#expr, #allowed_time_zones)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
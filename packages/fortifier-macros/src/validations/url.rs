@@ -1,18 +1,66 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+use syn::{Ident, LitInt, Result, Type, meta::ParseNestedMeta, token};
 
 use crate::{
     generics::Generic,
+    util::parse_expr_or_array,
     validation::{Execution, Validation},
 };
 
 #[derive(Default)]
-pub struct Url {}
+pub struct Url {
+    schemes: Option<TokenStream>,
+    hosts: Option<TokenStream>,
+    ports: Option<TokenStream>,
+    deny_ip_hosts: bool,
+    deny_credentials: bool,
+    require_tld: bool,
+    max_length: Option<usize>,
+}
 
 impl Validation for Url {
-    fn parse(_type: &Type, _meta: &ParseNestedMeta<'_>) -> Result<Self> {
-        Ok(Url::default())
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = Url::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("schemes") {
+                    result.schemes = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else if meta.path.is_ident("hosts") {
+                    result.hosts = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else if meta.path.is_ident("ports") {
+                    result.ports = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else if meta.path.is_ident("deny_ip_hosts") {
+                    result.deny_ip_hosts = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("deny_credentials") {
+                    result.deny_credentials = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("require_tld") {
+                    result.require_tld = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("max_length") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    result.max_length = Some(lit.base10_parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
     }
 
     fn ident(&self) -> Ident {
@@ -31,12 +79,72 @@ impl Validation for Url {
         vec![]
     }
 
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        let mut checks = vec![quote!(::fortifier::zod::ZodCheck::Url)];
+
+        if let Some(max_length) = self.max_length {
+            checks.push(quote!(::fortifier::zod::ZodCheck::Length {
+                equal: None,
+                min: None,
+                max: Some(#max_length),
+            }));
+        }
+
+        checks
+    }
+
     fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
         match execution {
-            Execution::Sync => Some(quote! {
-                ::fortifier::ValidateUrl::validate_url(&#expr)
-            }),
+            Execution::Sync => Some(self.call(format_ident!("validate_url"), expr)),
             Execution::Async => None,
         }
     }
+
+    fn output_type(&self) -> Option<TokenStream> {
+        Some(quote!(::fortifier::Url))
+    }
+
+    fn output_expr(&self, expr: &TokenStream) -> Option<TokenStream> {
+        Some(self.call(format_ident!("parse_url"), expr))
+    }
+}
+
+impl Url {
+    fn call(&self, method: Ident, expr: &TokenStream) -> TokenStream {
+        let schemes = match &self.schemes {
+            Some(schemes) => quote!(Some(::std::convert::AsRef::<[&str]>::as_ref(&#schemes))),
+            None => quote!(None),
+        };
+        let hosts = match &self.hosts {
+            Some(hosts) => quote!(Some(::std::convert::AsRef::<[&str]>::as_ref(&#hosts))),
+            None => quote!(None),
+        };
+        let ports = match &self.ports {
+            Some(ports) => quote!(Some(::std::convert::AsRef::<[u16]>::as_ref(&#ports))),
+            None => quote!(None),
+        };
+        let deny_ip_hosts = self.deny_ip_hosts;
+        let deny_credentials = self.deny_credentials;
+        let require_tld = self.require_tld;
+        let max_length = match self.max_length {
+            Some(max_length) => quote!(Some(#max_length)),
+            None => quote!(None),
+        };
+
+        quote! {
+            ::fortifier::ValidateUrl::#method(
+                &#expr,
+                ::fortifier::UrlOptions {
+                    schemes: #schemes,
+                    hosts: #hosts,
+                    ports: #ports,
+                    deny_ip_hosts: #deny_ip_hosts,
+                    deny_credentials: #deny_credentials,
+                    require_tld: #require_tld,
+                    max_length: #max_length,
+                },
+            )
+        }
+    }
 }
@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Result, Type, meta::ParseNestedMeta, token};
+
+use crate::{
+    generics::Generic,
+    util::parse_expr_or_array,
+    validation::{Execution, Validation},
+};
+
+#[derive(Default)]
+pub struct Uuid {
+    versions: Option<TokenStream>,
+}
+
+impl Validation for Uuid {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        let mut result = Uuid::default();
+
+        if meta.input.peek(token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("versions") {
+                    result.versions = Some(parse_expr_or_array(meta.value()?)?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown parameter"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn ident(&self) -> Ident {
+        format_ident!("Uuid")
+    }
+
+    fn error_type(&self) -> TokenStream {
+        quote!(::fortifier::UuidError)
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        vec![]
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    #[cfg(feature = "zod")]
+    fn zod_checks(&self) -> Vec<TokenStream> {
+        vec![quote!(::fortifier::zod::ZodCheck::Regex(
+            "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+                .to_owned()
+        ))]
+    }
+
+    fn expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        let versions = match &self.versions {
+            Some(versions) => quote!(Some(::std::convert::AsRef::<[u8]>::as_ref(&#versions))),
+            None => quote!(None),
+        };
+
+        match execution {
+            Execution::Sync => Some(quote! {
+                ::fortifier::ValidateUuid::validate_uuid(&#expr, #versions)
+            }),
+            Execution::Async => None,
+        }
+    }
+}
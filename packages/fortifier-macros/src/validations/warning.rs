@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use syn::{Ident, Result, Type, meta::ParseNestedMeta};
+
+use crate::{
+    generics::Generic,
+    validation::{Execution, Validation},
+};
+
+/// Validation with warning severity, which reports warnings instead of errors.
+pub struct Warning {
+    validation: Box<dyn Validation>,
+}
+
+impl Warning {
+    pub fn new(validation: Box<dyn Validation>) -> Self {
+        Self { validation }
+    }
+}
+
+impl Validation for Warning {
+    fn parse(_type: &Type, meta: &ParseNestedMeta<'_>) -> Result<Self> {
+        Err(meta.error("use `severity = warning` instead"))
+    }
+
+    fn ident(&self) -> Ident {
+        self.validation.ident()
+    }
+
+    fn error_type(&self) -> TokenStream {
+        self.validation.error_type()
+    }
+
+    fn error_attributes(&self) -> TokenStream {
+        self.validation.error_attributes()
+    }
+
+    fn error_generics(&self) -> Vec<Generic> {
+        self.validation.error_generics()
+    }
+
+    fn error_where_predicates(&self) -> Vec<TokenStream> {
+        self.validation.error_where_predicates()
+    }
+
+    #[cfg(feature = "zod")]
+    fn error_typescript(&self) -> TokenStream {
+        self.validation.error_typescript()
+    }
+
+    #[cfg(feature = "zod")]
+    fn error_typescript_where_predicates(&self) -> Vec<TokenStream> {
+        self.validation.error_typescript_where_predicates()
+    }
+
+    // Warnings don't cause validation to fail, so there are no Zod checks.
+
+    fn expr(&self, _execution: Execution, _expr: &TokenStream) -> Option<TokenStream> {
+        None
+    }
+
+    fn warning_expr(&self, execution: Execution, expr: &TokenStream) -> Option<TokenStream> {
+        self.validation.expr(execution, expr)
+    }
+}
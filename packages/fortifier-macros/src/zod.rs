@@ -0,0 +1,553 @@
+use proc_macro2::{Group, Literal, TokenStream};
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Error, Expr, Field, Fields, Generics, Ident, LitStr,
+    Result, Token, Variant, Visibility, meta::ParseNestedMeta, parse_quote,
+};
+
+use crate::validate::{
+    Validate,
+    field::{LiteralOrIdent, ValidateField, ValidateFieldPrefix},
+};
+
+pub struct ToZod<'a> {
+    input: &'a DeriveInput,
+    validate: Validate<'a>,
+    schema: TokenStream,
+}
+
+impl<'a> ToZod<'a> {
+    pub fn parse(input: &'a DeriveInput) -> Result<Self> {
+        let validate = Validate::parse(input)?;
+        let attributes = SerdeAttributes::parse(&input.attrs)?;
+
+        let schema = match &input.data {
+            Data::Struct(data) => {
+                if attributes.transparent
+                    && let Some(field) = data.fields.iter().next()
+                {
+                    let ident = match &field.ident {
+                        Some(ident) => LiteralOrIdent::Ident(ident.clone()),
+                        None => LiteralOrIdent::Literal(Literal::usize_unsuffixed(0)),
+                    };
+
+                    field_schema(&input.vis, &input.generics, &input.ident, ident, field)?
+                } else {
+                    fields_schema(
+                        &input.vis,
+                        &input.generics,
+                        &input.ident,
+                        &data.fields,
+                        attributes.rename_all,
+                        None,
+                    )?
+                }
+            }
+            Data::Enum(data) => enum_schema(input, data, &attributes)?,
+            Data::Union(data) => {
+                return Err(Error::new_spanned(
+                    data.union_token,
+                    "unions are not supported",
+                ));
+            }
+        };
+
+        Ok(ToZod {
+            input,
+            validate,
+            schema,
+        })
+    }
+}
+
+impl<'a> ToTokens for ToZod<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.input.ident;
+        let name = ident.to_string();
+        let schema = &self.schema;
+
+        let mut generics = self.input.generics.clone();
+        let type_params = generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect::<Vec<_>>();
+        let where_clause = generics.make_where_clause();
+        for type_param in &type_params {
+            where_clause
+                .predicates
+                .push(parse_quote!(#type_param: ::fortifier::zod::ToZod));
+        }
+        where_clause
+            .predicates
+            .push(parse_quote!(Self: ::fortifier::ValidateWithContext));
+        where_clause.predicates.push(parse_quote!(
+            <Self as ::fortifier::ValidateWithContext>::Error: ::fortifier::zod::ToTypeScript
+        ));
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+        // Generic types are inlined, because the name of the schema depends on the type arguments.
+        let schema = if self.input.generics.params.is_empty() {
+            quote! {
+                generator.define_schema(#name, |generator| #schema)
+            }
+        } else {
+            schema.clone()
+        };
+
+        let typescript_definition = self.validate.typescript_definition();
+
+        tokens.append_all(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::fortifier::zod::ToZod for #ident #type_generics #where_clause {
+                #[allow(unused_variables)]
+                fn zod(generator: &mut ::fortifier::zod::ZodGenerator, checks: &[::fortifier::zod::ZodCheck]) -> String {
+                    <<Self as ::fortifier::ValidateWithContext>::Error as ::fortifier::zod::ToTypeScript>::typescript(generator);
+
+                    #schema
+                }
+            }
+
+            #typescript_definition
+        });
+    }
+}
+
+fn enum_schema(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attributes: &SerdeAttributes,
+) -> Result<TokenStream> {
+    let mut variant_schemas = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        let variant_attributes = SerdeAttributes::parse(&variant.attrs)?;
+        if variant_attributes.skip {
+            continue;
+        }
+
+        variant_schemas.push(variant_schema(
+            input,
+            variant,
+            attributes,
+            &variant_attributes,
+        )?);
+    }
+
+    Ok(match variant_schemas.len() {
+        0 => quote!("z.never()".to_owned()),
+        1 => variant_schemas.remove(0),
+        _ => quote!(format!("z.union([{}])", [#( #variant_schemas ),*].join(", "))),
+    })
+}
+
+fn variant_schema(
+    input: &DeriveInput,
+    variant: &Variant,
+    attributes: &SerdeAttributes,
+    variant_attributes: &SerdeAttributes,
+) -> Result<TokenStream> {
+    let name = variant_attributes.rename.clone().unwrap_or_else(|| {
+        attributes
+            .rename_all
+            .map(|rule| rule.apply_to_variant(&variant.ident.to_string()))
+            .unwrap_or_else(|| variant.ident.to_string())
+    });
+    let literal = format!("z.literal({})", string(&name));
+
+    let rename_all = variant_attributes
+        .rename_all
+        .or(attributes.rename_all_fields);
+    let type_prefix = format_ident!("{}{}", input.ident, variant.ident);
+    let fields_schema = |tag_property: Option<TokenStream>| {
+        fields_schema(
+            &input.vis,
+            &input.generics,
+            &type_prefix,
+            &variant.fields,
+            rename_all,
+            tag_property,
+        )
+    };
+
+    Ok(
+        match (
+            &attributes.tag,
+            &attributes.content,
+            attributes.untagged || variant_attributes.untagged,
+        ) {
+            (_, _, true) => fields_schema(None)?,
+            (Some(tag), Some(content), false) => {
+                let tag_property = format!("{}: {literal}", key(tag));
+
+                if matches!(variant.fields, Fields::Unit) {
+                    quote!(format!("z.object({{ {} }})", #tag_property))
+                } else {
+                    let content_key = key(content);
+                    let content_schema = fields_schema(None)?;
+
+                    quote!(
+                        format!("z.object({{ {}, {}: {} }})", #tag_property, #content_key, #content_schema)
+                    )
+                }
+            }
+            (Some(tag), None, false) => {
+                let tag_property = format!("{}: {literal}", key(tag));
+
+                match &variant.fields {
+                    Fields::Named(_) => fields_schema(Some(quote!(#tag_property.to_owned())))?,
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        let content_schema = fields_schema(None)?;
+
+                        quote!(
+                            format!("z.object({{ {} }}).and({})", #tag_property, #content_schema)
+                        )
+                    }
+                    Fields::Unnamed(fields) => {
+                        return Err(Error::new_spanned(
+                            fields,
+                            "tuple variants are not supported in internally tagged enums",
+                        ));
+                    }
+                    Fields::Unit => quote!(format!("z.object({{ {} }})", #tag_property)),
+                }
+            }
+            (None, _, false) => {
+                if matches!(variant.fields, Fields::Unit) {
+                    quote!(#literal.to_owned())
+                } else {
+                    let variant_key = key(&name);
+                    let content_schema = fields_schema(None)?;
+
+                    quote!(format!("z.object({{ {}: {} }})", #variant_key, #content_schema))
+                }
+            }
+        },
+    )
+}
+
+fn fields_schema(
+    visibility: &Visibility,
+    generics: &Generics,
+    type_prefix: &Ident,
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    tag_property: Option<TokenStream>,
+) -> Result<TokenStream> {
+    match fields {
+        Fields::Named(fields) => {
+            let mut properties = tag_property.into_iter().collect::<Vec<_>>();
+
+            for field in &fields.named {
+                let Some(ident) = &field.ident else {
+                    continue;
+                };
+
+                let attributes = SerdeAttributes::parse(&field.attrs)?;
+                if attributes.skip {
+                    continue;
+                }
+                if attributes.flatten {
+                    return Err(Error::new_spanned(
+                        field,
+                        "`#[serde(flatten)]` is not supported",
+                    ));
+                }
+
+                let name = attributes.rename.unwrap_or_else(|| {
+                    let name = ident.to_string();
+                    let name = name.strip_prefix("r#").unwrap_or(&name);
+
+                    rename_all
+                        .map(|rule| rule.apply_to_field(name))
+                        .unwrap_or_else(|| name.to_owned())
+                });
+                let key = key(&name);
+                let optional = if attributes.default {
+                    ".optional()"
+                } else {
+                    ""
+                };
+                let schema = field_schema(
+                    visibility,
+                    generics,
+                    type_prefix,
+                    LiteralOrIdent::Ident(ident.clone()),
+                    field,
+                )?;
+
+                properties.push(quote!(format!("{}: {}{}", #key, #schema, #optional)));
+            }
+
+            Ok(if properties.is_empty() {
+                quote!("z.object({})".to_owned())
+            } else {
+                quote!(format!("z.object({{ {} }})", [#( #properties ),*].join(", ")))
+            })
+        }
+        Fields::Unnamed(fields) => {
+            let mut schemas = Vec::with_capacity(fields.unnamed.len());
+
+            for (index, field) in fields.unnamed.iter().enumerate() {
+                if SerdeAttributes::parse(&field.attrs)?.skip {
+                    continue;
+                }
+
+                schemas.push(field_schema(
+                    visibility,
+                    generics,
+                    type_prefix,
+                    LiteralOrIdent::Literal(Literal::usize_unsuffixed(index)),
+                    field,
+                )?);
+            }
+
+            Ok(
+                if fields.unnamed.len() == 1
+                    && let Some(schema) = schemas.first()
+                {
+                    schema.clone()
+                } else {
+                    quote!(format!("z.tuple([{}])", [#( #schemas ),*].join(", ")))
+                },
+            )
+        }
+        Fields::Unit => Ok(quote!("z.null()".to_owned())),
+    }
+}
+
+fn field_schema(
+    visibility: &Visibility,
+    generics: &Generics,
+    type_prefix: &Ident,
+    ident: LiteralOrIdent,
+    field: &Field,
+) -> Result<TokenStream> {
+    let r#type = &field.ty;
+    let checks = ValidateField::parse(
+        visibility,
+        generics,
+        type_prefix,
+        ident,
+        ValidateFieldPrefix::default(),
+        field,
+    )?
+    .zod_checks();
+
+    Ok(quote! {
+        <#r#type as ::fortifier::zod::ToZod>::zod(generator, &[#( #checks ),*])
+    })
+}
+
+/// Format a string as a JavaScript string literal.
+fn string(value: &str) -> String {
+    format!("{value:?}")
+}
+
+/// Format a string as a JavaScript object key.
+fn key(value: &str) -> String {
+    let mut chars = value.chars();
+
+    if chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$')
+    {
+        value.to_owned()
+    } else {
+        string(value)
+    }
+}
+
+#[derive(Default)]
+struct SerdeAttributes {
+    rename: Option<String>,
+    rename_all: Option<RenameRule>,
+    rename_all_fields: Option<RenameRule>,
+    tag: Option<String>,
+    content: Option<String>,
+    untagged: bool,
+    transparent: bool,
+    default: bool,
+    flatten: bool,
+    skip: bool,
+}
+
+impl SerdeAttributes {
+    fn parse(attributes: &[Attribute]) -> Result<Self> {
+        let mut result = SerdeAttributes::default();
+
+        for attribute in attributes {
+            if !attribute.path().is_ident("serde") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    result.rename = Some(deserialize_value(&meta)?.value());
+                } else if meta.path.is_ident("rename_all") {
+                    result.rename_all = Some(RenameRule::parse(&deserialize_value(&meta)?)?);
+                } else if meta.path.is_ident("rename_all_fields") {
+                    result.rename_all_fields = Some(RenameRule::parse(&deserialize_value(&meta)?)?);
+                } else if meta.path.is_ident("tag") {
+                    result.tag = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("content") {
+                    result.content = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("untagged") {
+                    result.untagged = true;
+                } else if meta.path.is_ident("transparent") {
+                    result.transparent = true;
+                } else if meta.path.is_ident("default") {
+                    result.default = true;
+                    skip_value(&meta)?;
+                } else if meta.path.is_ident("flatten") {
+                    result.flatten = true;
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                    result.skip = true;
+                } else {
+                    skip_value(&meta)?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Parse a value which may be specified separately for serialization and deserialization (e.g. `rename`).
+fn deserialize_value(meta: &ParseNestedMeta<'_>) -> Result<LitStr> {
+    if meta.input.peek(Token![=]) {
+        return meta.value()?.parse();
+    }
+
+    let mut value = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("deserialize") {
+            value = Some(meta.value()?.parse()?);
+        } else {
+            skip_value(&meta)?;
+        }
+
+        Ok(())
+    })?;
+
+    value.ok_or_else(|| meta.error("missing `deserialize` parameter"))
+}
+
+/// Skip the value of an unsupported parameter.
+fn skip_value(meta: &ParseNestedMeta<'_>) -> Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.input.parse::<Group>()?;
+    }
+
+    Ok(())
+}
+
+/// Rename rule, matching `#[serde(rename_all = "...")]`.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(value: &LitStr) -> Result<Self> {
+        Ok(match value.value().as_str() {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return Err(Error::new_spanned(value, "unknown rename rule")),
+        })
+    }
+
+    /// Apply to a variant name, which is expected to be in `PascalCase`.
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => lowercase_first(variant),
+            Self::Snake => {
+                let mut snake = String::new();
+                for (index, char) in variant.char_indices() {
+                    if index > 0 && char.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(char.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Apply to a field name, which is expected to be in `snake_case`.
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for char in field.chars() {
+                    if char == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(char.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(char);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => lowercase_first(&Self::Pascal.apply_to_field(field)),
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+/// Lowercase the first character, like Serde does for `camelCase`.
+fn lowercase_first(value: &str) -> String {
+    let mut chars = value.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenameRule;
+
+    #[test]
+    fn camel_case() {
+        assert_eq!(RenameRule::Camel.apply_to_variant("MinLength"), "minLength");
+        assert_eq!(RenameRule::Camel.apply_to_variant("Über"), "Über");
+        assert_eq!(RenameRule::Camel.apply_to_variant(""), "");
+
+        assert_eq!(RenameRule::Camel.apply_to_field("min_length"), "minLength");
+        assert_eq!(RenameRule::Camel.apply_to_field("über_alles"), "überAlles");
+        assert_eq!(RenameRule::Camel.apply_to_field("__"), "");
+    }
+}
@@ -0,0 +1,47 @@
+use std::{rc::Rc, sync::Arc, time::SystemTime};
+
+/// Source of the current time for relative date and time validations.
+///
+/// Supply a clock through the validation context to make validations deterministic, e.g. in tests.
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// Clock using the system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock fixed at a point in time.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+macro_rules! clock_with_deref {
+    ($type:ty) => {
+        impl<C> Clock for $type
+        where
+            C: Clock + ?Sized,
+        {
+            fn now(&self) -> SystemTime {
+                C::now(self)
+            }
+        }
+    };
+}
+
+clock_with_deref!(&C);
+clock_with_deref!(Arc<C>);
+clock_with_deref!(Box<C>);
+clock_with_deref!(Rc<C>);
@@ -4,12 +4,22 @@ use std::{
     ops::Deref,
 };
 
+use constant_string::constant_string;
+
+constant_string!(NestedErrorCode, NESTED_ERROR_CODE, "nested");
+
 /// Validation errors.
 #[derive(Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ValidationErrors<E>(Vec<E>);
 
+impl<E> Default for ValidationErrors<E> {
+    fn default() -> Self {
+        Self(vec![])
+    }
+}
+
 impl<E> Deref for ValidationErrors<E> {
     type Target = Vec<E>;
 
@@ -47,6 +57,53 @@ impl<E> IntoIterator for ValidationErrors<E> {
     }
 }
 
+/// Validation errors and warnings.
+///
+/// Warnings are reported by validations with warning severity, which don't cause validation to fail.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ValidationReport<E> {
+    /// The errors.
+    pub errors: ValidationErrors<E>,
+
+    /// The warnings.
+    pub warnings: ValidationErrors<E>,
+}
+
+impl<E> Default for ValidationReport<E> {
+    fn default() -> Self {
+        Self {
+            errors: ValidationErrors::default(),
+            warnings: ValidationErrors::default(),
+        }
+    }
+}
+
+impl<E> ValidationReport<E> {
+    /// Whether there are no errors. Warnings are allowed.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Convert into a result, with the warnings if there are no errors, or the whole report otherwise.
+    pub fn into_result(self) -> Result<ValidationErrors<E>, ValidationReport<E>> {
+        if self.errors.is_empty() {
+            Ok(self.warnings)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<E: Debug> Display for ValidationReport<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl<E: Error> Error for ValidationReport<E> {}
+
 /// Validation error with index.
 #[derive(Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -57,6 +114,7 @@ pub struct IndexedValidationError<E: Error> {
 
     /// The error.
     #[cfg_attr(feature = "serde", serde(flatten))]
+    #[cfg_attr(feature = "utoipa", schema(inline))]
     pub error: E,
 }
 
@@ -81,10 +139,12 @@ impl<E: Error> Error for IndexedValidationError<E> {}
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct KeyedValidationError<K, E: Error> {
     /// The key.
+    #[cfg_attr(feature = "utoipa", schema(inline))]
     pub key: K,
 
     /// The error.
     #[cfg_attr(feature = "serde", serde(flatten))]
+    #[cfg_attr(feature = "utoipa", schema(inline))]
     pub error: E,
 }
 
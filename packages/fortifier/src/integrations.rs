@@ -1,2 +1,6 @@
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(feature = "utoipa")]
+pub mod utoipa;
+#[cfg(feature = "zod")]
+pub mod zod;
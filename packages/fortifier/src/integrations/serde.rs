@@ -8,6 +8,8 @@ use serde::de::{Error, Unexpected, Visitor};
 pub mod errors {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+    use crate::NestedErrorCode;
+
     /// Deserialize with `errors` field.
     pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
     where
@@ -31,12 +33,12 @@ pub mod errors {
     {
         #[derive(Serialize)]
         struct Wrapper<'a, T> {
-            code: &'static str,
+            code: NestedErrorCode,
             errors: &'a T,
         }
 
         Wrapper {
-            code: "nested",
+            code: NestedErrorCode,
             errors: value,
         }
         .serialize(serializer)
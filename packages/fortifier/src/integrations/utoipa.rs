@@ -0,0 +1,21 @@
+//! Utoipa utilities
+
+use utoipa::ToSchema;
+
+use crate::{NestedErrorCode, ValidationErrors};
+
+// Nested validation errors are serialized with an `errors` field (see `fortifier::serde::errors`),
+// so the schema of `ValidationErrors` does not match the serialized shape. This type is only used
+// to generate the matching schema.
+
+/// Nested validation errors.
+#[derive(ToSchema)]
+pub struct NestedErrors<E> {
+    /// The error code.
+    #[schema(inline)]
+    pub code: NestedErrorCode,
+
+    /// The nested errors.
+    #[schema(inline)]
+    pub errors: ValidationErrors<E>,
+}
@@ -0,0 +1,623 @@
+//! Zod utilities
+
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+    convert::Infallible,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use serde::Serialize;
+
+use crate::{IndexedValidationError, KeyedValidationError, ValidationErrors};
+
+/// Zod schema and TypeScript type generator.
+///
+/// Collects named Zod schemas and TypeScript types, in dependency order, and formats them as a TypeScript module.
+#[derive(Debug, Default)]
+pub struct ZodGenerator {
+    names: HashSet<String>,
+    definitions: Vec<String>,
+}
+
+impl ZodGenerator {
+    /// Constructs a new [`ZodGenerator`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the Zod schema of a type, including the schemas and validation error types it depends on.
+    pub fn add<T: ToZod + ?Sized>(&mut self) -> &mut Self {
+        T::zod(self, &[]);
+
+        self
+    }
+
+    /// Add the TypeScript type of an error, including the types it depends on.
+    pub fn add_error<E: ToTypeScript + ?Sized>(&mut self) -> &mut Self {
+        E::typescript(self);
+
+        self
+    }
+
+    /// Define a named Zod schema and a TypeScript type inferred from it.
+    ///
+    /// The schema is only defined once. Returns the identifier of the schema.
+    pub fn define_schema(
+        &mut self,
+        name: &str,
+        schema: impl FnOnce(&mut Self) -> String,
+    ) -> String {
+        let ident = format!("{name}Schema");
+
+        if self.names.insert(ident.clone()) {
+            let schema = schema(self);
+
+            self.definitions.push(format!(
+                "export const {ident} = {schema};\nexport type {name} = z.infer<typeof {ident}>;"
+            ));
+        }
+
+        ident
+    }
+
+    /// Define a named TypeScript type.
+    ///
+    /// The name may include type parameters (e.g. `LengthError<T>`). The type is only defined once.
+    pub fn define_type(&mut self, name: &str, r#type: impl FnOnce(&mut Self) -> String) {
+        if self.names.insert(name.to_owned()) {
+            let r#type = r#type(self);
+
+            self.definitions
+                .push(format!("export type {name} = {type};"));
+        }
+    }
+}
+
+impl fmt::Display for ZodGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "import {{ z }} from \"zod\";")?;
+
+        for definition in &self.definitions {
+            writeln!(f)?;
+            writeln!(f, "{definition}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Zod check, derived from a validation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ZodCheck {
+    /// Email address check.
+    EmailAddress,
+    /// Length check.
+    Length {
+        /// The required length.
+        equal: Option<usize>,
+        /// The minimum length.
+        min: Option<usize>,
+        /// The maximum length.
+        max: Option<usize>,
+    },
+    /// Phone number check.
+    PhoneNumber,
+    /// Range check.
+    ///
+    /// The values are formatted as JavaScript literals, see [`literal`].
+    Range {
+        /// The minimum value (inclusive).
+        min: Option<String>,
+        /// The maximum value (inclusive).
+        max: Option<String>,
+        /// The minimum value (exclusive).
+        exclusive_min: Option<String>,
+        /// The maximum value (exclusive).
+        exclusive_max: Option<String>,
+    },
+    /// Regular expression check.
+    Regex(String),
+    /// URL check.
+    Url,
+}
+
+/// Convert to a Zod schema.
+pub trait ToZod {
+    /// The Zod schema expression.
+    ///
+    /// The checks apply to the innermost type, i.e. after unwrapping options and pointers.
+    fn zod(generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String;
+}
+
+/// Convert to a TypeScript type.
+pub trait ToTypeScript {
+    /// The TypeScript type expression.
+    fn typescript(generator: &mut ZodGenerator) -> String;
+}
+
+/// Format a value as a JavaScript literal.
+pub fn literal<T: Serialize + ?Sized>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "undefined".to_owned())
+}
+
+/// Format a TypeScript type for an error.
+///
+/// The `message` field is included if the `message` feature is enabled.
+pub fn error_type(code: &str, subcode: Option<&str>, fields: &[(&str, &str)]) -> String {
+    let mut properties = vec![format!("code: \"{code}\"")];
+
+    if let Some(subcode) = subcode {
+        properties.push(format!("subcode: \"{subcode}\""));
+    }
+
+    properties.extend(
+        fields
+            .iter()
+            .map(|(name, r#type)| format!("{name}: {type}")),
+    );
+
+    if cfg!(feature = "message") {
+        properties.push("message: string".to_owned());
+    }
+
+    format!("{{ {} }}", properties.join("; "))
+}
+
+/// Format the TypeScript type for nested validation errors.
+///
+/// Nested validation errors are serialized with an `errors` field (see [`crate::serde::errors`]).
+pub fn nested_errors<E: ToTypeScript + ?Sized>(generator: &mut ZodGenerator) -> String {
+    generator.define_type("NestedErrors<E>", |_| {
+        "{ code: \"nested\"; errors: Array<E> }".to_owned()
+    });
+
+    format!("NestedErrors<{}>", E::typescript(generator))
+}
+
+fn regex_check(pattern: &str) -> String {
+    format!(".regex(new RegExp({}))", literal(pattern))
+}
+
+fn string_checks(checks: &[ZodCheck]) -> String {
+    checks
+        .iter()
+        .map(|check| match check {
+            ZodCheck::EmailAddress => ".email()".to_owned(),
+            ZodCheck::Length { equal, min, max } => length_checks(*equal, *min, *max),
+            ZodCheck::PhoneNumber => regex_check(r"^\+?[0-9 ()./-]+$"),
+            ZodCheck::Range { .. } => String::new(),
+            ZodCheck::Regex(pattern) => regex_check(pattern),
+            ZodCheck::Url => ".url()".to_owned(),
+        })
+        .collect()
+}
+
+fn number_checks(checks: &[ZodCheck]) -> String {
+    checks
+        .iter()
+        .map(|check| match check {
+            ZodCheck::Range {
+                min,
+                max,
+                exclusive_min,
+                exclusive_max,
+            } => [
+                min.as_ref().map(|min| format!(".gte({min})")),
+                exclusive_min
+                    .as_ref()
+                    .map(|exclusive_min| format!(".gt({exclusive_min})")),
+                max.as_ref().map(|max| format!(".lte({max})")),
+                exclusive_max
+                    .as_ref()
+                    .map(|exclusive_max| format!(".lt({exclusive_max})")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+fn array_checks(checks: &[ZodCheck]) -> String {
+    checks
+        .iter()
+        .map(|check| match check {
+            ZodCheck::Length { equal, min, max } => length_checks(*equal, *min, *max),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+fn record_checks(checks: &[ZodCheck]) -> String {
+    checks
+        .iter()
+        .map(|check| match check {
+            ZodCheck::Length { equal, min, max } => [
+                equal.map(|equal| format!(" === {equal}")),
+                min.map(|min| format!(" >= {min}")),
+                max.map(|max| format!(" <= {max}")),
+            ]
+            .into_iter()
+            .flatten()
+            .map(|condition| format!(".refine((value) => Object.keys(value).length{condition})"))
+            .collect(),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+fn length_checks(equal: Option<usize>, min: Option<usize>, max: Option<usize>) -> String {
+    [
+        equal.map(|equal| format!(".length({equal})")),
+        min.map(|min| format!(".min({min})")),
+        max.map(|max| format!(".max({max})")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+impl ToZod for bool {
+    fn zod(_generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        "z.boolean()".to_owned()
+    }
+}
+
+impl ToTypeScript for bool {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "boolean".to_owned()
+    }
+}
+
+macro_rules! to_zod_number {
+    ($schema:literal, $($type:ty),*) => {
+        $(
+            impl ToZod for $type {
+                fn zod(_generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+                    format!("{}{}", $schema, number_checks(checks))
+                }
+            }
+
+            impl ToTypeScript for $type {
+                fn typescript(_generator: &mut ZodGenerator) -> String {
+                    "number".to_owned()
+                }
+            }
+        )*
+    };
+}
+
+to_zod_number!("z.number().int()", i8, i16, i32, i64, i128, isize);
+to_zod_number!(
+    "z.number().int().nonnegative()",
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize
+);
+to_zod_number!("z.number()", f32, f64);
+
+macro_rules! to_zod_string {
+    ($($type:ty),*) => {
+        $(
+            impl ToZod for $type {
+                fn zod(_generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+                    format!("z.string(){}", string_checks(checks))
+                }
+            }
+
+            impl ToTypeScript for $type {
+                fn typescript(_generator: &mut ZodGenerator) -> String {
+                    "string".to_owned()
+                }
+            }
+        )*
+    };
+}
+
+to_zod_string!(str, String);
+
+impl ToZod for char {
+    fn zod(_generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+        format!("z.string().length(1){}", string_checks(checks))
+    }
+}
+
+impl ToTypeScript for char {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "string".to_owned()
+    }
+}
+
+impl ToTypeScript for Infallible {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "never".to_owned()
+    }
+}
+
+impl<T> ToZod for Option<T>
+where
+    T: ToZod,
+{
+    fn zod(generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+        format!("{}.nullish()", T::zod(generator, checks))
+    }
+}
+
+impl<T> ToTypeScript for Option<T>
+where
+    T: ToTypeScript,
+{
+    fn typescript(generator: &mut ZodGenerator) -> String {
+        format!("{} | null", T::typescript(generator))
+    }
+}
+
+macro_rules! to_zod_with_deref {
+    ($type:ty) => {
+        impl<T> ToZod for $type
+        where
+            T: ToZod + ?Sized,
+        {
+            fn zod(generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+                T::zod(generator, checks)
+            }
+        }
+
+        impl<T> ToTypeScript for $type
+        where
+            T: ToTypeScript + ?Sized,
+        {
+            fn typescript(generator: &mut ZodGenerator) -> String {
+                T::typescript(generator)
+            }
+        }
+    };
+}
+
+to_zod_with_deref!(&T);
+to_zod_with_deref!(Arc<T>);
+to_zod_with_deref!(Box<T>);
+to_zod_with_deref!(Rc<T>);
+to_zod_with_deref!(Ref<'_, T>);
+to_zod_with_deref!(RefMut<'_, T>);
+
+impl<T> ToZod for crate::Validated<T>
+where
+    T: ToZod,
+{
+    fn zod(generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+        T::zod(generator, checks)
+    }
+}
+
+impl<T> ToZod for Cow<'_, T>
+where
+    T: ToZod + ToOwned + ?Sized,
+{
+    fn zod(generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+        T::zod(generator, checks)
+    }
+}
+
+impl<T> ToTypeScript for Cow<'_, T>
+where
+    T: ToTypeScript + ToOwned + ?Sized,
+{
+    fn typescript(generator: &mut ZodGenerator) -> String {
+        T::typescript(generator)
+    }
+}
+
+macro_rules! to_zod_with_items {
+    ($type:ty) => {
+        impl<T> ToZod for $type
+        where
+            T: ToZod,
+        {
+            fn zod(generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+                format!(
+                    "z.array({}){}",
+                    T::zod(generator, &[]),
+                    array_checks(checks)
+                )
+            }
+        }
+
+        impl<T> ToTypeScript for $type
+        where
+            T: ToTypeScript,
+        {
+            fn typescript(generator: &mut ZodGenerator) -> String {
+                format!("Array<{}>", T::typescript(generator))
+            }
+        }
+    };
+}
+
+to_zod_with_items!([T]);
+to_zod_with_items!(BTreeSet<T>);
+to_zod_with_items!(HashSet<T>);
+#[cfg(feature = "indexmap")]
+to_zod_with_items!(indexmap::IndexSet<T>);
+to_zod_with_items!(LinkedList<T>);
+to_zod_with_items!(Vec<T>);
+to_zod_with_items!(VecDeque<T>);
+
+impl<T, const N: usize> ToZod for [T; N]
+where
+    T: ToZod,
+{
+    fn zod(generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        format!("z.array({}).length({N})", T::zod(generator, &[]))
+    }
+}
+
+macro_rules! to_zod_with_keys {
+    ($type:ty) => {
+        impl<K, V> ToZod for $type
+        where
+            V: ToZod,
+        {
+            fn zod(generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+                // Keys are always serialized as strings in JSON.
+                format!(
+                    "z.record(z.string(), {}){}",
+                    V::zod(generator, &[]),
+                    record_checks(checks)
+                )
+            }
+        }
+    };
+}
+
+to_zod_with_keys!(BTreeMap<K, V>);
+to_zod_with_keys!(HashMap<K, V>);
+#[cfg(feature = "indexmap")]
+to_zod_with_keys!(indexmap::IndexMap<K, V>);
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToZod for chrono::DateTime<Tz> {
+    fn zod(_generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        "z.string().datetime({ offset: true })".to_owned()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToZod for chrono::NaiveDateTime {
+    fn zod(_generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        "z.string().datetime({ local: true })".to_owned()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToZod for chrono::NaiveDate {
+    fn zod(_generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        "z.string().date()".to_owned()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToZod for chrono::NaiveTime {
+    fn zod(_generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        "z.string().time()".to_owned()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToTypeScript for chrono::DateTime<Tz> {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "string".to_owned()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToTypeScript for chrono::NaiveDateTime {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "string".to_owned()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToTypeScript for chrono::NaiveDate {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "string".to_owned()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToTypeScript for chrono::NaiveTime {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "string".to_owned()
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl ToZod for rust_decimal::Decimal {
+    fn zod(_generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        // Decimals are serialized as strings by default.
+        "z.string()".to_owned()
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl ToTypeScript for rust_decimal::Decimal {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "string".to_owned()
+    }
+}
+
+#[cfg(feature = "email-address")]
+impl ToZod for email_address::EmailAddress {
+    fn zod(_generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+        format!("z.string().email(){}", string_checks(checks))
+    }
+}
+
+#[cfg(feature = "url")]
+impl ToZod for url::Url {
+    fn zod(_generator: &mut ZodGenerator, checks: &[ZodCheck]) -> String {
+        format!("z.string().url(){}", string_checks(checks))
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToZod for uuid::Uuid {
+    fn zod(_generator: &mut ZodGenerator, _checks: &[ZodCheck]) -> String {
+        "z.string().uuid()".to_owned()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToTypeScript for uuid::Uuid {
+    fn typescript(_generator: &mut ZodGenerator) -> String {
+        "string".to_owned()
+    }
+}
+
+impl<E> ToTypeScript for ValidationErrors<E>
+where
+    E: ToTypeScript,
+{
+    fn typescript(generator: &mut ZodGenerator) -> String {
+        format!("Array<{}>", E::typescript(generator))
+    }
+}
+
+impl<E> ToTypeScript for IndexedValidationError<E>
+where
+    E: ToTypeScript + std::error::Error,
+{
+    fn typescript(generator: &mut ZodGenerator) -> String {
+        generator.define_type("IndexedValidationError<E>", |_| {
+            "{ index: number } & E".to_owned()
+        });
+
+        format!("IndexedValidationError<{}>", E::typescript(generator))
+    }
+}
+
+impl<K, E> ToTypeScript for KeyedValidationError<K, E>
+where
+    K: ToTypeScript,
+    E: ToTypeScript + std::error::Error,
+{
+    fn typescript(generator: &mut ZodGenerator) -> String {
+        generator.define_type("KeyedValidationError<K, E>", |_| {
+            "{ key: K } & E".to_owned()
+        });
+
+        format!(
+            "KeyedValidationError<{}, {}>",
+            K::typescript(generator),
+            E::typescript(generator)
+        )
+    }
+}
@@ -2,15 +2,25 @@
 
 //! Fortifier.
 
+mod clock;
 mod error;
 mod integrations;
+mod sanitize;
+#[cfg(feature = "schema")]
+mod schema;
 mod validate;
+mod validated;
 mod validations;
 
+pub use clock::*;
 pub use error::*;
 #[allow(unused_imports)]
 pub use integrations::*;
+pub use sanitize::*;
+#[cfg(feature = "schema")]
+pub use schema::*;
 pub use validate::*;
+pub use validated::*;
 pub use validations::*;
 
 #[cfg(feature = "macros")]
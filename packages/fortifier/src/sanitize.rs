@@ -0,0 +1,371 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+    hash::{BuildHasher, Hash},
+    mem,
+    pin::Pin,
+    rc::Rc,
+    sync::Arc,
+};
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{Validate, ValidateWithContext, ValidationErrors};
+
+/// Sanitize a schema.
+pub trait Sanitize {
+    /// Sanitize schema in place.
+    fn sanitize(&mut self);
+
+    /// Sanitize schema in place, then validate it using all validators.
+    fn sanitize_and_validate(
+        &mut self,
+    ) -> Pin<
+        Box<
+            impl Future<Output = Result<(), ValidationErrors<<Self as ValidateWithContext>::Error>>>
+            + Send,
+        >,
+    >
+    where
+        Self: Validate + Sync,
+    {
+        self.sanitize();
+        self.validate()
+    }
+
+    /// Sanitize schema in place, then validate it using only synchronous validators.
+    fn sanitize_and_validate_sync(
+        &mut self,
+    ) -> Result<(), ValidationErrors<<Self as ValidateWithContext>::Error>>
+    where
+        Self: Validate,
+    {
+        self.sanitize();
+        self.validate_sync()
+    }
+}
+
+macro_rules! sanitize_with_items {
+    ($type:ty) => {
+        sanitize_with_items!($type,);
+    };
+    ($type:ty, $( $generic:ident ),*$( , )*) => {
+        impl<T, $( $generic ),*> Sanitize for $type
+        where
+            T: Sanitize,
+        {
+            fn sanitize(&mut self) {
+                for item in self.iter_mut() {
+                    item.sanitize();
+                }
+            }
+        }
+    };
+}
+
+sanitize_with_items!([T]);
+sanitize_with_items!(LinkedList<T>);
+sanitize_with_items!(Option<T>);
+sanitize_with_items!(Vec<T>);
+sanitize_with_items!(VecDeque<T>);
+
+impl<T, const N: usize> Sanitize for [T; N]
+where
+    T: Sanitize,
+{
+    fn sanitize(&mut self) {
+        for item in self.iter_mut() {
+            item.sanitize();
+        }
+    }
+}
+
+impl<T> Sanitize for Box<T>
+where
+    T: Sanitize + ?Sized,
+{
+    fn sanitize(&mut self) {
+        T::sanitize(self)
+    }
+}
+
+impl<T> Sanitize for Arc<T>
+where
+    T: Sanitize + Clone,
+{
+    fn sanitize(&mut self) {
+        Arc::make_mut(self).sanitize()
+    }
+}
+
+impl<T> Sanitize for Rc<T>
+where
+    T: Sanitize + Clone,
+{
+    fn sanitize(&mut self) {
+        Rc::make_mut(self).sanitize()
+    }
+}
+
+// Items of sets can't be mutated in place, so sets are rebuilt.
+fn rebuild<C, T>(items: &mut C)
+where
+    C: Default + IntoIterator<Item = T> + FromIterator<T>,
+    T: Sanitize,
+{
+    *items = mem::take(items)
+        .into_iter()
+        .map(|mut item| {
+            item.sanitize();
+            item
+        })
+        .collect();
+}
+
+impl<T> Sanitize for BTreeSet<T>
+where
+    T: Sanitize + Ord,
+{
+    fn sanitize(&mut self) {
+        rebuild(self)
+    }
+}
+
+impl<T, S> Sanitize for HashSet<T, S>
+where
+    T: Sanitize + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn sanitize(&mut self) {
+        rebuild(self)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> Sanitize for IndexSet<T, S>
+where
+    T: Sanitize + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn sanitize(&mut self) {
+        rebuild(self)
+    }
+}
+
+macro_rules! sanitize_with_values {
+    ($type:ty) => {
+        sanitize_with_values!($type,);
+    };
+    ($type:ty, $( $generic:ident ),*$( , )*) => {
+        impl<K, V, $( $generic ),*> Sanitize for $type
+        where
+            V: Sanitize,
+        {
+            fn sanitize(&mut self) {
+                for value in self.values_mut() {
+                    value.sanitize();
+                }
+            }
+        }
+    };
+}
+
+sanitize_with_values!(BTreeMap<K, V>);
+sanitize_with_values!(HashMap<K, V, S>, S);
+#[cfg(feature = "indexmap")]
+sanitize_with_values!(IndexMap<K, V, S>, S);
+
+/// Sanitize strings.
+pub trait SanitizeString {
+    /// Sanitize each string in place using a function.
+    fn sanitize_string(&mut self, f: impl Fn(&mut String) + Copy);
+}
+
+impl SanitizeString for String {
+    fn sanitize_string(&mut self, f: impl Fn(&mut String) + Copy) {
+        f(self)
+    }
+}
+
+impl SanitizeString for Cow<'_, str> {
+    fn sanitize_string(&mut self, f: impl Fn(&mut String) + Copy) {
+        f(self.to_mut())
+    }
+}
+
+macro_rules! sanitize_string_with_items {
+    ($type:ty) => {
+        sanitize_string_with_items!($type,);
+    };
+    ($type:ty, $( $generic:ident ),*$( , )*) => {
+        impl<T, $( $generic ),*> SanitizeString for $type
+        where
+            T: SanitizeString,
+        {
+            fn sanitize_string(&mut self, f: impl Fn(&mut String) + Copy) {
+                for item in self.iter_mut() {
+                    item.sanitize_string(f);
+                }
+            }
+        }
+    };
+}
+
+sanitize_string_with_items!([T]);
+sanitize_string_with_items!(LinkedList<T>);
+sanitize_string_with_items!(Option<T>);
+sanitize_string_with_items!(Vec<T>);
+sanitize_string_with_items!(VecDeque<T>);
+
+impl<T, const N: usize> SanitizeString for [T; N]
+where
+    T: SanitizeString,
+{
+    fn sanitize_string(&mut self, f: impl Fn(&mut String) + Copy) {
+        for item in self.iter_mut() {
+            item.sanitize_string(f);
+        }
+    }
+}
+
+impl<T> SanitizeString for Box<T>
+where
+    T: SanitizeString + ?Sized,
+{
+    fn sanitize_string(&mut self, f: impl Fn(&mut String) + Copy) {
+        T::sanitize_string(self, f)
+    }
+}
+
+macro_rules! sanitize_string_with_values {
+    ($type:ty) => {
+        sanitize_string_with_values!($type,);
+    };
+    ($type:ty, $( $generic:ident ),*$( , )*) => {
+        impl<K, V, $( $generic ),*> SanitizeString for $type
+        where
+            V: SanitizeString,
+        {
+            fn sanitize_string(&mut self, f: impl Fn(&mut String) + Copy) {
+                for value in self.values_mut() {
+                    value.sanitize_string(f);
+                }
+            }
+        }
+    };
+}
+
+sanitize_string_with_values!(BTreeMap<K, V>);
+sanitize_string_with_values!(HashMap<K, V, S>, S);
+#[cfg(feature = "indexmap")]
+sanitize_string_with_values!(IndexMap<K, V, S>, S);
+
+/// Remove leading and trailing whitespace.
+pub fn sanitize_trim(value: &mut String) {
+    let trimmed = value.trim();
+
+    if trimmed.len() != value.len() {
+        *value = trimmed.to_owned();
+    }
+}
+
+/// Convert to lowercase.
+pub fn sanitize_lowercase(value: &mut String) {
+    *value = value.to_lowercase();
+}
+
+/// Replace each sequence of whitespace with a single space.
+pub fn sanitize_collapse_whitespace(value: &mut String) {
+    let mut result = String::with_capacity(value.len());
+    let mut whitespace = false;
+
+    for c in value.chars() {
+        if c.is_whitespace() {
+            whitespace = true;
+        } else {
+            if whitespace {
+                result.push(' ');
+                whitespace = false;
+            }
+
+            result.push(c);
+        }
+    }
+
+    if whitespace {
+        result.push(' ');
+    }
+
+    *value = result;
+}
+
+/// Normalize to Unicode Normalization Form C (canonical composition).
+#[cfg(feature = "unicode-normalization")]
+pub fn sanitize_nfc(value: &mut String) {
+    use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick};
+
+    if is_nfc_quick(value.chars()) != IsNormalized::Yes {
+        *value = value.nfc().collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, collections::BTreeMap};
+
+    #[cfg(feature = "unicode-normalization")]
+    use super::sanitize_nfc;
+    use super::{SanitizeString, sanitize_collapse_whitespace, sanitize_lowercase, sanitize_trim};
+
+    fn sanitized(value: &str, f: impl Fn(&mut String)) -> String {
+        let mut value = value.to_owned();
+        f(&mut value);
+        value
+    }
+
+    #[test]
+    fn trim() {
+        assert_eq!(sanitized("Alice", sanitize_trim), "Alice");
+        assert_eq!(sanitized("  Alice \n", sanitize_trim), "Alice");
+        assert_eq!(sanitized("   ", sanitize_trim), "");
+    }
+
+    #[test]
+    fn lowercase() {
+        assert_eq!(
+            sanitized("ALICE@Example.COM", sanitize_lowercase),
+            "alice@example.com"
+        );
+    }
+
+    #[test]
+    fn collapse_whitespace() {
+        assert_eq!(
+            sanitized("Alice  van\t\nder Berg", sanitize_collapse_whitespace),
+            "Alice van der Berg"
+        );
+        assert_eq!(
+            sanitized("  Alice  ", sanitize_collapse_whitespace),
+            " Alice "
+        );
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn nfc() {
+        assert_eq!(sanitized("e\u{301}", sanitize_nfc), "\u{e9}");
+        assert_eq!(sanitized("\u{e9}", sanitize_nfc), "\u{e9}");
+    }
+
+    #[test]
+    fn containers() {
+        let mut value = Some(vec![" a ".to_owned(), " b".to_owned()]);
+        value.sanitize_string(sanitize_trim);
+        assert_eq!(value, Some(vec!["a".to_owned(), "b".to_owned()]));
+
+        let mut value = BTreeMap::from([("key", Cow::Borrowed("A"))]);
+        value.sanitize_string(sanitize_lowercase);
+        assert_eq!(value, BTreeMap::from([("key", Cow::Borrowed("a"))]));
+    }
+}
@@ -0,0 +1,732 @@
+//! Runtime schema validation of JSON values.
+
+#[cfg(any(feature = "email-address", feature = "phone-number", feature = "url"))]
+use std::borrow::Cow;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use constant_string::constant_string;
+#[cfg(feature = "regex")]
+use regex::Regex;
+use serde::{
+    Deserialize, Serialize, Serializer,
+    ser::{Error as _, SerializeMap},
+};
+use serde_json::Value;
+
+#[cfg(feature = "email-address")]
+use crate::{EmailAddressError, EmailAddressOptions, EmailAddressPolicy, ValidateEmailAddress};
+use crate::{
+    IndexedValidationError, LengthError, RangeError, ValidateLength, ValidateRange,
+    ValidationErrors,
+};
+#[cfg(feature = "phone-number")]
+use crate::{PhoneNumberCountry, PhoneNumberError, PhoneNumberOptions, ValidatePhoneNumber};
+#[cfg(feature = "regex")]
+use crate::{RegexError, ValidateRegex};
+#[cfg(feature = "url")]
+use crate::{UrlError, UrlOptions, ValidateUrl};
+
+/// JSON value type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ValueType {
+    /// Null.
+    Null,
+    /// Boolean.
+    Boolean,
+    /// Integer.
+    Integer,
+    /// Number.
+    Number,
+    /// String.
+    String,
+    /// Array.
+    Array,
+    /// Object.
+    Object,
+}
+
+impl ValueType {
+    /// Get the type of a JSON value.
+    ///
+    /// Numbers without a fractional part are considered integers.
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(_) => Self::Boolean,
+            Value::Number(number) => {
+                if number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().is_some_and(|number| number.fract() == 0.0)
+                {
+                    Self::Integer
+                } else {
+                    Self::Number
+                }
+            }
+            Value::String(_) => Self::String,
+            Value::Array(_) => Self::Array,
+            Value::Object(_) => Self::Object,
+        }
+    }
+}
+
+impl Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Null => "null",
+                Self::Boolean => "boolean",
+                Self::Integer => "integer",
+                Self::Number => "number",
+                Self::String => "string",
+                Self::Array => "array",
+                Self::Object => "object",
+            }
+        )
+    }
+}
+
+/// Schema, validating a JSON value at runtime.
+///
+/// # Example
+///
+/// ```
+/// use fortifier::{Schema, SchemaValidation};
+/// use serde_json::json;
+///
+/// let schema = Schema::object()
+///     .required_property(
+///         "name",
+///         Schema::string().validation(SchemaValidation::Length {
+///             equal: None,
+///             min: Some(1),
+///             max: Some(256),
+///         }),
+///     )
+///     .property("tags", Schema::array(Schema::string()));
+///
+/// assert!(schema.validate(&json!({ "name": "John Doe" })).is_ok());
+/// assert!(schema.validate(&json!({ "name": "", "tags": [1] })).is_err());
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Schema {
+    /// The kind of schema.
+    #[serde(flatten)]
+    pub kind: SchemaKind,
+
+    /// The validations of the value.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validations: Vec<SchemaValidation>,
+}
+
+impl Schema {
+    /// Constructs a new [`Schema`].
+    pub fn new(kind: SchemaKind) -> Self {
+        Self {
+            kind,
+            validations: vec![],
+        }
+    }
+
+    /// Constructs a schema accepting any value.
+    pub fn any() -> Self {
+        Self::new(SchemaKind::Any)
+    }
+
+    /// Constructs a boolean schema.
+    pub fn boolean() -> Self {
+        Self::new(SchemaKind::Boolean)
+    }
+
+    /// Constructs an integer schema.
+    pub fn integer() -> Self {
+        Self::new(SchemaKind::Integer)
+    }
+
+    /// Constructs a number schema.
+    pub fn number() -> Self {
+        Self::new(SchemaKind::Number)
+    }
+
+    /// Constructs a string schema.
+    pub fn string() -> Self {
+        Self::new(SchemaKind::String)
+    }
+
+    /// Constructs an array schema.
+    pub fn array(items: Schema) -> Self {
+        Self::new(SchemaKind::Array {
+            items: Box::new(items),
+        })
+    }
+
+    /// Constructs an object schema without properties.
+    pub fn object() -> Self {
+        Self::new(SchemaKind::Object {
+            properties: BTreeMap::new(),
+        })
+    }
+
+    /// Add an optional property.
+    ///
+    /// Has no effect if this is not an object schema.
+    pub fn property(self, name: impl Into<String>, schema: Schema) -> Self {
+        self.with_property(name.into(), schema, false)
+    }
+
+    /// Add a required property.
+    ///
+    /// Has no effect if this is not an object schema.
+    pub fn required_property(self, name: impl Into<String>, schema: Schema) -> Self {
+        self.with_property(name.into(), schema, true)
+    }
+
+    /// Add a validation.
+    pub fn validation(mut self, validation: SchemaValidation) -> Self {
+        self.validations.push(validation);
+        self
+    }
+
+    /// Validate a JSON value.
+    pub fn validate(&self, value: &Value) -> Result<(), ValidationErrors<SchemaError>> {
+        let (mut errors, nested_errors) = self.errors(value);
+        errors.extend(nested_errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.into())
+        }
+    }
+
+    fn with_property(mut self, name: String, schema: Schema, required: bool) -> Self {
+        if let SchemaKind::Object { properties } = &mut self.kind {
+            properties.insert(name, SchemaProperty { schema, required });
+        }
+
+        self
+    }
+
+    /// Errors of the value itself and errors of its items or properties.
+    fn errors(&self, value: &Value) -> (Vec<SchemaError>, Vec<SchemaError>) {
+        let actual = ValueType::of(value);
+        if let Some(expected) = self.kind.value_type()
+            && expected != actual
+            && !(expected == ValueType::Number && actual == ValueType::Integer)
+        {
+            return (
+                vec![SchemaError::Type(TypeError::new(expected, actual))],
+                vec![],
+            );
+        }
+
+        let errors = self
+            .validations
+            .iter()
+            .filter_map(|validation| validation.validate(value).err())
+            .collect();
+
+        let nested_errors = match (&self.kind, value) {
+            (SchemaKind::Array { items }, Value::Array(values)) => values
+                .iter()
+                .enumerate()
+                .flat_map(|(index, value)| {
+                    items.wrapped_errors(value, |error| {
+                        SchemaError::Item(IndexedValidationError::new(index, error))
+                    })
+                })
+                .collect(),
+            (SchemaKind::Object { properties }, Value::Object(values)) => properties
+                .iter()
+                .flat_map(|(name, property)| match values.get(name) {
+                    None | Some(Value::Null) => {
+                        if property.required {
+                            vec![SchemaError::Property {
+                                path: name.clone(),
+                                error: Box::new(SchemaError::Required(RequiredError::default())),
+                            }]
+                        } else {
+                            vec![]
+                        }
+                    }
+                    Some(value) => {
+                        property
+                            .schema
+                            .wrapped_errors(value, |error| SchemaError::Property {
+                                path: name.clone(),
+                                error,
+                            })
+                    }
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        (errors, nested_errors)
+    }
+
+    /// Errors of a child value, with errors of its items or properties grouped as nested errors.
+    fn wrapped_errors(
+        &self,
+        value: &Value,
+        wrap: impl Fn(Box<SchemaError>) -> SchemaError,
+    ) -> Vec<SchemaError> {
+        let (errors, nested_errors) = self.errors(value);
+
+        errors
+            .into_iter()
+            .chain((!nested_errors.is_empty()).then(|| SchemaError::Nested(nested_errors.into())))
+            .map(|error| wrap(Box::new(error)))
+            .collect()
+    }
+}
+
+/// Kind of schema.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SchemaKind {
+    /// Any value.
+    Any,
+    /// Boolean.
+    Boolean,
+    /// Integer.
+    Integer,
+    /// Number.
+    Number,
+    /// String.
+    String,
+    /// Array.
+    Array {
+        /// The schema of the items.
+        items: Box<Schema>,
+    },
+    /// Object.
+    Object {
+        /// The properties.
+        #[serde(default)]
+        properties: BTreeMap<String, SchemaProperty>,
+    },
+}
+
+impl SchemaKind {
+    /// The expected value type, if any.
+    pub fn value_type(&self) -> Option<ValueType> {
+        match self {
+            Self::Any => None,
+            Self::Boolean => Some(ValueType::Boolean),
+            Self::Integer => Some(ValueType::Integer),
+            Self::Number => Some(ValueType::Number),
+            Self::String => Some(ValueType::String),
+            Self::Array { .. } => Some(ValueType::Array),
+            Self::Object { .. } => Some(ValueType::Object),
+        }
+    }
+}
+
+/// Object schema property.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SchemaProperty {
+    /// The schema of the property.
+    #[serde(flatten)]
+    pub schema: Schema,
+
+    /// Whether the property is required.
+    ///
+    /// Optional properties may be missing or `null`.
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Schema validation.
+///
+/// Validations that do not apply to the type of a value are ignored, e.g. a regular expression validation on a number.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum SchemaValidation {
+    /// Email address validation of a string.
+    #[cfg(feature = "email-address")]
+    EmailAddress {
+        /// Whether display text is allowed.
+        #[serde(default)]
+        allow_display_text: bool,
+
+        /// Whether a domain literal is allowed.
+        #[serde(default = "default_allow_domain_literal")]
+        allow_domain_literal: bool,
+
+        /// The minimum number of domain segments.
+        #[serde(default)]
+        minimum_sub_domains: usize,
+    },
+
+    /// Length validation of a string (in characters), array or object.
+    Length {
+        /// The exact length.
+        equal: Option<usize>,
+
+        /// The minimum length.
+        min: Option<usize>,
+
+        /// The maximum length.
+        max: Option<usize>,
+    },
+
+    /// Phone number validation of a string.
+    #[cfg(feature = "phone-number")]
+    PhoneNumber {
+        /// The default country.
+        default_country: Option<PhoneNumberCountry>,
+
+        /// The allowed countries.
+        allowed_countries: Option<Vec<PhoneNumberCountry>>,
+    },
+
+    /// Range validation of a number.
+    Range {
+        /// The minimum value.
+        min: Option<f64>,
+
+        /// The maximum value.
+        max: Option<f64>,
+
+        /// The exclusive minimum value.
+        exclusive_min: Option<f64>,
+
+        /// The exclusive maximum value.
+        exclusive_max: Option<f64>,
+    },
+
+    /// Regular expression validation of a string.
+    #[cfg(feature = "regex")]
+    Regex {
+        /// The regular expression.
+        #[serde(with = "regex_pattern")]
+        pattern: Regex,
+    },
+
+    /// URL validation of a string.
+    #[cfg(feature = "url")]
+    Url,
+}
+
+impl SchemaValidation {
+    /// Validate a JSON value.
+    pub fn validate(&self, value: &Value) -> Result<(), SchemaError> {
+        match self {
+            #[cfg(feature = "email-address")]
+            Self::EmailAddress {
+                allow_display_text,
+                allow_domain_literal,
+                minimum_sub_domains,
+            } => value
+                .validate_email_address(
+                    EmailAddressOptions {
+                        allow_display_text: *allow_display_text,
+                        allow_domain_literal: *allow_domain_literal,
+                        minimum_sub_domains: *minimum_sub_domains,
+                    },
+                    EmailAddressPolicy::default(),
+                )
+                .map_err(SchemaError::EmailAddress),
+            Self::Length { equal, min, max } => value
+                .validate_length(*equal, *min, *max)
+                .map_err(SchemaError::Length),
+            #[cfg(feature = "phone-number")]
+            Self::PhoneNumber {
+                default_country,
+                allowed_countries,
+            } => value
+                .validate_phone_number(
+                    *default_country,
+                    allowed_countries.clone(),
+                    PhoneNumberOptions::default(),
+                )
+                .map_err(SchemaError::PhoneNumber),
+            Self::Range {
+                min,
+                max,
+                exclusive_min,
+                exclusive_max,
+            } => value
+                .validate_range(*min, *max, *exclusive_min, *exclusive_max)
+                .map_err(SchemaError::Range),
+            #[cfg(feature = "regex")]
+            Self::Regex { pattern } => value.validate_regex(pattern).map_err(SchemaError::Regex),
+            #[cfg(feature = "url")]
+            Self::Url => value
+                .validate_url(UrlOptions::default())
+                .map_err(SchemaError::Url),
+        }
+    }
+}
+
+#[cfg(feature = "email-address")]
+fn default_allow_domain_literal() -> bool {
+    true
+}
+
+#[cfg(feature = "regex")]
+mod regex_pattern {
+    use regex::Regex;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        Regex::new(&pattern).map_err(D::Error::custom)
+    }
+
+    pub fn serialize<S>(value: &Regex, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(value.as_str())
+    }
+}
+
+/// Schema validation error.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum SchemaError {
+    /// Error of an object property.
+    Property {
+        /// The property name.
+        path: String,
+
+        /// The error.
+        #[serde(flatten)]
+        error: Box<SchemaError>,
+    },
+
+    /// Error of an array item.
+    Item(IndexedValidationError<Box<SchemaError>>),
+
+    /// Errors of array items or object properties.
+    Nested(#[serde(with = "crate::integrations::serde::errors")] ValidationErrors<SchemaError>),
+
+    /// Type error.
+    Type(TypeError),
+
+    /// Required error.
+    Required(RequiredError),
+
+    /// Email address error.
+    #[cfg(feature = "email-address")]
+    EmailAddress(EmailAddressError),
+
+    /// Length error.
+    Length(LengthError<usize>),
+
+    /// Phone number error.
+    #[cfg(feature = "phone-number")]
+    PhoneNumber(PhoneNumberError),
+
+    /// Range error.
+    Range(RangeError<f64>),
+
+    /// Regular expression error.
+    #[cfg(feature = "regex")]
+    Regex(RegexError),
+
+    /// URL error.
+    #[cfg(feature = "url")]
+    Url(UrlError),
+}
+
+// Flattening the recursive error with `#[serde(flatten)]` creates infinitely nested serializer types,
+// so property and item errors are serialized through an intermediate JSON value instead.
+impl Serialize for SchemaError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Property { path, error } => serialize_with_entry(serializer, "path", path, error),
+            Self::Item(IndexedValidationError { index, error }) => {
+                serialize_with_entry(serializer, "index", index, error)
+            }
+            Self::Nested(errors) => {
+                crate::integrations::serde::errors::serialize(errors, serializer)
+            }
+            Self::Type(error) => error.serialize(serializer),
+            Self::Required(error) => error.serialize(serializer),
+            #[cfg(feature = "email-address")]
+            Self::EmailAddress(error) => error.serialize(serializer),
+            Self::Length(error) => error.serialize(serializer),
+            #[cfg(feature = "phone-number")]
+            Self::PhoneNumber(error) => error.serialize(serializer),
+            Self::Range(error) => error.serialize(serializer),
+            #[cfg(feature = "regex")]
+            Self::Regex(error) => error.serialize(serializer),
+            #[cfg(feature = "url")]
+            Self::Url(error) => error.serialize(serializer),
+        }
+    }
+}
+
+fn serialize_with_entry<S, V>(
+    serializer: S,
+    key: &str,
+    value: &V,
+    error: &SchemaError,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: Serialize,
+{
+    let Value::Object(entries) = serde_json::to_value(error).map_err(S::Error::custom)? else {
+        return Err(S::Error::custom("error is not an object"));
+    };
+
+    let mut map = serializer.serialize_map(Some(entries.len() + 1))?;
+    map.serialize_entry(key, value)?;
+    for (key, value) in &entries {
+        map.serialize_entry(key, value)?;
+    }
+    map.end()
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for SchemaError {}
+
+constant_string!(TypeErrorCode, TYPE_ERROR_CODE, "type");
+
+/// Type validation error.
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeError {
+    /// The error code.
+    #[serde(default)]
+    code: TypeErrorCode,
+
+    /// The expected type.
+    expected: ValueType,
+
+    /// The actual type.
+    actual: ValueType,
+
+    /// A human-readable error message.
+    #[cfg(feature = "message")]
+    message: String,
+}
+
+impl TypeError {
+    /// Constructs a new [`TypeError`].
+    pub fn new(expected: ValueType, actual: ValueType) -> Self {
+        Self {
+            code: TypeErrorCode,
+            expected,
+            actual,
+            #[cfg(feature = "message")]
+            message: format!("expected {expected}, found {actual}"),
+        }
+    }
+}
+
+impl Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for TypeError {}
+
+constant_string!(RequiredErrorCode, REQUIRED_ERROR_CODE, "required");
+
+/// Required validation error.
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequiredError {
+    /// The error code.
+    #[serde(default)]
+    code: RequiredErrorCode,
+
+    /// A human-readable error message.
+    #[cfg(feature = "message")]
+    message: String,
+}
+
+impl Default for RequiredError {
+    fn default() -> Self {
+        Self {
+            code: RequiredErrorCode,
+
+            #[cfg(feature = "message")]
+            message: "value is required".to_owned(),
+        }
+    }
+}
+
+impl Display for RequiredError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for RequiredError {}
+
+#[cfg(feature = "email-address")]
+impl ValidateEmailAddress for Value {
+    fn email_address(&self) -> Option<Cow<'_, str>> {
+        self.as_str().map(Cow::Borrowed)
+    }
+}
+
+impl ValidateLength<usize> for Value {
+    fn length(&self) -> Option<usize> {
+        match self {
+            Value::String(value) => value.length(),
+            Value::Array(values) => Some(values.len()),
+            Value::Object(values) => Some(values.len()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "phone-number")]
+impl ValidatePhoneNumber for Value {
+    fn phone_number(&self) -> Option<Cow<'_, str>> {
+        self.as_str().map(Cow::Borrowed)
+    }
+}
+
+impl ValidateRange<f64> for Value {
+    fn range_value(&self) -> Option<f64> {
+        self.as_f64()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl ValidateRegex for Value {
+    fn validate_regex(&self, regex: impl crate::AsRegex) -> Result<(), RegexError> {
+        match self.as_str() {
+            Some(value) => value.validate_regex(regex),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+impl ValidateUrl for Value {
+    fn url(&self) -> Option<Cow<'_, str>> {
+        self.as_str().map(Cow::Borrowed)
+    }
+}
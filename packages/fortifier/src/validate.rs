@@ -1,6 +1,6 @@
 use std::{
     cell::{Ref, RefMut},
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     error::Error,
     fmt::Debug,
     pin::Pin,
@@ -8,7 +8,12 @@ use std::{
     sync::Arc,
 };
 
-use crate::error::{IndexedValidationError, KeyedValidationError, ValidationErrors};
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+
+use crate::error::{
+    IndexedValidationError, KeyedValidationError, ValidationErrors, ValidationReport,
+};
 
 /// Validate a schema with context.
 pub trait ValidateWithContext {
@@ -19,6 +24,8 @@ pub trait ValidateWithContext {
     type Error: Error + Send + Sync;
 
     /// Validate schema using all validators with context.
+    ///
+    /// Warnings are not returned, use [`validate_report_with_context`](Self::validate_report_with_context) to read them.
     fn validate_with_context(
         &self,
         context: &Self::Context,
@@ -33,6 +40,8 @@ pub trait ValidateWithContext {
     }
 
     /// Validate schema using only synchronous validators with context.
+    ///
+    /// Warnings are not returned, use [`validate_report_sync_with_context`](Self::validate_report_sync_with_context) to read them.
     fn validate_sync_with_context(
         &self,
         context: &Self::Context,
@@ -43,11 +52,81 @@ pub trait ValidateWithContext {
         &self,
         context: &Self::Context,
     ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>>;
+
+    /// Validate schema using only synchronous validators with warning severity with context.
+    ///
+    /// Warnings are returned as errors.
+    fn validate_warnings_sync_with_context(
+        &self,
+        _context: &Self::Context,
+    ) -> Result<(), ValidationErrors<Self::Error>> {
+        Ok(())
+    }
+
+    /// Validate schema using only asynchronous validators with warning severity with context.
+    ///
+    /// Warnings are returned as errors.
+    fn validate_warnings_async_with_context(
+        &self,
+        _context: &Self::Context,
+    ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Validate schema using all validators with context, collecting errors and warnings.
+    fn validate_report_with_context(
+        &self,
+        context: &Self::Context,
+    ) -> Pin<Box<impl Future<Output = ValidationReport<Self::Error>> + Send>>
+    where
+        Self: Sync,
+    {
+        Box::pin(async {
+            let mut report = ValidationReport::default();
+
+            if let Err(errors) = self.validate_with_context(context).await {
+                report.errors = errors;
+            }
+
+            let sync_warnings = self.validate_warnings_sync_with_context(context).err();
+            let async_warnings = self
+                .validate_warnings_async_with_context(context)
+                .await
+                .err();
+
+            report.warnings = sync_warnings
+                .into_iter()
+                .chain(async_warnings)
+                .flatten()
+                .collect();
+
+            report
+        })
+    }
+
+    /// Validate schema using only synchronous validators with context, collecting errors and warnings.
+    fn validate_report_sync_with_context(
+        &self,
+        context: &Self::Context,
+    ) -> ValidationReport<Self::Error> {
+        ValidationReport {
+            errors: self
+                .validate_sync_with_context(context)
+                .err()
+                .unwrap_or_default(),
+            warnings: self
+                .validate_warnings_sync_with_context(context)
+                .err()
+                .unwrap_or_default(),
+        }
+    }
 }
 
 /// Validate a schema.
 pub trait Validate: ValidateWithContext<Context = ()> {
     /// Validate schema using all validators.
+    ///
+    /// Warnings are not returned, use [`validate_report`](Self::validate_report) to read them.
     fn validate(
         &self,
     ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<<Self>::Error>>> + Send>>
@@ -58,6 +137,8 @@ pub trait Validate: ValidateWithContext<Context = ()> {
     }
 
     /// Validate schema using only synchronous validators.
+    ///
+    /// Warnings are not returned, use [`validate_report_sync`](Self::validate_report_sync) to read them.
     fn validate_sync(&self) -> Result<(), ValidationErrors<Self::Error>> {
         self.validate_sync_with_context(&())
     }
@@ -68,6 +149,61 @@ pub trait Validate: ValidateWithContext<Context = ()> {
     ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>> {
         self.validate_async_with_context(&())
     }
+
+    /// Validate schema using all validators, collecting errors and warnings.
+    fn validate_report(
+        &self,
+    ) -> Pin<Box<impl Future<Output = ValidationReport<Self::Error>> + Send>>
+    where
+        Self: Sync,
+    {
+        self.validate_report_with_context(&())
+    }
+
+    /// Validate schema using only synchronous validators, collecting errors and warnings.
+    fn validate_report_sync(&self) -> ValidationReport<Self::Error> {
+        self.validate_report_sync_with_context(&())
+    }
+}
+
+/// Validate a schema with context and convert it into an output with parsed values.
+pub trait ValidateOutputWithContext: ValidateWithContext + Sized {
+    /// Validated output.
+    type Output;
+
+    /// Validate schema using all validators with context, and convert it into output.
+    #[expect(clippy::type_complexity)]
+    fn validate_into_output_with_context(
+        self,
+        context: &Self::Context,
+    ) -> Pin<Box<impl Future<Output = Result<Self::Output, ValidationErrors<Self::Error>>> + Send>>
+    where
+        Self: Send + Sync;
+
+    /// Validate schema using only synchronous validators with context, and convert it into output.
+    fn validate_into_output_sync_with_context(
+        self,
+        context: &Self::Context,
+    ) -> Result<Self::Output, ValidationErrors<Self::Error>>;
+}
+
+/// Validate a schema and convert it into an output with parsed values.
+pub trait ValidateOutput: ValidateOutputWithContext<Context = ()> {
+    /// Validate schema using all validators, and convert it into output.
+    #[expect(clippy::type_complexity)]
+    fn validate_into_output(
+        self,
+    ) -> Pin<Box<impl Future<Output = Result<Self::Output, ValidationErrors<Self::Error>>> + Send>>
+    where
+        Self: Send + Sync,
+    {
+        self.validate_into_output_with_context(&())
+    }
+
+    /// Validate schema using only synchronous validators, and convert it into output.
+    fn validate_into_output_sync(self) -> Result<Self::Output, ValidationErrors<Self::Error>> {
+        self.validate_into_output_sync_with_context(&())
+    }
 }
 
 /// Generate a dereference validate implementation for a type.
@@ -96,6 +232,22 @@ macro_rules! validate_with_deref {
             > {
                 T::validate_async_with_context(self, context)
             }
+
+            fn validate_warnings_sync_with_context(
+                &self,
+                context: &Self::Context,
+            ) -> Result<(), $crate::ValidationErrors<Self::Error>> {
+                T::validate_warnings_sync_with_context(self, context)
+            }
+
+            fn validate_warnings_async_with_context(
+                &self,
+                context: &Self::Context,
+            ) -> ::std::pin::Pin<
+                Box<impl Future<Output = Result<(), $crate::ValidationErrors<Self::Error>>> + Send>,
+            > {
+                T::validate_warnings_async_with_context(self, context)
+            }
         }
     };
 }
@@ -137,6 +289,83 @@ where
             }
         })
     }
+
+    fn validate_warnings_sync_with_context(
+        &self,
+        context: &Self::Context,
+    ) -> Result<(), ValidationErrors<Self::Error>> {
+        if let Some(value) = &self {
+            T::validate_warnings_sync_with_context(value, context)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_warnings_async_with_context(
+        &self,
+        context: &Self::Context,
+    ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>> {
+        Box::pin(async move {
+            if let Some(value) = self {
+                T::validate_warnings_async_with_context(value, context).await
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+fn indexed_errors<E>(
+    results: impl IntoIterator<Item = Result<(), ValidationErrors<E>>>,
+) -> Result<(), ValidationErrors<IndexedValidationError<E>>>
+where
+    E: Error,
+{
+    let errors = results
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, result)| {
+            result
+                .err()
+                .into_iter()
+                .flatten()
+                .map(move |error| IndexedValidationError { index, error })
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into())
+    }
+}
+
+fn keyed_errors<'a, K, E>(
+    results: impl IntoIterator<Item = (&'a K, Result<(), ValidationErrors<E>>)>,
+) -> Result<(), ValidationErrors<KeyedValidationError<K, E>>>
+where
+    K: Clone + 'a,
+    E: Error,
+{
+    let errors = results
+        .into_iter()
+        .flat_map(|(key, result)| {
+            result
+                .err()
+                .into_iter()
+                .flatten()
+                .map(move |error| KeyedValidationError {
+                    key: key.clone(),
+                    error,
+                })
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into())
+    }
 }
 
 impl<T> ValidateWithContext for Vec<T>
@@ -151,21 +380,10 @@ where
         &self,
         context: &Self::Context,
     ) -> Result<(), ValidationErrors<Self::Error>> {
-        let mut errors = vec![];
-
-        for (index, value) in self.iter().enumerate() {
-            if let Err(error) = value.validate_sync_with_context(context) {
-                for error in error {
-                    errors.push(IndexedValidationError { index, error });
-                }
-            }
-        }
-
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(errors.into())
-        }
+        indexed_errors(
+            self.iter()
+                .map(|value| value.validate_sync_with_context(context)),
+        )
     }
 
     fn validate_async_with_context(
@@ -173,82 +391,114 @@ where
         context: &Self::Context,
     ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>> {
         Box::pin(async move {
-            let mut errors = vec![];
+            let mut results = Vec::with_capacity(self.len());
 
-            for (index, value) in self.iter().enumerate() {
-                if let Err(error) = value.validate_async_with_context(context).await {
-                    for error in error {
-                        errors.push(IndexedValidationError { index, error });
-                    }
-                }
+            for value in self {
+                results.push(value.validate_async_with_context(context).await);
             }
 
-            if errors.is_empty() {
-                Ok(())
-            } else {
-                Err(errors.into())
-            }
+            indexed_errors(results)
         })
     }
-}
 
-// TODO: Should this validate both keys and values?
-impl<K, V> ValidateWithContext for HashMap<K, V>
-where
-    K: Clone + Debug + Send + Sync,
-    V: ValidateWithContext + Send + Sync,
-    V::Error: Error + Send + Sync,
-{
-    type Context = V::Context;
-    type Error = KeyedValidationError<K, V::Error>;
-
-    fn validate_sync_with_context(
+    fn validate_warnings_sync_with_context(
         &self,
         context: &Self::Context,
     ) -> Result<(), ValidationErrors<Self::Error>> {
-        let mut errors = vec![];
-
-        for (key, value) in self.iter() {
-            if let Err(error) = value.validate_sync_with_context(context) {
-                for error in error {
-                    errors.push(KeyedValidationError {
-                        key: key.clone(),
-                        error,
-                    });
-                }
-            }
-        }
-
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(errors.into())
-        }
+        indexed_errors(
+            self.iter()
+                .map(|value| value.validate_warnings_sync_with_context(context)),
+        )
     }
 
-    fn validate_async_with_context(
+    fn validate_warnings_async_with_context(
         &self,
         context: &Self::Context,
     ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>> {
         Box::pin(async move {
-            let mut errors = vec![];
-
-            for (key, value) in self.iter() {
-                if let Err(error) = value.validate_async_with_context(context).await {
-                    for error in error {
-                        errors.push(KeyedValidationError {
-                            key: key.clone(),
-                            error,
-                        });
-                    }
-                }
-            }
+            let mut results = Vec::with_capacity(self.len());
 
-            if errors.is_empty() {
-                Ok(())
-            } else {
-                Err(errors.into())
+            for value in self {
+                results.push(value.validate_warnings_async_with_context(context).await);
             }
+
+            indexed_errors(results)
         })
     }
 }
+
+macro_rules! validate_with_keys {
+    ($type:ty) => {
+        // TODO: Should this validate both keys and values?
+        impl<K, V> ValidateWithContext for $type
+        where
+            K: Clone + Debug + Send + Sync,
+            V: ValidateWithContext + Send + Sync,
+            V::Error: Error + Send + Sync,
+        {
+            type Context = V::Context;
+            type Error = KeyedValidationError<K, V::Error>;
+
+            fn validate_sync_with_context(
+                &self,
+                context: &Self::Context,
+            ) -> Result<(), ValidationErrors<Self::Error>> {
+                keyed_errors(
+                    self.iter()
+                        .map(|(key, value)| (key, value.validate_sync_with_context(context))),
+                )
+            }
+
+            fn validate_async_with_context(
+                &self,
+                context: &Self::Context,
+            ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>>
+            {
+                Box::pin(async move {
+                    let mut results = Vec::with_capacity(self.len());
+
+                    for (key, value) in self.iter() {
+                        results.push((key, value.validate_async_with_context(context).await));
+                    }
+
+                    keyed_errors(results)
+                })
+            }
+
+            fn validate_warnings_sync_with_context(
+                &self,
+                context: &Self::Context,
+            ) -> Result<(), ValidationErrors<Self::Error>> {
+                keyed_errors(
+                    self.iter().map(|(key, value)| {
+                        (key, value.validate_warnings_sync_with_context(context))
+                    }),
+                )
+            }
+
+            fn validate_warnings_async_with_context(
+                &self,
+                context: &Self::Context,
+            ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>>
+            {
+                Box::pin(async move {
+                    let mut results = Vec::with_capacity(self.len());
+
+                    for (key, value) in self.iter() {
+                        results.push((
+                            key,
+                            value.validate_warnings_async_with_context(context).await,
+                        ));
+                    }
+
+                    keyed_errors(results)
+                })
+            }
+        }
+    };
+}
+
+validate_with_keys!(BTreeMap<K, V>);
+validate_with_keys!(HashMap<K, V>);
+#[cfg(feature = "indexmap")]
+validate_with_keys!(IndexMap<K, V>);
@@ -0,0 +1,114 @@
+use std::{ops::Deref, pin::Pin};
+
+use crate::{Validate, ValidateWithContext, ValidationErrors};
+
+/// Value which passed validation.
+///
+/// Can only be constructed by validating a value, so holding a [`Validated<T>`] proves the value is valid.
+/// There is no mutable access to the value, as mutating it could invalidate it.
+///
+/// The proof only covers the validators which were run. [`Validated::new_sync`] and deserialization (with the `serde`
+/// feature) run only synchronous validators, so asynchronous validators (e.g. database lookups) may not have passed.
+///
+/// Validating a [`Validated<T>`] always succeeds, so fields of this type are not validated again.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Validated<T>(T);
+
+impl<T> Validated<T>
+where
+    T: Validate + Sync,
+{
+    /// Validate a value using all validators.
+    pub async fn new(value: T) -> Result<Self, ValidationErrors<T::Error>> {
+        Self::new_with_context(value, &()).await
+    }
+}
+
+impl<T> Validated<T>
+where
+    T: ValidateWithContext,
+{
+    /// Validate a value using all validators with context.
+    pub async fn new_with_context(
+        value: T,
+        context: &T::Context,
+    ) -> Result<Self, ValidationErrors<T::Error>>
+    where
+        T: Sync,
+    {
+        value.validate_with_context(context).await?;
+
+        Ok(Self(value))
+    }
+
+    /// Validate a value using only synchronous validators with context.
+    ///
+    /// Asynchronous validators are not run.
+    pub fn new_sync(value: T, context: &T::Context) -> Result<Self, ValidationErrors<T::Error>> {
+        value.validate_sync_with_context(context)?;
+
+        Ok(Self(value))
+    }
+}
+
+impl<T> Validated<T> {
+    /// Consume the wrapper, returning the value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Validated<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The value already passed validation, so validation always succeeds.
+impl<T> ValidateWithContext for Validated<T>
+where
+    T: ValidateWithContext,
+{
+    type Context = T::Context;
+
+    type Error = T::Error;
+
+    fn validate_sync_with_context(
+        &self,
+        _context: &Self::Context,
+    ) -> Result<(), ValidationErrors<Self::Error>> {
+        Ok(())
+    }
+
+    fn validate_async_with_context(
+        &self,
+        _context: &Self::Context,
+    ) -> Pin<Box<impl Future<Output = Result<(), ValidationErrors<Self::Error>>> + Send>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Deserialize and validate using only synchronous validators.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Validated<T>
+where
+    T: serde::Deserialize<'de> + Validate,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+
+        Self::new_sync(value, &()).map_err(serde::de::Error::custom)
+    }
+}
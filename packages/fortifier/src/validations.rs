@@ -1,21 +1,93 @@
+mod base64;
+mod cidr;
+mod content;
+#[cfg(feature = "country-code")]
+mod country_code;
+#[cfg(feature = "credit-card")]
+mod credit_card;
+#[cfg(feature = "currency-code")]
+mod currency_code;
+#[cfg(feature = "chrono")]
+mod date;
+mod disallow;
+#[cfg(feature = "ean")]
+mod ean;
 #[cfg(feature = "email-address")]
 mod email_address;
+mod hex;
+#[cfg(feature = "hostname")]
+mod hostname;
+#[cfg(feature = "iban")]
+mod iban;
+mod ip;
+#[cfg(feature = "isbn")]
+mod isbn;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "language-tag")]
+mod language_tag;
 mod length;
+mod membership;
+mod numeric;
+#[cfg(feature = "password")]
+mod password;
 #[cfg(feature = "phone-number")]
 mod phone_number;
 mod range;
 #[cfg(feature = "regex")]
 mod regex;
+mod semver;
+mod slug;
+mod socket_addr;
+#[cfg(feature = "time-zone")]
+mod time_zone;
 #[cfg(feature = "url")]
 mod url;
+mod uuid;
 
+pub use base64::*;
+pub use cidr::*;
+pub use content::*;
+#[cfg(feature = "country-code")]
+pub use country_code::*;
+#[cfg(feature = "credit-card")]
+pub use credit_card::*;
+#[cfg(feature = "currency-code")]
+pub use currency_code::*;
+#[cfg(feature = "chrono")]
+pub use date::*;
+pub use disallow::*;
+#[cfg(feature = "ean")]
+pub use ean::*;
 #[cfg(feature = "email-address")]
 pub use email_address::*;
+pub use hex::*;
+#[cfg(feature = "hostname")]
+pub use hostname::*;
+#[cfg(feature = "iban")]
+pub use iban::*;
+pub use ip::*;
+#[cfg(feature = "isbn")]
+pub use isbn::*;
+#[cfg(feature = "json")]
+pub use json::*;
+#[cfg(feature = "language-tag")]
+pub use language_tag::*;
 pub use length::*;
+pub use membership::*;
+pub use numeric::*;
+#[cfg(feature = "password")]
+pub use password::*;
 #[cfg(feature = "phone-number")]
 pub use phone_number::*;
 pub use range::*;
 #[cfg(feature = "regex")]
 pub use regex::*;
+pub use semver::*;
+pub use slug::*;
+pub use socket_addr::*;
+#[cfg(feature = "time-zone")]
+pub use time_zone::*;
 #[cfg(feature = "url")]
 pub use url::*;
+pub use uuid::*;
@@ -0,0 +1,426 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(Base64ErrorCode, BASE64_ERROR_CODE, "base64");
+
+/// Base64 validation options.
+///
+/// The default options allow the standard alphabet, with or without padding.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Base64Options {
+    /// Use the URL-safe alphabet (`-` and `_`) instead of the standard alphabet (`+` and `/`).
+    pub url_safe: bool,
+    /// Require (`Some(true)`) or deny (`Some(false)`) padding with `=`.
+    ///
+    /// Padding is optional if `None`.
+    pub padding: Option<bool>,
+}
+
+/// Base64 validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum Base64Error {
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: Base64ErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid length error.
+    InvalidLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: Base64ErrorCode,
+
+        /// The actual length.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid padding error.
+    InvalidPadding {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: Base64ErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Missing padding error.
+    MissingPadding {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: Base64ErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unexpected padding error.
+    UnexpectedPadding {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: Base64ErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for Base64Error {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for Base64Error {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("Base64Error", |_| {
+            [
+                error_type(
+                    BASE64_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(
+                    BASE64_ERROR_CODE,
+                    Some("invalidLength"),
+                    &[("value", "number")],
+                ),
+                error_type(BASE64_ERROR_CODE, Some("invalidPadding"), &[]),
+                error_type(BASE64_ERROR_CODE, Some("missingPadding"), &[]),
+                error_type(BASE64_ERROR_CODE, Some("unexpectedPadding"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "Base64Error".to_owned()
+    }
+}
+
+/// Validate a base64 string.
+pub trait ValidateBase64 {
+    /// The base64 string.
+    fn base64(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate base64 string.
+    fn validate_base64(&self, options: Base64Options) -> Result<(), Base64Error> {
+        self.base64()
+            .map(|base64| validate_base64(&base64, options))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate a base64 or base64url string.
+///
+/// See [RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648).
+pub fn validate_base64(value: &str, options: Base64Options) -> Result<(), Base64Error> {
+    let data = value.trim_end_matches('=');
+    let padding = value.len() - data.len();
+
+    for character in data.chars() {
+        let valid = match character {
+            'A'..='Z' | 'a'..='z' | '0'..='9' => true,
+            '+' | '/' => !options.url_safe,
+            '-' | '_' => options.url_safe,
+            '=' => {
+                return Err(Base64Error::InvalidPadding {
+                    code: Base64ErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "padding must be at the end".to_owned(),
+                });
+            }
+            _ => false,
+        };
+
+        if !valid {
+            return Err(Base64Error::InvalidCharacter {
+                code: Base64ErrorCode,
+                character,
+                #[cfg(feature = "message")]
+                message: format!("invalid character `{character}`"),
+            });
+        }
+    }
+
+    // A single character in the final group can't encode a whole byte.
+    let remainder = data.len() % 4;
+    if remainder == 1 {
+        return Err(Base64Error::InvalidLength {
+            code: Base64ErrorCode,
+            value: value.len(),
+            #[cfg(feature = "message")]
+            message: format!("invalid base64 length {}", value.len()),
+        });
+    }
+
+    if padding > 0 {
+        if remainder == 0 || padding != 4 - remainder {
+            return Err(Base64Error::InvalidPadding {
+                code: Base64ErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid number of padding characters".to_owned(),
+            });
+        }
+
+        if options.padding == Some(false) {
+            return Err(Base64Error::UnexpectedPadding {
+                code: Base64ErrorCode,
+                #[cfg(feature = "message")]
+                message: "padding is not allowed".to_owned(),
+            });
+        }
+    } else if remainder != 0 && options.padding == Some(true) {
+        return Err(Base64Error::MissingPadding {
+            code: Base64ErrorCode,
+            #[cfg(feature = "message")]
+            message: "padding is required".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateBase64 for str {
+    fn base64(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateBase64 for &str {
+    fn base64(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateBase64 for String {
+    fn base64(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateBase64 for Cow<'_, str> {
+    fn base64(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateBase64 for Option<T>
+where
+    T: ValidateBase64,
+{
+    fn base64(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self {
+            T::base64(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateBase64 for $type
+        where
+            T: ValidateBase64,
+        {
+            fn base64(&self) -> Option<Cow<'_, str>> {
+                T::base64(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{Base64Error, Base64ErrorCode, Base64Options, ValidateBase64};
+
+    const URL_SAFE: Base64Options = Base64Options {
+        url_safe: true,
+        padding: None,
+    };
+
+    #[test]
+    fn ok() {
+        let options = Base64Options::default();
+
+        assert_eq!((*"Zm9vYg==").validate_base64(options), Ok(()));
+        assert_eq!("Zm9vYg==".validate_base64(options), Ok(()));
+        assert_eq!("Zm9vYg==".to_owned().validate_base64(options), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("Zm9vYg==").validate_base64(options),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_base64(options), Ok(()));
+        assert_eq!(Some("Zm9vYg==").validate_base64(options), Ok(()));
+
+        assert_eq!((&"Zm9vYg==").validate_base64(options), Ok(()));
+        assert_eq!(Box::new("Zm9vYg==").validate_base64(options), Ok(()));
+        assert_eq!(Arc::new("Zm9vYg==").validate_base64(options), Ok(()));
+        assert_eq!(Rc::new("Zm9vYg==").validate_base64(options), Ok(()));
+
+        let cell = RefCell::new("Zm9vYg==");
+        assert_eq!(cell.borrow().validate_base64(options), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_base64(options), Ok(()));
+
+        for value in ["", "Zg", "Zm8", "Zm9v", "Zm9vYmE=", "+/+/"] {
+            assert_eq!(value.validate_base64(options), Ok(()), "{value}");
+        }
+
+        assert_eq!("-_-_".validate_base64(URL_SAFE), Ok(()));
+        assert_eq!(
+            "Zm9vYg==".validate_base64(Base64Options {
+                padding: Some(true),
+                ..Default::default()
+            }),
+            Ok(())
+        );
+        assert_eq!(
+            "Zm9vYg".validate_base64(Base64Options {
+                padding: Some(false),
+                ..Default::default()
+            }),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn invalid_character_error() {
+        assert_eq!(
+            "-_-_".validate_base64(Base64Options::default()),
+            Err(Base64Error::InvalidCharacter {
+                code: Base64ErrorCode,
+                character: '-',
+                #[cfg(feature = "message")]
+                message: "invalid character `-`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "+/+/".validate_base64(URL_SAFE),
+            Err(Base64Error::InvalidCharacter {
+                code: Base64ErrorCode,
+                character: '+',
+                #[cfg(feature = "message")]
+                message: "invalid character `+`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_length_error() {
+        assert_eq!(
+            "Zm9vY".validate_base64(Base64Options::default()),
+            Err(Base64Error::InvalidLength {
+                code: Base64ErrorCode,
+                value: 5,
+                #[cfg(feature = "message")]
+                message: "invalid base64 length 5".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_padding_error() {
+        assert_eq!(
+            "Zg==Zg==".validate_base64(Base64Options::default()),
+            Err(Base64Error::InvalidPadding {
+                code: Base64ErrorCode,
+                #[cfg(feature = "message")]
+                message: "padding must be at the end".to_owned(),
+            })
+        );
+
+        for value in ["Zm9v=", "Zm8==", "Zg="] {
+            assert_eq!(
+                value.validate_base64(Base64Options::default()),
+                Err(Base64Error::InvalidPadding {
+                    code: Base64ErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "invalid number of padding characters".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn missing_padding_error() {
+        assert_eq!(
+            "Zm9vYg".validate_base64(Base64Options {
+                padding: Some(true),
+                ..Default::default()
+            }),
+            Err(Base64Error::MissingPadding {
+                code: Base64ErrorCode,
+                #[cfg(feature = "message")]
+                message: "padding is required".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn unexpected_padding_error() {
+        assert_eq!(
+            "Zm9vYg==".validate_base64(Base64Options {
+                padding: Some(false),
+                ..Default::default()
+            }),
+            Err(Base64Error::UnexpectedPadding {
+                code: Base64ErrorCode,
+                #[cfg(feature = "message")]
+                message: "padding is not allowed".to_owned(),
+            })
+        );
+    }
+}
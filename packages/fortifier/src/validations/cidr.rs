@@ -0,0 +1,517 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    net::IpAddr,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+use crate::validations::ip::{IpOptions, IpVersion, IpViolation};
+
+constant_string!(CidrErrorCode, CIDR_ERROR_CODE, "cidr");
+
+/// CIDR validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum CidrError {
+    /// Missing prefix length error.
+    MissingPrefixLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid address error.
+    InvalidAddress {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid prefix length error.
+    InvalidPrefixLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// The maximum prefix length for the address version.
+        max: u8,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Host bits set error.
+    ///
+    /// The address has bits set after the prefix, e.g. `10.0.0.1/8` instead of `10.0.0.0/8`.
+    HostBitsSet {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed version error.
+    DisallowedVersion {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// The actual version.
+        value: IpVersion,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Private network error.
+    Private {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Loopback network error.
+    Loopback {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Link-local network error.
+    LinkLocal {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Multicast network error.
+    Multicast {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unspecified network error.
+    Unspecified {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Broadcast network error.
+    Broadcast {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CidrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for CidrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for CidrError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for CidrError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::{validations::ip::IP_VERSION_TYPESCRIPT, zod::error_type};
+
+        generator.define_type("CidrError", |_| {
+            [
+                error_type(CIDR_ERROR_CODE, Some("missingPrefixLength"), &[]),
+                error_type(CIDR_ERROR_CODE, Some("invalidAddress"), &[]),
+                error_type(
+                    CIDR_ERROR_CODE,
+                    Some("invalidPrefixLength"),
+                    &[("max", "number")],
+                ),
+                error_type(CIDR_ERROR_CODE, Some("hostBitsSet"), &[]),
+                error_type(
+                    CIDR_ERROR_CODE,
+                    Some("disallowedVersion"),
+                    &[("value", IP_VERSION_TYPESCRIPT)],
+                ),
+                error_type(CIDR_ERROR_CODE, Some("private"), &[]),
+                error_type(CIDR_ERROR_CODE, Some("loopback"), &[]),
+                error_type(CIDR_ERROR_CODE, Some("linkLocal"), &[]),
+                error_type(CIDR_ERROR_CODE, Some("multicast"), &[]),
+                error_type(CIDR_ERROR_CODE, Some("unspecified"), &[]),
+                error_type(CIDR_ERROR_CODE, Some("broadcast"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "CidrError".to_owned()
+    }
+}
+
+impl From<IpViolation> for CidrError {
+    fn from(value: IpViolation) -> Self {
+        let code = CidrErrorCode;
+        #[cfg(feature = "message")]
+        let message = value.message("network");
+
+        match value {
+            IpViolation::Version(version) => Self::DisallowedVersion {
+                code,
+                value: version,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Private => Self::Private {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Loopback => Self::Loopback {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::LinkLocal => Self::LinkLocal {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Multicast => Self::Multicast {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Unspecified => Self::Unspecified {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Broadcast => Self::Broadcast {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+        }
+    }
+}
+
+/// Validate a CIDR network.
+pub trait ValidateCidr {
+    /// The CIDR network.
+    fn cidr(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate CIDR network.
+    fn validate_cidr(&self, options: IpOptions) -> Result<(), CidrError> {
+        self.cidr()
+            .map(|cidr| parse_cidr(&cidr, options))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Parse a CIDR network (e.g. `10.0.0.0/8`) and return the network address and prefix length.
+///
+/// The options are checked against the network address.
+pub fn parse_cidr(value: &str, options: IpOptions) -> Result<(IpAddr, u8), CidrError> {
+    let Some((address, prefix_length)) = value.split_once('/') else {
+        return Err(CidrError::MissingPrefixLength {
+            code: CidrErrorCode,
+            #[cfg(feature = "message")]
+            message: "missing prefix length".to_owned(),
+        });
+    };
+
+    let address = address
+        .parse::<IpAddr>()
+        .map_err(|_| CidrError::InvalidAddress {
+            code: CidrErrorCode,
+            #[cfg(feature = "message")]
+            message: "invalid IP address".to_owned(),
+        })?;
+
+    let max = match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+
+    // Reject leading zeros and signs, which `u8::from_str` accepts.
+    let prefix_length = Some(prefix_length)
+        .filter(|prefix_length| *prefix_length == "0" || !prefix_length.starts_with(['0', '+']))
+        .and_then(|prefix_length| prefix_length.parse::<u8>().ok())
+        .filter(|prefix_length| *prefix_length <= max)
+        .ok_or(CidrError::InvalidPrefixLength {
+            code: CidrErrorCode,
+            max,
+            #[cfg(feature = "message")]
+            message: format!("prefix length must be between 0 and {max}"),
+        })?;
+
+    let host_bits_set = match address {
+        IpAddr::V4(address) => {
+            u32::from(address)
+                & !u32::MAX
+                    .checked_shl(32 - u32::from(prefix_length))
+                    .unwrap_or(0)
+                != 0
+        }
+        IpAddr::V6(address) => {
+            u128::from(address)
+                & !u128::MAX
+                    .checked_shl(128 - u32::from(prefix_length))
+                    .unwrap_or(0)
+                != 0
+        }
+    };
+
+    if host_bits_set {
+        return Err(CidrError::HostBitsSet {
+            code: CidrErrorCode,
+            #[cfg(feature = "message")]
+            message: "address has bits set after the prefix".to_owned(),
+        });
+    }
+
+    options.check(address)?;
+
+    Ok((address, prefix_length))
+}
+
+impl ValidateCidr for str {
+    fn cidr(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCidr for &str {
+    fn cidr(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateCidr for String {
+    fn cidr(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCidr for Cow<'_, str> {
+    fn cidr(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateCidr for Option<T>
+where
+    T: ValidateCidr,
+{
+    fn cidr(&self) -> Option<Cow<'_, str>> {
+        if let Some(cidr) = self {
+            T::cidr(cidr)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateCidr for $type
+        where
+            T: ValidateCidr,
+        {
+            fn cidr(&self) -> Option<Cow<'_, str>> {
+                T::cidr(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        cell::RefCell,
+        net::{IpAddr, Ipv4Addr},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use super::{CidrError, CidrErrorCode, IpOptions, IpVersion, ValidateCidr, parse_cidr};
+
+    #[test]
+    fn ok() {
+        let options = IpOptions::default();
+
+        assert_eq!((*"10.0.0.0/8").validate_cidr(options), Ok(()));
+        assert_eq!("0.0.0.0/0".validate_cidr(options), Ok(()));
+        assert_eq!("192.168.1.1/32".to_owned().validate_cidr(options), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("2001:db8::/32").validate_cidr(options),
+            Ok(())
+        );
+        assert_eq!("::/0".validate_cidr(options), Ok(()));
+
+        assert_eq!(None::<&str>.validate_cidr(options), Ok(()));
+        assert_eq!(Some("10.0.0.0/8").validate_cidr(options), Ok(()));
+
+        assert_eq!((&"10.0.0.0/8").validate_cidr(options), Ok(()));
+        assert_eq!(Box::new("10.0.0.0/8").validate_cidr(options), Ok(()));
+        assert_eq!(Arc::new("10.0.0.0/8").validate_cidr(options), Ok(()));
+        assert_eq!(Rc::new("10.0.0.0/8").validate_cidr(options), Ok(()));
+
+        let cell = RefCell::new("10.0.0.0/8");
+        assert_eq!(cell.borrow().validate_cidr(options), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_cidr(options), Ok(()));
+
+        assert_eq!(
+            parse_cidr("172.16.0.0/12", options),
+            Ok((IpAddr::V4(Ipv4Addr::new(172, 16, 0, 0)), 12))
+        );
+    }
+
+    #[test]
+    fn invalid_error() {
+        let options = IpOptions::default();
+
+        assert_eq!(
+            "10.0.0.0".validate_cidr(options),
+            Err(CidrError::MissingPrefixLength {
+                code: CidrErrorCode,
+                #[cfg(feature = "message")]
+                message: "missing prefix length".to_owned(),
+            })
+        );
+        assert_eq!(
+            "10.0.0/8".validate_cidr(options),
+            Err(CidrError::InvalidAddress {
+                code: CidrErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid IP address".to_owned(),
+            })
+        );
+        for value in ["10.0.0.0/33", "10.0.0.0/", "10.0.0.0/+8", "10.0.0.0/08"] {
+            assert_eq!(
+                value.validate_cidr(options),
+                Err(CidrError::InvalidPrefixLength {
+                    code: CidrErrorCode,
+                    max: 32,
+                    #[cfg(feature = "message")]
+                    message: "prefix length must be between 0 and 32".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+        assert_eq!(
+            "10.0.0.1/8".validate_cidr(options),
+            Err(CidrError::HostBitsSet {
+                code: CidrErrorCode,
+                #[cfg(feature = "message")]
+                message: "address has bits set after the prefix".to_owned(),
+            })
+        );
+        assert_eq!(
+            "2001:db8::1/32".validate_cidr(options),
+            Err(CidrError::HostBitsSet {
+                code: CidrErrorCode,
+                #[cfg(feature = "message")]
+                message: "address has bits set after the prefix".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn options_error() {
+        let options = IpOptions {
+            v6: false,
+            allow_private: false,
+            allow_unspecified: false,
+            ..IpOptions::default()
+        };
+
+        assert_eq!(
+            "2001:db8::/32".validate_cidr(options),
+            Err(CidrError::DisallowedVersion {
+                code: CidrErrorCode,
+                value: IpVersion::V6,
+                #[cfg(feature = "message")]
+                message: "IPv6 network is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            "192.168.0.0/16".validate_cidr(options),
+            Err(CidrError::Private {
+                code: CidrErrorCode,
+                #[cfg(feature = "message")]
+                message: "private network is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            "0.0.0.0/8".validate_cidr(options),
+            Err(CidrError::Unspecified {
+                code: CidrErrorCode,
+                #[cfg(feature = "message")]
+                message: "unspecified network is not allowed".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,633 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(ContentErrorCode, CONTENT_ERROR_CODE, "content");
+
+/// String content validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum ContentError {
+    /// Value does not contain the pattern.
+    Contains {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The required pattern.
+        pattern: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value contains the pattern.
+    NotContains {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The disallowed pattern.
+        pattern: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value does not start with the prefix.
+    StartsWith {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The required prefix.
+        prefix: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value does not end with the suffix.
+    EndsWith {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The required suffix.
+        suffix: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value contains a non-ASCII character.
+    Ascii {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The first invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value contains a non-alphanumeric character.
+    Alphanumeric {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The first invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value is empty or only contains whitespace.
+    NotBlank {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value contains a control, bidirectional override or zero-width character.
+    NoControlCharacters {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The first invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value contains a character outside of the character set.
+    Charset {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: ContentErrorCode,
+
+        /// The character set.
+        charset: String,
+
+        /// The first invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for ContentError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for ContentError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("ContentError", |_| {
+            [
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("contains"),
+                    &[("pattern", "string")],
+                ),
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("notContains"),
+                    &[("pattern", "string")],
+                ),
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("startsWith"),
+                    &[("prefix", "string")],
+                ),
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("endsWith"),
+                    &[("suffix", "string")],
+                ),
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("ascii"),
+                    &[("character", "string")],
+                ),
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("alphanumeric"),
+                    &[("character", "string")],
+                ),
+                error_type(CONTENT_ERROR_CODE, Some("notBlank"), &[]),
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("noControlCharacters"),
+                    &[("character", "string")],
+                ),
+                error_type(
+                    CONTENT_ERROR_CODE,
+                    Some("charset"),
+                    &[("charset", "string"), ("character", "string")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "ContentError".to_owned()
+    }
+}
+
+/// Parse a character set, e.g. `a-z0-9_-`, into inclusive ranges.
+///
+/// A `-` between two characters denotes a range, otherwise it is a literal `-`.
+pub fn parse_charset(charset: &str) -> Vec<(char, char)> {
+    let mut ranges = vec![];
+    let mut chars = charset.chars().peekable();
+
+    while let Some(start) = chars.next() {
+        let mut lookahead = chars.clone();
+
+        if lookahead.next() == Some('-')
+            && let Some(end) = lookahead.next()
+        {
+            ranges.push((start, end));
+            chars = lookahead;
+        } else {
+            ranges.push((start, start));
+        }
+    }
+
+    ranges
+}
+
+/// Whether the character is a C0 or C1 control, bidirectional override or zero-width character.
+pub fn is_control_character(character: char) -> bool {
+    character.is_control()
+        || matches!(
+            character,
+            // Bidirectional marks, embeddings, overrides and isolates.
+            '\u{061C}'
+                | '\u{200E}'
+                | '\u{200F}'
+                | '\u{202A}'..='\u{202E}'
+                | '\u{2066}'..='\u{2069}'
+                // Zero-width characters.
+                | '\u{200B}'..='\u{200D}'
+                | '\u{2060}'
+                | '\u{FEFF}'
+        )
+}
+
+/// Validate string content.
+pub trait ValidateContent {
+    /// The string content.
+    fn content(&self) -> Option<&str>;
+
+    /// Validate the string contains the pattern.
+    fn validate_contains(&self, pattern: &str) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if content.contains(pattern) {
+            Ok(())
+        } else {
+            Err(ContentError::Contains {
+                code: ContentErrorCode,
+                pattern: pattern.to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("value does not contain `{pattern}`"),
+            })
+        }
+    }
+
+    /// Validate the string does not contain the pattern.
+    fn validate_not_contains(&self, pattern: &str) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if content.contains(pattern) {
+            Err(ContentError::NotContains {
+                code: ContentErrorCode,
+                pattern: pattern.to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("value contains `{pattern}`"),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate the string starts with the prefix.
+    fn validate_starts_with(&self, prefix: &str) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if content.starts_with(prefix) {
+            Ok(())
+        } else {
+            Err(ContentError::StartsWith {
+                code: ContentErrorCode,
+                prefix: prefix.to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("value does not start with `{prefix}`"),
+            })
+        }
+    }
+
+    /// Validate the string ends with the suffix.
+    fn validate_ends_with(&self, suffix: &str) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if content.ends_with(suffix) {
+            Ok(())
+        } else {
+            Err(ContentError::EndsWith {
+                code: ContentErrorCode,
+                suffix: suffix.to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("value does not end with `{suffix}`"),
+            })
+        }
+    }
+
+    /// Validate the string only contains ASCII characters.
+    fn validate_ascii(&self) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if let Some(character) = content.chars().find(|c| !c.is_ascii()) {
+            Err(ContentError::Ascii {
+                code: ContentErrorCode,
+                character,
+                #[cfg(feature = "message")]
+                message: format!("character `{character}` is not ASCII"),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate the string only contains alphanumeric characters.
+    fn validate_alphanumeric(&self) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if let Some(character) = content.chars().find(|c| !c.is_alphanumeric()) {
+            Err(ContentError::Alphanumeric {
+                code: ContentErrorCode,
+                character,
+                #[cfg(feature = "message")]
+                message: format!("character `{character}` is not alphanumeric"),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate the string is not empty after trimming whitespace.
+    fn validate_not_blank(&self) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if content.trim().is_empty() {
+            Err(ContentError::NotBlank {
+                code: ContentErrorCode,
+                #[cfg(feature = "message")]
+                message: "value is blank".to_owned(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate the string does not contain control, bidirectional override or zero-width characters.
+    ///
+    /// If `allow_whitespace` is set, tabs, line feeds and carriage returns are allowed.
+    fn validate_no_control_characters(&self, allow_whitespace: bool) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        if let Some(character) = content.chars().find(|c| {
+            is_control_character(*c) && !(allow_whitespace && matches!(c, '\t' | '\n' | '\r'))
+        }) {
+            Err(ContentError::NoControlCharacters {
+                code: ContentErrorCode,
+                character,
+                #[cfg(feature = "message")]
+                message: format!("character {character:?} is not allowed"),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate the string only contains characters of the character set, e.g. `a-z0-9_-`.
+    fn validate_charset(&self, charset: &str) -> Result<(), ContentError> {
+        let Some(content) = self.content() else {
+            return Ok(());
+        };
+
+        let ranges = parse_charset(charset);
+
+        if let Some(character) = content.chars().find(|c| {
+            !ranges
+                .iter()
+                .any(|(start, end)| (*start..=*end).contains(c))
+        }) {
+            Err(ContentError::Charset {
+                code: ContentErrorCode,
+                charset: charset.to_owned(),
+                character,
+                #[cfg(feature = "message")]
+                message: format!("character `{character}` is not in character set `{charset}`"),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ValidateContent for str {
+    fn content(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl ValidateContent for String {
+    fn content(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl ValidateContent for Cow<'_, str> {
+    fn content(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T> ValidateContent for Option<T>
+where
+    T: ValidateContent,
+{
+    fn content(&self) -> Option<&str> {
+        if let Some(value) = self {
+            T::content(value)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateContent for $type
+        where
+            T: ValidateContent + ?Sized,
+        {
+            fn content(&self) -> Option<&str> {
+                T::content(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{ContentError, ContentErrorCode, ValidateContent, parse_charset};
+
+    #[test]
+    fn ok() {
+        assert_eq!("sk_live".validate_starts_with("sk_"), Ok(()));
+        assert_eq!("sk_live".to_owned().validate_ends_with("live"), Ok(()));
+        assert_eq!(Cow::Borrowed("a<b").validate_contains("<"), Ok(()));
+        assert_eq!(Some("ab").validate_not_contains("<"), Ok(()));
+        assert_eq!(None::<String>.validate_contains("<"), Ok(()));
+        assert_eq!(Arc::new("abc").validate_ascii(), Ok(()));
+        assert_eq!(Box::new("abc123").validate_alphanumeric(), Ok(()));
+        assert_eq!(Rc::new("my-slug_1").validate_charset("a-z0-9_-"), Ok(()));
+        assert_eq!(RefCell::new("a-b").borrow().validate_charset("-ab"), Ok(()));
+    }
+
+    #[test]
+    fn contains_error() {
+        assert_eq!(
+            "ab".validate_contains("<"),
+            Err(ContentError::Contains {
+                code: ContentErrorCode,
+                pattern: "<".to_owned(),
+                #[cfg(feature = "message")]
+                message: "value does not contain `<`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "a<b".validate_not_contains("<"),
+            Err(ContentError::NotContains {
+                code: ContentErrorCode,
+                pattern: "<".to_owned(),
+                #[cfg(feature = "message")]
+                message: "value contains `<`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn affix_error() {
+        assert_eq!(
+            "pk_live".validate_starts_with("sk_"),
+            Err(ContentError::StartsWith {
+                code: ContentErrorCode,
+                prefix: "sk_".to_owned(),
+                #[cfg(feature = "message")]
+                message: "value does not start with `sk_`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "sk_test".validate_ends_with("live"),
+            Err(ContentError::EndsWith {
+                code: ContentErrorCode,
+                suffix: "live".to_owned(),
+                #[cfg(feature = "message")]
+                message: "value does not end with `live`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn characters_error() {
+        assert_eq!(
+            "café".validate_ascii(),
+            Err(ContentError::Ascii {
+                code: ContentErrorCode,
+                character: 'é',
+                #[cfg(feature = "message")]
+                message: "character `é` is not ASCII".to_owned(),
+            })
+        );
+        assert_eq!(
+            "a b".validate_alphanumeric(),
+            Err(ContentError::Alphanumeric {
+                code: ContentErrorCode,
+                character: ' ',
+                #[cfg(feature = "message")]
+                message: "character ` ` is not alphanumeric".to_owned(),
+            })
+        );
+        assert_eq!(
+            "My-slug".validate_charset("a-z0-9_-"),
+            Err(ContentError::Charset {
+                code: ContentErrorCode,
+                charset: "a-z0-9_-".to_owned(),
+                character: 'M',
+                #[cfg(feature = "message")]
+                message: "character `M` is not in character set `a-z0-9_-`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn blank_error() {
+        assert_eq!("\u{3000}a ".validate_not_blank(), Ok(()));
+        assert_eq!(None::<&str>.validate_not_blank(), Ok(()));
+        assert_eq!(
+            " \t\u{3000}".validate_not_blank(),
+            Err(ContentError::NotBlank {
+                code: ContentErrorCode,
+                #[cfg(feature = "message")]
+                message: "value is blank".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn control_characters_error() {
+        assert_eq!("café 👍".validate_no_control_characters(false), Ok(()));
+        assert_eq!("a\r\n\tb".validate_no_control_characters(true), Ok(()));
+
+        for character in [
+            '\0', '\n', '\u{7F}', '\u{85}', '\u{202E}', '\u{2067}', '\u{200B}', '\u{FEFF}',
+        ] {
+            assert_eq!(
+                format!("a{character}b").validate_no_control_characters(false),
+                Err(ContentError::NoControlCharacters {
+                    code: ContentErrorCode,
+                    character,
+                    #[cfg(feature = "message")]
+                    message: format!("character {character:?} is not allowed"),
+                })
+            );
+        }
+
+        assert!("a\u{1B}b".validate_no_control_characters(true).is_err());
+    }
+
+    #[test]
+    fn charset() {
+        assert_eq!(
+            parse_charset("a-z0-9_-"),
+            vec![('a', 'z'), ('0', '9'), ('_', '_'), ('-', '-')]
+        );
+        assert_eq!(parse_charset("-a"), vec![('-', '-'), ('a', 'a')]);
+        assert_eq!(parse_charset("a-"), vec![('a', 'a'), ('-', '-')]);
+    }
+}
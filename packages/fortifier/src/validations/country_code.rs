@@ -0,0 +1,591 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+use crate::PhoneNumberCountry;
+
+constant_string!(CountryCodeErrorCode, COUNTRY_CODE_ERROR_CODE, "countryCode");
+
+/// ISO 3166-1 alpha-3 codes of the [`PhoneNumberCountry`] countries, sorted by code.
+///
+/// `AC` (Ascension Island), `TA` (Tristan da Cunha) and `XK` (Kosovo) have no alpha-3 code.
+const ALPHA_3: &[(&str, PhoneNumberCountry)] = &[
+    ("ABW", PhoneNumberCountry::AW),
+    ("AFG", PhoneNumberCountry::AF),
+    ("AGO", PhoneNumberCountry::AO),
+    ("AIA", PhoneNumberCountry::AI),
+    ("ALA", PhoneNumberCountry::AX),
+    ("ALB", PhoneNumberCountry::AL),
+    ("AND", PhoneNumberCountry::AD),
+    ("ARE", PhoneNumberCountry::AE),
+    ("ARG", PhoneNumberCountry::AR),
+    ("ARM", PhoneNumberCountry::AM),
+    ("ASM", PhoneNumberCountry::AS),
+    ("ATG", PhoneNumberCountry::AG),
+    ("AUS", PhoneNumberCountry::AU),
+    ("AUT", PhoneNumberCountry::AT),
+    ("AZE", PhoneNumberCountry::AZ),
+    ("BDI", PhoneNumberCountry::BI),
+    ("BEL", PhoneNumberCountry::BE),
+    ("BEN", PhoneNumberCountry::BJ),
+    ("BES", PhoneNumberCountry::BQ),
+    ("BFA", PhoneNumberCountry::BF),
+    ("BGD", PhoneNumberCountry::BD),
+    ("BGR", PhoneNumberCountry::BG),
+    ("BHR", PhoneNumberCountry::BH),
+    ("BHS", PhoneNumberCountry::BS),
+    ("BIH", PhoneNumberCountry::BA),
+    ("BLM", PhoneNumberCountry::BL),
+    ("BLR", PhoneNumberCountry::BY),
+    ("BLZ", PhoneNumberCountry::BZ),
+    ("BMU", PhoneNumberCountry::BM),
+    ("BOL", PhoneNumberCountry::BO),
+    ("BRA", PhoneNumberCountry::BR),
+    ("BRB", PhoneNumberCountry::BB),
+    ("BRN", PhoneNumberCountry::BN),
+    ("BTN", PhoneNumberCountry::BT),
+    ("BWA", PhoneNumberCountry::BW),
+    ("CAF", PhoneNumberCountry::CF),
+    ("CAN", PhoneNumberCountry::CA),
+    ("CCK", PhoneNumberCountry::CC),
+    ("CHE", PhoneNumberCountry::CH),
+    ("CHL", PhoneNumberCountry::CL),
+    ("CHN", PhoneNumberCountry::CN),
+    ("CIV", PhoneNumberCountry::CI),
+    ("CMR", PhoneNumberCountry::CM),
+    ("COD", PhoneNumberCountry::CD),
+    ("COG", PhoneNumberCountry::CG),
+    ("COK", PhoneNumberCountry::CK),
+    ("COL", PhoneNumberCountry::CO),
+    ("COM", PhoneNumberCountry::KM),
+    ("CPV", PhoneNumberCountry::CV),
+    ("CRI", PhoneNumberCountry::CR),
+    ("CUB", PhoneNumberCountry::CU),
+    ("CUW", PhoneNumberCountry::CW),
+    ("CXR", PhoneNumberCountry::CX),
+    ("CYM", PhoneNumberCountry::KY),
+    ("CYP", PhoneNumberCountry::CY),
+    ("CZE", PhoneNumberCountry::CZ),
+    ("DEU", PhoneNumberCountry::DE),
+    ("DJI", PhoneNumberCountry::DJ),
+    ("DMA", PhoneNumberCountry::DM),
+    ("DNK", PhoneNumberCountry::DK),
+    ("DOM", PhoneNumberCountry::DO),
+    ("DZA", PhoneNumberCountry::DZ),
+    ("ECU", PhoneNumberCountry::EC),
+    ("EGY", PhoneNumberCountry::EG),
+    ("ERI", PhoneNumberCountry::ER),
+    ("ESH", PhoneNumberCountry::EH),
+    ("ESP", PhoneNumberCountry::ES),
+    ("EST", PhoneNumberCountry::EE),
+    ("ETH", PhoneNumberCountry::ET),
+    ("FIN", PhoneNumberCountry::FI),
+    ("FJI", PhoneNumberCountry::FJ),
+    ("FLK", PhoneNumberCountry::FK),
+    ("FRA", PhoneNumberCountry::FR),
+    ("FRO", PhoneNumberCountry::FO),
+    ("FSM", PhoneNumberCountry::FM),
+    ("GAB", PhoneNumberCountry::GA),
+    ("GBR", PhoneNumberCountry::GB),
+    ("GEO", PhoneNumberCountry::GE),
+    ("GGY", PhoneNumberCountry::GG),
+    ("GHA", PhoneNumberCountry::GH),
+    ("GIB", PhoneNumberCountry::GI),
+    ("GIN", PhoneNumberCountry::GN),
+    ("GLP", PhoneNumberCountry::GP),
+    ("GMB", PhoneNumberCountry::GM),
+    ("GNB", PhoneNumberCountry::GW),
+    ("GNQ", PhoneNumberCountry::GQ),
+    ("GRC", PhoneNumberCountry::GR),
+    ("GRD", PhoneNumberCountry::GD),
+    ("GRL", PhoneNumberCountry::GL),
+    ("GTM", PhoneNumberCountry::GT),
+    ("GUF", PhoneNumberCountry::GF),
+    ("GUM", PhoneNumberCountry::GU),
+    ("GUY", PhoneNumberCountry::GY),
+    ("HKG", PhoneNumberCountry::HK),
+    ("HND", PhoneNumberCountry::HN),
+    ("HRV", PhoneNumberCountry::HR),
+    ("HTI", PhoneNumberCountry::HT),
+    ("HUN", PhoneNumberCountry::HU),
+    ("IDN", PhoneNumberCountry::ID),
+    ("IMN", PhoneNumberCountry::IM),
+    ("IND", PhoneNumberCountry::IN),
+    ("IOT", PhoneNumberCountry::IO),
+    ("IRL", PhoneNumberCountry::IE),
+    ("IRN", PhoneNumberCountry::IR),
+    ("IRQ", PhoneNumberCountry::IQ),
+    ("ISL", PhoneNumberCountry::IS),
+    ("ISR", PhoneNumberCountry::IL),
+    ("ITA", PhoneNumberCountry::IT),
+    ("JAM", PhoneNumberCountry::JM),
+    ("JEY", PhoneNumberCountry::JE),
+    ("JOR", PhoneNumberCountry::JO),
+    ("JPN", PhoneNumberCountry::JP),
+    ("KAZ", PhoneNumberCountry::KZ),
+    ("KEN", PhoneNumberCountry::KE),
+    ("KGZ", PhoneNumberCountry::KG),
+    ("KHM", PhoneNumberCountry::KH),
+    ("KIR", PhoneNumberCountry::KI),
+    ("KNA", PhoneNumberCountry::KN),
+    ("KOR", PhoneNumberCountry::KR),
+    ("KWT", PhoneNumberCountry::KW),
+    ("LAO", PhoneNumberCountry::LA),
+    ("LBN", PhoneNumberCountry::LB),
+    ("LBR", PhoneNumberCountry::LR),
+    ("LBY", PhoneNumberCountry::LY),
+    ("LCA", PhoneNumberCountry::LC),
+    ("LIE", PhoneNumberCountry::LI),
+    ("LKA", PhoneNumberCountry::LK),
+    ("LSO", PhoneNumberCountry::LS),
+    ("LTU", PhoneNumberCountry::LT),
+    ("LUX", PhoneNumberCountry::LU),
+    ("LVA", PhoneNumberCountry::LV),
+    ("MAC", PhoneNumberCountry::MO),
+    ("MAF", PhoneNumberCountry::MF),
+    ("MAR", PhoneNumberCountry::MA),
+    ("MCO", PhoneNumberCountry::MC),
+    ("MDA", PhoneNumberCountry::MD),
+    ("MDG", PhoneNumberCountry::MG),
+    ("MDV", PhoneNumberCountry::MV),
+    ("MEX", PhoneNumberCountry::MX),
+    ("MHL", PhoneNumberCountry::MH),
+    ("MKD", PhoneNumberCountry::MK),
+    ("MLI", PhoneNumberCountry::ML),
+    ("MLT", PhoneNumberCountry::MT),
+    ("MMR", PhoneNumberCountry::MM),
+    ("MNE", PhoneNumberCountry::ME),
+    ("MNG", PhoneNumberCountry::MN),
+    ("MNP", PhoneNumberCountry::MP),
+    ("MOZ", PhoneNumberCountry::MZ),
+    ("MRT", PhoneNumberCountry::MR),
+    ("MSR", PhoneNumberCountry::MS),
+    ("MTQ", PhoneNumberCountry::MQ),
+    ("MUS", PhoneNumberCountry::MU),
+    ("MWI", PhoneNumberCountry::MW),
+    ("MYS", PhoneNumberCountry::MY),
+    ("MYT", PhoneNumberCountry::YT),
+    ("NAM", PhoneNumberCountry::NA),
+    ("NCL", PhoneNumberCountry::NC),
+    ("NER", PhoneNumberCountry::NE),
+    ("NFK", PhoneNumberCountry::NF),
+    ("NGA", PhoneNumberCountry::NG),
+    ("NIC", PhoneNumberCountry::NI),
+    ("NIU", PhoneNumberCountry::NU),
+    ("NLD", PhoneNumberCountry::NL),
+    ("NOR", PhoneNumberCountry::NO),
+    ("NPL", PhoneNumberCountry::NP),
+    ("NRU", PhoneNumberCountry::NR),
+    ("NZL", PhoneNumberCountry::NZ),
+    ("OMN", PhoneNumberCountry::OM),
+    ("PAK", PhoneNumberCountry::PK),
+    ("PAN", PhoneNumberCountry::PA),
+    ("PER", PhoneNumberCountry::PE),
+    ("PHL", PhoneNumberCountry::PH),
+    ("PLW", PhoneNumberCountry::PW),
+    ("PNG", PhoneNumberCountry::PG),
+    ("POL", PhoneNumberCountry::PL),
+    ("PRI", PhoneNumberCountry::PR),
+    ("PRK", PhoneNumberCountry::KP),
+    ("PRT", PhoneNumberCountry::PT),
+    ("PRY", PhoneNumberCountry::PY),
+    ("PSE", PhoneNumberCountry::PS),
+    ("PYF", PhoneNumberCountry::PF),
+    ("QAT", PhoneNumberCountry::QA),
+    ("REU", PhoneNumberCountry::RE),
+    ("ROU", PhoneNumberCountry::RO),
+    ("RUS", PhoneNumberCountry::RU),
+    ("RWA", PhoneNumberCountry::RW),
+    ("SAU", PhoneNumberCountry::SA),
+    ("SDN", PhoneNumberCountry::SD),
+    ("SEN", PhoneNumberCountry::SN),
+    ("SGP", PhoneNumberCountry::SG),
+    ("SHN", PhoneNumberCountry::SH),
+    ("SJM", PhoneNumberCountry::SJ),
+    ("SLB", PhoneNumberCountry::SB),
+    ("SLE", PhoneNumberCountry::SL),
+    ("SLV", PhoneNumberCountry::SV),
+    ("SMR", PhoneNumberCountry::SM),
+    ("SOM", PhoneNumberCountry::SO),
+    ("SPM", PhoneNumberCountry::PM),
+    ("SRB", PhoneNumberCountry::RS),
+    ("SSD", PhoneNumberCountry::SS),
+    ("STP", PhoneNumberCountry::ST),
+    ("SUR", PhoneNumberCountry::SR),
+    ("SVK", PhoneNumberCountry::SK),
+    ("SVN", PhoneNumberCountry::SI),
+    ("SWE", PhoneNumberCountry::SE),
+    ("SWZ", PhoneNumberCountry::SZ),
+    ("SXM", PhoneNumberCountry::SX),
+    ("SYC", PhoneNumberCountry::SC),
+    ("SYR", PhoneNumberCountry::SY),
+    ("TCA", PhoneNumberCountry::TC),
+    ("TCD", PhoneNumberCountry::TD),
+    ("TGO", PhoneNumberCountry::TG),
+    ("THA", PhoneNumberCountry::TH),
+    ("TJK", PhoneNumberCountry::TJ),
+    ("TKL", PhoneNumberCountry::TK),
+    ("TKM", PhoneNumberCountry::TM),
+    ("TLS", PhoneNumberCountry::TL),
+    ("TON", PhoneNumberCountry::TO),
+    ("TTO", PhoneNumberCountry::TT),
+    ("TUN", PhoneNumberCountry::TN),
+    ("TUR", PhoneNumberCountry::TR),
+    ("TUV", PhoneNumberCountry::TV),
+    ("TWN", PhoneNumberCountry::TW),
+    ("TZA", PhoneNumberCountry::TZ),
+    ("UGA", PhoneNumberCountry::UG),
+    ("UKR", PhoneNumberCountry::UA),
+    ("URY", PhoneNumberCountry::UY),
+    ("USA", PhoneNumberCountry::US),
+    ("UZB", PhoneNumberCountry::UZ),
+    ("VAT", PhoneNumberCountry::VA),
+    ("VCT", PhoneNumberCountry::VC),
+    ("VEN", PhoneNumberCountry::VE),
+    ("VGB", PhoneNumberCountry::VG),
+    ("VIR", PhoneNumberCountry::VI),
+    ("VNM", PhoneNumberCountry::VN),
+    ("VUT", PhoneNumberCountry::VU),
+    ("WLF", PhoneNumberCountry::WF),
+    ("WSM", PhoneNumberCountry::WS),
+    ("YEM", PhoneNumberCountry::YE),
+    ("ZAF", PhoneNumberCountry::ZA),
+    ("ZMB", PhoneNumberCountry::ZM),
+    ("ZWE", PhoneNumberCountry::ZW),
+];
+
+/// Country code format.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CountryCodeFormat {
+    /// ISO 3166-1 alpha-2 (e.g. `NL`).
+    #[default]
+    Alpha2,
+    /// ISO 3166-1 alpha-3 (e.g. `NLD`).
+    Alpha3,
+}
+
+/// Country code validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum CountryCodeError {
+    /// Unknown country code error.
+    Unknown {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CountryCodeErrorCode,
+
+        /// The actual value.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed country error.
+    Disallowed {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CountryCodeErrorCode,
+
+        /// The allowed countries.
+        #[cfg_attr(feature = "utoipa", schema(value_type = Vec<String>))]
+        allowed: Vec<PhoneNumberCountry>,
+
+        /// The actual country.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        value: PhoneNumberCountry,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for CountryCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for CountryCodeError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for CountryCodeError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("CountryCodeError", |_| {
+            [
+                error_type(
+                    COUNTRY_CODE_ERROR_CODE,
+                    Some("unknown"),
+                    &[("value", "string")],
+                ),
+                error_type(
+                    COUNTRY_CODE_ERROR_CODE,
+                    Some("disallowed"),
+                    &[("allowed", "string[]"), ("value", "string")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "CountryCodeError".to_owned()
+    }
+}
+
+/// Validate a country code.
+pub trait ValidateCountryCode {
+    /// The country code.
+    fn country_code(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate country code.
+    fn validate_country_code(
+        &self,
+        format: CountryCodeFormat,
+        allowed: Option<&[PhoneNumberCountry]>,
+    ) -> Result<(), CountryCodeError> {
+        self.parse_country_code(format, allowed)?;
+
+        Ok(())
+    }
+
+    /// Validate country code and return the country.
+    fn parse_country_code(
+        &self,
+        format: CountryCodeFormat,
+        allowed: Option<&[PhoneNumberCountry]>,
+    ) -> Result<Option<PhoneNumberCountry>, CountryCodeError> {
+        self.country_code()
+            .map(|country_code| parse_country_code(&country_code, format, allowed))
+            .transpose()
+    }
+}
+
+/// Parse an uppercase ISO 3166-1 country code.
+///
+/// The countries are those of [`PhoneNumberCountry`], so country codes are consistent with phone number validation.
+pub fn parse_country_code(
+    value: &str,
+    format: CountryCodeFormat,
+    allowed: Option<&[PhoneNumberCountry]>,
+) -> Result<PhoneNumberCountry, CountryCodeError> {
+    let country = match format {
+        CountryCodeFormat::Alpha2 => country_from_alpha_2(value),
+        CountryCodeFormat::Alpha3 => ALPHA_3
+            .binary_search_by_key(&value, |(alpha_3, _)| alpha_3)
+            .ok()
+            .map(|index| ALPHA_3[index].1),
+    };
+
+    let Some(country) = country else {
+        return Err(CountryCodeError::Unknown {
+            code: CountryCodeErrorCode,
+            value: value.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("unknown country code `{value}`"),
+        });
+    };
+
+    if let Some(allowed) = allowed
+        && !allowed.contains(&country)
+    {
+        #[cfg(feature = "message")]
+        let message = format!(
+            "country `{}` is not allowed, must be one of `{}`",
+            country.as_ref(),
+            allowed
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        return Err(CountryCodeError::Disallowed {
+            code: CountryCodeErrorCode,
+            allowed: allowed.to_vec(),
+            value: country,
+            #[cfg(feature = "message")]
+            message,
+        });
+    }
+
+    Ok(country)
+}
+
+/// Find the country of an uppercase ISO 3166-1 alpha-2 code.
+pub(crate) fn country_from_alpha_2(value: &str) -> Option<PhoneNumberCountry> {
+    if value.len() == 2 && value.bytes().all(|byte| byte.is_ascii_uppercase()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+impl ValidateCountryCode for str {
+    fn country_code(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCountryCode for &str {
+    fn country_code(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateCountryCode for String {
+    fn country_code(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCountryCode for Cow<'_, str> {
+    fn country_code(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateCountryCode for Option<T>
+where
+    T: ValidateCountryCode,
+{
+    fn country_code(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self {
+            T::country_code(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateCountryCode for $type
+        where
+            T: ValidateCountryCode,
+        {
+            fn country_code(&self) -> Option<Cow<'_, str>> {
+                T::country_code(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{
+        ALPHA_3, CountryCodeError, CountryCodeErrorCode, CountryCodeFormat, PhoneNumberCountry,
+        ValidateCountryCode,
+    };
+
+    const ALPHA_2: CountryCodeFormat = CountryCodeFormat::Alpha2;
+
+    #[test]
+    fn alpha_3_sorted() {
+        assert!(ALPHA_3.is_sorted_by_key(|(alpha_3, _)| alpha_3));
+    }
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"NL").validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!("NL".validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!("NL".to_owned().validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("NL").validate_country_code(ALPHA_2, None),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!(Some("NL").validate_country_code(ALPHA_2, None), Ok(()));
+
+        assert_eq!((&"NL").validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!(Box::new("NL").validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!(Arc::new("NL").validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!(Rc::new("NL").validate_country_code(ALPHA_2, None), Ok(()));
+
+        let cell = RefCell::new("NL");
+        assert_eq!(cell.borrow().validate_country_code(ALPHA_2, None), Ok(()));
+        assert_eq!(
+            cell.borrow_mut().validate_country_code(ALPHA_2, None),
+            Ok(())
+        );
+
+        assert_eq!(
+            "NLD".parse_country_code(CountryCodeFormat::Alpha3, None),
+            Ok(Some(PhoneNumberCountry::NL))
+        );
+        assert_eq!(
+            "GBR".parse_country_code(
+                CountryCodeFormat::Alpha3,
+                Some(&[PhoneNumberCountry::GB, PhoneNumberCountry::IE])
+            ),
+            Ok(Some(PhoneNumberCountry::GB))
+        );
+    }
+
+    #[test]
+    fn unknown_error() {
+        for (value, format) in [
+            ("nl", ALPHA_2),
+            ("NLD", ALPHA_2),
+            ("ZZ", ALPHA_2),
+            ("NL", CountryCodeFormat::Alpha3),
+            ("ZZZ", CountryCodeFormat::Alpha3),
+        ] {
+            assert_eq!(
+                value.validate_country_code(format, None),
+                Err(CountryCodeError::Unknown {
+                    code: CountryCodeErrorCode,
+                    value: value.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("unknown country code `{value}`"),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn disallowed_error() {
+        assert_eq!(
+            "BE".validate_country_code(
+                ALPHA_2,
+                Some(&[PhoneNumberCountry::NL, PhoneNumberCountry::LU])
+            ),
+            Err(CountryCodeError::Disallowed {
+                code: CountryCodeErrorCode,
+                allowed: vec![PhoneNumberCountry::NL, PhoneNumberCountry::LU],
+                value: PhoneNumberCountry::BE,
+                #[cfg(feature = "message")]
+                message: "country `BE` is not allowed, must be one of `NL, LU`".to_owned(),
+            })
+        );
+    }
+}
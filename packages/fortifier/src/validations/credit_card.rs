@@ -0,0 +1,569 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    ops::RangeInclusive,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(CreditCardErrorCode, CREDIT_CARD_ERROR_CODE, "creditCard");
+
+/// Credit card brand.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum CreditCardBrand {
+    /// American Express.
+    AmericanExpress,
+    /// Diners Club.
+    DinersClub,
+    /// Discover.
+    Discover,
+    /// JCB.
+    Jcb,
+    /// Maestro.
+    Maestro,
+    /// Mastercard.
+    Mastercard,
+    /// UnionPay.
+    UnionPay,
+    /// Visa.
+    Visa,
+}
+
+impl fmt::Display for CreditCardBrand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::AmericanExpress => "American Express",
+            Self::DinersClub => "Diners Club",
+            Self::Discover => "Discover",
+            Self::Jcb => "JCB",
+            Self::Maestro => "Maestro",
+            Self::Mastercard => "Mastercard",
+            Self::UnionPay => "UnionPay",
+            Self::Visa => "Visa",
+        })
+    }
+}
+
+/// Brand prefix ranges and lengths.
+struct BrandRule {
+    brand: CreditCardBrand,
+    prefixes: &'static [(u32, u32)],
+    lengths: RangeInclusive<usize>,
+}
+
+/// Brand rules.
+///
+/// Ordered from most to least specific prefix, as some ranges overlap (e.g. Discover and UnionPay).
+const BRANDS: [BrandRule; 8] = [
+    BrandRule {
+        brand: CreditCardBrand::AmericanExpress,
+        prefixes: &[(34, 34), (37, 37)],
+        lengths: 15..=15,
+    },
+    BrandRule {
+        brand: CreditCardBrand::Discover,
+        prefixes: &[(6011, 6011), (644, 649), (65, 65), (622126, 622925)],
+        lengths: 16..=19,
+    },
+    BrandRule {
+        brand: CreditCardBrand::Maestro,
+        prefixes: &[
+            (5018, 5018),
+            (5020, 5020),
+            (5038, 5038),
+            (5893, 5893),
+            (6304, 6304),
+            (6759, 6759),
+            (6761, 6763),
+        ],
+        lengths: 12..=19,
+    },
+    BrandRule {
+        brand: CreditCardBrand::Jcb,
+        prefixes: &[(3528, 3589)],
+        lengths: 16..=19,
+    },
+    BrandRule {
+        brand: CreditCardBrand::DinersClub,
+        prefixes: &[(300, 305), (36, 36), (38, 39)],
+        lengths: 14..=19,
+    },
+    BrandRule {
+        brand: CreditCardBrand::Mastercard,
+        prefixes: &[(51, 55), (2221, 2720)],
+        lengths: 16..=16,
+    },
+    BrandRule {
+        brand: CreditCardBrand::UnionPay,
+        prefixes: &[(62, 62)],
+        lengths: 16..=19,
+    },
+    BrandRule {
+        brand: CreditCardBrand::Visa,
+        prefixes: &[(4, 4)],
+        lengths: 13..=19,
+    },
+];
+
+/// Credit card validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum CreditCardError {
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CreditCardErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unknown brand error.
+    UnknownBrand {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CreditCardErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid length error.
+    InvalidLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CreditCardErrorCode,
+
+        /// The brand.
+        brand: CreditCardBrand,
+
+        /// The actual number of digits.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Checksum error.
+    Checksum {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CreditCardErrorCode,
+
+        /// The brand.
+        brand: CreditCardBrand,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed brand error.
+    DisallowedBrand {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CreditCardErrorCode,
+
+        /// Allowed brands.
+        allowed: Vec<CreditCardBrand>,
+
+        /// The actual brand.
+        value: CreditCardBrand,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for CreditCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for CreditCardError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for CreditCardError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        const BRAND_TYPESCRIPT: &str = "\"americanExpress\" | \"dinersClub\" | \"discover\" | \"jcb\" | \"maestro\" | \"mastercard\" | \"unionPay\" | \"visa\"";
+
+        generator.define_type("CreditCardError", |_| {
+            [
+                error_type(
+                    CREDIT_CARD_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(CREDIT_CARD_ERROR_CODE, Some("unknownBrand"), &[]),
+                error_type(
+                    CREDIT_CARD_ERROR_CODE,
+                    Some("invalidLength"),
+                    &[("brand", BRAND_TYPESCRIPT), ("value", "number")],
+                ),
+                error_type(
+                    CREDIT_CARD_ERROR_CODE,
+                    Some("checksum"),
+                    &[("brand", BRAND_TYPESCRIPT)],
+                ),
+                error_type(
+                    CREDIT_CARD_ERROR_CODE,
+                    Some("disallowedBrand"),
+                    &[
+                        ("allowed", &format!("Array<{BRAND_TYPESCRIPT}>")),
+                        ("value", BRAND_TYPESCRIPT),
+                    ],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "CreditCardError".to_owned()
+    }
+}
+
+/// Validate a credit card number.
+pub trait ValidateCreditCard {
+    /// The credit card number.
+    fn credit_card(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate credit card number.
+    fn validate_credit_card(
+        &self,
+        allowed_brands: Option<Vec<CreditCardBrand>>,
+    ) -> Result<(), CreditCardError> {
+        self.parse_credit_card(allowed_brands)?;
+
+        Ok(())
+    }
+
+    /// Validate credit card number and return the brand.
+    fn parse_credit_card(
+        &self,
+        allowed_brands: Option<Vec<CreditCardBrand>>,
+    ) -> Result<Option<CreditCardBrand>, CreditCardError> {
+        self.credit_card()
+            .map(|credit_card| parse_credit_card(&credit_card, allowed_brands))
+            .transpose()
+    }
+}
+
+/// Parse a credit card number, ignoring spaces and hyphens, and return the brand.
+pub fn parse_credit_card(
+    value: &str,
+    allowed_brands: Option<Vec<CreditCardBrand>>,
+) -> Result<CreditCardBrand, CreditCardError> {
+    let mut digits = Vec::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character.to_digit(10) {
+            Some(digit) => digits.push(digit),
+            None if character == ' ' || character == '-' => {}
+            None => {
+                return Err(CreditCardError::InvalidCharacter {
+                    code: CreditCardErrorCode,
+                    character,
+                    #[cfg(feature = "message")]
+                    message: format!("invalid character `{character}`"),
+                });
+            }
+        }
+    }
+
+    let Some((brand, lengths)) = BRANDS.iter().find_map(|rule| {
+        rule.prefixes
+            .iter()
+            .any(|(start, end)| {
+                let prefix_length = start.ilog10() as usize + 1;
+
+                digits.len() >= prefix_length
+                    && (*start..=*end).contains(
+                        &digits[..prefix_length]
+                            .iter()
+                            .fold(0, |prefix, digit| prefix * 10 + digit),
+                    )
+            })
+            .then_some((rule.brand, &rule.lengths))
+    }) else {
+        return Err(CreditCardError::UnknownBrand {
+            code: CreditCardErrorCode,
+            #[cfg(feature = "message")]
+            message: "unknown credit card brand".to_owned(),
+        });
+    };
+
+    if !lengths.contains(&digits.len()) {
+        return Err(CreditCardError::InvalidLength {
+            code: CreditCardErrorCode,
+            brand,
+            value: digits.len(),
+            #[cfg(feature = "message")]
+            message: format!(
+                "{brand} number must have {} digits",
+                if lengths.start() == lengths.end() {
+                    lengths.start().to_string()
+                } else {
+                    format!("{} to {}", lengths.start(), lengths.end())
+                }
+            ),
+        });
+    }
+
+    if !luhn(&digits) {
+        return Err(CreditCardError::Checksum {
+            code: CreditCardErrorCode,
+            brand,
+            #[cfg(feature = "message")]
+            message: format!("invalid {brand} number checksum"),
+        });
+    }
+
+    if let Some(allowed_brands) = allowed_brands
+        && !allowed_brands.contains(&brand)
+    {
+        #[cfg(feature = "message")]
+        let message = format!(
+            "brand `{brand}` is not allowed, must be one of `{}`",
+            allowed_brands
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        return Err(CreditCardError::DisallowedBrand {
+            code: CreditCardErrorCode,
+            allowed: allowed_brands,
+            value: brand,
+            #[cfg(feature = "message")]
+            message,
+        });
+    }
+
+    Ok(brand)
+}
+
+/// Luhn (mod 10) checksum.
+fn luhn(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                *digit
+            }
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+impl ValidateCreditCard for str {
+    fn credit_card(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCreditCard for &str {
+    fn credit_card(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateCreditCard for String {
+    fn credit_card(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCreditCard for Cow<'_, str> {
+    fn credit_card(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateCreditCard for Option<T>
+where
+    T: ValidateCreditCard,
+{
+    fn credit_card(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self {
+            T::credit_card(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateCreditCard for $type
+        where
+            T: ValidateCreditCard,
+        {
+            fn credit_card(&self) -> Option<Cow<'_, str>> {
+                T::credit_card(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{CreditCardBrand, CreditCardError, CreditCardErrorCode, ValidateCreditCard};
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"4111 1111 1111 1111").validate_credit_card(None), Ok(()));
+        assert_eq!("4111-1111-1111-1111".validate_credit_card(None), Ok(()));
+        assert_eq!(
+            "4111111111111111".to_owned().validate_credit_card(None),
+            Ok(())
+        );
+        assert_eq!(
+            Cow::<str>::Borrowed("4111111111111111").validate_credit_card(None),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_credit_card(None), Ok(()));
+        assert_eq!(Some("4111111111111111").validate_credit_card(None), Ok(()));
+
+        assert_eq!((&"4111111111111111").validate_credit_card(None), Ok(()));
+        assert_eq!(
+            Box::new("4111111111111111").validate_credit_card(None),
+            Ok(())
+        );
+        assert_eq!(
+            Arc::new("4111111111111111").validate_credit_card(None),
+            Ok(())
+        );
+        assert_eq!(
+            Rc::new("4111111111111111").validate_credit_card(None),
+            Ok(())
+        );
+
+        let cell = RefCell::new("4111111111111111");
+        assert_eq!(cell.borrow().validate_credit_card(None), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_credit_card(None), Ok(()));
+    }
+
+    #[test]
+    fn brands() {
+        for (value, brand) in [
+            ("378282246310005", CreditCardBrand::AmericanExpress),
+            ("36227206271667", CreditCardBrand::DinersClub),
+            ("6011111111111117", CreditCardBrand::Discover),
+            ("6221260000000000", CreditCardBrand::Discover),
+            ("3530111333300000", CreditCardBrand::Jcb),
+            ("6759649826438453", CreditCardBrand::Maestro),
+            ("5555555555554444", CreditCardBrand::Mastercard),
+            ("2223003122003222", CreditCardBrand::Mastercard),
+            ("6200000000000005", CreditCardBrand::UnionPay),
+            ("4012888888881881", CreditCardBrand::Visa),
+        ] {
+            assert_eq!(value.parse_credit_card(None), Ok(Some(brand)), "{value}");
+        }
+    }
+
+    #[test]
+    fn invalid_error() {
+        assert_eq!(
+            "4111 1111 1111 111a".validate_credit_card(None),
+            Err(CreditCardError::InvalidCharacter {
+                code: CreditCardErrorCode,
+                character: 'a',
+                #[cfg(feature = "message")]
+                message: "invalid character `a`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "9111111111111111".validate_credit_card(None),
+            Err(CreditCardError::UnknownBrand {
+                code: CreditCardErrorCode,
+                #[cfg(feature = "message")]
+                message: "unknown credit card brand".to_owned(),
+            })
+        );
+        assert_eq!(
+            "3782822463100051".validate_credit_card(None),
+            Err(CreditCardError::InvalidLength {
+                code: CreditCardErrorCode,
+                brand: CreditCardBrand::AmericanExpress,
+                value: 16,
+                #[cfg(feature = "message")]
+                message: "American Express number must have 15 digits".to_owned(),
+            })
+        );
+        assert_eq!(
+            "4111111111111112".validate_credit_card(None),
+            Err(CreditCardError::Checksum {
+                code: CreditCardErrorCode,
+                brand: CreditCardBrand::Visa,
+                #[cfg(feature = "message")]
+                message: "invalid Visa number checksum".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn disallowed_brand_error() {
+        assert_eq!(
+            "4111111111111111".validate_credit_card(Some(vec![CreditCardBrand::Visa])),
+            Ok(())
+        );
+        assert_eq!(
+            "5555555555554444".validate_credit_card(Some(vec![CreditCardBrand::Visa])),
+            Err(CreditCardError::DisallowedBrand {
+                code: CreditCardErrorCode,
+                allowed: vec![CreditCardBrand::Visa],
+                value: CreditCardBrand::Mastercard,
+                #[cfg(feature = "message")]
+                message: "brand `Mastercard` is not allowed, must be one of `Visa`".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(
+    CurrencyCodeErrorCode,
+    CURRENCY_CODE_ERROR_CODE,
+    "currencyCode"
+);
+
+/// ISO 4217 alphabetic currency codes, sorted.
+const CURRENCIES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD",
+    "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP",
+    "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP",
+    "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS",
+    "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW",
+    "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD",
+    "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN",
+    "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR",
+    "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL",
+    "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY",
+    "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES",
+    "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR",
+    "XOF", "XPD", "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG", "ZWL",
+];
+
+/// Currency code validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum CurrencyCodeError {
+    /// Unknown currency code error.
+    Unknown {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CurrencyCodeErrorCode,
+
+        /// The actual value.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed currency error.
+    Disallowed {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: CurrencyCodeErrorCode,
+
+        /// The allowed currency codes.
+        allowed: Vec<String>,
+
+        /// The actual currency code.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for CurrencyCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for CurrencyCodeError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for CurrencyCodeError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("CurrencyCodeError", |_| {
+            [
+                error_type(
+                    CURRENCY_CODE_ERROR_CODE,
+                    Some("unknown"),
+                    &[("value", "string")],
+                ),
+                error_type(
+                    CURRENCY_CODE_ERROR_CODE,
+                    Some("disallowed"),
+                    &[("allowed", "string[]"), ("value", "string")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "CurrencyCodeError".to_owned()
+    }
+}
+
+/// Validate a currency code.
+pub trait ValidateCurrencyCode {
+    /// The currency code.
+    fn currency_code(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate currency code.
+    fn validate_currency_code(&self, allowed: Option<&[&str]>) -> Result<(), CurrencyCodeError> {
+        self.currency_code()
+            .map(|currency_code| validate_currency_code(&currency_code, allowed))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate an uppercase ISO 4217 alphabetic currency code (e.g. `EUR`).
+pub fn validate_currency_code(
+    value: &str,
+    allowed: Option<&[&str]>,
+) -> Result<(), CurrencyCodeError> {
+    if CURRENCIES.binary_search(&value).is_err() {
+        return Err(CurrencyCodeError::Unknown {
+            code: CurrencyCodeErrorCode,
+            value: value.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("unknown currency code `{value}`"),
+        });
+    }
+
+    if let Some(allowed) = allowed
+        && !allowed.contains(&value)
+    {
+        return Err(CurrencyCodeError::Disallowed {
+            code: CurrencyCodeErrorCode,
+            allowed: allowed
+                .iter()
+                .map(|allowed| (*allowed).to_owned())
+                .collect(),
+            value: value.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!(
+                "currency `{value}` is not allowed, must be one of `{}`",
+                allowed.join(", ")
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateCurrencyCode for str {
+    fn currency_code(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCurrencyCode for &str {
+    fn currency_code(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateCurrencyCode for String {
+    fn currency_code(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateCurrencyCode for Cow<'_, str> {
+    fn currency_code(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateCurrencyCode for Option<T>
+where
+    T: ValidateCurrencyCode,
+{
+    fn currency_code(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self {
+            T::currency_code(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateCurrencyCode for $type
+        where
+            T: ValidateCurrencyCode,
+        {
+            fn currency_code(&self) -> Option<Cow<'_, str>> {
+                T::currency_code(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{CURRENCIES, CurrencyCodeError, CurrencyCodeErrorCode, ValidateCurrencyCode};
+
+    #[test]
+    fn currencies_sorted() {
+        assert!(CURRENCIES.is_sorted());
+    }
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"EUR").validate_currency_code(None), Ok(()));
+        assert_eq!("EUR".validate_currency_code(None), Ok(()));
+        assert_eq!("EUR".to_owned().validate_currency_code(None), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("EUR").validate_currency_code(None),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_currency_code(None), Ok(()));
+        assert_eq!(Some("EUR").validate_currency_code(None), Ok(()));
+
+        assert_eq!((&"EUR").validate_currency_code(None), Ok(()));
+        assert_eq!(Box::new("EUR").validate_currency_code(None), Ok(()));
+        assert_eq!(Arc::new("EUR").validate_currency_code(None), Ok(()));
+        assert_eq!(Rc::new("EUR").validate_currency_code(None), Ok(()));
+
+        let cell = RefCell::new("EUR");
+        assert_eq!(cell.borrow().validate_currency_code(None), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_currency_code(None), Ok(()));
+
+        assert_eq!("USD".validate_currency_code(Some(&["EUR", "USD"])), Ok(()));
+    }
+
+    #[test]
+    fn unknown_error() {
+        for value in ["eur", "EURO", "ABC", ""] {
+            assert_eq!(
+                value.validate_currency_code(None),
+                Err(CurrencyCodeError::Unknown {
+                    code: CurrencyCodeErrorCode,
+                    value: value.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("unknown currency code `{value}`"),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn disallowed_error() {
+        assert_eq!(
+            "GBP".validate_currency_code(Some(&["EUR", "USD"])),
+            Err(CurrencyCodeError::Disallowed {
+                code: CurrencyCodeErrorCode,
+                allowed: vec!["EUR".to_owned(), "USD".to_owned()],
+                value: "GBP".to_owned(),
+                #[cfg(feature = "message")]
+                message: "currency `GBP` is not allowed, must be one of `EUR, USD`".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,500 @@
+use std::{
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt::{self, Debug, Display},
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::{DateTime, Months, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc};
+use constant_string::constant_string;
+
+use crate::Clock;
+
+constant_string!(DateErrorCode, DATE_ERROR_CODE, "date");
+
+/// Relative date validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum DateError<T> {
+    /// Value is not in the past.
+    Past {
+        /// The actual value.
+        value: T,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: DateErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value is not in the future.
+    Future {
+        /// The actual value.
+        value: T,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: DateErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value is in the future.
+    PastOrPresent {
+        /// The actual value.
+        value: T,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: DateErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value is less than the minimum age in years ago.
+    MinAge {
+        /// The minimum age in years.
+        min_age: u32,
+
+        /// The actual value.
+        value: T,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: DateErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value is not within the duration from now.
+    Within {
+        /// The maximum duration from now in seconds.
+        within: u64,
+
+        /// The actual value.
+        value: T,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: DateErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl<T: Debug> fmt::Display for DateError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl<T: Debug> Error for DateError<T> {}
+
+#[cfg(feature = "zod")]
+impl<T> crate::zod::ToTypeScript for DateError<T>
+where
+    T: crate::zod::ToTypeScript,
+{
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("DateError<T>", |_| {
+            [
+                error_type(DATE_ERROR_CODE, Some("past"), &[("value", "T")]),
+                error_type(DATE_ERROR_CODE, Some("future"), &[("value", "T")]),
+                error_type(DATE_ERROR_CODE, Some("pastOrPresent"), &[("value", "T")]),
+                error_type(
+                    DATE_ERROR_CODE,
+                    Some("minAge"),
+                    &[("minAge", "number"), ("value", "T")],
+                ),
+                error_type(
+                    DATE_ERROR_CODE,
+                    Some("within"),
+                    &[("within", "number"), ("value", "T")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        format!("DateError<{}>", T::typescript(generator))
+    }
+}
+
+/// Date or date and time value that can be compared to the current time of a [`Clock`].
+pub trait DateValue: Clone + Display + PartialOrd {
+    /// The current time of the clock, in the same representation as this value.
+    ///
+    /// Naive dates and date times use UTC.
+    fn now<C: Clock + ?Sized>(&self, clock: &C) -> Self;
+
+    /// Subtract a number of years, or `None` on overflow.
+    fn checked_sub_years(&self, years: u32) -> Option<Self>;
+
+    /// The signed duration since another value.
+    fn duration_since(&self, other: &Self) -> TimeDelta;
+}
+
+impl DateValue for NaiveDate {
+    fn now<C: Clock + ?Sized>(&self, clock: &C) -> Self {
+        DateTime::<Utc>::from(clock.now()).date_naive()
+    }
+
+    fn checked_sub_years(&self, years: u32) -> Option<Self> {
+        self.checked_sub_months(Months::new(years.checked_mul(12)?))
+    }
+
+    fn duration_since(&self, other: &Self) -> TimeDelta {
+        self.signed_duration_since(*other)
+    }
+}
+
+impl DateValue for NaiveDateTime {
+    fn now<C: Clock + ?Sized>(&self, clock: &C) -> Self {
+        DateTime::<Utc>::from(clock.now()).naive_utc()
+    }
+
+    fn checked_sub_years(&self, years: u32) -> Option<Self> {
+        self.checked_sub_months(Months::new(years.checked_mul(12)?))
+    }
+
+    fn duration_since(&self, other: &Self) -> TimeDelta {
+        self.signed_duration_since(*other)
+    }
+}
+
+impl<Tz> DateValue for DateTime<Tz>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    fn now<C: Clock + ?Sized>(&self, clock: &C) -> Self {
+        DateTime::<Utc>::from(clock.now()).with_timezone(&self.timezone())
+    }
+
+    fn checked_sub_years(&self, years: u32) -> Option<Self> {
+        self.clone()
+            .checked_sub_months(Months::new(years.checked_mul(12)?))
+    }
+
+    fn duration_since(&self, other: &Self) -> TimeDelta {
+        self.clone().signed_duration_since(other.clone())
+    }
+}
+
+/// Validate a date relative to the current time.
+pub trait ValidateDate<T>
+where
+    T: DateValue,
+{
+    /// The date value.
+    fn date_value(&self) -> Option<T>;
+
+    /// Validate the date is in the past.
+    fn validate_past<C: Clock + ?Sized>(&self, clock: &C) -> Result<(), DateError<T>> {
+        let Some(value) = self.date_value() else {
+            return Ok(());
+        };
+
+        if value < value.now(clock) {
+            Ok(())
+        } else {
+            Err(DateError::Past {
+                #[cfg(feature = "message")]
+                message: format!("value {value} is not in the past"),
+                value,
+                code: DateErrorCode,
+            })
+        }
+    }
+
+    /// Validate the date is in the future.
+    fn validate_future<C: Clock + ?Sized>(&self, clock: &C) -> Result<(), DateError<T>> {
+        let Some(value) = self.date_value() else {
+            return Ok(());
+        };
+
+        if value > value.now(clock) {
+            Ok(())
+        } else {
+            Err(DateError::Future {
+                #[cfg(feature = "message")]
+                message: format!("value {value} is not in the future"),
+                value,
+                code: DateErrorCode,
+            })
+        }
+    }
+
+    /// Validate the date is in the past or present.
+    fn validate_past_or_present<C: Clock + ?Sized>(&self, clock: &C) -> Result<(), DateError<T>> {
+        let Some(value) = self.date_value() else {
+            return Ok(());
+        };
+
+        if value <= value.now(clock) {
+            Ok(())
+        } else {
+            Err(DateError::PastOrPresent {
+                #[cfg(feature = "message")]
+                message: format!("value {value} is in the future"),
+                value,
+                code: DateErrorCode,
+            })
+        }
+    }
+
+    /// Validate the date is at least the minimum age in years ago, e.g. for a birth date.
+    fn validate_min_age<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+        min_age: u32,
+    ) -> Result<(), DateError<T>> {
+        let Some(value) = self.date_value() else {
+            return Ok(());
+        };
+
+        if value
+            .now(clock)
+            .checked_sub_years(min_age)
+            .is_some_and(|max| value <= max)
+        {
+            Ok(())
+        } else {
+            Err(DateError::MinAge {
+                min_age,
+                #[cfg(feature = "message")]
+                message: format!("value {value} is less than {min_age} years ago"),
+                value,
+                code: DateErrorCode,
+            })
+        }
+    }
+
+    /// Validate the date is within the duration from now, in the past or the future.
+    fn validate_within<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+        within: Duration,
+    ) -> Result<(), DateError<T>> {
+        let Some(value) = self.date_value() else {
+            return Ok(());
+        };
+
+        let duration = value.duration_since(&value.now(clock)).abs();
+
+        if TimeDelta::from_std(within).is_ok_and(|within| duration <= within) {
+            Ok(())
+        } else {
+            let within = within.as_secs();
+
+            Err(DateError::Within {
+                within,
+                #[cfg(feature = "message")]
+                message: format!("value {value} is not within {within} seconds from now"),
+                value,
+                code: DateErrorCode,
+            })
+        }
+    }
+}
+
+impl ValidateDate<NaiveDate> for NaiveDate {
+    fn date_value(&self) -> Option<NaiveDate> {
+        Some(*self)
+    }
+}
+
+impl ValidateDate<NaiveDateTime> for NaiveDateTime {
+    fn date_value(&self) -> Option<NaiveDateTime> {
+        Some(*self)
+    }
+}
+
+impl<Tz> ValidateDate<DateTime<Tz>> for DateTime<Tz>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    fn date_value(&self) -> Option<DateTime<Tz>> {
+        Some(self.clone())
+    }
+}
+
+impl<D, T> ValidateDate<D> for Option<T>
+where
+    D: DateValue,
+    T: ValidateDate<D>,
+{
+    fn date_value(&self) -> Option<D> {
+        if let Some(value) = self {
+            T::date_value(value)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<D, T> ValidateDate<D> for $type
+        where
+            D: DateValue,
+            T: ValidateDate<D> + ?Sized,
+        {
+            fn date_value(&self) -> Option<D> {
+                T::date_value(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+    use crate::FixedClock;
+
+    use super::{DateError, DateErrorCode, ValidateDate};
+
+    fn clock() -> FixedClock {
+        FixedClock(
+            "2025-06-15T12:00:00Z"
+                .parse::<DateTime<Utc>>()
+                .expect("valid date time")
+                .into(),
+        )
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().expect("valid date")
+    }
+
+    #[test]
+    fn ok() {
+        let clock = clock();
+
+        assert_eq!(date("2025-06-14").validate_past(&clock), Ok(()));
+        assert_eq!(date("2025-06-16").validate_future(&clock), Ok(()));
+        assert_eq!(date("2025-06-15").validate_past_or_present(&clock), Ok(()));
+        assert_eq!(date("2007-06-15").validate_min_age(&clock, 18), Ok(()));
+        assert_eq!(
+            date("2025-07-15").validate_within(&clock, Duration::from_secs(30 * 86_400)),
+            Ok(())
+        );
+        assert_eq!(None::<NaiveDate>.validate_past(&clock), Ok(()));
+        assert_eq!(
+            Arc::new(
+                "2025-06-15T11:59:59"
+                    .parse::<NaiveDateTime>()
+                    .expect("valid date time")
+            )
+            .validate_past(&clock),
+            Ok(())
+        );
+        assert_eq!(
+            "2025-06-15T13:30:00+02:00"
+                .parse::<DateTime<FixedOffset>>()
+                .expect("valid date time")
+                .validate_past(&Arc::new(clock)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn relative_error() {
+        let clock = clock();
+
+        assert_eq!(
+            date("2025-06-15").validate_past(&clock),
+            Err(DateError::Past {
+                value: date("2025-06-15"),
+                code: DateErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 2025-06-15 is not in the past".to_owned(),
+            })
+        );
+        assert_eq!(
+            date("2025-06-15").validate_future(&clock),
+            Err(DateError::Future {
+                value: date("2025-06-15"),
+                code: DateErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 2025-06-15 is not in the future".to_owned(),
+            })
+        );
+        assert_eq!(
+            date("2025-06-16").validate_past_or_present(&clock),
+            Err(DateError::PastOrPresent {
+                value: date("2025-06-16"),
+                code: DateErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 2025-06-16 is in the future".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn min_age_error() {
+        let clock = clock();
+
+        assert_eq!(
+            date("2007-06-16").validate_min_age(&clock, 18),
+            Err(DateError::MinAge {
+                min_age: 18,
+                value: date("2007-06-16"),
+                code: DateErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 2007-06-16 is less than 18 years ago".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn within_error() {
+        let clock = clock();
+
+        assert_eq!(
+            date("2025-05-01").validate_within(&clock, Duration::from_secs(30 * 86_400)),
+            Err(DateError::Within {
+                within: 2_592_000,
+                value: date("2025-05-01"),
+                code: DateErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 2025-05-01 is not within 2592000 seconds from now".to_owned(),
+            })
+        );
+    }
+}
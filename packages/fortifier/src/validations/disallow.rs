@@ -0,0 +1,78 @@
+use std::{error::Error, fmt};
+
+use constant_string::constant_string;
+
+constant_string!(DisallowErrorCode, DISALLOW_ERROR_CODE, "disallow");
+
+/// Disallow validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct DisallowError {
+    /// The error code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "utoipa", schema(inline, required))]
+    code: DisallowErrorCode,
+
+    /// A human-readable error message.
+    #[cfg(feature = "message")]
+    message: String,
+}
+
+impl Default for DisallowError {
+    fn default() -> Self {
+        Self {
+            code: DisallowErrorCode,
+
+            #[cfg(feature = "message")]
+            message: "value is not allowed".to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DisallowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for DisallowError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for DisallowError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        generator.define_type("DisallowError", |_| {
+            crate::zod::error_type(DISALLOW_ERROR_CODE, None, &[])
+        });
+
+        "DisallowError".to_owned()
+    }
+}
+
+/// Validate that a value is not disallowed.
+pub fn validate_disallow(disallowed: bool) -> Result<(), DisallowError> {
+    if disallowed {
+        Err(DisallowError::default())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DisallowError, validate_disallow};
+
+    #[test]
+    fn ok() {
+        assert_eq!(validate_disallow(false), Ok(()));
+    }
+
+    #[test]
+    fn error() {
+        assert_eq!(validate_disallow(true), Err(DisallowError::default()));
+    }
+}
@@ -0,0 +1,270 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(EanErrorCode, EAN_ERROR_CODE, "ean");
+
+/// EAN validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum EanError {
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EanErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid length error.
+    InvalidLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EanErrorCode,
+
+        /// The actual number of digits.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Checksum error.
+    Checksum {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EanErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for EanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for EanError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for EanError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("EanError", |_| {
+            [
+                error_type(
+                    EAN_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(
+                    EAN_ERROR_CODE,
+                    Some("invalidLength"),
+                    &[("value", "number")],
+                ),
+                error_type(EAN_ERROR_CODE, Some("checksum"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "EanError".to_owned()
+    }
+}
+
+/// Validate an EAN.
+pub trait ValidateEan {
+    /// The EAN.
+    fn ean(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate EAN.
+    fn validate_ean(&self) -> Result<(), EanError> {
+        self.ean().map(|ean| validate_ean(&ean)).transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate an EAN-8 or EAN-13.
+pub fn validate_ean(value: &str) -> Result<(), EanError> {
+    let mut digits = Vec::with_capacity(13);
+
+    for character in value.chars() {
+        match character.to_digit(10) {
+            Some(digit) => digits.push(digit),
+            None => {
+                return Err(EanError::InvalidCharacter {
+                    code: EanErrorCode,
+                    character,
+                    #[cfg(feature = "message")]
+                    message: format!("invalid character `{character}`"),
+                });
+            }
+        }
+    }
+
+    if digits.len() != 8 && digits.len() != 13 {
+        return Err(EanError::InvalidLength {
+            code: EanErrorCode,
+            value: digits.len(),
+            #[cfg(feature = "message")]
+            message: "EAN must have 8 or 13 digits".to_owned(),
+        });
+    }
+
+    // Weights alternate 3 and 1, starting from the check digit with weight 1.
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| if index % 2 == 1 { digit * 3 } else { *digit })
+        .sum();
+
+    if !sum.is_multiple_of(10) {
+        return Err(EanError::Checksum {
+            code: EanErrorCode,
+            #[cfg(feature = "message")]
+            message: "invalid EAN checksum".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateEan for str {
+    fn ean(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateEan for &str {
+    fn ean(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateEan for String {
+    fn ean(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateEan for Cow<'_, str> {
+    fn ean(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateEan for Option<T>
+where
+    T: ValidateEan,
+{
+    fn ean(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self { T::ean(s) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateEan for $type
+        where
+            T: ValidateEan,
+        {
+            fn ean(&self) -> Option<Cow<'_, str>> {
+                T::ean(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{EanError, EanErrorCode, ValidateEan};
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"4006381333931").validate_ean(), Ok(()));
+        assert_eq!("96385074".validate_ean(), Ok(()));
+        assert_eq!("4006381333931".to_owned().validate_ean(), Ok(()));
+        assert_eq!(Cow::<str>::Borrowed("4006381333931").validate_ean(), Ok(()));
+
+        assert_eq!(None::<&str>.validate_ean(), Ok(()));
+        assert_eq!(Some("4006381333931").validate_ean(), Ok(()));
+
+        assert_eq!((&"4006381333931").validate_ean(), Ok(()));
+        assert_eq!(Box::new("4006381333931").validate_ean(), Ok(()));
+        assert_eq!(Arc::new("4006381333931").validate_ean(), Ok(()));
+        assert_eq!(Rc::new("4006381333931").validate_ean(), Ok(()));
+
+        let cell = RefCell::new("4006381333931");
+        assert_eq!(cell.borrow().validate_ean(), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_ean(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_error() {
+        assert_eq!(
+            "4006381 333931".validate_ean(),
+            Err(EanError::InvalidCharacter {
+                code: EanErrorCode,
+                character: ' ',
+                #[cfg(feature = "message")]
+                message: "invalid character ` `".to_owned(),
+            })
+        );
+        assert_eq!(
+            "400638133393".validate_ean(),
+            Err(EanError::InvalidLength {
+                code: EanErrorCode,
+                value: 12,
+                #[cfg(feature = "message")]
+                message: "EAN must have 8 or 13 digits".to_owned(),
+            })
+        );
+        assert_eq!(
+            "4006381333932".validate_ean(),
+            Err(EanError::Checksum {
+                code: EanErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid EAN checksum".to_owned(),
+            })
+        );
+    }
+}
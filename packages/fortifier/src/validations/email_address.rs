@@ -1,15 +1,16 @@
 use std::{
     borrow::Cow,
     cell::{Ref, RefMut},
+    collections::{BTreeSet, HashSet},
     error::Error,
-    fmt,
+    fmt, iter,
+    pin::Pin,
     rc::Rc,
     sync::Arc,
 };
 
 use constant_string::constant_string;
-use email_address::EmailAddress;
-pub use email_address::Options as EmailAddressOptions;
+pub use email_address::{EmailAddress, Options as EmailAddressOptions};
 
 constant_string!(
     EmailAddressErrorCode,
@@ -17,6 +18,313 @@ constant_string!(
     "emailAddress"
 );
 
+/// Known disposable email address domains, sorted for binary search.
+const DISPOSABLE_DOMAINS: [&str; 59] = [
+    "10minutemail.com",
+    "10minutemail.net",
+    "20minutemail.com",
+    "33mail.com",
+    "anonbox.net",
+    "burnermail.io",
+    "discard.email",
+    "dispostable.com",
+    "dropmail.me",
+    "emailondeck.com",
+    "fakeinbox.com",
+    "fakemail.net",
+    "getairmail.com",
+    "getnada.com",
+    "guerrillamail.biz",
+    "guerrillamail.com",
+    "guerrillamail.de",
+    "guerrillamail.info",
+    "guerrillamail.net",
+    "guerrillamail.org",
+    "guerrillamailblock.com",
+    "harakirimail.com",
+    "incognitomail.org",
+    "jetable.org",
+    "mailcatch.com",
+    "maildrop.cc",
+    "mailinator.com",
+    "mailinator.net",
+    "mailinator2.com",
+    "mailnesia.com",
+    "mailsac.com",
+    "mailtothis.com",
+    "mintemail.com",
+    "moakt.com",
+    "mohmal.com",
+    "mytemp.email",
+    "nada.email",
+    "sharklasers.com",
+    "spam4.me",
+    "spambox.us",
+    "spamgourmet.com",
+    "temp-mail.io",
+    "temp-mail.org",
+    "tempail.com",
+    "tempinbox.com",
+    "tempmail.dev",
+    "tempmail.net",
+    "tempmailo.com",
+    "tempr.email",
+    "throwawaymail.com",
+    "tmail.ws",
+    "tmpmail.net",
+    "tmpmail.org",
+    "trashmail.com",
+    "trashmail.de",
+    "trashmail.net",
+    "yopmail.com",
+    "yopmail.fr",
+    "yopmail.net",
+];
+
+/// Set of email address domains.
+///
+/// Domains are compared case-insensitively.
+pub trait DomainSet {
+    /// Whether the set contains the domain.
+    fn contains_domain(&self, domain: &str) -> bool;
+}
+
+impl<T> DomainSet for &T
+where
+    T: DomainSet + ?Sized,
+{
+    fn contains_domain(&self, domain: &str) -> bool {
+        T::contains_domain(self, domain)
+    }
+}
+
+macro_rules! domain_set_with_items {
+    ($type:ty) => {
+        domain_set_with_items!($type,);
+    };
+    ($type:ty, $( $generic:ident ),*$( , )*) => {
+        impl<U, $( $generic ),*> DomainSet for $type
+        where
+            U: AsRef<str>,
+        {
+            fn contains_domain(&self, domain: &str) -> bool {
+                self.iter()
+                    .any(|item| item.as_ref().eq_ignore_ascii_case(domain))
+            }
+        }
+    };
+}
+
+domain_set_with_items!([U]);
+domain_set_with_items!(BTreeSet<U>);
+domain_set_with_items!(HashSet<U, S>, S);
+domain_set_with_items!(Vec<U>);
+
+impl<U, const N: usize> DomainSet for [U; N]
+where
+    U: AsRef<str>,
+{
+    fn contains_domain(&self, domain: &str) -> bool {
+        self.as_slice().contains_domain(domain)
+    }
+}
+
+/// Email address policy.
+///
+/// The default policy allows any email address.
+#[derive(Clone, Copy, Default)]
+pub struct EmailAddressPolicy<'a> {
+    /// Allowed domains. Subdomains of allowed domains are not allowed.
+    pub allowed_domains: Option<&'a dyn DomainSet>,
+    /// Denied domains, including their subdomains.
+    pub denied_domains: Option<&'a dyn DomainSet>,
+    /// Deny domains of known disposable email address providers, including their subdomains.
+    pub deny_disposable: bool,
+    /// Deny subaddressing (e.g. `user+tag@example.com`).
+    pub deny_subaddressing: bool,
+    /// Deny domains for which the [`DomainResolver`] can not determine the deliverability (e.g. due
+    /// to a timeout). By default, these domains are allowed.
+    pub deny_unknown_deliverability: bool,
+}
+
+impl fmt::Debug for EmailAddressPolicy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmailAddressPolicy")
+            .field("allowed_domains", &self.allowed_domains.is_some())
+            .field("denied_domains", &self.denied_domains.is_some())
+            .field("deny_disposable", &self.deny_disposable)
+            .field("deny_subaddressing", &self.deny_subaddressing)
+            .field(
+                "deny_unknown_deliverability",
+                &self.deny_unknown_deliverability,
+            )
+            .finish()
+    }
+}
+
+impl EmailAddressPolicy<'_> {
+    fn check(&self, email_address: &EmailAddress) -> Result<(), EmailAddressError> {
+        let code = EmailAddressErrorCode;
+        let domain = email_address.domain();
+
+        if let Some(allowed_domains) = self.allowed_domains
+            && !allowed_domains.contains_domain(domain)
+        {
+            return Err(EmailAddressError::DisallowedDomain {
+                code,
+                value: domain.to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("domain `{domain}` is not allowed"),
+            });
+        }
+
+        if let Some(denied_domains) = self.denied_domains
+            && parent_domains(domain).any(|parent| denied_domains.contains_domain(parent))
+        {
+            return Err(EmailAddressError::DeniedDomain {
+                code,
+                value: domain.to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("domain `{domain}` is denied"),
+            });
+        }
+
+        if self.deny_disposable && is_disposable(domain) {
+            return Err(EmailAddressError::DisposableDomain {
+                code,
+                value: domain.to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("domain `{domain}` is a disposable email address provider"),
+            });
+        }
+
+        if self.deny_subaddressing && email_address.local_part().contains('+') {
+            return Err(EmailAddressError::Subaddressing {
+                code,
+                #[cfg(feature = "message")]
+                message: "subaddressing is not allowed".to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Iterate over the domain and its parent domains, so subdomains are also matched.
+fn parent_domains(domain: &str) -> impl Iterator<Item = &str> {
+    iter::successors(Some(domain), |parent| {
+        parent.split_once('.').map(|(_, rest)| rest)
+    })
+}
+
+fn is_disposable(domain: &str) -> bool {
+    let domain = domain.to_ascii_lowercase();
+
+    parent_domains(&domain).any(|parent| DISPOSABLE_DOMAINS.binary_search(&parent).is_ok())
+}
+
+/// Email address domain deliverability.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Deliverability {
+    /// The domain can receive email.
+    Deliverable,
+    /// The domain can not receive email, e.g. it does not exist or has a null MX record.
+    Undeliverable,
+    /// The deliverability could not be determined, e.g. due to a timeout or a server failure.
+    Unknown,
+}
+
+/// Resolver for email address domain deliverability.
+///
+/// Implement this trait to check domains using DNS (e.g. with the `hickory-resolver` feature) or
+/// an in-memory set of domains in tests.
+pub trait DomainResolver: Send + Sync {
+    /// Whether the domain can receive email, i.e. whether it has MX, A or AAAA records.
+    fn is_deliverable<'a>(
+        &'a self,
+        domain: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Deliverability> + Send + 'a>>;
+}
+
+macro_rules! domain_resolver_with_deref {
+    ($type:ty) => {
+        impl<T> DomainResolver for $type
+        where
+            T: DomainResolver + ?Sized,
+        {
+            fn is_deliverable<'a>(
+                &'a self,
+                domain: &'a str,
+            ) -> Pin<Box<dyn Future<Output = Deliverability> + Send + 'a>> {
+                T::is_deliverable(self, domain)
+            }
+        }
+    };
+}
+
+domain_resolver_with_deref!(&T);
+domain_resolver_with_deref!(Arc<T>);
+domain_resolver_with_deref!(Box<T>);
+
+#[cfg(feature = "hickory-resolver")]
+impl<P> DomainResolver for hickory_resolver::Resolver<P>
+where
+    P: hickory_resolver::name_server::ConnectionProvider,
+{
+    fn is_deliverable<'a>(
+        &'a self,
+        domain: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Deliverability> + Send + 'a>> {
+        Box::pin(async move {
+            // Use a fully qualified name, so the search domains of the system are not appended.
+            let name = format!("{}.", domain.trim_end_matches('.'));
+
+            match self.mx_lookup(name.as_str()).await {
+                Ok(lookup) => {
+                    let mut exchanges = lookup.iter().map(|mx| mx.exchange()).peekable();
+
+                    if exchanges.peek().is_some() {
+                        // A null MX record (RFC 7505) explicitly indicates the domain does not accept email.
+                        return if exchanges.any(|exchange| !exchange.is_root()) {
+                            Deliverability::Deliverable
+                        } else {
+                            Deliverability::Undeliverable
+                        };
+                    }
+                }
+                Err(error) if error.is_nx_domain() => return Deliverability::Undeliverable,
+                Err(error) if !is_negative_response(&error) => return Deliverability::Unknown,
+                // Without MX records, the A and AAAA records are used (RFC 5321).
+                Err(_) => {}
+            }
+
+            match self.lookup_ip(name.as_str()).await {
+                Ok(lookup) if lookup.iter().next().is_some() => Deliverability::Deliverable,
+                Ok(_) => Deliverability::Undeliverable,
+                Err(error) if is_negative_response(&error) => Deliverability::Undeliverable,
+                Err(_) => Deliverability::Unknown,
+            }
+        })
+    }
+}
+
+/// Whether the lookup error is a definitive negative response, i.e. the domain does not exist or
+/// has no records of the type. Other errors (e.g. timeouts or `SERVFAIL`) are temporary.
+#[cfg(feature = "hickory-resolver")]
+fn is_negative_response(error: &hickory_resolver::ResolveError) -> bool {
+    use hickory_resolver::proto::{ProtoErrorKind, op::ResponseCode};
+
+    error.proto().is_some_and(|error| {
+        matches!(
+            error.kind(),
+            ProtoErrorKind::NoRecordsFound {
+                response_code: ResponseCode::NXDomain | ResponseCode::NoError,
+                ..
+            }
+        )
+    })
+}
+
 /// Email validation error.
 #[derive(Debug, Eq, PartialEq)]
 #[cfg_attr(
@@ -213,6 +521,93 @@ pub enum EmailAddressError {
         #[cfg_attr(feature = "utoipa", schema(inline, required))]
         code: EmailAddressErrorCode,
 
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed domain error.
+    ///
+    /// The domain is not in the allowed domains.
+    DisallowedDomain {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EmailAddressErrorCode,
+
+        /// The actual domain.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Denied domain error.
+    DeniedDomain {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EmailAddressErrorCode,
+
+        /// The actual domain.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disposable domain error.
+    DisposableDomain {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EmailAddressErrorCode,
+
+        /// The actual domain.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Subaddressing error.
+    Subaddressing {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EmailAddressErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Undeliverable domain error.
+    ///
+    /// The domain has no MX, A or AAAA records.
+    UndeliverableDomain {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EmailAddressErrorCode,
+
+        /// The actual domain.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unknown deliverability error.
+    ///
+    /// The deliverability of the domain could not be determined, e.g. due to a timeout.
+    UnknownDeliverability {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: EmailAddressErrorCode,
+
+        /// The actual domain.
+        value: String,
+
         /// A human-readable error message.
         #[cfg(feature = "message")]
         message: String,
@@ -227,6 +622,76 @@ impl fmt::Display for EmailAddressError {
 
 impl Error for EmailAddressError {}
 
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for EmailAddressError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("EmailAddressError", |_| {
+            [
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("invalidCharacter"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("missingSeparator"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("localPartEmpty"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("localPartTooLong"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("domainEmpty"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("domainTooLong"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("subDomainEmpty"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("subDomainTooLong"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("domainTooFew"), &[]),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("domainInvalidSeparator"),
+                    &[],
+                ),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("unbalancedQuotes"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("invalidComment"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("invalidIPAddress"), &[]),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("unsupportedDomainLiteral"),
+                    &[],
+                ),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("unsupportedDisplayName"),
+                    &[],
+                ),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("missingDisplayName"), &[]),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("missingEndBracket"), &[]),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("disallowedDomain"),
+                    &[("value", "string")],
+                ),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("deniedDomain"),
+                    &[("value", "string")],
+                ),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("disposableDomain"),
+                    &[("value", "string")],
+                ),
+                error_type(EMAIL_ADDRESS_ERROR_CODE, Some("subaddressing"), &[]),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("undeliverableDomain"),
+                    &[("value", "string")],
+                ),
+                error_type(
+                    EMAIL_ADDRESS_ERROR_CODE,
+                    Some("unknownDeliverability"),
+                    &[("value", "string")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "EmailAddressError".to_owned()
+    }
+}
+
 impl From<email_address::Error> for EmailAddressError {
     fn from(value: email_address::Error) -> Self {
         let code = EmailAddressErrorCode;
@@ -347,16 +812,90 @@ pub trait ValidateEmailAddress {
     fn validate_email_address(
         &self,
         options: EmailAddressOptions,
+        policy: EmailAddressPolicy<'_>,
     ) -> Result<(), EmailAddressError> {
-        let Some(email_address) = self.email_address() else {
-            return Ok(());
-        };
-
-        EmailAddress::parse_with_options(&email_address, options)
-            .map_err(EmailAddressError::from)?;
+        self.parse_email_address(options, policy)?;
 
         Ok(())
     }
+
+    /// Validate email address and return the parsed email address.
+    fn parse_email_address(
+        &self,
+        options: EmailAddressOptions,
+        policy: EmailAddressPolicy<'_>,
+    ) -> Result<Option<EmailAddress>, EmailAddressError> {
+        self.email_address()
+            .map(|email_address| parse_email_address(&email_address, options, policy))
+            .transpose()
+    }
+
+    /// Validate email address domain is deliverable.
+    ///
+    /// Invalid email addresses and email addresses rejected by the policy are skipped without a
+    /// lookup, as they are reported by [`ValidateEmailAddress::validate_email_address`].
+    fn validate_email_address_deliverability<'a>(
+        &self,
+        options: EmailAddressOptions,
+        policy: EmailAddressPolicy<'_>,
+        resolver: &'a dyn DomainResolver,
+    ) -> Pin<Box<dyn Future<Output = Result<(), EmailAddressError>> + Send + 'a>> {
+        let domain = self.email_address().and_then(|email_address| {
+            parse_email_address(&email_address, options, policy)
+                .ok()
+                .map(|email_address| email_address.domain().to_owned())
+        });
+        let deny_unknown = policy.deny_unknown_deliverability;
+
+        Box::pin(async move {
+            match domain {
+                Some(domain) => {
+                    validate_email_address_deliverability(&domain, resolver, deny_unknown).await
+                }
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+/// Validate an email address domain is deliverable.
+///
+/// If the deliverability can not be determined, the domain is allowed unless `deny_unknown` is set.
+pub async fn validate_email_address_deliverability(
+    domain: &str,
+    resolver: &dyn DomainResolver,
+    deny_unknown: bool,
+) -> Result<(), EmailAddressError> {
+    match resolver.is_deliverable(domain).await {
+        Deliverability::Deliverable => Ok(()),
+        Deliverability::Undeliverable => Err(EmailAddressError::UndeliverableDomain {
+            code: EmailAddressErrorCode,
+            value: domain.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("domain `{domain}` can not receive email"),
+        }),
+        Deliverability::Unknown if deny_unknown => Err(EmailAddressError::UnknownDeliverability {
+            code: EmailAddressErrorCode,
+            value: domain.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("deliverability of domain `{domain}` could not be determined"),
+        }),
+        Deliverability::Unknown => Ok(()),
+    }
+}
+
+/// Parse an email address.
+pub fn parse_email_address(
+    value: &str,
+    options: EmailAddressOptions,
+    policy: EmailAddressPolicy<'_>,
+) -> Result<EmailAddress, EmailAddressError> {
+    let email_address =
+        EmailAddress::parse_with_options(value, options).map_err(EmailAddressError::from)?;
+
+    policy.check(&email_address)?;
+
+    Ok(email_address)
 }
 
 impl ValidateEmailAddress for str {
@@ -424,58 +963,107 @@ validate_with_deref!(RefMut<'_, T>);
 
 #[cfg(test)]
 mod tests {
-    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+    use std::{borrow::Cow, cell::RefCell, pin::Pin, rc::Rc, sync::Arc};
 
     use email_address::EmailAddress;
 
-    use super::{EmailAddressError, EmailAddressOptions, ValidateEmailAddress};
+    use super::{
+        DISPOSABLE_DOMAINS, Deliverability, DomainResolver, EmailAddressError,
+        EmailAddressErrorCode, EmailAddressOptions, EmailAddressPolicy, ValidateEmailAddress,
+        parse_email_address,
+    };
+
+    struct Resolver;
+
+    impl DomainResolver for Resolver {
+        fn is_deliverable<'a>(
+            &'a self,
+            domain: &'a str,
+        ) -> Pin<Box<dyn Future<Output = Deliverability> + Send + 'a>> {
+            Box::pin(async move {
+                match domain {
+                    "example.com" => Deliverability::Deliverable,
+                    "example.net" => Deliverability::Unknown,
+                    _ => Deliverability::Undeliverable,
+                }
+            })
+        }
+    }
 
     #[test]
     fn ok() {
         let options = EmailAddressOptions::default().without_display_text();
 
-        assert_eq!((*"admin@localhost").validate_email_address(options), Ok(()));
-        assert_eq!("admin@localhost".validate_email_address(options), Ok(()));
         assert_eq!(
-            "admin@localhost".to_owned().validate_email_address(options),
+            (*"admin@localhost").validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
         assert_eq!(
-            Cow::<str>::Borrowed("admin@localhost").validate_email_address(options),
+            "admin@localhost".validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
         assert_eq!(
-            Cow::<str>::Owned("admin@localhost".to_owned()).validate_email_address(options),
+            "admin@localhost"
+                .to_owned()
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
         assert_eq!(
-            EmailAddress::new_unchecked("admin@localhost").validate_email_address(options),
+            Cow::<str>::Borrowed("admin@localhost")
+                .validate_email_address(options, EmailAddressPolicy::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Cow::<str>::Owned("admin@localhost".to_owned())
+                .validate_email_address(options, EmailAddressPolicy::default()),
+            Ok(())
+        );
+        assert_eq!(
+            EmailAddress::new_unchecked("admin@localhost")
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
 
-        assert_eq!(None::<&str>.validate_email_address(options), Ok(()));
         assert_eq!(
-            Some("admin@localhost").validate_email_address(options),
+            None::<&str>.validate_email_address(options, EmailAddressPolicy::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Some("admin@localhost").validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
 
-        assert_eq!((&"admin@localhost").validate_email_address(options), Ok(()));
         assert_eq!(
-            Box::new("admin@localhost").validate_email_address(options),
+            (&"admin@localhost").validate_email_address(options, EmailAddressPolicy::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Box::new("admin@localhost")
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
         assert_eq!(
-            Arc::new("admin@localhost").validate_email_address(options),
+            Arc::new("admin@localhost")
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
         assert_eq!(
-            Rc::new("admin@localhost").validate_email_address(options),
+            Rc::new("admin@localhost")
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Ok(())
         );
 
         let cell = RefCell::new("admin@localhost");
-        assert_eq!(cell.borrow().validate_email_address(options), Ok(()));
-        assert_eq!(cell.borrow_mut().validate_email_address(options), Ok(()));
+        assert_eq!(
+            cell.borrow()
+                .validate_email_address(options, EmailAddressPolicy::default()),
+            Ok(())
+        );
+        assert_eq!(
+            cell.borrow_mut()
+                .validate_email_address(options, EmailAddressPolicy::default()),
+            Ok(())
+        );
     }
 
     #[test]
@@ -483,69 +1071,74 @@ mod tests {
         let options = EmailAddressOptions::default().without_display_text();
 
         assert_eq!(
-            (*"admin").validate_email_address(options),
+            (*"admin").validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            "admin".validate_email_address(options),
+            "admin".validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            "admin".to_owned().validate_email_address(options),
+            "admin"
+                .to_owned()
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            Cow::<str>::Borrowed("admin").validate_email_address(options),
+            Cow::<str>::Borrowed("admin")
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            Cow::<str>::Owned("admin".to_owned()).validate_email_address(options),
+            Cow::<str>::Owned("admin".to_owned())
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            EmailAddress::new_unchecked("admin").validate_email_address(options),
+            EmailAddress::new_unchecked("admin")
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
 
         assert_eq!(
-            Some("admin").validate_email_address(options),
+            Some("admin").validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
 
         assert_eq!(
-            (&"admin").validate_email_address(options),
+            (&"admin").validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            Box::new("admin").validate_email_address(options),
+            Box::new("admin").validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            Arc::new("admin").validate_email_address(options),
+            Arc::new("admin").validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            Rc::new("admin").validate_email_address(options),
+            Rc::new("admin").validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
@@ -553,16 +1146,271 @@ mod tests {
 
         let cell = RefCell::new("admin");
         assert_eq!(
-            cell.borrow().validate_email_address(options),
+            cell.borrow()
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
         assert_eq!(
-            cell.borrow_mut().validate_email_address(options),
+            cell.borrow_mut()
+                .validate_email_address(options, EmailAddressPolicy::default()),
             Err(EmailAddressError::from(
                 email_address::Error::MissingSeparator
             ))
         );
     }
+
+    #[test]
+    fn parse() {
+        let options = EmailAddressOptions::default();
+
+        assert_eq!(
+            parse_email_address("admin@localhost", options, EmailAddressPolicy::default()),
+            Ok(EmailAddress::new_unchecked("admin@localhost"))
+        );
+        assert_eq!(
+            "admin@localhost".parse_email_address(options, EmailAddressPolicy::default()),
+            Ok(Some(EmailAddress::new_unchecked("admin@localhost")))
+        );
+        assert_eq!(
+            None::<&str>.parse_email_address(options, EmailAddressPolicy::default()),
+            Ok(None)
+        );
+        assert_eq!(
+            "invalid".parse_email_address(options, EmailAddressPolicy::default()),
+            Err(EmailAddressError::from(
+                email_address::Error::MissingSeparator
+            ))
+        );
+    }
+
+    #[test]
+    fn disposable_domains_sorted() {
+        assert!(DISPOSABLE_DOMAINS.is_sorted());
+    }
+
+    #[test]
+    fn policy_error() {
+        let options = EmailAddressOptions::default();
+
+        let allowed_domains = vec!["example.com".to_owned()];
+        let policy = EmailAddressPolicy {
+            allowed_domains: Some(&allowed_domains),
+            ..Default::default()
+        };
+        assert_eq!(
+            "admin@EXAMPLE.com".validate_email_address(options, policy),
+            Ok(())
+        );
+        assert_eq!(
+            "admin@example.org".validate_email_address(options, policy),
+            Err(EmailAddressError::DisallowedDomain {
+                code: EmailAddressErrorCode,
+                value: "example.org".to_owned(),
+                #[cfg(feature = "message")]
+                message: "domain `example.org` is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            "admin@mail.example.com".validate_email_address(options, policy),
+            Err(EmailAddressError::DisallowedDomain {
+                code: EmailAddressErrorCode,
+                value: "mail.example.com".to_owned(),
+                #[cfg(feature = "message")]
+                message: "domain `mail.example.com` is not allowed".to_owned(),
+            })
+        );
+
+        let policy = EmailAddressPolicy {
+            denied_domains: Some(&["example.org"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            "admin@example.com".validate_email_address(options, policy),
+            Ok(())
+        );
+        assert_eq!(
+            "admin@notexample.org".validate_email_address(options, policy),
+            Ok(())
+        );
+        for domain in ["example.org", "EXAMPLE.org", "mail.example.org"] {
+            assert_eq!(
+                format!("admin@{domain}").validate_email_address(options, policy),
+                Err(EmailAddressError::DeniedDomain {
+                    code: EmailAddressErrorCode,
+                    value: domain.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("domain `{domain}` is denied"),
+                }),
+                "{domain}"
+            );
+        }
+
+        let policy = EmailAddressPolicy {
+            deny_disposable: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "admin@example.com".validate_email_address(options, policy),
+            Ok(())
+        );
+        for domain in ["mailinator.com", "YOPMAIL.com", "inbox.guerrillamail.com"] {
+            assert_eq!(
+                format!("admin@{domain}").validate_email_address(options, policy),
+                Err(EmailAddressError::DisposableDomain {
+                    code: EmailAddressErrorCode,
+                    value: domain.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("domain `{domain}` is a disposable email address provider"),
+                }),
+                "{domain}"
+            );
+        }
+
+        let policy = EmailAddressPolicy {
+            deny_subaddressing: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "admin@example.com".validate_email_address(options, policy),
+            Ok(())
+        );
+        assert_eq!(
+            "admin+newsletter@example.com".validate_email_address(options, policy),
+            Err(EmailAddressError::Subaddressing {
+                code: EmailAddressErrorCode,
+                #[cfg(feature = "message")]
+                message: "subaddressing is not allowed".to_owned(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn undeliverable_domain_error() {
+        let options = EmailAddressOptions::default();
+        let policy = EmailAddressPolicy::default();
+        let resolver = Arc::new(Resolver);
+
+        assert_eq!(
+            "admin@example.com"
+                .validate_email_address_deliverability(options, policy, &resolver)
+                .await,
+            Ok(())
+        );
+        assert_eq!(
+            None::<&str>
+                .validate_email_address_deliverability(options, policy, &resolver)
+                .await,
+            Ok(())
+        );
+        assert_eq!(
+            "invalid"
+                .validate_email_address_deliverability(options, policy, &resolver)
+                .await,
+            Ok(())
+        );
+        assert_eq!(
+            "admin@gmial.com"
+                .validate_email_address_deliverability(options, policy, &resolver)
+                .await,
+            Err(EmailAddressError::UndeliverableDomain {
+                code: EmailAddressErrorCode,
+                value: "gmial.com".to_owned(),
+                #[cfg(feature = "message")]
+                message: "domain `gmial.com` can not receive email".to_owned(),
+            })
+        );
+
+        let policy = EmailAddressPolicy {
+            denied_domains: Some(&["gmial.com"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            "admin@gmial.com"
+                .validate_email_address_deliverability(options, policy, &resolver)
+                .await,
+            Ok(())
+        );
+    }
+
+    #[cfg(feature = "hickory-resolver")]
+    #[test]
+    fn negative_response() {
+        use hickory_resolver::{
+            ResolveError,
+            proto::{ProtoError, ProtoErrorKind, op::ResponseCode},
+        };
+
+        use super::is_negative_response;
+
+        fn no_records_found(response_code: ResponseCode) -> ResolveError {
+            ProtoError::from(ProtoErrorKind::NoRecordsFound {
+                query: Box::default(),
+                soa: None,
+                ns: None,
+                negative_ttl: None,
+                response_code,
+                trusted: true,
+                authorities: None,
+            })
+            .into()
+        }
+
+        assert!(is_negative_response(&no_records_found(
+            ResponseCode::NXDomain
+        )));
+        assert!(is_negative_response(&no_records_found(
+            ResponseCode::NoError
+        )));
+        assert!(!is_negative_response(&no_records_found(
+            ResponseCode::ServFail
+        )));
+        assert!(!is_negative_response(&no_records_found(
+            ResponseCode::Refused
+        )));
+        assert!(!is_negative_response(
+            &ProtoError::from(ProtoErrorKind::Timeout).into()
+        ));
+    }
+
+    #[tokio::test]
+    async fn unknown_deliverability_error() {
+        let options = EmailAddressOptions::default();
+        let resolver = Arc::new(Resolver);
+
+        assert_eq!(
+            "admin@example.net"
+                .validate_email_address_deliverability(
+                    options,
+                    EmailAddressPolicy::default(),
+                    &resolver
+                )
+                .await,
+            Ok(())
+        );
+
+        let policy = EmailAddressPolicy {
+            deny_unknown_deliverability: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "admin@example.com"
+                .validate_email_address_deliverability(options, policy, &resolver)
+                .await,
+            Ok(())
+        );
+        assert_eq!(
+            "admin@example.net"
+                .validate_email_address_deliverability(options, policy, &resolver)
+                .await,
+            Err(EmailAddressError::UnknownDeliverability {
+                code: EmailAddressErrorCode,
+                value: "example.net".to_owned(),
+                #[cfg(feature = "message")]
+                message: "deliverability of domain `example.net` could not be determined"
+                    .to_owned(),
+            })
+        );
+    }
 }
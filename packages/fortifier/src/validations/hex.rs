@@ -0,0 +1,400 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(HexErrorCode, HEX_ERROR_CODE, "hex");
+
+/// Hexadecimal validation options.
+///
+/// The default options allow any non-empty sequence of hexadecimal digits.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct HexOptions<'a> {
+    /// Required prefix (e.g. `#` or `0x`), compared case-insensitively.
+    pub prefix: Option<&'a str>,
+    /// Allowed numbers of digits, excluding the prefix (e.g. `[3, 6]` for CSS colors).
+    pub lengths: Option<&'a [usize]>,
+    /// Require an even number of digits, so the value represents whole bytes.
+    pub even_length: bool,
+}
+
+/// Hexadecimal validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum HexError {
+    /// Missing prefix error.
+    MissingPrefix {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HexErrorCode,
+
+        /// The required prefix.
+        prefix: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Empty value error.
+    Empty {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HexErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HexErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed number of digits error.
+    DisallowedLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HexErrorCode,
+
+        /// The allowed numbers of digits.
+        allowed: Vec<usize>,
+
+        /// The actual number of digits.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Odd number of digits error.
+    OddLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HexErrorCode,
+
+        /// The actual number of digits.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for HexError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for HexError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("HexError", |_| {
+            [
+                error_type(
+                    HEX_ERROR_CODE,
+                    Some("missingPrefix"),
+                    &[("prefix", "string")],
+                ),
+                error_type(HEX_ERROR_CODE, Some("empty"), &[]),
+                error_type(
+                    HEX_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(
+                    HEX_ERROR_CODE,
+                    Some("disallowedLength"),
+                    &[("allowed", "number[]"), ("value", "number")],
+                ),
+                error_type(HEX_ERROR_CODE, Some("oddLength"), &[("value", "number")]),
+            ]
+            .join(" | ")
+        });
+
+        "HexError".to_owned()
+    }
+}
+
+/// Validate a hexadecimal string.
+pub trait ValidateHex {
+    /// The hexadecimal string.
+    fn hex(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate hexadecimal string.
+    fn validate_hex(&self, options: HexOptions<'_>) -> Result<(), HexError> {
+        self.hex()
+            .map(|hex| validate_hex(&hex, options))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate a hexadecimal string (e.g. `#ff8800` or `0x1f`).
+pub fn validate_hex(value: &str, options: HexOptions<'_>) -> Result<(), HexError> {
+    let digits = match options.prefix {
+        Some(prefix) => match value.get(..prefix.len()) {
+            Some(start) if start.eq_ignore_ascii_case(prefix) => &value[prefix.len()..],
+            _ => {
+                return Err(HexError::MissingPrefix {
+                    code: HexErrorCode,
+                    prefix: prefix.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("value must start with `{prefix}`"),
+                });
+            }
+        },
+        None => value,
+    };
+
+    if digits.is_empty() {
+        return Err(HexError::Empty {
+            code: HexErrorCode,
+            #[cfg(feature = "message")]
+            message: "value must contain hexadecimal digits".to_owned(),
+        });
+    }
+
+    if let Some(character) = digits
+        .chars()
+        .find(|character| !character.is_ascii_hexdigit())
+    {
+        return Err(HexError::InvalidCharacter {
+            code: HexErrorCode,
+            character,
+            #[cfg(feature = "message")]
+            message: format!("invalid character `{character}`"),
+        });
+    }
+
+    // All characters are ASCII hexadecimal digits, so the number of bytes is the number of digits.
+    let length = digits.len();
+
+    if let Some(lengths) = options.lengths
+        && !lengths.contains(&length)
+    {
+        return Err(HexError::DisallowedLength {
+            code: HexErrorCode,
+            allowed: lengths.to_vec(),
+            value: length,
+            #[cfg(feature = "message")]
+            message: format!("{length} hexadecimal digits are not allowed"),
+        });
+    }
+
+    if options.even_length && !length.is_multiple_of(2) {
+        return Err(HexError::OddLength {
+            code: HexErrorCode,
+            value: length,
+            #[cfg(feature = "message")]
+            message: "value must have an even number of hexadecimal digits".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateHex for str {
+    fn hex(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateHex for &str {
+    fn hex(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateHex for String {
+    fn hex(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateHex for Cow<'_, str> {
+    fn hex(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateHex for Option<T>
+where
+    T: ValidateHex,
+{
+    fn hex(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self { T::hex(s) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateHex for $type
+        where
+            T: ValidateHex,
+        {
+            fn hex(&self) -> Option<Cow<'_, str>> {
+                T::hex(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{HexError, HexErrorCode, HexOptions, ValidateHex};
+
+    const COLOR: HexOptions = HexOptions {
+        prefix: Some("#"),
+        lengths: Some(&[3, 6]),
+        even_length: false,
+    };
+
+    #[test]
+    fn ok() {
+        let options = HexOptions::default();
+
+        assert_eq!((*"deadBEEF").validate_hex(options), Ok(()));
+        assert_eq!("deadBEEF".validate_hex(options), Ok(()));
+        assert_eq!("deadBEEF".to_owned().validate_hex(options), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("deadBEEF").validate_hex(options),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_hex(options), Ok(()));
+        assert_eq!(Some("deadBEEF").validate_hex(options), Ok(()));
+
+        assert_eq!((&"deadBEEF").validate_hex(options), Ok(()));
+        assert_eq!(Box::new("deadBEEF").validate_hex(options), Ok(()));
+        assert_eq!(Arc::new("deadBEEF").validate_hex(options), Ok(()));
+        assert_eq!(Rc::new("deadBEEF").validate_hex(options), Ok(()));
+
+        let cell = RefCell::new("deadBEEF");
+        assert_eq!(cell.borrow().validate_hex(options), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_hex(options), Ok(()));
+
+        assert_eq!("#f80".validate_hex(COLOR), Ok(()));
+        assert_eq!("#ff8800".validate_hex(COLOR), Ok(()));
+        assert_eq!(
+            "0X1f".validate_hex(HexOptions {
+                prefix: Some("0x"),
+                even_length: true,
+                ..Default::default()
+            }),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn missing_prefix_error() {
+        assert_eq!(
+            "ff8800".validate_hex(COLOR),
+            Err(HexError::MissingPrefix {
+                code: HexErrorCode,
+                prefix: "#".to_owned(),
+                #[cfg(feature = "message")]
+                message: "value must start with `#`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn empty_error() {
+        assert_eq!(
+            "#".validate_hex(COLOR),
+            Err(HexError::Empty {
+                code: HexErrorCode,
+                #[cfg(feature = "message")]
+                message: "value must contain hexadecimal digits".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_character_error() {
+        assert_eq!(
+            "#ff880g".validate_hex(COLOR),
+            Err(HexError::InvalidCharacter {
+                code: HexErrorCode,
+                character: 'g',
+                #[cfg(feature = "message")]
+                message: "invalid character `g`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn disallowed_length_error() {
+        assert_eq!(
+            "#ff88".validate_hex(COLOR),
+            Err(HexError::DisallowedLength {
+                code: HexErrorCode,
+                allowed: vec![3, 6],
+                value: 4,
+                #[cfg(feature = "message")]
+                message: "4 hexadecimal digits are not allowed".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn odd_length_error() {
+        assert_eq!(
+            "abc".validate_hex(HexOptions {
+                even_length: true,
+                ..Default::default()
+            }),
+            Err(HexError::OddLength {
+                code: HexErrorCode,
+                value: 3,
+                #[cfg(feature = "message")]
+                message: "value must have an even number of hexadecimal digits".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(HostnameErrorCode, HOSTNAME_ERROR_CODE, "hostname");
+
+/// Maximum length of a hostname, excluding the trailing dot.
+const MAX_LENGTH: usize = 253;
+
+/// Maximum length of a hostname label.
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Hostname validation options.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct HostnameOptions {
+    /// Allow internationalized domain names (e.g. `bücher.example`).
+    ///
+    /// Non-ASCII hostnames are converted to their ASCII (Punycode) form before the label rules are checked.
+    pub allow_idn: bool,
+}
+
+/// Hostname validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum HostnameError {
+    /// Empty hostname error.
+    Empty {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HostnameErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Hostname too long error.
+    TooLong {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HostnameErrorCode,
+
+        /// The maximum length.
+        max: usize,
+
+        /// The actual length.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Empty label error.
+    EmptyLabel {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HostnameErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Label too long error.
+    LabelTooLong {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HostnameErrorCode,
+
+        /// The maximum length.
+        max: usize,
+
+        /// The actual length.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HostnameErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid hyphen error.
+    ///
+    /// A label can not start or end with a hyphen.
+    InvalidHyphen {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HostnameErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid internationalized domain name error.
+    InvalidIdn {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: HostnameErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for HostnameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for HostnameError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for HostnameError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("HostnameError", |_| {
+            [
+                error_type(HOSTNAME_ERROR_CODE, Some("empty"), &[]),
+                error_type(
+                    HOSTNAME_ERROR_CODE,
+                    Some("tooLong"),
+                    &[("max", "number"), ("value", "number")],
+                ),
+                error_type(HOSTNAME_ERROR_CODE, Some("emptyLabel"), &[]),
+                error_type(
+                    HOSTNAME_ERROR_CODE,
+                    Some("labelTooLong"),
+                    &[("max", "number"), ("value", "number")],
+                ),
+                error_type(
+                    HOSTNAME_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(HOSTNAME_ERROR_CODE, Some("invalidHyphen"), &[]),
+                error_type(HOSTNAME_ERROR_CODE, Some("invalidIdn"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "HostnameError".to_owned()
+    }
+}
+
+/// Validate a hostname.
+pub trait ValidateHostname {
+    /// The hostname.
+    fn hostname(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate hostname.
+    fn validate_hostname(&self, options: HostnameOptions) -> Result<(), HostnameError> {
+        self.hostname()
+            .map(|hostname| validate_hostname(&hostname, options))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate a hostname according to the RFC 1123 label rules.
+///
+/// A single trailing dot (fully qualified domain name) is allowed.
+pub fn validate_hostname(value: &str, options: HostnameOptions) -> Result<(), HostnameError> {
+    let value = if options.allow_idn && !value.is_ascii() {
+        Cow::Owned(
+            idna::domain_to_ascii_strict(value).map_err(|_| HostnameError::InvalidIdn {
+                code: HostnameErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid internationalized domain name".to_owned(),
+            })?,
+        )
+    } else {
+        Cow::Borrowed(value)
+    };
+
+    let hostname = value.strip_suffix('.').unwrap_or(&value);
+
+    if hostname.is_empty() {
+        return Err(HostnameError::Empty {
+            code: HostnameErrorCode,
+            #[cfg(feature = "message")]
+            message: "hostname is empty".to_owned(),
+        });
+    }
+
+    if hostname.len() > MAX_LENGTH {
+        return Err(HostnameError::TooLong {
+            code: HostnameErrorCode,
+            max: MAX_LENGTH,
+            value: hostname.len(),
+            #[cfg(feature = "message")]
+            message: format!("hostname must be at most {MAX_LENGTH} characters"),
+        });
+    }
+
+    for label in hostname.split('.') {
+        if label.is_empty() {
+            return Err(HostnameError::EmptyLabel {
+                code: HostnameErrorCode,
+                #[cfg(feature = "message")]
+                message: "hostname contains an empty label".to_owned(),
+            });
+        }
+
+        if let Some(character) = label
+            .chars()
+            .find(|character| !character.is_ascii_alphanumeric() && *character != '-')
+        {
+            return Err(HostnameError::InvalidCharacter {
+                code: HostnameErrorCode,
+                character,
+                #[cfg(feature = "message")]
+                message: format!("invalid character `{character}`"),
+            });
+        }
+
+        if label.len() > MAX_LABEL_LENGTH {
+            return Err(HostnameError::LabelTooLong {
+                code: HostnameErrorCode,
+                max: MAX_LABEL_LENGTH,
+                value: label.len(),
+                #[cfg(feature = "message")]
+                message: format!("label must be at most {MAX_LABEL_LENGTH} characters"),
+            });
+        }
+
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(HostnameError::InvalidHyphen {
+                code: HostnameErrorCode,
+                #[cfg(feature = "message")]
+                message: "label can not start or end with a hyphen".to_owned(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl ValidateHostname for str {
+    fn hostname(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateHostname for &str {
+    fn hostname(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateHostname for String {
+    fn hostname(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateHostname for Cow<'_, str> {
+    fn hostname(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateHostname for Option<T>
+where
+    T: ValidateHostname,
+{
+    fn hostname(&self) -> Option<Cow<'_, str>> {
+        if let Some(hostname) = self {
+            T::hostname(hostname)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateHostname for $type
+        where
+            T: ValidateHostname,
+        {
+            fn hostname(&self) -> Option<Cow<'_, str>> {
+                T::hostname(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{HostnameError, HostnameErrorCode, HostnameOptions, ValidateHostname};
+
+    const IDN: HostnameOptions = HostnameOptions { allow_idn: true };
+
+    #[test]
+    fn ok() {
+        let options = HostnameOptions::default();
+
+        assert_eq!((*"localhost").validate_hostname(options), Ok(()));
+        assert_eq!("example.com".validate_hostname(options), Ok(()));
+        assert_eq!("example.com.".to_owned().validate_hostname(options), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("1password.example").validate_hostname(options),
+            Ok(())
+        );
+        assert_eq!("xn--bcher-kva.example".validate_hostname(options), Ok(()));
+        assert_eq!(
+            format!("{}.com", "a".repeat(63)).validate_hostname(options),
+            Ok(())
+        );
+
+        assert_eq!("bücher.example".validate_hostname(IDN), Ok(()));
+        assert_eq!("例え.テスト".validate_hostname(IDN), Ok(()));
+
+        assert_eq!(None::<&str>.validate_hostname(options), Ok(()));
+        assert_eq!(Some("example.com").validate_hostname(options), Ok(()));
+
+        assert_eq!((&"example.com").validate_hostname(options), Ok(()));
+        assert_eq!(Box::new("example.com").validate_hostname(options), Ok(()));
+        assert_eq!(Arc::new("example.com").validate_hostname(options), Ok(()));
+        assert_eq!(Rc::new("example.com").validate_hostname(options), Ok(()));
+
+        let cell = RefCell::new("example.com");
+        assert_eq!(cell.borrow().validate_hostname(options), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_hostname(options), Ok(()));
+    }
+
+    #[test]
+    fn invalid_error() {
+        let options = HostnameOptions::default();
+
+        for value in ["", "."] {
+            assert_eq!(
+                value.validate_hostname(options),
+                Err(HostnameError::Empty {
+                    code: HostnameErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "hostname is empty".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+        assert_eq!(
+            ["a".repeat(63).as_str(); 4]
+                .join(".")
+                .validate_hostname(options),
+            Err(HostnameError::TooLong {
+                code: HostnameErrorCode,
+                max: 253,
+                value: 255,
+                #[cfg(feature = "message")]
+                message: "hostname must be at most 253 characters".to_owned(),
+            })
+        );
+        for value in ["example..com", ".example.com", "example.com.."] {
+            assert_eq!(
+                value.validate_hostname(options),
+                Err(HostnameError::EmptyLabel {
+                    code: HostnameErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "hostname contains an empty label".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+        assert_eq!(
+            format!("{}.com", "a".repeat(64)).validate_hostname(options),
+            Err(HostnameError::LabelTooLong {
+                code: HostnameErrorCode,
+                max: 63,
+                value: 64,
+                #[cfg(feature = "message")]
+                message: "label must be at most 63 characters".to_owned(),
+            })
+        );
+        assert_eq!(
+            "my_host.example".validate_hostname(options),
+            Err(HostnameError::InvalidCharacter {
+                code: HostnameErrorCode,
+                character: '_',
+                #[cfg(feature = "message")]
+                message: "invalid character `_`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "bücher.example".validate_hostname(options),
+            Err(HostnameError::InvalidCharacter {
+                code: HostnameErrorCode,
+                character: 'ü',
+                #[cfg(feature = "message")]
+                message: "invalid character `ü`".to_owned(),
+            })
+        );
+        for value in ["-example.com", "example-.com"] {
+            assert_eq!(
+                value.validate_hostname(options),
+                Err(HostnameError::InvalidHyphen {
+                    code: HostnameErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "label can not start or end with a hyphen".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+        assert_eq!(
+            "bücher_.example".validate_hostname(IDN),
+            Err(HostnameError::InvalidIdn {
+                code: HostnameErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid internationalized domain name".to_owned(),
+            })
+        );
+    }
+}
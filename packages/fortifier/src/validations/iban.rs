@@ -0,0 +1,428 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(IbanErrorCode, IBAN_ERROR_CODE, "iban");
+
+/// IBAN lengths by country code, from the SWIFT IBAN registry.
+const COUNTRY_LENGTHS: [(&str, usize); 89] = [
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HN", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+    ("YE", 30),
+];
+
+/// IBAN validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum IbanError {
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IbanErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid country code error.
+    InvalidCountryCode {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IbanErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid length error.
+    InvalidLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IbanErrorCode,
+
+        /// The country code.
+        country: String,
+
+        /// The required length for the country.
+        length: usize,
+
+        /// The actual length.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Checksum error.
+    Checksum {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IbanErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for IbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for IbanError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for IbanError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("IbanError", |_| {
+            [
+                error_type(
+                    IBAN_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(IBAN_ERROR_CODE, Some("invalidCountryCode"), &[]),
+                error_type(
+                    IBAN_ERROR_CODE,
+                    Some("invalidLength"),
+                    &[
+                        ("country", "string"),
+                        ("length", "number"),
+                        ("value", "number"),
+                    ],
+                ),
+                error_type(IBAN_ERROR_CODE, Some("checksum"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "IbanError".to_owned()
+    }
+}
+
+/// Validate an IBAN.
+pub trait ValidateIban {
+    /// The IBAN.
+    fn iban(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate IBAN.
+    fn validate_iban(&self) -> Result<(), IbanError> {
+        self.iban().map(|iban| validate_iban(&iban)).transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate an IBAN, ignoring spaces and letter case.
+pub fn validate_iban(value: &str) -> Result<(), IbanError> {
+    let mut iban = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        if character.is_ascii_alphanumeric() {
+            iban.push(character.to_ascii_uppercase());
+        } else if character != ' ' {
+            return Err(IbanError::InvalidCharacter {
+                code: IbanErrorCode,
+                character,
+                #[cfg(feature = "message")]
+                message: format!("invalid character `{character}`"),
+            });
+        }
+    }
+
+    let Some((country, length)) = iban.get(..2).and_then(|country| {
+        COUNTRY_LENGTHS
+            .iter()
+            .find(|(code, _)| *code == country)
+            .copied()
+    }) else {
+        return Err(IbanError::InvalidCountryCode {
+            code: IbanErrorCode,
+            #[cfg(feature = "message")]
+            message: "invalid country code".to_owned(),
+        });
+    };
+
+    if iban.len() != length {
+        return Err(IbanError::InvalidLength {
+            code: IbanErrorCode,
+            country: country.to_owned(),
+            length,
+            value: iban.len(),
+            #[cfg(feature = "message")]
+            message: format!("IBAN for country `{country}` must have {length} characters"),
+        });
+    }
+
+    let check_digits_valid = iban[2..4]
+        .chars()
+        .all(|character| character.is_ascii_digit());
+
+    // Move the country code and check digits to the end, convert letters to numbers (A = 10, ..., Z = 35)
+    // and compute the remainder piecewise to avoid overflow.
+    let remainder = iban[4..]
+        .chars()
+        .chain(iban[..4].chars())
+        .filter_map(|character| character.to_digit(36))
+        .fold(0, |remainder, digit| {
+            if digit < 10 {
+                (remainder * 10 + digit) % 97
+            } else {
+                (remainder * 100 + digit) % 97
+            }
+        });
+
+    if !check_digits_valid || remainder != 1 {
+        return Err(IbanError::Checksum {
+            code: IbanErrorCode,
+            #[cfg(feature = "message")]
+            message: "invalid IBAN checksum".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateIban for str {
+    fn iban(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateIban for &str {
+    fn iban(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateIban for String {
+    fn iban(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateIban for Cow<'_, str> {
+    fn iban(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateIban for Option<T>
+where
+    T: ValidateIban,
+{
+    fn iban(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self { T::iban(s) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateIban for $type
+        where
+            T: ValidateIban,
+        {
+            fn iban(&self) -> Option<Cow<'_, str>> {
+                T::iban(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{IbanError, IbanErrorCode, ValidateIban};
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"NL91 ABNA 0417 1643 00").validate_iban(), Ok(()));
+        assert_eq!("NL91ABNA0417164300".validate_iban(), Ok(()));
+        assert_eq!("nl91abna0417164300".to_owned().validate_iban(), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("DE89 3704 0044 0532 0130 00").validate_iban(),
+            Ok(())
+        );
+        assert_eq!("GB29 NWBK 6016 1331 9268 19".validate_iban(), Ok(()));
+        assert_eq!("NO93 8601 1117 947".validate_iban(), Ok(()));
+        assert_eq!(
+            "LC55 HEMM 0001 0001 0012 0012 0002 3015".validate_iban(),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_iban(), Ok(()));
+        assert_eq!(Some("NL91ABNA0417164300").validate_iban(), Ok(()));
+
+        assert_eq!((&"NL91ABNA0417164300").validate_iban(), Ok(()));
+        assert_eq!(Box::new("NL91ABNA0417164300").validate_iban(), Ok(()));
+        assert_eq!(Arc::new("NL91ABNA0417164300").validate_iban(), Ok(()));
+        assert_eq!(Rc::new("NL91ABNA0417164300").validate_iban(), Ok(()));
+
+        let cell = RefCell::new("NL91ABNA0417164300");
+        assert_eq!(cell.borrow().validate_iban(), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_iban(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_error() {
+        assert_eq!(
+            "NL91-ABNA-0417-1643-00".validate_iban(),
+            Err(IbanError::InvalidCharacter {
+                code: IbanErrorCode,
+                character: '-',
+                #[cfg(feature = "message")]
+                message: "invalid character `-`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "XX91ABNA0417164300".validate_iban(),
+            Err(IbanError::InvalidCountryCode {
+                code: IbanErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid country code".to_owned(),
+            })
+        );
+        assert_eq!(
+            "NL91ABNA041716430".validate_iban(),
+            Err(IbanError::InvalidLength {
+                code: IbanErrorCode,
+                country: "NL".to_owned(),
+                length: 18,
+                value: 17,
+                #[cfg(feature = "message")]
+                message: "IBAN for country `NL` must have 18 characters".to_owned(),
+            })
+        );
+        assert_eq!(
+            "NL92ABNA0417164300".validate_iban(),
+            Err(IbanError::Checksum {
+                code: IbanErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid IBAN checksum".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,680 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(IpErrorCode, IP_ERROR_CODE, "ip");
+
+/// IP address version.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum IpVersion {
+    /// IPv4.
+    V4,
+    /// IPv6.
+    V6,
+}
+
+impl fmt::Display for IpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::V4 => "IPv4",
+            Self::V6 => "IPv6",
+        })
+    }
+}
+
+/// IP address validation options.
+///
+/// Shared by the IP address, CIDR and socket address validations. IPv6 addresses embedding an IPv4 address are checked
+/// against the IPv4 ranges. These are IPv4-mapped (e.g. `::ffff:127.0.0.1`), IPv4-compatible (e.g. `::127.0.0.1`) and
+/// NAT64 (e.g. `64:ff9b::7f00:1`) addresses.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IpOptions {
+    /// Allow IPv4 addresses.
+    pub v4: bool,
+    /// Allow IPv6 addresses.
+    pub v6: bool,
+    /// Allow private addresses (`10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16` and `fc00::/7`).
+    pub allow_private: bool,
+    /// Allow loopback addresses (`127.0.0.0/8` and `::1`).
+    pub allow_loopback: bool,
+    /// Allow link-local addresses (`169.254.0.0/16` and `fe80::/10`).
+    pub allow_link_local: bool,
+    /// Allow multicast addresses (`224.0.0.0/4` and `ff00::/8`).
+    pub allow_multicast: bool,
+    /// Allow unspecified addresses (`0.0.0.0/8` and `::`).
+    pub allow_unspecified: bool,
+    /// Allow the broadcast address (`255.255.255.255`).
+    pub allow_broadcast: bool,
+}
+
+impl Default for IpOptions {
+    fn default() -> Self {
+        Self {
+            v4: true,
+            v6: true,
+            allow_private: true,
+            allow_loopback: true,
+            allow_link_local: true,
+            allow_multicast: true,
+            allow_unspecified: true,
+            allow_broadcast: true,
+        }
+    }
+}
+
+/// IP address options violation.
+pub(crate) enum IpViolation {
+    Version(IpVersion),
+    Private,
+    Loopback,
+    LinkLocal,
+    Multicast,
+    Unspecified,
+    Broadcast,
+}
+
+impl IpViolation {
+    #[cfg(feature = "message")]
+    pub(crate) fn message(&self, noun: &str) -> String {
+        match self {
+            Self::Version(version) => format!("{version} {noun} is not allowed"),
+            Self::Private => format!("private {noun} is not allowed"),
+            Self::Loopback => format!("loopback {noun} is not allowed"),
+            Self::LinkLocal => format!("link-local {noun} is not allowed"),
+            Self::Multicast => format!("multicast {noun} is not allowed"),
+            Self::Unspecified => format!("unspecified {noun} is not allowed"),
+            Self::Broadcast => format!("broadcast {noun} is not allowed"),
+        }
+    }
+}
+
+impl IpOptions {
+    /// Check an address against the options.
+    pub(crate) fn check(&self, address: IpAddr) -> Result<(), IpViolation> {
+        let version = match address {
+            IpAddr::V4(_) => IpVersion::V4,
+            IpAddr::V6(_) => IpVersion::V6,
+        };
+
+        if (version == IpVersion::V4 && !self.v4) || (version == IpVersion::V6 && !self.v6) {
+            return Err(IpViolation::Version(version));
+        }
+
+        let address = match address {
+            IpAddr::V6(address) => embedded_ipv4(address)
+                .map(IpAddr::V4)
+                .unwrap_or(IpAddr::V6(address)),
+            address => address,
+        };
+
+        let (private, loopback, link_local, multicast, unspecified, broadcast) = match address {
+            IpAddr::V4(address) => (
+                address.is_private(),
+                address.is_loopback(),
+                address.is_link_local(),
+                address.is_multicast(),
+                // `0.0.0.0/8` ("this network"), `0.0.0.0` connects to the local host on some systems.
+                address.octets()[0] == 0,
+                address.is_broadcast(),
+            ),
+            IpAddr::V6(address) => (
+                address.is_unique_local(),
+                address.is_loopback(),
+                address.is_unicast_link_local(),
+                address.is_multicast(),
+                address.is_unspecified(),
+                false,
+            ),
+        };
+
+        if unspecified && !self.allow_unspecified {
+            Err(IpViolation::Unspecified)
+        } else if broadcast && !self.allow_broadcast {
+            Err(IpViolation::Broadcast)
+        } else if private && !self.allow_private {
+            Err(IpViolation::Private)
+        } else if loopback && !self.allow_loopback {
+            Err(IpViolation::Loopback)
+        } else if link_local && !self.allow_link_local {
+            Err(IpViolation::LinkLocal)
+        } else if multicast && !self.allow_multicast {
+            Err(IpViolation::Multicast)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// IPv4 address embedded in an IPv6 address.
+///
+/// Supports IPv4-mapped (`::ffff:0:0/96`), IPv4-compatible (`::/96`, except `::` and `::1`) and NAT64 (`64:ff9b::/96`)
+/// addresses.
+fn embedded_ipv4(address: Ipv6Addr) -> Option<Ipv4Addr> {
+    if let Some(address) = address.to_ipv4_mapped() {
+        return Some(address);
+    }
+
+    let [a, b, c, d, e, f, _, _] = address.segments();
+    let [.., w, x, y, z] = address.octets();
+
+    match (a, b, c, d, e, f) {
+        (0, 0, 0, 0, 0, 0) if !address.is_unspecified() && !address.is_loopback() => {
+            Some(Ipv4Addr::new(w, x, y, z))
+        }
+        (0x64, 0xff9b, 0, 0, 0, 0) => Some(Ipv4Addr::new(w, x, y, z)),
+        _ => None,
+    }
+}
+
+/// IP address validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum IpError {
+    /// Invalid address error.
+    InvalidAddress {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed version error.
+    DisallowedVersion {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// The actual version.
+        value: IpVersion,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Private address error.
+    Private {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Loopback address error.
+    Loopback {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Link-local address error.
+    LinkLocal {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Multicast address error.
+    Multicast {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unspecified address error.
+    Unspecified {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Broadcast address error.
+    Broadcast {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IpErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for IpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for IpError {}
+
+#[cfg(feature = "zod")]
+pub(crate) const IP_VERSION_TYPESCRIPT: &str = "\"v4\" | \"v6\"";
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for IpError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("IpError", |_| {
+            [
+                error_type(IP_ERROR_CODE, Some("invalidAddress"), &[]),
+                error_type(
+                    IP_ERROR_CODE,
+                    Some("disallowedVersion"),
+                    &[("value", IP_VERSION_TYPESCRIPT)],
+                ),
+                error_type(IP_ERROR_CODE, Some("private"), &[]),
+                error_type(IP_ERROR_CODE, Some("loopback"), &[]),
+                error_type(IP_ERROR_CODE, Some("linkLocal"), &[]),
+                error_type(IP_ERROR_CODE, Some("multicast"), &[]),
+                error_type(IP_ERROR_CODE, Some("unspecified"), &[]),
+                error_type(IP_ERROR_CODE, Some("broadcast"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "IpError".to_owned()
+    }
+}
+
+impl From<IpViolation> for IpError {
+    fn from(value: IpViolation) -> Self {
+        let code = IpErrorCode;
+        #[cfg(feature = "message")]
+        let message = value.message("address");
+
+        match value {
+            IpViolation::Version(version) => Self::DisallowedVersion {
+                code,
+                value: version,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Private => Self::Private {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Loopback => Self::Loopback {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::LinkLocal => Self::LinkLocal {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Multicast => Self::Multicast {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Unspecified => Self::Unspecified {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Broadcast => Self::Broadcast {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+        }
+    }
+}
+
+/// Validate an IP address.
+pub trait ValidateIp {
+    /// The IP address.
+    fn ip(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate IP address.
+    fn validate_ip(&self, options: IpOptions) -> Result<(), IpError> {
+        self.parse_ip(options)?;
+
+        Ok(())
+    }
+
+    /// Validate IP address and return the parsed IP address.
+    fn parse_ip(&self, options: IpOptions) -> Result<Option<IpAddr>, IpError> {
+        self.ip().map(|ip| parse_ip(&ip, options)).transpose()
+    }
+}
+
+/// Parse an IP address.
+pub fn parse_ip(value: &str, options: IpOptions) -> Result<IpAddr, IpError> {
+    let address = value
+        .parse::<IpAddr>()
+        .map_err(|_| IpError::InvalidAddress {
+            code: IpErrorCode,
+            #[cfg(feature = "message")]
+            message: "invalid IP address".to_owned(),
+        })?;
+
+    options.check(address)?;
+
+    Ok(address)
+}
+
+impl ValidateIp for str {
+    fn ip(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateIp for &str {
+    fn ip(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateIp for String {
+    fn ip(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateIp for Cow<'_, str> {
+    fn ip(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+macro_rules! validate_address {
+    ($type:ty) => {
+        impl ValidateIp for $type {
+            fn ip(&self) -> Option<Cow<'_, str>> {
+                Some(self.to_string().into())
+            }
+
+            fn parse_ip(&self, options: IpOptions) -> Result<Option<IpAddr>, IpError> {
+                // Address has already been parsed, but the options still apply.
+                let address = IpAddr::from(*self);
+
+                options.check(address)?;
+
+                Ok(Some(address))
+            }
+        }
+    };
+}
+
+validate_address!(IpAddr);
+validate_address!(Ipv4Addr);
+validate_address!(Ipv6Addr);
+
+impl<T> ValidateIp for Option<T>
+where
+    T: ValidateIp,
+{
+    fn ip(&self) -> Option<Cow<'_, str>> {
+        if let Some(ip) = self { T::ip(ip) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateIp for $type
+        where
+            T: ValidateIp,
+        {
+            fn ip(&self) -> Option<Cow<'_, str>> {
+                T::ip(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        cell::RefCell,
+        net::{IpAddr, Ipv4Addr, Ipv6Addr},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use super::{IpError, IpErrorCode, IpOptions, IpVersion, ValidateIp};
+
+    const PUBLIC: IpOptions = IpOptions {
+        v4: true,
+        v6: true,
+        allow_private: false,
+        allow_loopback: false,
+        allow_link_local: false,
+        allow_multicast: false,
+        allow_unspecified: false,
+        allow_broadcast: false,
+    };
+
+    #[test]
+    fn ok() {
+        let options = IpOptions::default();
+
+        assert_eq!((*"192.168.1.1").validate_ip(options), Ok(()));
+        assert_eq!("::1".validate_ip(options), Ok(()));
+        assert_eq!("10.0.0.1".to_owned().validate_ip(options), Ok(()));
+        assert_eq!(Cow::<str>::Borrowed("fe80::1").validate_ip(options), Ok(()));
+        assert_eq!(Ipv4Addr::LOCALHOST.validate_ip(options), Ok(()));
+        assert_eq!(Ipv6Addr::LOCALHOST.validate_ip(options), Ok(()));
+        assert_eq!(IpAddr::V4(Ipv4Addr::BROADCAST).validate_ip(options), Ok(()));
+
+        assert_eq!(None::<&str>.validate_ip(options), Ok(()));
+        assert_eq!(Some("8.8.8.8").validate_ip(PUBLIC), Ok(()));
+
+        assert_eq!((&"8.8.8.8").validate_ip(PUBLIC), Ok(()));
+        assert_eq!(Box::new("8.8.8.8").validate_ip(PUBLIC), Ok(()));
+        assert_eq!(Arc::new("2001:4860:4860::8888").validate_ip(PUBLIC), Ok(()));
+        assert_eq!(Rc::new("2001:4860:4860::8888").validate_ip(PUBLIC), Ok(()));
+
+        let cell = RefCell::new("8.8.8.8");
+        assert_eq!(cell.borrow().validate_ip(PUBLIC), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_ip(PUBLIC), Ok(()));
+    }
+
+    #[test]
+    fn invalid_address_error() {
+        for value in [
+            "",
+            "localhost",
+            "256.0.0.1",
+            "1.2.3",
+            "::g",
+            "192.168.1.1/24",
+        ] {
+            assert_eq!(
+                value.validate_ip(IpOptions::default()),
+                Err(IpError::InvalidAddress {
+                    code: IpErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "invalid IP address".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn disallowed_version_error() {
+        let v4 = IpOptions {
+            v6: false,
+            ..IpOptions::default()
+        };
+        let v6 = IpOptions {
+            v4: false,
+            ..IpOptions::default()
+        };
+
+        assert_eq!("127.0.0.1".validate_ip(v4), Ok(()));
+        assert_eq!("::1".validate_ip(v6), Ok(()));
+        assert_eq!(
+            "::1".validate_ip(v4),
+            Err(IpError::DisallowedVersion {
+                code: IpErrorCode,
+                value: IpVersion::V6,
+                #[cfg(feature = "message")]
+                message: "IPv6 address is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            Ipv4Addr::LOCALHOST.validate_ip(v6),
+            Err(IpError::DisallowedVersion {
+                code: IpErrorCode,
+                value: IpVersion::V4,
+                #[cfg(feature = "message")]
+                message: "IPv4 address is not allowed".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn range_error() {
+        for value in ["10.0.0.1", "172.16.0.1", "192.168.1.1", "fd00::1"] {
+            assert_eq!(
+                value.validate_ip(PUBLIC),
+                Err(IpError::Private {
+                    code: IpErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "private address is not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        for value in [
+            "127.0.0.1",
+            "127.1.2.3",
+            "::1",
+            "::ffff:127.0.0.1",
+            "::127.0.0.1",
+            "64:ff9b::7f00:1",
+        ] {
+            assert_eq!(
+                value.validate_ip(PUBLIC),
+                Err(IpError::Loopback {
+                    code: IpErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "loopback address is not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        for value in ["169.254.169.254", "fe80::1", "64:ff9b::a9fe:a9fe"] {
+            assert_eq!(
+                value.validate_ip(PUBLIC),
+                Err(IpError::LinkLocal {
+                    code: IpErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "link-local address is not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        for value in ["224.0.0.1", "ff02::1"] {
+            assert_eq!(
+                value.validate_ip(PUBLIC),
+                Err(IpError::Multicast {
+                    code: IpErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "multicast address is not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        for value in ["0.0.0.0", "0.1.2.3", "::", "::ffff:0.0.0.0", "64:ff9b::"] {
+            assert_eq!(
+                value.validate_ip(PUBLIC),
+                Err(IpError::Unspecified {
+                    code: IpErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "unspecified address is not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        for value in ["255.255.255.255", "::ffff:255.255.255.255"] {
+            assert_eq!(
+                value.validate_ip(PUBLIC),
+                Err(IpError::Broadcast {
+                    code: IpErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "broadcast address is not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        // Public IPv4 addresses embedded in IPv6 addresses are allowed.
+        assert_eq!("::8.8.8.8".validate_ip(PUBLIC), Ok(()));
+        assert_eq!("64:ff9b::808:808".validate_ip(PUBLIC), Ok(()));
+
+        assert_eq!(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)).validate_ip(PUBLIC),
+            Err(IpError::Private {
+                code: IpErrorCode,
+                #[cfg(feature = "message")]
+                message: "private address is not allowed".to_owned(),
+            })
+        );
+    }
+}
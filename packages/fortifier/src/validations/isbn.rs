@@ -0,0 +1,325 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(IsbnErrorCode, ISBN_ERROR_CODE, "isbn");
+
+/// ISBN validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum IsbnError {
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IsbnErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid length error.
+    InvalidLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IsbnErrorCode,
+
+        /// The actual number of digits.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid prefix error.
+    ///
+    /// An ISBN-13 must start with 978 or 979.
+    InvalidPrefix {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IsbnErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Checksum error.
+    Checksum {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: IsbnErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for IsbnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for IsbnError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for IsbnError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("IsbnError", |_| {
+            [
+                error_type(
+                    ISBN_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(
+                    ISBN_ERROR_CODE,
+                    Some("invalidLength"),
+                    &[("value", "number")],
+                ),
+                error_type(ISBN_ERROR_CODE, Some("invalidPrefix"), &[]),
+                error_type(ISBN_ERROR_CODE, Some("checksum"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "IsbnError".to_owned()
+    }
+}
+
+/// Validate an ISBN.
+pub trait ValidateIsbn {
+    /// The ISBN.
+    fn isbn(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate ISBN.
+    fn validate_isbn(&self) -> Result<(), IsbnError> {
+        self.isbn().map(|isbn| validate_isbn(&isbn)).transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate an ISBN-10 or ISBN-13, ignoring spaces and hyphens.
+pub fn validate_isbn(value: &str) -> Result<(), IsbnError> {
+    let mut digits = Vec::with_capacity(13);
+
+    for character in value.chars() {
+        match character {
+            '0'..='9' => digits.push(character as u32 - '0' as u32),
+            // The ISBN-10 check digit can be 10, written as `X`.
+            'X' | 'x' if digits.len() == 9 => digits.push(10),
+            ' ' | '-' => {}
+            _ => {
+                return Err(IsbnError::InvalidCharacter {
+                    code: IsbnErrorCode,
+                    character,
+                    #[cfg(feature = "message")]
+                    message: format!("invalid character `{character}`"),
+                });
+            }
+        }
+    }
+
+    let valid = match digits.len() {
+        10 => digits
+            .iter()
+            .zip((1..=10).rev())
+            .map(|(digit, weight)| digit * weight)
+            .sum::<u32>()
+            .is_multiple_of(11),
+        13 => {
+            if digits[..3] != [9, 7, 8] && digits[..3] != [9, 7, 9] {
+                return Err(IsbnError::InvalidPrefix {
+                    code: IsbnErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "ISBN-13 must start with 978 or 979".to_owned(),
+                });
+            }
+
+            digits.iter().all(|digit| *digit < 10)
+                && digits
+                    .iter()
+                    .enumerate()
+                    .map(|(index, digit)| if index % 2 == 1 { digit * 3 } else { *digit })
+                    .sum::<u32>()
+                    .is_multiple_of(10)
+        }
+        length => {
+            return Err(IsbnError::InvalidLength {
+                code: IsbnErrorCode,
+                value: length,
+                #[cfg(feature = "message")]
+                message: "ISBN must have 10 or 13 digits".to_owned(),
+            });
+        }
+    };
+
+    if !valid {
+        return Err(IsbnError::Checksum {
+            code: IsbnErrorCode,
+            #[cfg(feature = "message")]
+            message: "invalid ISBN checksum".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateIsbn for str {
+    fn isbn(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateIsbn for &str {
+    fn isbn(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateIsbn for String {
+    fn isbn(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateIsbn for Cow<'_, str> {
+    fn isbn(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateIsbn for Option<T>
+where
+    T: ValidateIsbn,
+{
+    fn isbn(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self { T::isbn(s) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateIsbn for $type
+        where
+            T: ValidateIsbn,
+        {
+            fn isbn(&self) -> Option<Cow<'_, str>> {
+                T::isbn(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{IsbnError, IsbnErrorCode, ValidateIsbn};
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"978-0-306-40615-7").validate_isbn(), Ok(()));
+        assert_eq!("9780306406157".validate_isbn(), Ok(()));
+        assert_eq!("0-306-40615-2".to_owned().validate_isbn(), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("0-8044-2957-X").validate_isbn(),
+            Ok(())
+        );
+        assert_eq!("080442957x".validate_isbn(), Ok(()));
+        assert_eq!("979-10-90636-07-1".validate_isbn(), Ok(()));
+
+        assert_eq!(None::<&str>.validate_isbn(), Ok(()));
+        assert_eq!(Some("9780306406157").validate_isbn(), Ok(()));
+
+        assert_eq!((&"9780306406157").validate_isbn(), Ok(()));
+        assert_eq!(Box::new("9780306406157").validate_isbn(), Ok(()));
+        assert_eq!(Arc::new("9780306406157").validate_isbn(), Ok(()));
+        assert_eq!(Rc::new("9780306406157").validate_isbn(), Ok(()));
+
+        let cell = RefCell::new("9780306406157");
+        assert_eq!(cell.borrow().validate_isbn(), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_isbn(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_error() {
+        assert_eq!(
+            "978030640615X".validate_isbn(),
+            Err(IsbnError::InvalidCharacter {
+                code: IsbnErrorCode,
+                character: 'X',
+                #[cfg(feature = "message")]
+                message: "invalid character `X`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "978030640615".validate_isbn(),
+            Err(IsbnError::InvalidLength {
+                code: IsbnErrorCode,
+                value: 12,
+                #[cfg(feature = "message")]
+                message: "ISBN must have 10 or 13 digits".to_owned(),
+            })
+        );
+        assert_eq!(
+            "9770306406157".validate_isbn(),
+            Err(IsbnError::InvalidPrefix {
+                code: IsbnErrorCode,
+                #[cfg(feature = "message")]
+                message: "ISBN-13 must start with 978 or 979".to_owned(),
+            })
+        );
+        assert_eq!(
+            "9780306406158".validate_isbn(),
+            Err(IsbnError::Checksum {
+                code: IsbnErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid ISBN checksum".to_owned(),
+            })
+        );
+        assert_eq!(
+            "0306406153".validate_isbn(),
+            Err(IsbnError::Checksum {
+                code: IsbnErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid ISBN checksum".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+use serde_json::error::Category;
+
+constant_string!(JsonErrorCode, JSON_ERROR_CODE, "json");
+
+/// JSON validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum JsonError {
+    /// Syntax error.
+    Syntax {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: JsonErrorCode,
+
+        /// The one-based line of the error.
+        line: usize,
+
+        /// The one-based column of the error.
+        column: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unexpected end of input error.
+    Eof {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: JsonErrorCode,
+
+        /// The one-based line of the error.
+        line: usize,
+
+        /// The one-based column of the error.
+        column: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for JsonError {}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(value: serde_json::Error) -> Self {
+        let line = value.line();
+        let column = value.column();
+
+        match value.classify() {
+            Category::Eof => JsonError::Eof {
+                code: JsonErrorCode,
+                line,
+                column,
+                #[cfg(feature = "message")]
+                message: value.to_string(),
+            },
+            Category::Io | Category::Syntax | Category::Data => JsonError::Syntax {
+                code: JsonErrorCode,
+                line,
+                column,
+                #[cfg(feature = "message")]
+                message: value.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for JsonError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("JsonError", |_| {
+            [
+                error_type(
+                    JSON_ERROR_CODE,
+                    Some("syntax"),
+                    &[("line", "number"), ("column", "number")],
+                ),
+                error_type(
+                    JSON_ERROR_CODE,
+                    Some("eof"),
+                    &[("line", "number"), ("column", "number")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "JsonError".to_owned()
+    }
+}
+
+/// Validate a JSON string.
+pub trait ValidateJson {
+    /// The JSON string.
+    fn json(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate JSON string.
+    fn validate_json(&self) -> Result<(), JsonError> {
+        self.json().map(|json| validate_json(&json)).transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate that a string is a JSON document.
+pub fn validate_json(value: &str) -> Result<(), JsonError> {
+    serde_json::from_str::<serde_json::Value>(value)?;
+
+    Ok(())
+}
+
+impl ValidateJson for str {
+    fn json(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateJson for &str {
+    fn json(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateJson for String {
+    fn json(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateJson for Cow<'_, str> {
+    fn json(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateJson for Option<T>
+where
+    T: ValidateJson,
+{
+    fn json(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self { T::json(s) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateJson for $type
+        where
+            T: ValidateJson,
+        {
+            fn json(&self) -> Option<Cow<'_, str>> {
+                T::json(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{JsonError, JsonErrorCode, ValidateJson};
+
+    #[test]
+    fn ok() {
+        assert_eq!((*r#"{"a":1}"#).validate_json(), Ok(()));
+        assert_eq!(r#"{"a":1}"#.validate_json(), Ok(()));
+        assert_eq!(r#"{"a":1}"#.to_owned().validate_json(), Ok(()));
+        assert_eq!(Cow::<str>::Borrowed(r#"{"a":1}"#).validate_json(), Ok(()));
+
+        assert_eq!(None::<&str>.validate_json(), Ok(()));
+        assert_eq!(Some(r#"{"a":1}"#).validate_json(), Ok(()));
+
+        assert_eq!((&r#"{"a":1}"#).validate_json(), Ok(()));
+        assert_eq!(Box::new(r#"{"a":1}"#).validate_json(), Ok(()));
+        assert_eq!(Arc::new(r#"{"a":1}"#).validate_json(), Ok(()));
+        assert_eq!(Rc::new(r#"{"a":1}"#).validate_json(), Ok(()));
+
+        let cell = RefCell::new(r#"{"a":1}"#);
+        assert_eq!(cell.borrow().validate_json(), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_json(), Ok(()));
+
+        for value in ["null", "1.5", r#""text""#, "[1, [2, {}]]", " true\n"] {
+            assert_eq!(value.validate_json(), Ok(()), "{value}");
+        }
+    }
+
+    #[test]
+    fn syntax_error() {
+        assert_eq!(
+            "{\n  \"a\": 1,\n}".validate_json(),
+            Err(JsonError::Syntax {
+                code: JsonErrorCode,
+                line: 3,
+                column: 1,
+                #[cfg(feature = "message")]
+                message: "trailing comma at line 3 column 1".to_owned(),
+            })
+        );
+        assert_eq!(
+            "1 2".validate_json(),
+            Err(JsonError::Syntax {
+                code: JsonErrorCode,
+                line: 1,
+                column: 3,
+                #[cfg(feature = "message")]
+                message: "trailing characters at line 1 column 3".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn eof_error() {
+        assert_eq!(
+            r#"{"a":"#.validate_json(),
+            Err(JsonError::Eof {
+                code: JsonErrorCode,
+                line: 1,
+                column: 5,
+                #[cfg(feature = "message")]
+                message: "EOF while parsing a value at line 1 column 5".to_owned(),
+            })
+        );
+        assert_eq!(
+            "".validate_json(),
+            Err(JsonError::Eof {
+                code: JsonErrorCode,
+                line: 1,
+                column: 0,
+                #[cfg(feature = "message")]
+                message: "EOF while parsing a value at line 1 column 0".to_owned(),
+            })
+        );
+    }
+}
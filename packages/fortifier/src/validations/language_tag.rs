@@ -0,0 +1,903 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+use super::country_code::country_from_alpha_2;
+
+constant_string!(LanguageTagErrorCode, LANGUAGE_TAG_ERROR_CODE, "languageTag");
+
+/// ISO 639-1 two-letter language codes, sorted.
+const LANGUAGES_2: &[&str] = &[
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bh",
+    "bi", "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da",
+    "de", "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr",
+    "fy", "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz",
+    "ia", "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj",
+    "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln",
+    "lo", "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb",
+    "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi",
+    "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "sh", "si",
+    "sk", "sl", "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th",
+    "ti", "tk", "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi",
+    "vo", "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+/// ISO 639-2, 639-3 and 639-5 three-letter language codes without a two-letter code, sorted and concatenated.
+const LANGUAGES_3: &str = "\
+    aaaaabaacaadaaeaafaagaahaaiaakaalaanaaoaapaaqaasaataauaavaawaaxaazabaabbabcabdabeabfabgabhabi\
+    abjablabmabnaboabpabqabrabsabtabuabvabwabxabyabzacaacbacdaceacfachaciackaclacmacnacpacqacracs\
+    actacuacvacwacxacyaczadaadbaddadeadfadgadhadiadjadladnadoadqadradsadtaduadwadxadyadzaeaaebaec\
+    aedaeeaekaelaemaenaeqaeraesaeuaewaeyaezafaafbafdafeafgafhafiafkafnafoafpafsaftafuafzagaagbagc\
+    agdageagfaggaghagiagjagkaglagmagnagoagqagragsagtaguagvagwagxagyagzahaahbahgahhahiahkahlahmahn\
+    ahoahpahrahsahtaiaaibaicaidaieaifaigaihaiiaijaikailaimainaioaipaiqairaitaiwaixaiyajaajgajiajn\
+    ajpajsajuajwajzakbakcakdakeakfakgakhakiakjakkaklakmakoakpakqakraksaktakuakvakwakxakyakzalaalc\
+    aldalealfalgalhalialjalkallalmalnaloalpalqalralsaltalualvalwalxalyalzamaambamcameamfamgamiamj\
+    amkamlammamnamoampamqamramsamtamuamvamwamxamyamzanaanbancandaneanfanganhanianjankanlanmannano\
+    anpanqanransantanuanvanwanxanyanzaoaaobaocaodaoeaofaogaoiaojaokaolaomaonaoraosaotaouaoxaozapa\
+    apbapcapdapeapfapgaphapiapjapkaplapmapnapoappapqaprapsaptapuapvapwapxapyapzaqaaqcaqdaqgaqkaql\
+    aqmaqnaqpaqraqtaqzarbarcardarearhariarjarkarlarnaroarparqarrarsartaruarvarwarxaryarzasaasbasc\
+    aseasfasgashasiasjaskaslasnasoaspasqasrassastasuasvaswasxasyaszataatbatcatdateatgathatiatjatk\
+    atlatmatnatoatpatqatratsattatuatvatwatxatyatzauaaubaucaudaufaugauhauiaujaukaulaumaunauoaupauq\
+    aurausautauuauwauxauyauzavbavdaviavkavlavmavnavoavsavtavuavvawaawbawcawdaweawgawhawiawkawmawn\
+    awoawrawsawtawuawvawwawxawyaxbaxeaxgaxkaxlaxmaxxayaaybaycaydayeaygayhayiaykaylaynayoaypayqayr\
+    aysaytayuayzazaazbazcazdazgazjazmaznazoaztazzbaababbacbadbaebafbagbahbaibajbalbanbaobapbarbas\
+    batbaubavbawbaxbaybbabbbbbcbbdbbebbfbbgbbhbbibbjbbkbblbbmbbnbbobbpbbqbbrbbsbbtbbubbvbbwbbxbby\
+    bcabcbbccbcdbcebcfbcgbchbcibcjbckbclbcmbcnbcobcpbcqbcrbcsbctbcubcvbcwbcybczbdabdbbdcbddbdebdf\
+    bdgbdhbdibdjbdkbdlbdmbdnbdobdpbdqbdrbdsbdtbdubdvbdwbdxbdybdzbeabebbecbedbeebefbegbehbeibejbek\
+    bembeobepbeqberbesbetbeubevbewbexbeybezbfabfbbfcbfdbfebffbfgbfhbfibfjbfkbflbfmbfnbfobfpbfqbfr\
+    bfsbftbfubfwbfxbfybfzbgabgbbgcbgdbgebgfbggbgibgjbgkbglbgnbgobgpbgqbgrbgsbgtbgubgvbgwbgxbgybgz\
+    bhabhbbhcbhdbhebhfbhgbhhbhibhjbhlbhmbhnbhobhpbhqbhrbhsbhtbhubhvbhwbhxbhybhzbiabibbidbiebifbig\
+    bikbilbimbinbiobipbiqbirbitbiubivbiwbixbiybizbjabjbbjcbjebjfbjgbjhbjibjjbjkbjlbjmbjnbjobjpbjr\
+    bjsbjtbjubjvbjwbjxbjybjzbkabkcbkdbkfbkgbkhbkibkjbkkbklbkmbknbkobkpbkqbkrbksbktbkubkvbkwbkxbky\
+    bkzblablbblcbldbleblfblhblibljblkbllblmblnbloblpblqblrblsbltblvblwblxblyblzbmabmbbmcbmdbmebmf\
+    bmgbmhbmibmjbmkbmlbmmbmnbmobmpbmqbmrbmsbmtbmubmvbmwbmxbmzbnabnbbncbndbnebnfbngbnibnjbnkbnlbnm\
+    bnnbnobnpbnqbnrbnsbntbnubnvbnwbnxbnybnzboabobboebofbogbohboibojbokbolbombonboobopboqborbotbou\
+    bovbowboxboybozbpabpcbpdbpebpgbphbpibpjbpkbplbpmbpnbpobppbpqbprbpsbptbpubpvbpwbpxbpybpzbqabqb\
+    bqcbqdbqfbqgbqhbqibqjbqkbqlbqmbqnbqobqpbqqbqrbqsbqtbqubqvbqwbqxbqybqzbrabrbbrcbrdbrfbrgbrhbri\
+    brjbrkbrlbrmbrnbrobrpbrqbrrbrsbrtbrubrvbrwbrxbrybrzbsabsbbscbsebsfbsgbshbsibsjbskbslbsmbsnbso\
+    bspbsqbsrbssbstbsubsvbswbsxbsybtabtcbtdbtebtfbtgbthbtibtjbtkbtmbtnbtobtpbtqbtrbtsbttbtubtvbtw\
+    btxbtybtzbuabubbucbudbuebufbugbuhbuibujbukbumbunbuobupbuqbusbutbuubuvbuwbuxbuybuzbvabvbbvcbvd\
+    bvebvfbvgbvhbvibvjbvkbvlbvmbvnbvobvpbvqbvrbvtbvubvvbvwbvxbvybvzbwabwbbwcbwdbwebwfbwgbwhbwibwj\
+    bwkbwlbwmbwnbwobwpbwqbwrbwsbwtbwubwwbwxbwybwzbxabxbbxcbxdbxebxfbxgbxhbxibxjbxkbxlbxmbxnbxobxp\
+    bxqbxrbxsbxubxvbxwbxzbyabybbycbydbyebyfbygbyhbyibyjbykbylbymbynbyobypbyqbyrbysbytbyvbywbyxbyz\
+    bzabzbbzcbzdbzebzfbzgbzhbzibzjbzkbzlbzmbznbzobzpbzqbzrbzsbztbzubzvbzwbzxbzybzzcaacabcaccadcae\
+    cafcagcahcaicajcakcalcamcancaocapcaqcarcascaucavcawcaxcaycazcbacbbcbccbdcbgcbicbjcbkcblcbncbo\
+    cbqcbrcbscbtcbucbvcbwcbycccccdcceccgcchccjcclccmccnccoccpccrccscdacdccddcdecdfcdhcdicdjcdmcdn\
+    cdocdrcdscdycdzceacebcegcekcelcencetceycfacfdcfgcfmcgacgccggcgkchbchcchdchfchgchhchjchkchlchm\
+    chnchochpchqchrchtchwchxchychzciacibciccidciecihcikcimcincipcirciwciycjacjecjhcjicjkcjmcjncjo\
+    cjpcjscjvcjyckbckhcklckmcknckockqckrckscktckuckvckxckyckzclaclccldcleclhclicljclkcllclmcloclt\
+    cluclwclycmacmccmecmgcmicmlcmmcmncmocmrcmscmtcnacnbcnccngcnhcnicnkcnlcnocnpcnqcnrcnscntcnucnw\
+    cnxcoacobcoccodcoecofcogcohcojcokcolcomconcoocopcoqcotcoucovcowcoxcozcpacpbcpccpecpfcpgcpicpn\
+    cpocppcpscpucpxcpycqdcracrbcrccrdcrfcrgcrhcricrjcrkcrlcrmcrncrocrpcrqcrrcrscrtcrvcrwcrxcrycrz\
+    csacsbcsccsdcsecsfcsgcshcsicsjcskcslcsmcsncsocspcsqcsrcsscstcsucsvcswcsxcsycszctactcctdctectg\
+    cthctlctmctnctoctpctscttctuctyctzcuacubcuccuhcuicujcukculcuocupcuqcurcuscutcuucuvcuwcuxcuycvg\
+    cvncwacwbcwdcwecwgcwtcyacybcyoczhczkcznczocztdaadacdaddaedagdahdaidajdakdaldamdaodaqdardasdau\
+    davdawdaxdaydazdbadbbdbddbedbfdbgdbidbjdbldbmdbndbodbpdbqdbrdbtdbudbvdbwdbydccdcrddadddddeddg\
+    ddiddjddnddoddrddsddwdecdeddeedefdegdehdeidekdeldemdendepdeqderdesdevdezdgadgbdgcdgddgedggdgh\
+    dgidgkdgldgndgodgrdgsdgtdgwdgxdgzdhddhgdhidhldhmdhndhodhrdhsdhudhvdhwdhxdiadibdicdiddifdigdih\
+    diidijdikdildimdindiodipdiqdirdisdiudiwdixdiydizdjadjbdjcdjddjedjfdjidjjdjkdjmdjndjodjrdjudjw\
+    dkadkgdkkdkrdksdkxdlgdlkdlmdlndmadmbdmcdmddmedmfdmgdmkdmldmmdmndmodmrdmsdmudmvdmwdmxdmydnadnd\
+    dnedngdnidnjdnkdnndnodnrdntdnudnvdnwdnydoadobdocdoedofdohdoidokdoldondoodopdoqdordosdotdovdow\
+    doxdoydozdppdradrbdrcdrddredrgdridrldrndrodrqdrsdrtdrudrydsbdsedshdsidsldsndsodsqdszdtadtbdtd\
+    dthdtidtkdtmdtndtodtpdtrdtsdttdtudtyduadubducduedufdugduhduidukduldumdunduodupduqdurdusduuduv\
+    duwduxduyduzdvadwadwkdwrdwsdwudwwdwydwzdyadybdyddygdyidymdyndyodyudyydzadzedzgdzldzneaaebcebg\
+    ebkeboebrebuecrecsecyeeeefaefeefiegaeglegmegoegxegyehsehueipeiteivejaekaekeekgekiekkeklekmeko\
+    ekpekrekyeleelhelielkelmeloeluelxemaembemeemgemiemkemmemnempemqemsemuemwemxemyemzenaenbencend\
+    enfenhenlenmennenoenqenrenuenvenwenxeotepieraergerherierkeroerrerserterweseesgeshesieskeslesm\
+    esnesoesqessesuesxesyetbetcethetnetoetretsettetuetxetzeuqeveevhevnewoexteyaeyoezaezefaafabfad\
+    faffagfahfaifajfakfalfamfanfapfarfatfaufaxfayfazfblfcsferffiffmfgrfiafiefiffilfipfirfitfiufiw\
+    fkkfkvflaflhflifllflnflrflyfmpfmufnbfngfnifodfoifomfonforfosfoxfpefqsfrcfrdfrkfrmfrofrpfrqfrr\
+    frsfrtfsefslfssfubfucfudfuefuffuhfuifujfumfunfuqfurfutfuufuvfuyfvrfwafwegaagabgacgadgaegafgag\
+    gahgaigajgakgalgamgangaogapgaqgargasgatgaugawgaxgaygazgbagbbgbdgbegbfgbggbhgbigbjgbkgblgbmgbn\
+    gbogbpgbqgbrgbsgbugbvgbwgbxgbygbzgccgcdgcegcfgclgcngcrgctgdagdbgdcgddgdegdfgdggdhgdigdjgdkgdl\
+    gdmgdngdogdqgdrgdsgdtgdugdxgeagebgecgedgefgeggehgeigejgekgelgemgeqgesgevgewgexgeygezgfkgftgga\
+    ggbggdggegggggkgglggtgguggwghaghcgheghhghkghlghnghoghrghsghtgiagibgicgidgiegiggihgiigilgimgin\
+    gipgiqgirgisgitgiugiwgixgiygizgjkgjmgjngjrgjugkagkdgkegkngkogkpgkuglbglcgldglhgljglkgllgloglr\
+    gluglwglygmagmbgmdgmegmggmhgmlgmmgmngmqgmrgmugmvgmwgmxgmygmzgnagnbgncgndgnegnggnhgnignjgnkgnl\
+    gnmgnngnognqgnrgntgnugnwgnzgoagobgocgodgoegofgoggohgoigojgokgolgomgongoogopgoqgorgosgotgougov\
+    gowgoxgoygozgpagpegpngqagqigqngqrgqugragrbgrcgrdgrggrhgrigrjgrkgrmgrogrqgrrgrsgrtgrugrvgrwgrx\
+    grygrzgsegsggslgsmgsngsogspgssgswgtagtuguagubgucgudguegufgugguhguigukgulgumgunguogupguqgurgus\
+    gutguuguwguxguzgvagvcgvegvfgvjgvlgvmgvngvogvpgvrgvsgvygwagwbgwcgwdgwegwfgwggwigwjgwmgwngwrgwt\
+    gwugwwgwxgxxgyagybgydgyegyfgyggyigylgymgyngyogyrgyygyzgzagzigznhaahabhachadhaehafhaghahhaihaj\
+    hakhalhamhanhaohaphaqharhashavhawhaxhayhazhbahbbhbnhbohbuhcahchhdnhdshdyheahedheghehheihemhgm\
+    hgwhhihhrhhyhiahibhidhifhighihhiihijhikhilhimhiohirhithiwhixhjihkahkehkhhkkhknhkshlahlbhldhle\
+    hlthluhmahmbhmchmdhmehmfhmghmhhmihmjhmkhmlhmmhmnhmphmqhmrhmshmthmuhmvhmwhmxhmyhmzhnahndhnehng\
+    hnhhnihnjhnnhnohnshnuhoahobhochodhoehohhoihojhokholhomhoohophorhoshothovhowhoyhozhpohpshrahrc\
+    hrehrkhrmhrohrphrthruhrwhrxhrzhsbhshhslhsnhsshtihtohtshtuhtxhubhuchudhuehufhughuhhuihujhukhul\
+    humhuohuphuqhurhushuthuuhuvhuwhuxhuyhuzhvchvehvkhvnhvvhwahwchwohyahywhyxiaiianiaribaibbibdibe\
+    ibgibhiblibmibnibribuibyicaichiclicridaidbidciddideidiidridsidtiduifaifbifeiffifkifmifuifyigb\
+    igeiggigligmignigoigsigwihbihiihpihwiiniirijcijeijjijnijoijsikeikiikkiklikoikpikriksiktikvikw\
+    ikxikzilailbilgiliilkilmiloilpilsiluilvimaimiimlimnimoimrimsimtimyinbincineinginhinjinlinminn\
+    inoinpinsintinzioriouiowipiipoiquiqwiraireirhiriirkirniroirriruirxiryisaiscisdiseisgishisiisk\
+    ismisnisoisristisuitbitcitditeitiitkitlitmitoitritsittitvitwitxityitziumivbivviwkiwmiwoiwsixc\
+    ixliyaiyoiyxizhizrizzjaajabjacjadjaejafjahjajjakjaljamjanjaojaqjasjatjaujaxjayjazjbejbijbjjbk\
+    jbmjbnjbojbrjbtjbujbwjcsjctjdajdgjdtjebjeejehjeijekjeljenjerjetjeujgbjgejgkjgojhijhsjiajibjic\
+    jidjiejigjihjiijiljimjiojiqjitjiujivjiyjjejjrjkajkmjkojkpjkrjksjkujlejlsjmajmbjmcjmdjmijmljmn\
+    jmrjmsjmwjmxjnajndjngjnijnjjnljnsjobjodjogjorjosjowjpajprjpxjqrjrajrbjrrjrtjrujsljuajubjucjud\
+    juhjuijukjuljumjunjuojupjurjusjutjuujuwjuyjvdjvnjwijyajyejyykaakabkackadkaekafkagkahkaikajkak\
+    kamkaokapkaqkarkavkawkaxkaykbakbbkbckbdkbekbgkbhkbikbjkbkkblkbmkbnkbokbpkbqkbrkbskbtkbukbvkbw\
+    kbxkbykbzkcakcbkcckcdkcekcfkcgkchkcikcjkckkclkcmkcnkcokcpkcqkcrkcskctkcukcvkcwkcxkcykczkdakdc\
+    kddkdekdfkdgkdhkdikdjkdkkdlkdmkdnkdokdpkdqkdrkdtkdukdwkdxkdykdzkeakebkeckedkeekefkegkehkeikej\
+    kekkelkemkenkeokepkeqkerkesketkeukevkewkexkeykezkfakfbkfckfdkfekffkfgkfhkfikfjkfkkflkfmkfnkfo\
+    kfpkfqkfrkfskftkfukfvkfwkfxkfykfzkgakgbkgekgfkggkgikgjkgkkglkgmkgnkgokgpkgqkgrkgskgtkgukgvkgw\
+    kgxkgykhakhbkhckhdkhekhfkhgkhhkhikhjkhkkhlkhnkhokhpkhqkhrkhskhtkhukhvkhwkhxkhykhzkiakibkickid\
+    kiekifkigkihkiikijkilkimkiokipkiqkiskitkiukivkiwkixkiykizkjakjbkjckjdkjekjgkjhkjikjjkjkkjlkjm\
+    kjnkjokjpkjqkjrkjskjtkjukjvkjxkjykjzkkakkbkkckkdkkekkfkkgkkhkkikkjkkkkklkkmkknkkokkpkkqkkrkks\
+    kktkkukkvkkwkkxkkykkzklaklbklckldkleklfklgklhklikljklkkllklmklnkloklpklqklrklskltkluklvklwklx\
+    klyklzkmakmbkmckmdkmekmfkmgkmhkmikmjkmkkmlkmmkmnkmokmpkmqkmrkmskmtkmukmvkmwkmxkmykmzknaknbknc\
+    kndkneknfkngkniknjknkknlknmknnknoknpknqknrknskntknuknvknwknxknyknzkoakockodkoekofkogkohkoikok\
+    kolkookopkoqkoskotkoukovkowkoykozkpakpbkpckpdkpekpfkpgkphkpikpjkpkkplkpmkpnkpokpqkprkpskptkpu\
+    kpvkpwkpxkpykpzkqakqbkqckqdkqekqfkqgkqhkqikqjkqkkqlkqmkqnkqokqpkqqkqrkqskqtkqukqvkqwkqxkqykqz\
+    krakrbkrckrdkrekrfkrhkrikrjkrkkrlkrnkrokrpkrrkrskrtkrukrvkrwkrxkrykrzksaksbkscksdkseksfksgksh\
+    ksiksjkskkslksmksnksokspksqksrksskstksuksvkswksxksykszktaktbktcktdktektfktgkthktiktjktkktlktm\
+    ktnktoktpktqktskttktuktvktwktxktyktzkubkuckudkuekufkugkuhkuikujkukkulkumkunkuokupkuqkuskutkuu\
+    kuvkuwkuxkuykuzkvakvbkvckvdkvekvfkvgkvhkvikvjkvkkvlkvmkvnkvokvpkvqkvrkvtkvukvvkvwkvxkvykvzkwa\
+    kwbkwckwdkwekwfkwgkwhkwikwjkwkkwlkwmkwnkwokwpkwrkwskwtkwukwvkwwkwxkwykwzkxakxbkxckxdkxfkxhkxi\
+    kxjkxkkxmkxnkxokxpkxqkxrkxskxtkxvkxwkxxkxykxzkyakybkyckydkyekyfkygkyhkyikyjkykkylkymkynkyokyp\
+    kyqkyrkyskytkyukyvkywkyxkyykyzkzakzbkzckzdkzekzfkzgkzikzkkzlkzmkznkzokzpkzqkzrkzskzukzvkzwkzx\
+    kzykzzlaalablacladlaelaflaglahlailajlallamlanlaplaqlarlaslaulawlaxlaylazlbblbclbelbflbglbilbj\
+    lbklbllbmlbnlbolbqlbrlbslbtlbulbvlbwlbxlbylbzlcclcdlcelcflchlcllcmlcplcqlcsldaldblddldgldhldi\
+    ldjldkldlldmldnldoldpldqlealeblecledleeleflehleilejleklellemlenleolepleqlerlesletleulevlewlex\
+    leylezlfalfnlgalgblgglghlgilgklgllgmlgnlgolgqlgrlgtlgulgzlhalhhlhilhllhmlhnlhplhslhtlhulialib\
+    liclidlielifliglihlijliklilliolipliqlirlisliulivliwlixliylizljaljeljiljlljpljwljxlkalkblkclkd\
+    lkelkhlkilkjlkllkmlknlkolkrlkslktlkulkyllallbllclldllellfllgllhllilljllklllllmllnllpllqllsllu\
+    llxlmalmblmclmdlmelmflmglmhlmilmjlmklmllmnlmolmplmqlmrlmulmvlmwlmxlmylnalnblndlnglnhlnilnjlnl\
+    lnmlnnlnslnulnwlnzloaloblocloelofloglohloilojloklollomlonlooloploqlorloslotloulovlowloxloyloz\
+    lpalpelpnlpolpxlqrlralrclrelrglrilrklrllrmlrnlrolrrlrtlrvlrzlsalsblsclsdlselshlsilsllsmlsnlso\
+    lsplsrlsslstlsvlswlsyltcltglthltiltnltoltsltulualucludluelufluilujluklullumlunluolupluqlurlus\
+    lutluuluvluwluyluzlvalvilvklvslvulwalwelwglwhlwllwmlwolwslwtlwulwwlxmlyalyglynlzhlzllznlzzmaa\
+    mabmadmaemafmagmaimajmakmammanmapmaqmasmatmaumavmawmaxmazmbambbmbcmbdmbembfmbhmbimbjmbkmblmbm\
+    mbnmbombpmbqmbrmbsmbtmbumbvmbwmbxmbymbzmcamcbmccmcdmcemcfmcgmchmcimcjmckmclmcmmcnmcomcpmcqmcr\
+    mcsmctmcumcvmcwmcxmcymczmdamdbmdcmddmdemdfmdgmdhmdimdjmdkmdlmdmmdnmdpmdqmdrmdsmdtmdumdvmdwmdx\
+    mdymdzmeamebmecmedmeemefmehmeimejmekmelmemmenmeomepmeqmermesmetmeumevmewmeymezmfamfbmfcmfdmfe\
+    mffmfgmfhmfimfjmfkmflmfmmfnmfomfpmfqmfrmfsmftmfumfvmfwmfxmfymfzmgamgbmgcmgdmgemgfmggmghmgimgj\
+    mgkmglmgmmgnmgomgpmgqmgrmgsmgtmgumgvmgwmgymgzmhamhbmhcmhdmhemhfmhgmhimhjmhkmhlmhmmhnmhomhpmhq\
+    mhrmhsmhtmhumhwmhxmhymhzmiamibmicmidmiemifmigmihmiimijmikmilmimminmiomipmiqmirmismitmiumiwmix\
+    miymizmjbmjcmjdmjemjgmjhmjimjjmjkmjlmjmmjnmjomjpmjqmjrmjsmjtmjumjvmjwmjxmjymjzmkamkbmkcmkemkf\
+    mkgmkhmkimkjmkkmklmkmmknmkomkpmkqmkrmksmktmkumkvmkwmkxmkymkzmlamlbmlcmlemlfmlhmlimljmlkmllmlm\
+    mlnmlomlpmlqmlrmlsmlumlvmlwmlxmlzmmammbmmcmmdmmemmfmmgmmhmmimmjmmkmmlmmmmmnmmommpmmqmmrmmtmmu\
+    mmvmmwmmxmmymmzmnamnbmncmndmnemnfmngmnhmnimnjmnkmnlmnmmnnmnomnpmnqmnrmnsmnumnvmnwmnxmnymnzmoa\
+    mocmodmoemogmohmoimojmokmommoomopmoqmormosmotmoumovmowmoxmoymozmpampbmpcmpdmpempgmphmpimpjmpk\
+    mplmpmmpnmpomppmpqmprmpsmptmpumpvmpwmpxmpympzmqamqbmqcmqemqfmqgmqhmqimqjmqkmqlmqmmqnmqomqpmqq\
+    mqrmqsmqtmqumqvmqwmqxmqymqzmramrbmrcmrdmremrfmrgmrhmrjmrkmrlmrmmrnmromrpmrqmrrmrsmrtmrumrvmrw\
+    mrxmrymrzmsbmscmsdmsemsfmsgmshmsimsjmskmslmsmmsnmsomspmsqmsrmssmsumsvmswmsxmsymszmtamtbmtcmtd\
+    mtemtfmtgmthmtimtjmtkmtlmtmmtnmtomtpmtqmtrmtsmttmtumtvmtwmtxmtymuamubmucmudmuemugmuhmuimujmuk\
+    mulmummunmuomupmuqmurmusmutmuumuvmuxmuymuzmvamvbmvdmvemvfmvgmvhmvimvkmvlmvnmvomvpmvqmvrmvsmvt\
+    mvumvvmvwmvxmvymvzmwamwbmwcmwemwfmwgmwhmwimwkmwlmwmmwnmwomwpmwqmwrmwsmwtmwumwvmwwmwzmxamxbmxc\
+    mxdmxemxfmxgmxhmximxjmxkmxlmxmmxnmxomxpmxqmxrmxsmxtmxumxvmxwmxxmxymxzmybmycmyemyfmygmyhmyjmyk\
+    mylmymmynmyomypmyrmysmyumyvmywmyxmyymyzmzamzbmzcmzdmzemzgmzhmzimzjmzkmzlmzmmznmzomzpmzqmzrmzs\
+    mztmzumzvmzwmzxmzymzznaanabnacnaenafnagnahnainajnaknalnamnannaonapnaqnarnasnatnawnaxnaynaznba\
+    nbbnbcnbdnbenbgnbhnbinbjnbknbmnbnnbonbpnbqnbrnbsnbtnbunbvnbwnbyncancbnccncdncencfncgnchncincj\
+    ncknclncmncnnconcqncrncsnctncuncxnczndandbndcnddndfndgndhndindjndkndlndmndnndpndqndrndsndtndu\
+    ndvndwndxndyndzneanebnecnedneenefnegnehneinejneknemnenneoneqnernesnetneunevnewnexneyneznfanfd\
+    nflnfrnfungangbngcngdngengfnggnghngingjngknglngmngnngpngqngrngsngtngungvngwngxngyngznhanhbnhc\
+    nhdnhenhfnhgnhhnhinhknhmnhnnhonhpnhqnhrnhtnhunhvnhwnhxnhynhznianibnicnidnienifnignihniinijnik\
+    nilnimninnioniqnirnisnitniunivniwnixniyniznjanjbnjdnjhnjinjjnjlnjmnjnnjonjrnjsnjtnjunjxnjynjz\
+    nkankbnkcnkdnkenkfnkgnkhnkinkjnkknkmnknnkonkpnkqnkrnksnktnkunkvnkwnkxnkznlanlcnlenlgnlinljnlk\
+    nllnlmnlonlqnlunlvnlwnlxnlynlznmanmbnmcnmdnmenmfnmgnmhnminmjnmknmlnmmnmnnmonmpnmqnmrnmsnmtnmu\
+    nmvnmwnmxnmynmznnannbnncnndnnennfnngnnhnninnjnnknnlnnmnnnnnpnnqnnrnntnnunnvnnwnnynnznoanocnod\
+    noenofnognohnoinojnoknolnomnonnopnoqnosnotnounovnownoynoznpanpbnpgnphnpinplnpnnponpsnpunpxnpy\
+    nqgnqknqlnqmnqnnqonqqnqtnqynranrbnrcnrenrfnrgnrinrknrlnrmnrnnrpnrrnrtnrunrxnrznsansbnscnsdnse\
+    nsfnsgnshnsinsknslnsmnsnnsonspnsqnsrnssnstnsunsvnswnsxnsynszntdntentgntintjntkntmntontpntrntu\
+    ntwntxntyntznuanubnucnudnuenufnugnuhnuinujnuknulnumnunnuonupnuqnurnusnutnuunuvnuwnuxnuynuznvh\
+    nvmnvonwanwbnwcnwenwgnwinwmnwonwrnwwnwxnwynxanxdnxenxgnxinxknxlnxmnxnnxonxqnxrnxxnybnycnydnye\
+    nyfnygnyhnyinyjnyknylnymnynnyonypnyqnyrnysnytnyunyvnywnyxnyynzanzbnzdnzinzknzmnzsnzunzynzzoaa\
+    oacoaroavobiobkoblobmoboobrobtobuocaochocmocoocuodaodkodtoduofoofsofuogbogcogeoggogooguohtohu\
+    oiaoieoinojbojcojgojpojsojvojwokaokbokcokdokeokgokhokiokjokkoklokmoknokookroksokuokvokxokzola\
+    oldoleolkolmoloolroltoluomaombomcomgomiomkomlomnomoompomqomromtomuomvomwomxomyonaonboneongoni\
+    onjonkonnonoonponronsontonuonwonxoodoogoonooroosopaopkopmopooptopyoraorcoreorgorhornoroorrors\
+    ortoruorvorworxoryorzosaoscosiosnosoospostosuosxotaotbotdoteotiotkotlotmotnotootqotrotsottotu\
+    otwotxotyotzouaouboueouioumovdowiowloyboydoymoyyozmpaapabpacpadpaepafpagpahpaipakpalpampaopap\
+    paqparpaspaupavpawpaxpaypazpbbpbcpbepbfpbgpbhpbipblpbmpbnpbopbppbrpbspbtpbupbvpbypcapcbpccpcd\
+    pcepcfpcgpchpcipcjpckpclpcmpcnpcppcwpdapdcpdipdnpdopdtpdupeapebpedpeepefpegpehpeipejpekpelpem\
+    peopeppeqpespevpexpeypezpfapfepflpgapgdpggpgipgkpglpgnpgspgupgzphaphdphgphhphiphjphkphlphmphn\
+    phophqphrphtphuphvphwpiapibpicpidpiepifpigpihpijpilpimpinpiopippirpispitpiupivpiwpixpiypizpjt\
+    pkapkbpkcpkgpkhpknpkopkppkrpkspktpkuplaplbplcpldpleplfplgplhpljplkpllplnploplqplrplspltpluplv\
+    plwplyplzpmapmbpmdpmepmfpmhpmipmjpmkpmlpmmpmnpmopmqpmrpmspmtpmwpmxpmypmzpnapnbpncpndpnepngpnh\
+    pnipnjpnkpnlpnmpnnpnopnppnqpnrpnspntpnupnvpnwpnxpnypnzpocpoepofpogpohpoipokpomponpoopoppoqpos\
+    potpovpowpoxpoypozppeppippkpplppmppnppopppppqppspptppupqapqepqmpqwpraprcprdpreprfprgprhpriprk\
+    prlprmprnproprpprqprrprsprtpruprwprxprzpsapscpsdpsepsgpshpsipslpsmpsnpsopsppsqpsrpsspstpsupsw\
+    psyptapthptiptnptoptpptqptrpttptuptvptwptypuapubpucpudpuepufpugpuipujpumpuopuppuqpurputpuupuw\
+    puxpuypwapwbpwgpwipwmpwnpwopwrpwwpxmpyepympynpyspyupyxpyypzhpznquaqubqucqudqufqugquhquiqukqul\
+    qumqunqupquqqurqusquvquwquxquyquzqvaqvcqveqvhqviqvjqvlqvmqvnqvoqvpqvsqvwqvyqvzqwaqwcqweqwhqwm\
+    qwsqwtqxaqxcqxhqxlqxnqxoqxpqxqqxrqxsqxtqxuqxwqyaqypraarabracradrafragrahrairajrakralramranrao\
+    rapraqrarrasratrauravrawraxrayrazrbbrbkrblrbprcfrdbrearebreeregreirejrelremrenrerresretreyrga\
+    rgergkrgnrgrrgsrgurhgrhpriaribrifrilrimrinrirritriurjgrjirjsrkarkbrkhrkirkmrktrkwrmarmbrmcrmd\
+    rmermfrmgrmhrmirmkrmlrmmrmnrmormprmqrmsrmtrmurmvrmwrmxrmyrmzrnbrndrngrnlrnnrnprnrrnwroarobroc\
+    rodroerofrogrolromrooroprorrourowrpnrptrrirrorrtrsbrskrslrsmrsnrtcrthrtmrtsrtwrubrucruerufrug\
+    ruhruirukruorupruqrutruuruyruzrwarwkrwlrwmrworwrrxdrxwrynrysryurzhsaasabsacsadsaesafsahsaisaj\
+    saksalsamsaosaqsarsassatsausavsawsaxsaysazsbasbbsbcsbdsbesbfsbgsbhsbisbjsbksblsbmsbnsbosbpsbq\
+    sbrsbssbtsbusbvsbwsbxsbysbzscbscescfscgschsciscksclscnscoscpscqscssctscuscvscwscxsdasdbsdcsde\
+    sdfsdgsdhsdjsdksdlsdnsdosdpsdqsdrsdssdtsdusdvsdxsdzseasebsecsedseesefsegsehseisejsekselsemsen\
+    seosepseqsersessetseusevsewseysezsfbsfesfmsfssfwsgasgbsgcsgdsgesggsghsgisgjsgksgmsgnsgpsgrsgs\
+    sgtsgusgwsgxsgysgzshashbshcshdsheshgshhshishjshkshlshmshnshoshpshqshrshsshtshushvshwshxshyshz\
+    siasibsidsiesifsigsihsiisijsiksilsimsiosipsiqsirsissitsiusivsiwsixsiysizsjasjbsjdsjesjgsjksjl\
+    sjmsjnsjosjpsjrsjssjtsjusjwskaskbskcskdskeskfskgskhskiskjskmsknskoskpskqskrskssktskuskvskwskx\
+    skyskzslaslcsldsleslfslgslhslisljsllslmslnslpslqslrslssltsluslwslxslyslzsmasmbsmcsmfsmgsmhsmi\
+    smjsmksmlsmmsmnsmpsmqsmrsmssmtsmusmvsmwsmxsmysmzsncsnesnfsngsnisnjsnksnlsnmsnnsnosnpsnqsnrsns\
+    snusnvsnwsnxsnysnzsoasobsocsodsoesogsohsoisojsoksolsonsoosopsoqsorsossousovsowsoxsoysozspbspc\
+    spdspespgspispksplspmspnsposppspqsprspssptspuspvspxspysqasqhsqjsqksqmsqnsqosqqsqrsqssqtsqusqx\
+    srasrbsrcsresrfsrgsrhsrisrksrlsrmsrnsrosrqsrrsrssrtsrusrvsrwsrxsrysrzssassbsscssdssessfssgssh\
+    ssissjssksslssmssnssosspssqssrssssstssussvssxssysszstastbstdstestfstgsthstistjstkstlstmstnsto\
+    stpstqstrstssttstustvstwstysuasubsucsuesugsuisujsuksuosuqsursussutsuvsuwsuxsuysuzsvasvbsvcsve\
+    svksvmsvssvxswbswcswfswgswhswiswjswkswlswmswnswoswpswqswrswsswtswuswvswwswxswysxbsxcsxesxgsxk\
+    sxlsxmsxnsxosxrsxssxusxwsyasybsycsydsyisyksylsymsynsyosyrsyssywsyxsyyszaszbszcszdszeszgszlszn\
+    szpszsszvszwszytaatabtactadtaetaftagtaitajtaktaltantaotaptaqtartastautavtawtaxtaytaztbatbctbd\
+    tbetbftbgtbhtbitbjtbktbltbmtbntbotbptbqtbrtbstbttbutbvtbwtbxtbytbztcatcbtcctcdtcetcftcgtchtci\
+    tcktcltcmtcntcotcptcqtcstcttcutcwtcxtcytcztdatdbtdctddtdetdftdgtdhtditdjtdktdltdmtdntdotdqtdr\
+    tdstdttdvtdxtdyteatebtectedteeteftegtehteitektemtenteotepteqtertestetteutevtewtexteyteztfitfn\
+    tfotfrtfttgatgbtgctgdtgetgftghtgitgjtgntgotgptgqtgrtgstgttgutgvtgwtgxtgytgzthdthethfthhthithk\
+    thlthmthnthpthqthrthsthtthuthvthythztiatictiftigtihtiitijtiktiltimtintiotiptiqtistittiutivtiw\
+    tixtiytiztjatjgtjitjjtjltjmtjntjotjptjstjutjwtkatkbtkdtketkftkgtkltkmtkntkptkqtkrtkstkttkutkv\
+    tkwtkxtkztlatlbtlctldtlftlgtlhtlitljtlktlltlmtlntlotlptlqtlrtlstlttlutlvtlxtlytmatmbtmctmdtme\
+    tmftmgtmhtmitmjtmktmltmmtmntmotmqtmrtmstmttmutmvtmwtmytmztnatnbtnctndtngtnhtnitnktnltnmtnntno\
+    tnptnqtnrtnstnttnutnvtnwtnxtnytnztobtoctodtoftogtohtoitojtoktoltomtootoptoqtortostoutovtowtox\
+    toytoztpatpctpetpftpgtpitpjtpktpltpmtpntpotpptpqtprtpttputpvtpwtpxtpytpztqbtqltqmtqntqotqptqq\
+    tqrtqttqutqwtratrbtrctrdtretrftrgtrhtritrjtrktrltrmtrntrotrptrqtrrtrstrttrutrvtrwtrxtrytrztsa\
+    tsbtsctsdtsetsgtshtsitsjtsktsltsmtsptsqtsrtsststtsutsvtswtsxtsytszttattbttcttdttettfttgtthtti\
+    ttjttkttlttmttnttottpttqttrttstttttuttvttwttyttztuatubtuctudtuetuftugtuhtuitujtultumtuntuotup\
+    tuqtustuttuutuvtuwtuxtuytuztvatvdtvetvktvltvmtvntvotvstvttvutvwtvxtvytwatwbtwctwdtwetwftwgtwh\
+    twltwmtwntwotwptwqtwrtwttwutwwtwxtwytxatxbtxctxetxgtxhtxitxjtxmtxntxotxqtxrtxstxttxutxxtxytya\
+    tyetyhtyityjtyltyntyptyrtystyttyutyvtyxtyytyztzatzhtzjtzltzmtzntzotzxuamuanuarubaubiublubrubu\
+    ubyudaudeudgudiudjudludmuduuesufiugaugbugeughugnugougyuhauhnuisuivujiukaukgukhukiukkuklukpukq\
+    uksukuukvukwukyulaulbulculeulfuliulkullulmulnuluulwumaumbumcumdumgumiummumnumoumpumrumsumuuna\
+    unduneunguniunkunmunnunrunuunxunzuonupiupvuraurburcureurfurgurhuriurjurkurlurmurnurourpurrurt\
+    uruurvurwurxuryurzusaushusiuskuspussusuutauteuthutputrutuuumuuruuuuveuvhuvluwauyauznuzsvaavae\
+    vafvagvahvaivajvalvamvanvaovapvarvasvauvavvayvbbvbkvecvedvelvemveovepvervgrvgtvicvidvifvigvil\
+    vinvisvitvivvkavkjvkkvklvkmvknvkovkpvktvkuvkzvlpvlsvmavmbvmcvmdvmevmfvmgvmhvmivmjvmkvmlvmmvmp\
+    vmqvmrvmsvmuvmvvmwvmxvmyvmzvnkvnmvnpvorvotvravrovrsvrtvsivslvsvvtovumvunvutvwawaawabwacwadwae\
+    wafwagwahwaiwajwakwalwamwanwaowapwaqwarwaswatwauwavwawwaxwaywazwbawbbwbewbfwbhwbiwbjwbkwblwbm\
+    wbpwbqwbrwbswbtwbvwbwwcawciwddwdgwdjwdkwdtwduwdyweawecwedwegwehweiwemwenweowepwerweswetweuwew\
+    wfgwgawgbwggwgiwgowguwgywhawhgwhkwhuwibwicwiewifwigwihwiiwijwikwilwimwinwirwiuwivwiywjawjiwka\
+    wkbwkdwklwkrwkuwkwwkywlawlcwlewlgwlhwliwlkwllwlmwlowlrwlswluwlvwlwwlxwlywmawmbwmcwmdwmewmgwmh\
+    wmiwmmwmnwmowmswmtwmwwmxwnbwncwndwnewngwniwnkwnmwnnwnownpwnuwnwwnywoawobwocwodwoewofwogwoiwok\
+    womwonwooworwoswowwoywpcwrbwrgwrhwriwrkwrlwrmwrnwrowrpwrrwrswruwrvwrwwrxwrywrzwsawsgwsiwskwsr\
+    wsswsuwsvwtfwthwtiwtkwtmwtwwuawubwudwuhwulwumwunwurwutwuuwuvwuxwuywwawwbwwowwrwwwwxawxwwybwyi\
+    wymwynwyrwyyxaaxabxacxadxaexagxaixajxakxalxamxanxaoxapxaqxarxasxatxauxavxawxayxbbxbcxbdxbexbg\
+    xbixbjxbmxbnxboxbpxbrxbwxbyxcbxccxcexcgxchxclxcmxcnxcoxcrxctxcuxcvxcwxcyxdaxdcxdkxdmxdoxdqxdy\
+    xebxedxegxelxemxepxerxesxetxeuxfaxgaxgbxgdxgfxggxgixglxgmxgnxgrxguxgwxhaxhcxhdxhexhmxhrxhtxhu\
+    xhvxibxiixilxinxirxisxivxiyxjbxjtxkaxkbxkcxkdxkexkfxkgxkixkjxkkxklxknxkoxkpxkqxkrxksxktxkuxkv\
+    xkwxkxxkyxkzxlaxlbxlcxldxlexlgxlixlnxloxlpxlsxluxlyxmaxmbxmcxmdxmexmfxmgxmhxmjxmkxmlxmmxmnxmo\
+    xmpxmqxmrxmsxmtxmuxmvxmwxmxxmyxmzxnaxnbxndxngxnhxnixnjxnkxnmxnnxnoxnqxnrxnsxntxnuxnyxnzxocxod\
+    xogxoixokxomxonxooxopxorxowxpaxpbxpcxpdxpexpfxpgxphxpixpjxpkxplxpmxpnxpoxppxpqxprxpsxptxpuxpv\
+    xpwxpxxpyxpzxqaxqtxraxrbxrdxrexrgxrixrmxrnxrrxrtxruxrwxsaxsbxscxsdxsexshxsixsjxslxsmxsnxsoxsp\
+    xsqxsrxssxsuxsvxsyxtaxtbxtcxtdxtextgxthxtixtjxtlxtmxtnxtoxtpxtqxtrxtsxttxtuxtvxtwxtyxuaxubxud\
+    xugxujxulxumxunxuoxupxurxutxuuxvexvixvnxvoxvsxwaxwcxwdxwexwgxwjxwkxwlxwoxwrxwtxwwxxbxxkxxmxxr\
+    xxtxyaxybxyjxykxylxytxyyxzhxzmxzpyaayabyacyadyaeyafyagyahyaiyajyakyalyamyanyaoyapyaqyaryasyat\
+    yauyavyawyaxyayyazybaybbybeybhybiybjybkyblybmybnyboybxybyychyclycnycpydayddydeydgydkyeayecyee\
+    yeiyejyelyeryesyetyeuyevyeyygaygiyglygmygpygrygsyguygwyhayhdyhlyhsyiayifyigyihyiiyijyikyilyim\
+    yinyipyiqyiryisyityiuyivyixyizykaykgykiykkyklykmyknykoykryktykuykyylaylbyleylgyliyllylmylnylo\
+    ylryluylyymbymcymdymeymgymhymiymkymlymmymnymoympymqymrymsymxymzynayndyneyngynkynlynnynoynqyns\
+    ynuyobyogyoiyokyolyomyonyotyoxyoyypaypbypgyphypkypmypnypoyppypzyrayrbyreyrkyrlyrmyrnyroyrsyrw\
+    yryyscysdysgyslysmysnysoyspysryssysyytaytlytpytwytyyuayubyucyudyueyufyugyuiyujyukyulyumyunyup\
+    yuqyuryutyuwyuxyuyyuzyvayvtywaywgywlywnywqywrywtywuywwyxayxgyxlyxmyxuyxyyyryyuyyzyzgyzkzaazab\
+    zaczadzaezafzagzahzaizajzakzalzamzaozapzaqzarzaszatzauzavzawzaxzayzazzbazbczbezblzbtzbuzbwzca\
+    zcdzchzdjzeazegzehzenzgazgbzghzgmzgnzgrzhbzhdzhizhnzhwzhxziazibzikzilzimzinziwzizzkazkbzkdzkg\
+    zkhzkkzknzkozkpzkrzktzkuzkvzkzzlazlezljzlmzlnzlqzlszlwzmazmbzmczmdzmezmfzmgzmhzmizmjzmkzmlzmm\
+    zmnzmozmpzmqzmrzmszmtzmuzmvzmwzmxzmyzmzznazndznezngznkznszoczohzomzoozoqzorzoszpazpbzpczpdzpe\
+    zpfzpgzphzpizpjzpkzplzpmzpnzpozppzpqzprzpszptzpuzpvzpwzpxzpyzpzzqezrazrgzrnzrozrpzrszsazskzsl\
+    zsmzsrzsuzteztgztlztmztnztpztqztszttztuztxztyzuazuhzumzunzuyzwazxxzybzygzyjzynzypzzazzj\
+";
+
+/// ISO 15924 script codes, sorted.
+///
+/// The private use range `Qaaa` to `Qabx` is checked separately.
+const SCRIPTS: &[&str] = &[
+    "Adlm", "Afak", "Aghb", "Ahom", "Arab", "Aran", "Armi", "Armn", "Avst", "Bali", "Bamu", "Bass",
+    "Batk", "Beng", "Bhks", "Blis", "Bopo", "Brah", "Brai", "Bugi", "Buhd", "Cakm", "Cans", "Cari",
+    "Cham", "Cher", "Cirt", "Copt", "Cprt", "Cyrl", "Cyrs", "Deva", "Dsrt", "Dupl", "Egyd", "Egyh",
+    "Egyp", "Elba", "Ethi", "Geok", "Geor", "Glag", "Goth", "Gran", "Grek", "Gujr", "Guru", "Hanb",
+    "Hang", "Hani", "Hano", "Hans", "Hant", "Hatr", "Hebr", "Hira", "Hluw", "Hmng", "Hrkt", "Hung",
+    "Inds", "Ital", "Jamo", "Java", "Jpan", "Jurc", "Kali", "Kana", "Khar", "Khmr", "Khoj", "Kitl",
+    "Kits", "Knda", "Kore", "Kpel", "Kthi", "Lana", "Laoo", "Latf", "Latg", "Latn", "Leke", "Lepc",
+    "Limb", "Lina", "Linb", "Lisu", "Loma", "Lyci", "Lydi", "Mahj", "Mand", "Mani", "Marc", "Maya",
+    "Mend", "Merc", "Mero", "Mlym", "Modi", "Mong", "Moon", "Mroo", "Mtei", "Mult", "Mymr", "Narb",
+    "Nbat", "Newa", "Nkgb", "Nkoo", "Nshu", "Ogam", "Olck", "Orkh", "Orya", "Osge", "Osma", "Palm",
+    "Pauc", "Perm", "Phag", "Phli", "Phlp", "Phlv", "Phnx", "Piqd", "Plrd", "Prti", "Rjng", "Roro",
+    "Runr", "Samr", "Sara", "Sarb", "Saur", "Sgnw", "Shaw", "Shrd", "Sidd", "Sind", "Sinh", "Sora",
+    "Sund", "Sylo", "Syrc", "Syre", "Syrj", "Syrn", "Tagb", "Takr", "Tale", "Talu", "Taml", "Tang",
+    "Tavt", "Telu", "Teng", "Tfng", "Tglg", "Thaa", "Thai", "Tibt", "Tirh", "Ugar", "Vaii", "Visp",
+    "Wara", "Wole", "Xpeo", "Xsux", "Yiii", "Zinh", "Zmth", "Zsye", "Zsym", "Zxxx", "Zyyy", "Zzzz",
+];
+
+/// UN M.49 area codes in the language subtag registry, sorted.
+const REGIONS: &[&str] = &[
+    "001", "002", "003", "005", "009", "011", "013", "014", "015", "017", "018", "019", "021",
+    "029", "030", "034", "035", "039", "053", "054", "057", "061", "142", "143", "145", "150",
+    "151", "154", "155", "202", "419",
+];
+
+/// Grandfathered tags which do not match the language tag syntax.
+const IRREGULAR: &[&str] = &[
+    "en-GB-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "sgn-BE-FR",
+    "sgn-BE-NL",
+    "sgn-CH-DE",
+];
+
+/// Language tag validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum LanguageTagError {
+    /// Malformed language tag error.
+    Malformed {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: LanguageTagErrorCode,
+
+        /// The actual value.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unknown language subtag error.
+    UnknownLanguage {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: LanguageTagErrorCode,
+
+        /// The language subtag.
+        subtag: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unknown script subtag error.
+    UnknownScript {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: LanguageTagErrorCode,
+
+        /// The script subtag.
+        subtag: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unknown region subtag error.
+    UnknownRegion {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: LanguageTagErrorCode,
+
+        /// The region subtag.
+        subtag: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed language error.
+    Disallowed {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: LanguageTagErrorCode,
+
+        /// The allowed languages.
+        allowed: Vec<String>,
+
+        /// The actual language subtag.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for LanguageTagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for LanguageTagError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for LanguageTagError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("LanguageTagError", |_| {
+            [
+                error_type(
+                    LANGUAGE_TAG_ERROR_CODE,
+                    Some("malformed"),
+                    &[("value", "string")],
+                ),
+                error_type(
+                    LANGUAGE_TAG_ERROR_CODE,
+                    Some("unknownLanguage"),
+                    &[("subtag", "string")],
+                ),
+                error_type(
+                    LANGUAGE_TAG_ERROR_CODE,
+                    Some("unknownScript"),
+                    &[("subtag", "string")],
+                ),
+                error_type(
+                    LANGUAGE_TAG_ERROR_CODE,
+                    Some("unknownRegion"),
+                    &[("subtag", "string")],
+                ),
+                error_type(
+                    LANGUAGE_TAG_ERROR_CODE,
+                    Some("disallowed"),
+                    &[("allowed", "string[]"), ("value", "string")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "LanguageTagError".to_owned()
+    }
+}
+
+/// Validate a language tag.
+pub trait ValidateLanguageTag {
+    /// The language tag.
+    fn language_tag(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate language tag.
+    fn validate_language_tag(&self, allowed: Option<&[&str]>) -> Result<(), LanguageTagError> {
+        self.language_tag()
+            .map(|language_tag| validate_language_tag(&language_tag, allowed))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate a BCP 47 language tag (e.g. `en-US` or `zh-Hant-TW`).
+///
+/// The tag must be well-formed according to [RFC 5646](https://datatracker.ietf.org/doc/html/rfc5646). Subtags are
+/// case-insensitive. The language, script and region subtags are checked against ISO 639, ISO 15924 and the country
+/// codes of [`country_code`](super::parse_country_code) or UN M.49 area codes. Extended language, variant and extension
+/// subtags are only checked for well-formedness.
+///
+/// The allowed languages are compared with the primary language subtag (e.g. `en` for `en-US`).
+pub fn validate_language_tag(
+    value: &str,
+    allowed: Option<&[&str]>,
+) -> Result<(), LanguageTagError> {
+    let language = parse_language_tag(value)?;
+
+    if let Some(allowed) = allowed
+        && !allowed
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(language))
+    {
+        let language = language.to_ascii_lowercase();
+
+        return Err(LanguageTagError::Disallowed {
+            code: LanguageTagErrorCode,
+            allowed: allowed
+                .iter()
+                .map(|allowed| (*allowed).to_owned())
+                .collect(),
+            #[cfg(feature = "message")]
+            message: format!(
+                "language `{language}` is not allowed, must be one of `{}`",
+                allowed.join(", ")
+            ),
+            value: language,
+        });
+    }
+
+    Ok(())
+}
+
+/// Parse a language tag and return the primary language subtag.
+fn parse_language_tag(value: &str) -> Result<&str, LanguageTagError> {
+    let malformed = || LanguageTagError::Malformed {
+        code: LanguageTagErrorCode,
+        value: value.to_owned(),
+        #[cfg(feature = "message")]
+        message: format!("malformed language tag `{value}`"),
+    };
+
+    let subtags = value.split('-').collect::<Vec<_>>();
+    if subtags.iter().any(|subtag| {
+        subtag.is_empty()
+            || subtag.len() > 8
+            || !subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+    }) {
+        return Err(malformed());
+    }
+
+    let language = subtags[0];
+
+    if IRREGULAR
+        .iter()
+        .any(|irregular| irregular.eq_ignore_ascii_case(value))
+    {
+        return Ok(language);
+    }
+
+    let mut subtags = subtags.into_iter().peekable();
+
+    // Private use tag (e.g. `x-whatever`).
+    if language.eq_ignore_ascii_case("x") {
+        subtags.next();
+
+        return if subtags.next().is_some() {
+            Ok(language)
+        } else {
+            Err(malformed())
+        };
+    }
+
+    if !(2..=8).contains(&language.len()) || !is_alphabetic(language) {
+        return Err(malformed());
+    }
+    if !is_language(language) {
+        return Err(LanguageTagError::UnknownLanguage {
+            code: LanguageTagErrorCode,
+            subtag: language.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("unknown language subtag `{language}`"),
+        });
+    }
+    subtags.next();
+
+    // Extended language subtags.
+    if language.len() <= 3 {
+        for _ in 0..3 {
+            match subtags.peek() {
+                Some(subtag) if subtag.len() == 3 && is_alphabetic(subtag) => {
+                    subtags.next();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    if let Some(script) = subtags.next_if(|subtag| subtag.len() == 4 && is_alphabetic(subtag))
+        && !is_script(script)
+    {
+        return Err(LanguageTagError::UnknownScript {
+            code: LanguageTagErrorCode,
+            subtag: script.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("unknown script subtag `{script}`"),
+        });
+    }
+
+    if let Some(region) = subtags.next_if(|subtag| {
+        (subtag.len() == 2 && is_alphabetic(subtag))
+            || (subtag.len() == 3 && subtag.bytes().all(|byte| byte.is_ascii_digit()))
+    }) && !is_region(region)
+    {
+        return Err(LanguageTagError::UnknownRegion {
+            code: LanguageTagErrorCode,
+            subtag: region.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("unknown region subtag `{region}`"),
+        });
+    }
+
+    // Variant subtags.
+    while subtags
+        .next_if(|subtag| {
+            subtag.len() >= 5 || (subtag.len() == 4 && subtag.as_bytes()[0].is_ascii_digit())
+        })
+        .is_some()
+    {}
+
+    // Extension and private use subtags.
+    while let Some(singleton) = subtags.next() {
+        if singleton.len() != 1 {
+            return Err(malformed());
+        }
+
+        let private_use = singleton.eq_ignore_ascii_case("x");
+        let mut count = 0;
+
+        while subtags
+            .next_if(|subtag| private_use || subtag.len() >= 2)
+            .is_some()
+        {
+            count += 1;
+        }
+
+        if count == 0 {
+            return Err(malformed());
+        }
+    }
+
+    Ok(language)
+}
+
+fn is_alphabetic(subtag: &str) -> bool {
+    subtag.bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+fn is_language(subtag: &str) -> bool {
+    let subtag = subtag.to_ascii_lowercase();
+
+    match subtag.len() {
+        2 => LANGUAGES_2.binary_search(&subtag.as_str()).is_ok(),
+        3 => {
+            // Private use range.
+            if ("qaa"..="qtz").contains(&subtag.as_str()) {
+                return true;
+            }
+
+            let (languages, _) = LANGUAGES_3.as_bytes().as_chunks::<3>();
+            languages
+                .binary_search_by(|language| language.as_slice().cmp(subtag.as_bytes()))
+                .is_ok()
+        }
+        _ => false,
+    }
+}
+
+fn is_script(subtag: &str) -> bool {
+    let mut script = subtag.to_ascii_lowercase();
+    script[..1].make_ascii_uppercase();
+
+    // Private use range.
+    ("Qaaa"..="Qabx").contains(&script.as_str()) || SCRIPTS.binary_search(&script.as_str()).is_ok()
+}
+
+fn is_region(subtag: &str) -> bool {
+    let region = subtag.to_ascii_uppercase();
+
+    // Private use codes.
+    matches!(region.as_str(), "AA" | "ZZ")
+        || ("QM"..="QZ").contains(&region.as_str())
+        || ("XA"..="XZ").contains(&region.as_str())
+        || country_from_alpha_2(&region).is_some()
+        || REGIONS.binary_search(&region.as_str()).is_ok()
+}
+
+impl ValidateLanguageTag for str {
+    fn language_tag(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateLanguageTag for &str {
+    fn language_tag(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateLanguageTag for String {
+    fn language_tag(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateLanguageTag for Cow<'_, str> {
+    fn language_tag(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateLanguageTag for Option<T>
+where
+    T: ValidateLanguageTag,
+{
+    fn language_tag(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self {
+            T::language_tag(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateLanguageTag for $type
+        where
+            T: ValidateLanguageTag,
+        {
+            fn language_tag(&self) -> Option<Cow<'_, str>> {
+                T::language_tag(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{
+        LANGUAGES_2, LANGUAGES_3, LanguageTagError, LanguageTagErrorCode, REGIONS, SCRIPTS,
+        ValidateLanguageTag,
+    };
+
+    #[test]
+    fn tables_sorted() {
+        assert!(LANGUAGES_2.is_sorted());
+        assert!(LANGUAGES_3.len().is_multiple_of(3));
+        assert!(LANGUAGES_3.as_bytes().as_chunks::<3>().0.is_sorted());
+        assert!(SCRIPTS.is_sorted());
+        assert!(REGIONS.is_sorted());
+    }
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"en-US").validate_language_tag(None), Ok(()));
+        assert_eq!("en-US".validate_language_tag(None), Ok(()));
+        assert_eq!("en-US".to_owned().validate_language_tag(None), Ok(()));
+        assert_eq!(
+            Cow::<str>::Borrowed("en-US").validate_language_tag(None),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_language_tag(None), Ok(()));
+        assert_eq!(Some("en-US").validate_language_tag(None), Ok(()));
+
+        assert_eq!((&"en-US").validate_language_tag(None), Ok(()));
+        assert_eq!(Box::new("en-US").validate_language_tag(None), Ok(()));
+        assert_eq!(Arc::new("en-US").validate_language_tag(None), Ok(()));
+        assert_eq!(Rc::new("en-US").validate_language_tag(None), Ok(()));
+
+        let cell = RefCell::new("en-US");
+        assert_eq!(cell.borrow().validate_language_tag(None), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_language_tag(None), Ok(()));
+
+        for value in [
+            "nl",
+            "NL-nl",
+            "gsw",
+            "zh-Hant-TW",
+            "zh-yue-HK",
+            "sr-Latn-RS",
+            "es-419",
+            "sl-rozaj-biske",
+            "de-CH-1901",
+            "en-US-u-ca-gregory",
+            "en-a-bbb-x-a-ccc",
+            "qaa-Qaaa-QM",
+            "x-whatever",
+            "i-klingon",
+            "zh-min-nan",
+        ] {
+            assert_eq!(value.validate_language_tag(None), Ok(()), "{value}");
+        }
+
+        assert_eq!("EN-gb".validate_language_tag(Some(&["en", "nl"])), Ok(()));
+    }
+
+    #[test]
+    fn malformed_error() {
+        for value in [
+            "",
+            "en-",
+            "en--US",
+            "en_US",
+            "e",
+            "en-US-u",
+            "en-US-x",
+            "abcdefghi",
+            "1en",
+            "en-a-b",
+            "en-QA1",
+            "x",
+        ] {
+            assert_eq!(
+                value.validate_language_tag(None),
+                Err(LanguageTagError::Malformed {
+                    code: LanguageTagErrorCode,
+                    value: value.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("malformed language tag `{value}`"),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_language_error() {
+        for value in ["qq", "eng", "abcd-US"] {
+            let subtag = value.split('-').next().unwrap_or_default();
+
+            assert_eq!(
+                value.validate_language_tag(None),
+                Err(LanguageTagError::UnknownLanguage {
+                    code: LanguageTagErrorCode,
+                    subtag: subtag.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("unknown language subtag `{subtag}`"),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_script_error() {
+        assert_eq!(
+            "en-Abcd".validate_language_tag(None),
+            Err(LanguageTagError::UnknownScript {
+                code: LanguageTagErrorCode,
+                subtag: "Abcd".to_owned(),
+                #[cfg(feature = "message")]
+                message: "unknown script subtag `Abcd`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_region_error() {
+        for subtag in ["YY", "999"] {
+            let value = format!("en-{subtag}");
+
+            assert_eq!(
+                value.validate_language_tag(None),
+                Err(LanguageTagError::UnknownRegion {
+                    code: LanguageTagErrorCode,
+                    subtag: subtag.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("unknown region subtag `{subtag}`"),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn disallowed_error() {
+        assert_eq!(
+            "FR-be".validate_language_tag(Some(&["en", "nl"])),
+            Err(LanguageTagError::Disallowed {
+                code: LanguageTagErrorCode,
+                allowed: vec!["en".to_owned(), "nl".to_owned()],
+                value: "fr".to_owned(),
+                #[cfg(feature = "message")]
+                message: "language `fr` is not allowed, must be one of `en, nl`".to_owned(),
+            })
+        );
+    }
+}
@@ -35,6 +35,13 @@ pub enum LengthError<T> {
         /// The actual length.
         value: T,
 
+        /// The unit the length is measured in, if specified.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        unit: Option<LengthUnit>,
+
         /// The error code.
         #[cfg_attr(feature = "serde", serde(default))]
         #[cfg_attr(feature = "utoipa", schema(inline, required))]
@@ -52,6 +59,13 @@ pub enum LengthError<T> {
         /// The actual length.
         value: T,
 
+        /// The unit the length is measured in, if specified.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        unit: Option<LengthUnit>,
+
         /// The error code.
         #[cfg_attr(feature = "serde", serde(default))]
         #[cfg_attr(feature = "utoipa", schema(inline, required))]
@@ -69,6 +83,13 @@ pub enum LengthError<T> {
         /// The length.
         value: T,
 
+        /// The unit the length is measured in, if specified.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        unit: Option<LengthUnit>,
+
         /// The error code.
         #[cfg_attr(feature = "serde", serde(default))]
         #[cfg_attr(feature = "utoipa", schema(inline, required))]
@@ -88,6 +109,140 @@ impl<T: Debug> fmt::Display for LengthError<T> {
 
 impl<T: Debug> Error for LengthError<T> {}
 
+#[cfg(feature = "zod")]
+impl<T> crate::zod::ToTypeScript for LengthError<T>
+where
+    T: crate::zod::ToTypeScript,
+{
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        #[cfg(not(feature = "unicode-segmentation"))]
+        const UNIT_TYPESCRIPT: &str = "\"bytes\" | \"chars\" | \"utf16\"";
+        #[cfg(feature = "unicode-segmentation")]
+        const UNIT_TYPESCRIPT: &str = "\"bytes\" | \"chars\" | \"utf16\" | \"graphemes\"";
+
+        generator.define_type("LengthError<T>", |_| {
+            [
+                error_type(
+                    LENGTH_ERROR_CODE,
+                    Some("equal"),
+                    &[("equal", "T"), ("value", "T"), ("unit?", UNIT_TYPESCRIPT)],
+                ),
+                error_type(
+                    LENGTH_ERROR_CODE,
+                    Some("min"),
+                    &[("min", "T"), ("value", "T"), ("unit?", UNIT_TYPESCRIPT)],
+                ),
+                error_type(
+                    LENGTH_ERROR_CODE,
+                    Some("max"),
+                    &[("max", "T"), ("value", "T"), ("unit?", UNIT_TYPESCRIPT)],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        format!("LengthError<{}>", T::typescript(generator))
+    }
+}
+
+/// Unit to measure a string length in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum LengthUnit {
+    /// UTF-8 bytes.
+    Bytes,
+    /// Unicode scalar values.
+    Chars,
+    /// UTF-16 code units.
+    Utf16,
+    /// Extended grapheme clusters (user-perceived characters).
+    #[cfg(feature = "unicode-segmentation")]
+    Graphemes,
+}
+
+impl LengthUnit {
+    /// Measure the length of a string in this unit.
+    pub fn measure(self, value: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => value.len(),
+            LengthUnit::Chars => value.chars().count(),
+            LengthUnit::Utf16 => value.encode_utf16().count(),
+            #[cfg(feature = "unicode-segmentation")]
+            LengthUnit::Graphemes => {
+                unicode_segmentation::UnicodeSegmentation::graphemes(value, true).count()
+            }
+        }
+    }
+}
+
+fn check_length<T>(
+    length: T,
+    equal: Option<T>,
+    min: Option<T>,
+    max: Option<T>,
+    unit: Option<LengthUnit>,
+) -> Result<(), LengthError<T>>
+where
+    T: Display + PartialEq + PartialOrd,
+{
+    if let Some(equal) = equal {
+        if length != equal {
+            #[cfg(feature = "message")]
+            let message = format!("length {length} is not equal to required length {equal}");
+
+            return Err(LengthError::Equal {
+                equal,
+                value: length,
+                unit,
+                code: LengthErrorCode,
+                #[cfg(feature = "message")]
+                message,
+            });
+        }
+    } else {
+        if let Some(min) = min
+            && length < min
+        {
+            #[cfg(feature = "message")]
+            let message = format!("length {length} is less than minimum length {min}");
+
+            return Err(LengthError::Min {
+                min,
+                value: length,
+                unit,
+                code: LengthErrorCode,
+                #[cfg(feature = "message")]
+                message,
+            });
+        }
+
+        if let Some(max) = max
+            && length > max
+        {
+            #[cfg(feature = "message")]
+            let message = format!("length {length} is greater than maximum length {max}");
+
+            return Err(LengthError::Max {
+                max,
+                value: length,
+                unit,
+                code: LengthErrorCode,
+                #[cfg(feature = "message")]
+                message,
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate a length.
 pub trait ValidateLength<T>
 where
@@ -107,52 +262,59 @@ where
             return Ok(());
         };
 
-        if let Some(equal) = equal {
-            if length != equal {
-                #[cfg(feature = "message")]
-                let message = format!("length {length} is not equal to required length {equal}");
+        check_length(length, equal, min, max, None)
+    }
+}
 
-                return Err(LengthError::Equal {
-                    equal,
-                    value: length,
-                    code: LengthErrorCode,
-                    #[cfg(feature = "message")]
-                    message,
-                });
-            }
-        } else {
-            if let Some(min) = min
-                && length < min
-            {
-                #[cfg(feature = "message")]
-                let message = format!("length {length} is less than minimum length {min}");
+/// Validate a string length measured in a [`LengthUnit`].
+pub trait ValidateLengthUnit {
+    /// The string to measure.
+    fn length_value(&self) -> Option<&str>;
 
-                return Err(LengthError::Min {
-                    min,
-                    value: length,
-                    code: LengthErrorCode,
-                    #[cfg(feature = "message")]
-                    message,
-                });
-            }
+    /// Validate length measured in the unit.
+    fn validate_length_in(
+        &self,
+        equal: Option<usize>,
+        min: Option<usize>,
+        max: Option<usize>,
+        unit: LengthUnit,
+    ) -> Result<(), LengthError<usize>> {
+        let Some(value) = self.length_value() else {
+            return Ok(());
+        };
 
-            if let Some(max) = max
-                && length > max
-            {
-                #[cfg(feature = "message")]
-                let message = format!("length {length} is greater than maximum length {max}");
+        check_length(unit.measure(value), equal, min, max, Some(unit))
+    }
+}
 
-                return Err(LengthError::Max {
-                    max,
-                    value: length,
-                    code: LengthErrorCode,
-                    #[cfg(feature = "message")]
-                    message,
-                });
-            }
-        }
+impl ValidateLengthUnit for str {
+    fn length_value(&self) -> Option<&str> {
+        Some(self)
+    }
+}
 
-        Ok(())
+impl ValidateLengthUnit for String {
+    fn length_value(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl ValidateLengthUnit for Cow<'_, str> {
+    fn length_value(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T> ValidateLengthUnit for Option<T>
+where
+    T: ValidateLengthUnit,
+{
+    fn length_value(&self) -> Option<&str> {
+        if let Some(value) = self {
+            T::length_value(value)
+        } else {
+            None
+        }
     }
 }
 
@@ -238,6 +400,26 @@ validate_with_deref!(Rc<T>);
 validate_with_deref!(Ref<'_, T>);
 validate_with_deref!(RefMut<'_, T>);
 
+macro_rules! validate_unit_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateLengthUnit for $type
+        where
+            T: ValidateLengthUnit + ?Sized,
+        {
+            fn length_value(&self) -> Option<&str> {
+                T::length_value(self)
+            }
+        }
+    };
+}
+
+validate_unit_with_deref!(&T);
+validate_unit_with_deref!(Arc<T>);
+validate_unit_with_deref!(Box<T>);
+validate_unit_with_deref!(Rc<T>);
+validate_unit_with_deref!(Ref<'_, T>);
+validate_unit_with_deref!(RefMut<'_, T>);
+
 impl<L, T> ValidateLength<L> for Cow<'_, T>
 where
     L: Display + PartialEq + PartialOrd,
@@ -264,7 +446,7 @@ mod tests {
 
     use crate::LengthErrorCode;
 
-    use super::{LengthError, ValidateLength};
+    use super::{LengthError, LengthUnit, ValidateLength, ValidateLengthUnit};
 
     #[test]
     fn ok() {
@@ -339,6 +521,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -349,6 +532,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -359,6 +543,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -369,6 +554,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -379,6 +565,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -390,6 +577,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -401,6 +589,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -411,6 +600,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -421,6 +611,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -431,6 +622,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -441,6 +633,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -451,6 +644,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -461,6 +655,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -471,6 +666,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -484,6 +680,7 @@ mod tests {
                 Err(LengthError::Equal {
                     equal: 2,
                     value: 1,
+                    unit: None,
                     code: LengthErrorCode,
                     #[cfg(feature = "message")]
                     message: "length 1 is not equal to required length 2".to_owned(),
@@ -494,6 +691,7 @@ mod tests {
                 Err(LengthError::Equal {
                     equal: 2,
                     value: 1,
+                    unit: None,
                     code: LengthErrorCode,
                     #[cfg(feature = "message")]
                     message: "length 1 is not equal to required length 2".to_owned(),
@@ -506,6 +704,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -516,6 +715,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -526,6 +726,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -536,6 +737,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -548,6 +750,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -558,6 +761,7 @@ mod tests {
             Err(LengthError::Equal {
                 equal: 2,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is not equal to required length 2".to_owned(),
@@ -572,6 +776,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -582,6 +787,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -592,6 +798,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -602,6 +809,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -612,6 +820,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -623,6 +832,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -634,6 +844,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -644,6 +855,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -654,6 +866,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -664,6 +877,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -674,6 +888,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -684,6 +899,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -694,6 +910,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -704,6 +921,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -717,6 +935,7 @@ mod tests {
                 Err(LengthError::Min {
                     min: 3,
                     value: 1,
+                    unit: None,
                     code: LengthErrorCode,
                     #[cfg(feature = "message")]
                     message: "length 1 is less than minimum length 3".to_owned(),
@@ -727,6 +946,7 @@ mod tests {
                 Err(LengthError::Min {
                     min: 3,
                     value: 1,
+                    unit: None,
                     code: LengthErrorCode,
                     #[cfg(feature = "message")]
                     message: "length 1 is less than minimum length 3".to_owned(),
@@ -739,6 +959,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -749,6 +970,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -759,6 +981,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -769,6 +992,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -781,6 +1005,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -791,6 +1016,7 @@ mod tests {
             Err(LengthError::Min {
                 min: 3,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is less than minimum length 3".to_owned(),
@@ -805,6 +1031,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -815,6 +1042,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -825,6 +1053,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -835,6 +1064,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -845,6 +1075,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -856,6 +1087,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -867,6 +1099,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -877,6 +1110,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -887,6 +1121,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -897,6 +1132,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -907,6 +1143,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -917,6 +1154,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -927,6 +1165,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -937,6 +1176,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -950,6 +1190,7 @@ mod tests {
                 Err(LengthError::Max {
                     max: 0,
                     value: 1,
+                    unit: None,
                     code: LengthErrorCode,
                     #[cfg(feature = "message")]
                     message: "length 1 is greater than maximum length 0".to_owned(),
@@ -960,6 +1201,7 @@ mod tests {
                 Err(LengthError::Max {
                     max: 0,
                     value: 1,
+                    unit: None,
                     code: LengthErrorCode,
                     #[cfg(feature = "message")]
                     message: "length 1 is greater than maximum length 0".to_owned(),
@@ -972,6 +1214,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -982,6 +1225,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -992,6 +1236,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -1002,6 +1247,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -1014,6 +1260,7 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
@@ -1024,10 +1271,51 @@ mod tests {
             Err(LengthError::Max {
                 max: 0,
                 value: 1,
+                unit: None,
                 code: LengthErrorCode,
                 #[cfg(feature = "message")]
                 message: "length 1 is greater than maximum length 0".to_owned(),
             })
         );
     }
+
+    #[test]
+    fn unit() {
+        let value = "e\u{301}👍";
+
+        assert_eq!(LengthUnit::Bytes.measure(value), 7);
+        assert_eq!(LengthUnit::Chars.measure(value), 3);
+        assert_eq!(LengthUnit::Utf16.measure(value), 4);
+        #[cfg(feature = "unicode-segmentation")]
+        assert_eq!(LengthUnit::Graphemes.measure(value), 2);
+
+        assert_eq!(
+            value.validate_length_in(None, None, Some(7), LengthUnit::Bytes),
+            Ok(())
+        );
+        assert_eq!(
+            Some(value.to_owned()).validate_length_in(None, None, Some(3), LengthUnit::Utf16),
+            Err(LengthError::Max {
+                max: 3,
+                value: 4,
+                unit: Some(LengthUnit::Utf16),
+                code: LengthErrorCode,
+                #[cfg(feature = "message")]
+                message: "length 4 is greater than maximum length 3".to_owned(),
+            })
+        );
+        assert_eq!(
+            Arc::new(Cow::Borrowed(value)).validate_length_in(
+                Some(3),
+                None,
+                None,
+                LengthUnit::Chars
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            None::<&str>.validate_length_in(None, Some(1), None, LengthUnit::Bytes),
+            Ok(())
+        );
+    }
 }
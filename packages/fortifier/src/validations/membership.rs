@@ -0,0 +1,468 @@
+use std::{
+    borrow::{Borrow, Cow},
+    cell::{Ref, RefMut},
+    collections::{BTreeSet, HashSet, LinkedList, VecDeque},
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::{BuildHasher, Hash},
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+#[cfg(feature = "indexmap")]
+use indexmap::IndexSet;
+
+constant_string!(OneOfErrorCode, ONE_OF_ERROR_CODE, "oneOf");
+constant_string!(NoneOfErrorCode, NONE_OF_ERROR_CODE, "noneOf");
+
+/// One of validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct OneOfError<T> {
+    /// The error code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "utoipa", schema(inline, required))]
+    pub code: OneOfErrorCode,
+
+    /// Allowed values.
+    pub allowed: Vec<T>,
+
+    /// The actual value.
+    pub value: T,
+
+    /// A human-readable error message.
+    #[cfg(feature = "message")]
+    pub message: String,
+}
+
+impl<T: Debug> fmt::Display for OneOfError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl<T: Debug> Error for OneOfError<T> {}
+
+#[cfg(feature = "zod")]
+impl<T> crate::zod::ToTypeScript for OneOfError<T>
+where
+    T: crate::zod::ToTypeScript,
+{
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        generator.define_type("OneOfError<T>", |_| {
+            crate::zod::error_type(
+                ONE_OF_ERROR_CODE,
+                None,
+                &[("allowed", "Array<T>"), ("value", "T")],
+            )
+        });
+
+        format!("OneOfError<{}>", T::typescript(generator))
+    }
+}
+
+/// None of validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct NoneOfError<T> {
+    /// The error code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "utoipa", schema(inline, required))]
+    pub code: NoneOfErrorCode,
+
+    /// Disallowed values.
+    pub disallowed: Vec<T>,
+
+    /// The actual value.
+    pub value: T,
+
+    /// A human-readable error message.
+    #[cfg(feature = "message")]
+    pub message: String,
+}
+
+impl<T: Debug> fmt::Display for NoneOfError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl<T: Debug> Error for NoneOfError<T> {}
+
+#[cfg(feature = "zod")]
+impl<T> crate::zod::ToTypeScript for NoneOfError<T>
+where
+    T: crate::zod::ToTypeScript,
+{
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        generator.define_type("NoneOfError<T>", |_| {
+            crate::zod::error_type(
+                NONE_OF_ERROR_CODE,
+                None,
+                &[("disallowed", "Array<T>"), ("value", "T")],
+            )
+        });
+
+        format!("NoneOfError<{}>", T::typescript(generator))
+    }
+}
+
+/// Set of values for a membership validation.
+pub trait ValueSet<T> {
+    /// Whether the set contains the value.
+    fn contains_value(&self, value: &T) -> bool;
+
+    /// Convert the set into values.
+    fn to_values(&self) -> Vec<T>;
+}
+
+impl<T, S> ValueSet<T> for &S
+where
+    S: ValueSet<T> + ?Sized,
+{
+    fn contains_value(&self, value: &T) -> bool {
+        S::contains_value(self, value)
+    }
+
+    fn to_values(&self) -> Vec<T> {
+        S::to_values(self)
+    }
+}
+
+macro_rules! value_set_with_items {
+    ($type:ty) => {
+        impl<T, U> ValueSet<T> for $type
+        where
+            U: Clone + Into<T> + PartialEq<T>,
+        {
+            fn contains_value(&self, value: &T) -> bool {
+                self.iter().any(|item| item == value)
+            }
+
+            fn to_values(&self) -> Vec<T> {
+                self.iter().cloned().map(Into::into).collect()
+            }
+        }
+    };
+}
+
+value_set_with_items!([U]);
+value_set_with_items!(LinkedList<U>);
+value_set_with_items!(Vec<U>);
+value_set_with_items!(VecDeque<U>);
+
+impl<T, U, const N: usize> ValueSet<T> for [U; N]
+where
+    U: Clone + Into<T> + PartialEq<T>,
+{
+    fn contains_value(&self, value: &T) -> bool {
+        self.as_slice().contains_value(value)
+    }
+
+    fn to_values(&self) -> Vec<T> {
+        self.as_slice().to_values()
+    }
+}
+
+impl<T, U> ValueSet<T> for BTreeSet<U>
+where
+    T: Ord,
+    U: Borrow<T> + Clone + Into<T> + Ord,
+{
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+
+    fn to_values(&self) -> Vec<T> {
+        self.iter().cloned().map(Into::into).collect()
+    }
+}
+
+impl<T, U, S> ValueSet<T> for HashSet<U, S>
+where
+    T: Eq + Hash + Ord,
+    U: Borrow<T> + Clone + Eq + Hash + Into<T>,
+    S: BuildHasher,
+{
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+
+    fn to_values(&self) -> Vec<T> {
+        // Sort the values, as the iteration order of a hash set is not deterministic.
+        let mut values: Vec<T> = self.iter().cloned().map(Into::into).collect();
+        values.sort();
+        values
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, U, S> ValueSet<T> for IndexSet<U, S>
+where
+    T: Eq + Hash,
+    U: Borrow<T> + Clone + Eq + Hash + Into<T>,
+    S: BuildHasher,
+{
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+
+    fn to_values(&self) -> Vec<T> {
+        self.iter().cloned().map(Into::into).collect()
+    }
+}
+
+#[cfg(feature = "message")]
+fn format_values<T: Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Validate membership of a set of values.
+pub trait ValidateMembership<T>
+where
+    T: Clone + Display,
+{
+    /// The value.
+    fn membership_value(&self) -> Option<&T>;
+
+    /// Validate the value is one of the allowed values.
+    fn validate_one_of(&self, allowed: impl ValueSet<T>) -> Result<(), OneOfError<T>> {
+        let Some(value) = self.membership_value() else {
+            return Ok(());
+        };
+
+        if allowed.contains_value(value) {
+            return Ok(());
+        }
+
+        let value = value.clone();
+        let allowed = allowed.to_values();
+
+        #[cfg(feature = "message")]
+        let message = format!(
+            "value `{value}` is not allowed, must be one of `{}`",
+            format_values(&allowed)
+        );
+
+        Err(OneOfError {
+            code: OneOfErrorCode,
+            allowed,
+            value,
+            #[cfg(feature = "message")]
+            message,
+        })
+    }
+
+    /// Validate the value is none of the disallowed values.
+    fn validate_none_of(&self, disallowed: impl ValueSet<T>) -> Result<(), NoneOfError<T>> {
+        let Some(value) = self.membership_value() else {
+            return Ok(());
+        };
+
+        if !disallowed.contains_value(value) {
+            return Ok(());
+        }
+
+        let value = value.clone();
+        let disallowed = disallowed.to_values();
+
+        #[cfg(feature = "message")]
+        let message = format!(
+            "value `{value}` is not allowed, must not be one of `{}`",
+            format_values(&disallowed)
+        );
+
+        Err(NoneOfError {
+            code: NoneOfErrorCode,
+            disallowed,
+            value,
+            #[cfg(feature = "message")]
+            message,
+        })
+    }
+}
+
+macro_rules! validate_with_copy {
+    ($type:ty) => {
+        impl ValidateMembership<$type> for $type {
+            fn membership_value(&self) -> Option<&Self> {
+                Some(self)
+            }
+        }
+    };
+}
+
+validate_with_copy!(bool);
+validate_with_copy!(u8);
+validate_with_copy!(u16);
+validate_with_copy!(u32);
+validate_with_copy!(u64);
+validate_with_copy!(u128);
+validate_with_copy!(usize);
+validate_with_copy!(i8);
+validate_with_copy!(i16);
+validate_with_copy!(i32);
+validate_with_copy!(i64);
+validate_with_copy!(i128);
+validate_with_copy!(isize);
+validate_with_copy!(char);
+#[cfg(feature = "decimal")]
+validate_with_copy!(rust_decimal::Decimal);
+#[cfg(feature = "uuid")]
+validate_with_copy!(uuid::Uuid);
+
+impl<'a> ValidateMembership<&'a str> for &'a str {
+    fn membership_value(&self) -> Option<&Self> {
+        Some(self)
+    }
+}
+
+impl ValidateMembership<String> for String {
+    fn membership_value(&self) -> Option<&Self> {
+        Some(self)
+    }
+}
+
+impl<'a> ValidateMembership<Cow<'a, str>> for Cow<'a, str> {
+    fn membership_value(&self) -> Option<&Self> {
+        Some(self)
+    }
+}
+
+impl<L, T> ValidateMembership<L> for Option<T>
+where
+    L: Clone + Display,
+    T: ValidateMembership<L>,
+{
+    fn membership_value(&self) -> Option<&L> {
+        if let Some(s) = self {
+            T::membership_value(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<V, T> ValidateMembership<V> for $type
+        where
+            V: Clone + Display,
+            T: ValidateMembership<V>,
+        {
+            fn membership_value(&self) -> Option<&V> {
+                T::membership_value(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    const RESERVED: &[&str] = &["admin", "root"];
+
+    #[test]
+    fn one_of_ok() {
+        assert_eq!("basic".to_owned().validate_one_of(["basic", "pro"]), Ok(()));
+        assert_eq!(3.validate_one_of([1, 2, 3].as_slice()), Ok(()));
+        assert_eq!(None::<String>.validate_one_of(["basic"]), Ok(()));
+
+        let allowed = HashSet::from(["basic".to_owned(), "pro".to_owned()]);
+        assert_eq!("pro".to_owned().validate_one_of(&allowed), Ok(()));
+    }
+
+    #[test]
+    fn one_of_error() {
+        assert_eq!(
+            "free".to_owned().validate_one_of(["basic", "pro"]),
+            Err(OneOfError {
+                code: OneOfErrorCode,
+                allowed: vec!["basic".to_owned(), "pro".to_owned()],
+                value: "free".to_owned(),
+                #[cfg(feature = "message")]
+                message: "value `free` is not allowed, must be one of `basic, pro`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn one_of_set_error() {
+        let allowed = HashSet::from(["team", "basic", "pro", "enterprise"].map(str::to_owned));
+
+        assert_eq!(
+            "free"
+                .to_owned()
+                .validate_one_of(&allowed)
+                .map_err(|error| error.allowed),
+            Err(["basic", "enterprise", "pro", "team"]
+                .map(str::to_owned)
+                .to_vec())
+        );
+
+        let allowed = BTreeSet::from([8, 2, 4]);
+
+        assert_eq!(
+            3.validate_one_of(&allowed).map_err(|error| error.allowed),
+            Err(vec![2, 4, 8])
+        );
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn one_of_index_set_error() {
+        let allowed = IndexSet::from(["pro", "basic", "team"]);
+
+        assert_eq!(
+            "free"
+                .validate_one_of(&allowed)
+                .map_err(|error| error.allowed),
+            Err(vec!["pro", "basic", "team"])
+        );
+    }
+
+    #[test]
+    fn none_of_ok() {
+        assert_eq!("john".to_owned().validate_none_of(RESERVED), Ok(()));
+        assert_eq!(Some(5).validate_none_of(vec![1, 2]), Ok(()));
+    }
+
+    #[test]
+    fn none_of_error() {
+        assert_eq!(
+            "root".to_owned().validate_none_of(RESERVED),
+            Err(NoneOfError {
+                code: NoneOfErrorCode,
+                disallowed: vec!["admin".to_owned(), "root".to_owned()],
+                value: "root".to_owned(),
+                #[cfg(feature = "message")]
+                message: "value `root` is not allowed, must not be one of `admin, root`".to_owned(),
+            })
+        );
+    }
+}
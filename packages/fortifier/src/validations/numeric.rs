@@ -0,0 +1,509 @@
+use std::{
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt::{self, Debug, Display},
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(NumericErrorCode, NUMERIC_ERROR_CODE, "numeric");
+
+/// Numeric validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum NumericError<T> {
+    /// Value is not a multiple of the step.
+    MultipleOf {
+        /// The step.
+        multiple_of: T,
+
+        /// The actual value.
+        value: T,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: NumericErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value is NaN or infinite.
+    Finite {
+        /// The actual value.
+        value: T,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: NumericErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value has more significant digits than the precision.
+    Precision {
+        /// The maximum number of significant digits.
+        precision: u32,
+
+        /// The actual number of significant digits.
+        value: u32,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: NumericErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Value has more digits after the decimal point than the scale.
+    Scale {
+        /// The maximum number of digits after the decimal point.
+        scale: u32,
+
+        /// The actual number of digits after the decimal point.
+        value: u32,
+
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: NumericErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl<T: Debug> fmt::Display for NumericError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl<T: Debug> Error for NumericError<T> {}
+
+#[cfg(feature = "zod")]
+impl<T> crate::zod::ToTypeScript for NumericError<T>
+where
+    T: crate::zod::ToTypeScript,
+{
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("NumericError<T>", |_| {
+            [
+                error_type(
+                    NUMERIC_ERROR_CODE,
+                    Some("multipleOf"),
+                    &[("multipleOf", "T"), ("value", "T")],
+                ),
+                error_type(NUMERIC_ERROR_CODE, Some("finite"), &[("value", "T")]),
+                error_type(
+                    NUMERIC_ERROR_CODE,
+                    Some("precision"),
+                    &[("precision", "number"), ("value", "number")],
+                ),
+                error_type(
+                    NUMERIC_ERROR_CODE,
+                    Some("scale"),
+                    &[("scale", "number"), ("value", "number")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        format!("NumericError<{}>", T::typescript(generator))
+    }
+}
+
+/// Numeric value.
+pub trait Numeric: Copy + Display {
+    /// Whether the value is neither NaN nor infinite.
+    fn is_finite(self) -> bool;
+
+    /// Whether the value is a multiple of the step.
+    fn is_multiple_of(self, multiple_of: Self) -> bool;
+}
+
+macro_rules! numeric_integer {
+    ($type:ty) => {
+        impl Numeric for $type {
+            fn is_finite(self) -> bool {
+                true
+            }
+
+            fn is_multiple_of(self, multiple_of: Self) -> bool {
+                match self.checked_rem(multiple_of) {
+                    Some(remainder) => remainder == 0,
+                    // Division by zero or overflow (`MIN % -1`).
+                    None => multiple_of != 0,
+                }
+            }
+        }
+    };
+}
+
+numeric_integer!(u8);
+numeric_integer!(u16);
+numeric_integer!(u32);
+numeric_integer!(u64);
+numeric_integer!(u128);
+numeric_integer!(usize);
+numeric_integer!(i8);
+numeric_integer!(i16);
+numeric_integer!(i32);
+numeric_integer!(i64);
+numeric_integer!(i128);
+numeric_integer!(isize);
+
+macro_rules! numeric_float {
+    ($type:ident) => {
+        impl Numeric for $type {
+            fn is_finite(self) -> bool {
+                $type::is_finite(self)
+            }
+
+            fn is_multiple_of(self, multiple_of: Self) -> bool {
+                if !self.is_finite() || !multiple_of.is_finite() || multiple_of == 0.0 {
+                    return false;
+                }
+
+                // Steps such as `0.1` are not exactly representable, so allow a relative error.
+                let quotient = self / multiple_of;
+
+                (quotient - quotient.round()).abs()
+                    <= 4.0 * $type::EPSILON * quotient.abs().max(1.0)
+            }
+        }
+    };
+}
+
+numeric_float!(f32);
+numeric_float!(f64);
+
+#[cfg(feature = "decimal")]
+impl Numeric for rust_decimal::Decimal {
+    fn is_finite(self) -> bool {
+        true
+    }
+
+    fn is_multiple_of(self, multiple_of: Self) -> bool {
+        self.checked_rem(multiple_of)
+            .is_some_and(|remainder| remainder.is_zero())
+    }
+}
+
+/// Validate a number.
+pub trait ValidateNumeric<T>
+where
+    T: Numeric,
+{
+    /// The numeric value.
+    fn numeric_value(&self) -> Option<T>;
+
+    /// Validate the number is a multiple of the step.
+    fn validate_multiple_of(&self, multiple_of: T) -> Result<(), NumericError<T>> {
+        let Some(value) = self.numeric_value() else {
+            return Ok(());
+        };
+
+        if value.is_multiple_of(multiple_of) {
+            Ok(())
+        } else {
+            Err(NumericError::MultipleOf {
+                multiple_of,
+                value,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: format!("value {value} is not a multiple of {multiple_of}"),
+            })
+        }
+    }
+
+    /// Validate the number is neither NaN nor infinite.
+    fn validate_finite(&self) -> Result<(), NumericError<T>> {
+        let Some(value) = self.numeric_value() else {
+            return Ok(());
+        };
+
+        if value.is_finite() {
+            Ok(())
+        } else {
+            Err(NumericError::Finite {
+                value,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: format!("value {value} is not finite"),
+            })
+        }
+    }
+}
+
+/// Validate the precision and scale of a decimal, e.g. to match a SQL `NUMERIC(10, 2)` column.
+#[cfg(feature = "decimal")]
+pub trait ValidateDecimal: ValidateNumeric<rust_decimal::Decimal> {
+    /// Validate the number of significant digits and the number of digits after the decimal point.
+    ///
+    /// Trailing zeros after the decimal point are ignored. If a scale is specified, the precision
+    /// is measured as stored with that scale, so integer digits are limited to `precision - scale`.
+    fn validate_decimal(
+        &self,
+        precision: Option<u32>,
+        scale: Option<u32>,
+    ) -> Result<(), NumericError<rust_decimal::Decimal>> {
+        let Some(value) = self.numeric_value() else {
+            return Ok(());
+        };
+
+        let value = value.normalize();
+        let value_scale = value.scale();
+        let digits = value
+            .mantissa()
+            .unsigned_abs()
+            .checked_ilog10()
+            .map_or(1, |log| log + 1);
+        let integer_digits = digits.saturating_sub(value_scale);
+
+        if let Some(scale) = scale
+            && value_scale > scale
+        {
+            return Err(NumericError::Scale {
+                scale,
+                value: value_scale,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: format!(
+                    "value has {value_scale} digits after the decimal point, more than scale {scale}"
+                ),
+            });
+        }
+
+        if let Some(precision) = precision {
+            let value_precision = integer_digits + scale.unwrap_or(value_scale).max(value_scale);
+
+            if value_precision > precision {
+                return Err(NumericError::Precision {
+                    precision,
+                    value: value_precision,
+                    code: NumericErrorCode,
+                    #[cfg(feature = "message")]
+                    message: format!(
+                        "value has {value_precision} significant digits, more than precision {precision}"
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<T> ValidateDecimal for T where T: ValidateNumeric<rust_decimal::Decimal> + ?Sized {}
+
+macro_rules! validate_with_copy {
+    ($type:ty) => {
+        impl ValidateNumeric<$type> for $type {
+            fn numeric_value(&self) -> Option<Self> {
+                Some(*self)
+            }
+        }
+    };
+}
+
+validate_with_copy!(u8);
+validate_with_copy!(u16);
+validate_with_copy!(u32);
+validate_with_copy!(u64);
+validate_with_copy!(u128);
+validate_with_copy!(usize);
+validate_with_copy!(i8);
+validate_with_copy!(i16);
+validate_with_copy!(i32);
+validate_with_copy!(i64);
+validate_with_copy!(i128);
+validate_with_copy!(isize);
+validate_with_copy!(f32);
+validate_with_copy!(f64);
+#[cfg(feature = "decimal")]
+validate_with_copy!(rust_decimal::Decimal);
+
+impl<N, T> ValidateNumeric<N> for Option<T>
+where
+    N: Numeric,
+    T: ValidateNumeric<N>,
+{
+    fn numeric_value(&self) -> Option<N> {
+        if let Some(value) = self {
+            T::numeric_value(value)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<N, T> ValidateNumeric<N> for $type
+        where
+            N: Numeric,
+            T: ValidateNumeric<N> + ?Sized,
+        {
+            fn numeric_value(&self) -> Option<N> {
+                T::numeric_value(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+    #[cfg(feature = "decimal")]
+    use rust_decimal::dec;
+
+    #[cfg(feature = "decimal")]
+    use super::ValidateDecimal;
+    use super::{NumericError, NumericErrorCode, ValidateNumeric};
+
+    #[test]
+    fn ok() {
+        assert_eq!(15u8.validate_multiple_of(5), Ok(()));
+        assert_eq!((-15i32).validate_multiple_of(-5), Ok(()));
+        assert_eq!(i64::MIN.validate_multiple_of(-1), Ok(()));
+        assert_eq!(1.75f32.validate_multiple_of(0.25), Ok(()));
+        assert_eq!(0.3f64.validate_multiple_of(0.1), Ok(()));
+        assert_eq!(Some(1.5f64).validate_finite(), Ok(()));
+        assert_eq!(None::<f64>.validate_finite(), Ok(()));
+        assert_eq!(Arc::new(3u32).validate_finite(), Ok(()));
+        assert_eq!(Box::new(4u32).validate_multiple_of(2), Ok(()));
+        assert_eq!(Rc::new(6u32).validate_multiple_of(3), Ok(()));
+        assert_eq!(RefCell::new(8u32).borrow().validate_multiple_of(4), Ok(()));
+
+        #[cfg(feature = "decimal")]
+        {
+            assert_eq!(dec!(12.75).validate_multiple_of(dec!(0.25)), Ok(()));
+            assert_eq!(
+                dec!(12345678.90).validate_decimal(Some(10), Some(2)),
+                Ok(())
+            );
+            assert_eq!(dec!(0.99).validate_decimal(Some(2), Some(2)), Ok(()));
+            assert_eq!(dec!(1.500).validate_decimal(Some(2), Some(1)), Ok(()));
+            assert_eq!(dec!(-123.45).validate_decimal(Some(5), None), Ok(()));
+        }
+    }
+
+    #[test]
+    fn multiple_of_error() {
+        assert_eq!(
+            7u8.validate_multiple_of(5),
+            Err(NumericError::MultipleOf {
+                multiple_of: 5,
+                value: 7,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 7 is not a multiple of 5".to_owned(),
+            })
+        );
+        assert_eq!(
+            1.3f64.validate_multiple_of(0.25),
+            Err(NumericError::MultipleOf {
+                multiple_of: 0.25,
+                value: 1.3,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 1.3 is not a multiple of 0.25".to_owned(),
+            })
+        );
+        assert!(3u8.validate_multiple_of(0).is_err());
+        assert!(f64::NAN.validate_multiple_of(0.5).is_err());
+
+        #[cfg(feature = "decimal")]
+        assert_eq!(
+            dec!(1.10).validate_multiple_of(dec!(0.25)),
+            Err(NumericError::MultipleOf {
+                multiple_of: dec!(0.25),
+                value: dec!(1.10),
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: "value 1.10 is not a multiple of 0.25".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn finite_error() {
+        assert_eq!(
+            f64::INFINITY.validate_finite(),
+            Err(NumericError::Finite {
+                value: f64::INFINITY,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: "value inf is not finite".to_owned(),
+            })
+        );
+        assert!(matches!(
+            f32::NAN.validate_finite(),
+            Err(NumericError::Finite { value, .. }) if value.is_nan()
+        ));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_error() {
+        assert_eq!(
+            dec!(1.234).validate_decimal(Some(10), Some(2)),
+            Err(NumericError::Scale {
+                scale: 2,
+                value: 3,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: "value has 3 digits after the decimal point, more than scale 2".to_owned(),
+            })
+        );
+        assert_eq!(
+            dec!(123456789.1).validate_decimal(Some(10), Some(2)),
+            Err(NumericError::Precision {
+                precision: 10,
+                value: 11,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: "value has 11 significant digits, more than precision 10".to_owned(),
+            })
+        );
+        assert_eq!(
+            Some(dec!(-0.001)).validate_decimal(Some(2), None),
+            Err(NumericError::Precision {
+                precision: 2,
+                value: 3,
+                code: NumericErrorCode,
+                #[cfg(feature = "message")]
+                message: "value has 3 significant digits, more than precision 2".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,666 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::{Arc, LazyLock},
+};
+
+use constant_string::constant_string;
+
+constant_string!(PasswordErrorCode, PASSWORD_ERROR_CODE, "password");
+
+/// Common passwords, one per line and sorted for binary search.
+static COMMON_PASSWORDS: LazyLock<Vec<&str>> = LazyLock::new(|| {
+    include_str!("../../data/common-passwords.txt")
+        .lines()
+        .collect()
+});
+
+/// Length of the longest common password.
+static MAX_COMMON_PASSWORD_LENGTH: LazyLock<usize> = LazyLock::new(|| {
+    COMMON_PASSWORDS
+        .iter()
+        .map(|password| password.len())
+        .max()
+        .unwrap_or_default()
+});
+
+/// Minimum length of user inputs which are checked, shorter values are too likely to occur by chance.
+const MIN_USER_INPUT_LENGTH: usize = 3;
+
+/// Password options.
+///
+/// The default options accept any password.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PasswordOptions<'a> {
+    /// Minimum number of character classes (lowercase, uppercase, digit and symbol).
+    pub min_character_classes: Option<usize>,
+    /// Minimum strength score, from `0` (weakest) to `4` (strongest).
+    ///
+    /// See [`password_score`].
+    pub min_score: Option<u8>,
+    /// Deny passwords from the bundled list of common passwords.
+    pub deny_common: bool,
+    /// Other values the password must not contain, e.g. the username or email address, as
+    /// `(field, value)` pairs.
+    ///
+    /// Values are compared case-insensitively. For email addresses, the local part is also checked.
+    pub user_inputs: &'a [(&'a str, Option<&'a str>)],
+}
+
+/// Unmet password requirement.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum PasswordRequirement {
+    /// Too few character classes.
+    CharacterClasses {
+        /// The minimum number of character classes.
+        min: usize,
+
+        /// The actual number of character classes.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Too low strength score.
+    Score {
+        /// The minimum score.
+        min: u8,
+
+        /// The actual score.
+        value: u8,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Common password.
+    Common {
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Password contains a user input.
+    ContainsUserInput {
+        /// The field of the user input.
+        field: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for PasswordRequirement {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        fn requirement_type(subcode: &str, fields: &[(&str, &str)]) -> String {
+            let mut properties = vec![format!("subcode: \"{subcode}\"")];
+
+            properties.extend(
+                fields
+                    .iter()
+                    .map(|(name, r#type)| format!("{name}: {type}")),
+            );
+
+            if cfg!(feature = "message") {
+                properties.push("message: string".to_owned());
+            }
+
+            format!("{{ {} }}", properties.join("; "))
+        }
+
+        generator.define_type("PasswordRequirement", |_| {
+            [
+                requirement_type(
+                    "characterClasses",
+                    &[("min", "number"), ("value", "number")],
+                ),
+                requirement_type("score", &[("min", "number"), ("value", "number")]),
+                requirement_type("common", &[]),
+                requirement_type("containsUserInput", &[("field", "string")]),
+            ]
+            .join(" | ")
+        });
+
+        "PasswordRequirement".to_owned()
+    }
+}
+
+/// Password validation error.
+///
+/// Lists each unmet requirement.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PasswordError {
+    /// The error code.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "utoipa", schema(inline, required))]
+    pub code: PasswordErrorCode,
+
+    /// The unmet requirements.
+    pub requirements: Vec<PasswordRequirement>,
+
+    /// A human-readable error message.
+    #[cfg(feature = "message")]
+    pub message: String,
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for PasswordError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for PasswordError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        let requirement = PasswordRequirement::typescript(generator);
+
+        generator.define_type("PasswordError", |_| {
+            crate::zod::error_type(
+                PASSWORD_ERROR_CODE,
+                None,
+                &[("requirements", &format!("Array<{requirement}>"))],
+            )
+        });
+
+        "PasswordError".to_owned()
+    }
+}
+
+/// Value of another field, which a password must not contain.
+pub trait PasswordUserInput {
+    /// The user input.
+    fn user_input(&self) -> Option<&str>;
+}
+
+impl PasswordUserInput for str {
+    fn user_input(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl PasswordUserInput for String {
+    fn user_input(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl PasswordUserInput for Cow<'_, str> {
+    fn user_input(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T> PasswordUserInput for Option<T>
+where
+    T: PasswordUserInput,
+{
+    fn user_input(&self) -> Option<&str> {
+        self.as_ref().and_then(T::user_input)
+    }
+}
+
+macro_rules! user_input_with_deref {
+    ($type:ty) => {
+        impl<T> PasswordUserInput for $type
+        where
+            T: PasswordUserInput + ?Sized,
+        {
+            fn user_input(&self) -> Option<&str> {
+                T::user_input(self)
+            }
+        }
+    };
+}
+
+user_input_with_deref!(&T);
+user_input_with_deref!(Arc<T>);
+user_input_with_deref!(Box<T>);
+user_input_with_deref!(Rc<T>);
+
+/// Validate a password.
+pub trait ValidatePassword {
+    /// The password.
+    fn password(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate password.
+    fn validate_password(&self, options: PasswordOptions<'_>) -> Result<(), PasswordError> {
+        self.password()
+            .map(|password| validate_password(&password, options))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate a password, collecting all unmet requirements.
+pub fn validate_password(value: &str, options: PasswordOptions<'_>) -> Result<(), PasswordError> {
+    let mut requirements = vec![];
+
+    if let Some(min) = options.min_character_classes {
+        let value = character_classes(value);
+
+        if value < min {
+            requirements.push(PasswordRequirement::CharacterClasses {
+                min,
+                value,
+                #[cfg(feature = "message")]
+                message: format!(
+                    "password must contain at least {min} of lowercase letters, uppercase letters, digits and symbols"
+                ),
+            });
+        }
+    }
+
+    if let Some(min) = options.min_score {
+        let value = password_score(value);
+
+        if value < min {
+            requirements.push(PasswordRequirement::Score {
+                min,
+                value,
+                #[cfg(feature = "message")]
+                message: format!("password strength must be at least {min}, but is {value}"),
+            });
+        }
+    }
+
+    if options.deny_common && is_common(value) {
+        requirements.push(PasswordRequirement::Common {
+            #[cfg(feature = "message")]
+            message: "password is too common".to_owned(),
+        });
+    }
+
+    let lowercase_value = value.to_lowercase();
+    for (field, user_input) in options.user_inputs {
+        if let Some(user_input) = user_input
+            && contains_user_input(&lowercase_value, user_input)
+        {
+            requirements.push(PasswordRequirement::ContainsUserInput {
+                field: (*field).to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("password must not contain {field}"),
+            });
+        }
+    }
+
+    if requirements.is_empty() {
+        Ok(())
+    } else {
+        Err(PasswordError {
+            code: PasswordErrorCode,
+            #[cfg(feature = "message")]
+            message: format!(
+                "password does not meet {} requirement{}",
+                requirements.len(),
+                if requirements.len() == 1 { "" } else { "s" }
+            ),
+            requirements,
+        })
+    }
+}
+
+/// Estimate the strength of a password, from `0` (weakest) to `4` (strongest).
+///
+/// The score is based on the estimated number of guesses: at most 10<sup>3</sup>, 10<sup>6</sup>, 10<sup>8</sup> and
+/// 10<sup>10</sup> guesses result in a score of `0` to `3`. The password is split into the parts that are the easiest
+/// to guess:
+///
+/// - Common passwords, also as part of the password, case-insensitive and with substitutions (e.g. `P4ssw0rd`),
+///   count as guessing from the bundled list of common passwords.
+/// - Repeated and sequential characters (e.g. `aaa` or `123`) count as two guesses each.
+/// - Other characters count as ten guesses each.
+///
+/// This is a simple estimate, which does not detect e.g. keyboard patterns, dates or dictionary words.
+pub fn password_score(value: &str) -> u8 {
+    let guesses = estimate_guesses(value);
+
+    if guesses <= 3.0 {
+        0
+    } else if guesses <= 6.0 {
+        1
+    } else if guesses <= 8.0 {
+        2
+    } else if guesses <= 10.0 {
+        3
+    } else {
+        4
+    }
+}
+
+/// Estimate the base 10 logarithm of the number of guesses.
+fn estimate_guesses(value: &str) -> f64 {
+    let characters = value.chars().collect::<Vec<_>>();
+    let lowercase_characters = characters
+        .iter()
+        .map(char::to_ascii_lowercase)
+        .collect::<Vec<_>>();
+
+    let common_guesses = (COMMON_PASSWORDS.len() as f64).log10();
+    let variation_guesses = 2f64.log10();
+
+    // Minimum number of guesses for each prefix of the password.
+    let mut minimum_guesses = vec![0.0; characters.len() + 1];
+
+    for end in 1..=characters.len() {
+        let predictable = end > 1 && {
+            let difference = characters[end - 1] as i64 - characters[end - 2] as i64;
+
+            (-1..=1).contains(&difference)
+        };
+
+        minimum_guesses[end] =
+            minimum_guesses[end - 1] + if predictable { variation_guesses } else { 1.0 };
+
+        for start in end.saturating_sub(*MAX_COMMON_PASSWORD_LENGTH)..end {
+            let part = lowercase_characters[start..end].iter().collect::<String>();
+            let substituted_part = part.chars().map(substitute).collect::<String>();
+
+            let mut guesses = if is_common_lowercase(&part) {
+                common_guesses
+            } else if is_common_lowercase(&substituted_part) {
+                common_guesses + variation_guesses
+            } else {
+                continue;
+            };
+
+            if characters[start..end]
+                .iter()
+                .any(|character| character.is_uppercase())
+            {
+                guesses += variation_guesses;
+            }
+
+            minimum_guesses[end] = f64::min(minimum_guesses[end], minimum_guesses[start] + guesses);
+        }
+    }
+
+    minimum_guesses[characters.len()]
+}
+
+/// Reverse common character substitutions (e.g. `4` for `a`).
+fn substitute(character: char) -> char {
+    match character {
+        '4' | '@' => 'a',
+        '8' => 'b',
+        '(' => 'c',
+        '3' => 'e',
+        '6' | '9' => 'g',
+        '1' | '!' | '|' => 'i',
+        '0' => 'o',
+        '5' | '$' => 's',
+        '7' | '+' => 't',
+        '2' => 'z',
+        character => character,
+    }
+}
+
+fn character_classes(value: &str) -> usize {
+    let mut lowercase = false;
+    let mut uppercase = false;
+    let mut digit = false;
+    let mut symbol = false;
+
+    for character in value.chars() {
+        if character.is_lowercase() {
+            lowercase = true;
+        } else if character.is_uppercase() {
+            uppercase = true;
+        } else if character.is_numeric() {
+            digit = true;
+        } else {
+            symbol = true;
+        }
+    }
+
+    [lowercase, uppercase, digit, symbol]
+        .into_iter()
+        .filter(|class| *class)
+        .count()
+}
+
+fn is_common(value: &str) -> bool {
+    is_common_lowercase(&value.to_lowercase())
+}
+
+fn is_common_lowercase(value: &str) -> bool {
+    COMMON_PASSWORDS.binary_search(&value).is_ok()
+}
+
+fn contains_user_input(lowercase_value: &str, user_input: &str) -> bool {
+    let user_input = user_input.trim().to_lowercase();
+
+    // Also check the local part of email addresses.
+    let local_part = user_input.split_once('@').map(|(local_part, _)| local_part);
+
+    [Some(user_input.as_str()), local_part]
+        .into_iter()
+        .flatten()
+        .filter(|user_input| user_input.chars().count() >= MIN_USER_INPUT_LENGTH)
+        .any(|user_input| lowercase_value.contains(user_input))
+}
+
+impl ValidatePassword for str {
+    fn password(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidatePassword for &str {
+    fn password(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidatePassword for String {
+    fn password(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidatePassword for Cow<'_, str> {
+    fn password(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidatePassword for Option<T>
+where
+    T: ValidatePassword,
+{
+    fn password(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self {
+            T::password(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidatePassword for $type
+        where
+            T: ValidatePassword,
+        {
+            fn password(&self) -> Option<Cow<'_, str>> {
+                T::password(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{
+        COMMON_PASSWORDS, PasswordError, PasswordErrorCode, PasswordOptions, PasswordRequirement,
+        ValidatePassword, password_score, validate_password,
+    };
+
+    #[test]
+    fn ok() {
+        let options = PasswordOptions {
+            min_character_classes: Some(3),
+            min_score: Some(3),
+            deny_common: true,
+            user_inputs: &[("username", Some("simon"))],
+        };
+
+        assert_eq!((*"Correct-Horse-42").validate_password(options), Ok(()));
+        assert_eq!("Correct-Horse-42".validate_password(options), Ok(()));
+        assert_eq!(
+            "Correct-Horse-42".to_owned().validate_password(options),
+            Ok(())
+        );
+        assert_eq!(
+            Cow::<str>::Borrowed("Correct-Horse-42").validate_password(options),
+            Ok(())
+        );
+        assert_eq!(
+            Cow::<str>::Owned("Correct-Horse-42".to_owned()).validate_password(options),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_password(options), Ok(()));
+        assert_eq!(Some("Correct-Horse-42").validate_password(options), Ok(()));
+
+        assert_eq!((&"Correct-Horse-42").validate_password(options), Ok(()));
+        assert_eq!(
+            Box::new("Correct-Horse-42").validate_password(options),
+            Ok(())
+        );
+        assert_eq!(
+            Arc::new("Correct-Horse-42").validate_password(options),
+            Ok(())
+        );
+        assert_eq!(
+            Rc::new("Correct-Horse-42").validate_password(options),
+            Ok(())
+        );
+
+        let cell = RefCell::new("Correct-Horse-42");
+        assert_eq!(cell.borrow().validate_password(options), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_password(options), Ok(()));
+    }
+
+    #[test]
+    fn requirements_error() {
+        let options = PasswordOptions {
+            min_character_classes: Some(3),
+            min_score: Some(3),
+            deny_common: true,
+            user_inputs: &[
+                ("username", Some("Simon")),
+                ("emailAddress", Some("simon.doe@example.com")),
+                ("nickname", None),
+            ],
+        };
+
+        assert_eq!(
+            validate_password("password", options),
+            Err(PasswordError {
+                code: PasswordErrorCode,
+                requirements: vec![
+                    PasswordRequirement::CharacterClasses {
+                        min: 3,
+                        value: 1,
+                        #[cfg(feature = "message")]
+                        message: "password must contain at least 3 of lowercase letters, uppercase letters, digits and symbols".to_owned(),
+                    },
+                    PasswordRequirement::Score {
+                        min: 3,
+                        value: 0,
+                        #[cfg(feature = "message")]
+                        message: "password strength must be at least 3, but is 0".to_owned(),
+                    },
+                    PasswordRequirement::Common {
+                        #[cfg(feature = "message")]
+                        message: "password is too common".to_owned(),
+                    },
+                ],
+                #[cfg(feature = "message")]
+                message: "password does not meet 3 requirements".to_owned(),
+            })
+        );
+
+        assert_eq!(
+            validate_password("Simon.Doe-2024!", options),
+            Err(PasswordError {
+                code: PasswordErrorCode,
+                requirements: vec![
+                    PasswordRequirement::ContainsUserInput {
+                        field: "username".to_owned(),
+                        #[cfg(feature = "message")]
+                        message: "password must not contain username".to_owned(),
+                    },
+                    PasswordRequirement::ContainsUserInput {
+                        field: "emailAddress".to_owned(),
+                        #[cfg(feature = "message")]
+                        message: "password must not contain emailAddress".to_owned(),
+                    },
+                ],
+                #[cfg(feature = "message")]
+                message: "password does not meet 2 requirements".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn score() {
+        assert_eq!(password_score(""), 0);
+        assert_eq!(password_score("qwerty"), 0);
+        assert_eq!(password_score("PASSWORD"), 0);
+        assert_eq!(password_score("P4ssw0rd"), 0);
+        assert_eq!(password_score("aaaaaaaa"), 1);
+        assert_eq!(password_score("abcdefghijkl"), 1);
+        assert_eq!(password_score("rwtpxk"), 1);
+        assert_eq!(password_score("Password123!"), 1);
+        assert_eq!(password_score("passwordpassword"), 1);
+        assert_eq!(password_score("MyP4ssw0rd!"), 1);
+        assert_eq!(password_score("rwtpxkq"), 2);
+        assert_eq!(password_score("rwtpxkqmz"), 3);
+        assert_eq!(password_score("Correct-Horse-42"), 4);
+    }
+
+    #[test]
+    fn common_passwords_sorted() {
+        assert!(COMMON_PASSWORDS.is_sorted_by(|a, b| a < b));
+        assert!(
+            COMMON_PASSWORDS
+                .iter()
+                .all(|password| *password == password.to_lowercase())
+        );
+    }
+}
@@ -8,11 +8,72 @@ use std::{
 };
 
 use constant_string::constant_string;
-pub use phonenumber::country::Id as PhoneNumberCountry;
-use phonenumber::{ParseError, PhoneNumber};
+use phonenumber::ParseError;
+pub use phonenumber::{PhoneNumber, Type as PhoneNumberType, country::Id as PhoneNumberCountry};
 
 constant_string!(PhoneNumberErrorCode, PHONE_NUMBER_ERROR_CODE, "phoneNumber");
 
+/// Phone number options.
+///
+/// The default options allow any phone number which can be parsed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PhoneNumberOptions<'a> {
+    /// Allowed phone number types (e.g. [`PhoneNumberType::Mobile`]).
+    pub types: Option<&'a [PhoneNumberType]>,
+    /// Require the phone number to be valid, instead of only parseable.
+    ///
+    /// See [`PhoneNumber::is_valid`].
+    pub strict_validity: bool,
+    /// Require the phone number to be in international format, with a leading `+`.
+    pub require_international_format: bool,
+}
+
+impl PhoneNumberOptions<'_> {
+    fn check(&self, value: &str, phone_number: &PhoneNumber) -> Result<(), PhoneNumberError> {
+        let code = PhoneNumberErrorCode;
+
+        if self.require_international_format && !value.trim_start().starts_with('+') {
+            return Err(PhoneNumberError::MissingInternationalPrefix {
+                code,
+                #[cfg(feature = "message")]
+                message: "phone number must be in international format, starting with `+`"
+                    .to_owned(),
+            });
+        }
+
+        if self.strict_validity && !phone_number.is_valid() {
+            return Err(PhoneNumberError::InvalidNumber {
+                code,
+                #[cfg(feature = "message")]
+                message: "phone number is not valid".to_owned(),
+            });
+        }
+
+        if let Some(types) = self.types {
+            let r#type = phone_number.number_type(&phonenumber::metadata::DATABASE);
+
+            if !types.contains(&r#type) {
+                return Err(PhoneNumberError::DisallowedType {
+                    code,
+                    allowed: types.to_vec(),
+                    value: r#type,
+                    #[cfg(feature = "message")]
+                    message: format!(
+                        "phone number type `{type:?}` is not allowed, must be one of `{}`",
+                        types
+                            .iter()
+                            .map(|r#type| format!("{type:?}"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Phone number validation error.
 #[derive(Debug, Eq, PartialEq)]
 #[cfg_attr(
@@ -109,6 +170,51 @@ pub enum PhoneNumberError {
         #[cfg_attr(feature = "utoipa", schema(value_type = Option<String>))]
         value: Option<PhoneNumberCountry>,
 
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid number error.
+    ///
+    /// The phone number can be parsed, but is not a valid number.
+    InvalidNumber {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: PhoneNumberErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed type error.
+    DisallowedType {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: PhoneNumberErrorCode,
+
+        /// Allowed types.
+        #[cfg_attr(feature = "utoipa", schema(value_type = Vec<String>))]
+        allowed: Vec<PhoneNumberType>,
+
+        /// The actual type.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        value: PhoneNumberType,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Missing international prefix error.
+    ///
+    /// The phone number is not in international format, starting with `+`.
+    MissingInternationalPrefix {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: PhoneNumberErrorCode,
+
         /// A human-readable error message.
         #[cfg(feature = "message")]
         message: String,
@@ -123,6 +229,43 @@ impl fmt::Display for PhoneNumberError {
 
 impl Error for PhoneNumberError {}
 
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for PhoneNumberError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("PhoneNumberError", |_| {
+            [
+                error_type(PHONE_NUMBER_ERROR_CODE, Some("noNumber"), &[]),
+                error_type(PHONE_NUMBER_ERROR_CODE, Some("invalidCountryCode"), &[]),
+                error_type(PHONE_NUMBER_ERROR_CODE, Some("tooShortAfterIdd"), &[]),
+                error_type(PHONE_NUMBER_ERROR_CODE, Some("tooShortNsn"), &[]),
+                error_type(PHONE_NUMBER_ERROR_CODE, Some("tooLong"), &[]),
+                error_type(PHONE_NUMBER_ERROR_CODE, Some("malformedInteger"), &[]),
+                error_type(
+                    PHONE_NUMBER_ERROR_CODE,
+                    Some("disallowedCountryCode"),
+                    &[("allowed", "Array<string>"), ("value", "string | null")],
+                ),
+                error_type(PHONE_NUMBER_ERROR_CODE, Some("invalidNumber"), &[]),
+                error_type(
+                    PHONE_NUMBER_ERROR_CODE,
+                    Some("disallowedType"),
+                    &[("allowed", "Array<string>"), ("value", "string")],
+                ),
+                error_type(
+                    PHONE_NUMBER_ERROR_CODE,
+                    Some("missingInternationalPrefix"),
+                    &[],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "PhoneNumberError".to_owned()
+    }
+}
+
 impl From<ParseError> for PhoneNumberError {
     fn from(value: ParseError) -> Self {
         let code = PhoneNumberErrorCode;
@@ -178,42 +321,46 @@ pub trait ValidatePhoneNumber {
         &self,
         default_country: Option<PhoneNumberCountry>,
         allowed_countries: Option<Vec<PhoneNumberCountry>>,
+        options: PhoneNumberOptions<'_>,
     ) -> Result<(), PhoneNumberError> {
-        let Some(phone_number) = self.phone_number() else {
-            return Ok(());
-        };
+        self.parse_phone_number(default_country, allowed_countries, options)?;
 
-        let phone_number =
-            phonenumber::parse(default_country, &phone_number).map_err(PhoneNumberError::from)?;
+        Ok(())
+    }
 
-        if let Some(allowed_countries) = allowed_countries {
-            match phone_number.country().id() {
-                Some(country) => {
-                    if !allowed_countries.contains(&country) {
-                        #[cfg(feature = "message")]
-                        let message = format!(
-                            "country code `{}` is not allowed, must be one of `{}`",
-                            country.as_ref(),
-                            allowed_countries
-                                .iter()
-                                .map(AsRef::as_ref)
-                                .collect::<Vec<_>>()
-                                .join(", ")
-                        );
-
-                        return Err(PhoneNumberError::DisallowedCountryCode {
-                            allowed: allowed_countries,
-                            value: Some(country),
-                            code: PhoneNumberErrorCode,
-                            #[cfg(feature = "message")]
-                            message,
-                        });
-                    }
-                }
-                None => {
+    /// Validate phone number and return the parsed phone number.
+    fn parse_phone_number(
+        &self,
+        default_country: Option<PhoneNumberCountry>,
+        allowed_countries: Option<Vec<PhoneNumberCountry>>,
+        options: PhoneNumberOptions<'_>,
+    ) -> Result<Option<PhoneNumber>, PhoneNumberError> {
+        self.phone_number()
+            .map(|phone_number| {
+                parse_phone_number(&phone_number, default_country, allowed_countries, options)
+            })
+            .transpose()
+    }
+}
+
+/// Parse a phone number.
+pub fn parse_phone_number(
+    value: &str,
+    default_country: Option<PhoneNumberCountry>,
+    allowed_countries: Option<Vec<PhoneNumberCountry>>,
+    options: PhoneNumberOptions<'_>,
+) -> Result<PhoneNumber, PhoneNumberError> {
+    let phone_number =
+        phonenumber::parse(default_country, value).map_err(PhoneNumberError::from)?;
+
+    if let Some(allowed_countries) = allowed_countries {
+        match phone_number.country().id() {
+            Some(country) => {
+                if !allowed_countries.contains(&country) {
                     #[cfg(feature = "message")]
                     let message = format!(
-                        "unknown country code, must be one of `{}`",
+                        "country code `{}` is not allowed, must be one of `{}`",
+                        country.as_ref(),
                         allowed_countries
                             .iter()
                             .map(AsRef::as_ref)
@@ -223,17 +370,38 @@ pub trait ValidatePhoneNumber {
 
                     return Err(PhoneNumberError::DisallowedCountryCode {
                         allowed: allowed_countries,
-                        value: None,
+                        value: Some(country),
                         code: PhoneNumberErrorCode,
                         #[cfg(feature = "message")]
                         message,
                     });
                 }
             }
+            None => {
+                #[cfg(feature = "message")]
+                let message = format!(
+                    "unknown country code, must be one of `{}`",
+                    allowed_countries
+                        .iter()
+                        .map(AsRef::as_ref)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+
+                return Err(PhoneNumberError::DisallowedCountryCode {
+                    allowed: allowed_countries,
+                    value: None,
+                    code: PhoneNumberErrorCode,
+                    #[cfg(feature = "message")]
+                    message,
+                });
+            }
         }
-
-        Ok(())
     }
+
+    options.check(value, &phone_number)?;
+
+    Ok(phone_number)
 }
 
 impl ValidatePhoneNumber for str {
@@ -307,116 +475,171 @@ mod tests {
 
     use crate::PhoneNumberErrorCode;
 
-    use super::{PhoneNumberCountry, PhoneNumberError, ValidatePhoneNumber};
+    use super::{
+        PhoneNumberCountry, PhoneNumberError, PhoneNumberOptions, PhoneNumberType,
+        ValidatePhoneNumber, parse_phone_number,
+    };
 
     #[test]
     fn ok() {
         assert_eq!(
-            (*"+44 20 7946 0000").validate_phone_number(None, None),
+            (*"+44 20 7946 0000").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Ok(())
         );
-        assert_eq!("+44 20 7946 0000".validate_phone_number(None, None), Ok(()));
         assert_eq!(
-            "+44 20 7946 0000"
-                .to_owned()
-                .validate_phone_number(None, None),
+            "+44 20 7946 0000".validate_phone_number(None, None, PhoneNumberOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            "+44 20 7946 0000".to_owned().validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Ok(())
         );
         assert_eq!(
-            Cow::<str>::Borrowed("+44 20 7946 0000").validate_phone_number(None, None),
+            Cow::<str>::Borrowed("+44 20 7946 0000").validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Ok(())
         );
         assert_eq!(
-            Cow::<str>::Owned("+44 20 7946 0000".to_owned()).validate_phone_number(None, None),
+            Cow::<str>::Owned("+44 20 7946 0000".to_owned()).validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Ok(())
         );
         assert_eq!(
             PhoneNumber::from_str("+44 20 7946 0000")
                 .expect("valid phone number")
-                .validate_phone_number(None, None),
+                .validate_phone_number(None, None, PhoneNumberOptions::default()),
             Ok(())
         );
 
-        assert_eq!(None::<&str>.validate_phone_number(None, None), Ok(()));
         assert_eq!(
-            Some("+44 20 7946 0000").validate_phone_number(None, None),
+            None::<&str>.validate_phone_number(None, None, PhoneNumberOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Some("+44 20 7946 0000").validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Ok(())
         );
 
         assert_eq!(
-            (&"+44 20 7946 0000").validate_phone_number(None, None),
+            (&"+44 20 7946 0000").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Ok(())
         );
         assert_eq!(
-            Box::new("+44 20 7946 0000").validate_phone_number(None, None),
+            Box::new("+44 20 7946 0000").validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Ok(())
         );
         assert_eq!(
-            Arc::new("+44 20 7946 0000").validate_phone_number(None, None),
+            Arc::new("+44 20 7946 0000").validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Ok(())
         );
         assert_eq!(
-            Rc::new("+44 20 7946 0000").validate_phone_number(None, None),
+            Rc::new("+44 20 7946 0000").validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Ok(())
         );
 
         let cell = RefCell::new("+44 20 7946 0000");
-        assert_eq!(cell.borrow().validate_phone_number(None, None), Ok(()));
-        assert_eq!(cell.borrow_mut().validate_phone_number(None, None), Ok(()));
+        assert_eq!(
+            cell.borrow()
+                .validate_phone_number(None, None, PhoneNumberOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            cell.borrow_mut()
+                .validate_phone_number(None, None, PhoneNumberOptions::default()),
+            Ok(())
+        );
     }
 
     #[test]
     fn invalid_error() {
         assert_eq!(
-            (*"+44").validate_phone_number(None, None),
+            (*"+44").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            "+44".validate_phone_number(None, None),
+            "+44".validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            "+44".to_owned().validate_phone_number(None, None),
+            "+44"
+                .to_owned()
+                .validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            Cow::<str>::Borrowed("+44").validate_phone_number(None, None),
+            Cow::<str>::Borrowed("+44").validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            Cow::<str>::Owned("+44".to_owned()).validate_phone_number(None, None),
+            Cow::<str>::Owned("+44".to_owned()).validate_phone_number(
+                None,
+                None,
+                PhoneNumberOptions::default()
+            ),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
 
         assert_eq!(
-            Some("+44").validate_phone_number(None, None),
+            Some("+44").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
 
         assert_eq!(
-            (&"+44").validate_phone_number(None, None),
+            (&"+44").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            Box::new("+44").validate_phone_number(None, None),
+            Box::new("+44").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            Arc::new("+44").validate_phone_number(None, None),
+            Arc::new("+44").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            Rc::new("+44").validate_phone_number(None, None),
+            Rc::new("+44").validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
 
         let cell = RefCell::new("+44");
         assert_eq!(
-            cell.borrow().validate_phone_number(None, None),
+            cell.borrow()
+                .validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
         assert_eq!(
-            cell.borrow_mut().validate_phone_number(None, None),
+            cell.borrow_mut()
+                .validate_phone_number(None, None, PhoneNumberOptions::default()),
             Err(PhoneNumberError::from(ParseError::NoNumber))
         );
     }
@@ -424,7 +647,11 @@ mod tests {
     #[test]
     fn disallowed_country_code_error() {
         assert_eq!(
-            "+44 20 7946 0000".validate_phone_number(None, Some(vec![PhoneNumberCountry::NL])),
+            "+44 20 7946 0000".validate_phone_number(
+                None,
+                Some(vec![PhoneNumberCountry::NL]),
+                PhoneNumberOptions::default()
+            ),
             Err(PhoneNumberError::DisallowedCountryCode {
                 allowed: vec![PhoneNumberCountry::NL],
                 value: Some(PhoneNumberCountry::GB),
@@ -434,4 +661,111 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn parse() {
+        let phone_number = PhoneNumber::from_str("+44 20 7946 0000").expect("valid phone number");
+
+        assert_eq!(
+            parse_phone_number(
+                "020 7946 0000",
+                Some(PhoneNumberCountry::GB),
+                None,
+                PhoneNumberOptions::default()
+            )
+            .map(|phone_number| phone_number.to_string()),
+            Ok("+442079460000".to_owned())
+        );
+        assert_eq!(
+            "+44 20 7946 0000".parse_phone_number(
+                None,
+                Some(vec![PhoneNumberCountry::GB]),
+                PhoneNumberOptions::default()
+            ),
+            Ok(Some(phone_number))
+        );
+        assert_eq!(
+            None::<&str>.parse_phone_number(None, None, PhoneNumberOptions::default()),
+            Ok(None)
+        );
+        assert_eq!(
+            "+44 20 7946 0000".parse_phone_number(
+                None,
+                Some(vec![PhoneNumberCountry::NL]),
+                PhoneNumberOptions::default()
+            ),
+            Err(PhoneNumberError::DisallowedCountryCode {
+                allowed: vec![PhoneNumberCountry::NL],
+                value: Some(PhoneNumberCountry::GB),
+                code: PhoneNumberErrorCode,
+                #[cfg(feature = "message")]
+                message: "country code `GB` is not allowed, must be one of `NL`".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn options_error() {
+        let options = PhoneNumberOptions {
+            types: Some(&[PhoneNumberType::Mobile, PhoneNumberType::FixedLineOrMobile]),
+            ..Default::default()
+        };
+        assert_eq!(
+            "+44 7400 123456".validate_phone_number(None, None, options),
+            Ok(())
+        );
+        assert_eq!(
+            "+1 650 253 0000".validate_phone_number(None, None, options),
+            Ok(())
+        );
+        assert_eq!(
+            "+44 800 123 4567".validate_phone_number(None, None, options),
+            Err(PhoneNumberError::DisallowedType {
+                code: PhoneNumberErrorCode,
+                allowed: vec![PhoneNumberType::Mobile, PhoneNumberType::FixedLineOrMobile],
+                value: PhoneNumberType::TollFree,
+                #[cfg(feature = "message")]
+                message: "phone number type `TollFree` is not allowed, must be one of `Mobile, FixedLineOrMobile`".to_owned(),
+            })
+        );
+
+        let options = PhoneNumberOptions {
+            strict_validity: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "+44 7400 123456".validate_phone_number(None, None, options),
+            Ok(())
+        );
+        assert_eq!(
+            "+44 7700 900123".validate_phone_number(None, None, PhoneNumberOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            "+44 7700 900123".validate_phone_number(None, None, options),
+            Err(PhoneNumberError::InvalidNumber {
+                code: PhoneNumberErrorCode,
+                #[cfg(feature = "message")]
+                message: "phone number is not valid".to_owned(),
+            })
+        );
+
+        let options = PhoneNumberOptions {
+            require_international_format: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "+44 20 7946 0000".validate_phone_number(Some(PhoneNumberCountry::GB), None, options),
+            Ok(())
+        );
+        assert_eq!(
+            "020 7946 0000".validate_phone_number(Some(PhoneNumberCountry::GB), None, options),
+            Err(PhoneNumberError::MissingInternationalPrefix {
+                code: PhoneNumberErrorCode,
+                #[cfg(feature = "message")]
+                message: "phone number must be in international format, starting with `+`"
+                    .to_owned(),
+            })
+        );
+    }
 }
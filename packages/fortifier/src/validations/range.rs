@@ -101,6 +101,44 @@ impl<T: Debug> fmt::Display for RangeError<T> {
 
 impl<T: Debug> Error for RangeError<T> {}
 
+#[cfg(feature = "zod")]
+impl<T> crate::zod::ToTypeScript for RangeError<T>
+where
+    T: crate::zod::ToTypeScript,
+{
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("RangeError<T>", |_| {
+            [
+                error_type(
+                    RANGE_ERROR_CODE,
+                    Some("min"),
+                    &[("min", "T"), ("value", "T")],
+                ),
+                error_type(
+                    RANGE_ERROR_CODE,
+                    Some("max"),
+                    &[("max", "T"), ("value", "T")],
+                ),
+                error_type(
+                    RANGE_ERROR_CODE,
+                    Some("exclusiveMin"),
+                    &[("exclusiveMin", "T"), ("value", "T")],
+                ),
+                error_type(
+                    RANGE_ERROR_CODE,
+                    Some("exclusiveMax"),
+                    &[("exclusiveMax", "T"), ("value", "T")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        format!("RangeError<{}>", T::typescript(generator))
+    }
+}
+
 /// Validate a range.
 pub trait ValidateRange<T>
 where
@@ -225,6 +263,28 @@ validate_with_copy!(chrono::NaiveTime);
 validate_with_copy!(chrono::TimeDelta);
 #[cfg(feature = "decimal")]
 validate_with_copy!(rust_decimal::Decimal);
+#[cfg(feature = "jiff")]
+validate_with_copy!(jiff::civil::Date);
+#[cfg(feature = "jiff")]
+validate_with_copy!(jiff::civil::DateTime);
+#[cfg(feature = "jiff")]
+validate_with_copy!(jiff::civil::Time);
+#[cfg(feature = "jiff")]
+validate_with_copy!(jiff::SignedDuration);
+#[cfg(feature = "jiff")]
+validate_with_copy!(jiff::Timestamp);
+#[cfg(feature = "time")]
+validate_with_copy!(time::Date);
+#[cfg(feature = "time")]
+validate_with_copy!(time::Duration);
+#[cfg(feature = "time")]
+validate_with_copy!(time::OffsetDateTime);
+#[cfg(feature = "time")]
+validate_with_copy!(time::PrimitiveDateTime);
+#[cfg(feature = "time")]
+validate_with_copy!(time::Time);
+#[cfg(feature = "time")]
+validate_with_copy!(time::UtcDateTime);
 #[cfg(feature = "uuid")]
 validate_with_copy!(uuid::Uuid);
 
@@ -245,6 +305,8 @@ macro_rules! validate_with_clone {
 }
 
 validate_with_clone!(String);
+#[cfg(feature = "jiff")]
+validate_with_clone!(jiff::Zoned);
 
 #[cfg(feature = "chrono")]
 impl<Tz> ValidateRange<chrono::DateTime<Tz>> for chrono::DateTime<Tz>
@@ -300,6 +362,8 @@ mod tests {
     use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
     #[cfg(feature = "decimal")]
     use rust_decimal::dec;
+    #[cfg(feature = "time")]
+    use time::{Date, Duration, Month, PrimitiveDateTime, Time};
     use uuid::Uuid;
 
     use super::{RangeError, RangeErrorCode, ValidateRange};
@@ -398,6 +462,104 @@ mod tests {
             Ok(())
         );
 
+        #[cfg(feature = "jiff")]
+        {
+            use jiff::{SignedDuration, Timestamp, civil, tz::TimeZone};
+
+            assert_eq!(
+                civil::date(2025, 4, 3).validate_range(
+                    Some(civil::date(2025, 1, 1)),
+                    None,
+                    None,
+                    None
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                civil::datetime(2025, 4, 3, 12, 34, 56, 0).validate_range(
+                    Some(civil::datetime(2025, 1, 1, 0, 0, 0, 0)),
+                    None,
+                    None,
+                    None
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                civil::time(12, 34, 56, 0).validate_range(
+                    Some(civil::time(0, 0, 0, 0)),
+                    None,
+                    None,
+                    None
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                SignedDuration::from_mins(3).validate_range(
+                    Some(SignedDuration::from_mins(1)),
+                    None,
+                    None,
+                    None
+                ),
+                Ok(())
+            );
+
+            let min = Timestamp::from_second(1_735_689_600).expect("valid timestamp");
+            let value = Timestamp::from_second(1_743_683_696).expect("valid timestamp");
+
+            assert_eq!(value.validate_range(Some(min), None, None, None), Ok(()));
+            assert_eq!(
+                value.to_zoned(TimeZone::UTC).validate_range(
+                    Some(min.to_zoned(TimeZone::UTC)),
+                    None,
+                    None,
+                    None
+                ),
+                Ok(())
+            );
+        }
+
+        #[cfg(feature = "time")]
+        {
+            let min = PrimitiveDateTime::new(
+                Date::from_calendar_date(2025, Month::January, 1).expect("valid date"),
+                Time::MIDNIGHT,
+            );
+            let value = PrimitiveDateTime::new(
+                Date::from_calendar_date(2025, Month::April, 3).expect("valid date"),
+                Time::from_hms(12, 34, 56).expect("valid time"),
+            );
+
+            assert_eq!(
+                value
+                    .date()
+                    .validate_range(Some(min.date()), None, None, None),
+                Ok(())
+            );
+            assert_eq!(
+                value
+                    .time()
+                    .validate_range(Some(min.time()), None, None, None),
+                Ok(())
+            );
+            assert_eq!(value.validate_range(Some(min), None, None, None), Ok(()));
+            assert_eq!(
+                value
+                    .assume_utc()
+                    .validate_range(Some(min.assume_utc()), None, None, None),
+                Ok(())
+            );
+            assert_eq!(
+                value
+                    .as_utc()
+                    .validate_range(Some(min.as_utc()), None, None, None),
+                Ok(())
+            );
+            assert_eq!(
+                Duration::minutes(3).validate_range(Some(Duration::minutes(1)), None, None, None),
+                Ok(())
+            );
+        }
+
         #[cfg(feature = "uuid")]
         assert_eq!(
             Uuid::max().validate_range(Some(Uuid::nil()), None, None, None),
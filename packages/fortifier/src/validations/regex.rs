@@ -77,6 +77,17 @@ impl fmt::Display for RegexError {
 
 impl Error for RegexError {}
 
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for RegexError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        generator.define_type("RegexError", |_| {
+            crate::zod::error_type(REGEX_ERROR_CODE, None, &[])
+        });
+
+        "RegexError".to_owned()
+    }
+}
+
 /// Validate a regular expression.
 pub trait ValidateRegex {
     /// Validate regular expression.
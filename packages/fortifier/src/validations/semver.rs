@@ -0,0 +1,387 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(SemverErrorCode, SEMVER_ERROR_CODE, "semver");
+
+/// Semantic version validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum SemverError {
+    /// Invalid version core error, the version does not start with `MAJOR.MINOR.PATCH`.
+    InvalidCore {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SemverErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Empty pre-release or build metadata identifier error.
+    EmptyIdentifier {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SemverErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SemverErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Leading zero in a numeric identifier error.
+    LeadingZero {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SemverErrorCode,
+
+        /// The numeric identifier.
+        identifier: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for SemverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for SemverError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for SemverError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("SemverError", |_| {
+            [
+                error_type(SEMVER_ERROR_CODE, Some("invalidCore"), &[]),
+                error_type(SEMVER_ERROR_CODE, Some("emptyIdentifier"), &[]),
+                error_type(
+                    SEMVER_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(
+                    SEMVER_ERROR_CODE,
+                    Some("leadingZero"),
+                    &[("identifier", "string")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "SemverError".to_owned()
+    }
+}
+
+/// Validate a semantic version.
+pub trait ValidateSemver {
+    /// The semantic version.
+    fn semver(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate semantic version.
+    fn validate_semver(&self) -> Result<(), SemverError> {
+        self.semver()
+            .map(|semver| validate_semver(&semver))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate a semantic version (e.g. `1.0.0-alpha.1+build.5`).
+///
+/// See [Semantic Versioning 2.0.0](https://semver.org/spec/v2.0.0.html).
+pub fn validate_semver(value: &str) -> Result<(), SemverError> {
+    let (value, build) = match value.split_once('+') {
+        Some((value, build)) => (value, Some(build)),
+        None => (value, None),
+    };
+    let (core, pre_release) = match value.split_once('-') {
+        Some((core, pre_release)) => (core, Some(pre_release)),
+        None => (value, None),
+    };
+
+    let parts = core.split('.').collect::<Vec<_>>();
+    if parts.len() != 3
+        || parts
+            .iter()
+            .any(|part| part.is_empty() || !part.bytes().all(|byte| byte.is_ascii_digit()))
+    {
+        return Err(SemverError::InvalidCore {
+            code: SemverErrorCode,
+            #[cfg(feature = "message")]
+            message: "version must start with `MAJOR.MINOR.PATCH`".to_owned(),
+        });
+    }
+
+    for part in parts {
+        check_leading_zero(part)?;
+    }
+
+    if let Some(pre_release) = pre_release {
+        for identifier in pre_release.split('.') {
+            check_identifier(identifier)?;
+
+            if identifier.bytes().all(|byte| byte.is_ascii_digit()) {
+                check_leading_zero(identifier)?;
+            }
+        }
+    }
+
+    if let Some(build) = build {
+        for identifier in build.split('.') {
+            check_identifier(identifier)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_identifier(identifier: &str) -> Result<(), SemverError> {
+    if identifier.is_empty() {
+        return Err(SemverError::EmptyIdentifier {
+            code: SemverErrorCode,
+            #[cfg(feature = "message")]
+            message: "identifiers must not be empty".to_owned(),
+        });
+    }
+
+    if let Some(character) = identifier
+        .chars()
+        .find(|character| !character.is_ascii_alphanumeric() && *character != '-')
+    {
+        return Err(SemverError::InvalidCharacter {
+            code: SemverErrorCode,
+            character,
+            #[cfg(feature = "message")]
+            message: format!("invalid character `{character}`"),
+        });
+    }
+
+    Ok(())
+}
+
+fn check_leading_zero(identifier: &str) -> Result<(), SemverError> {
+    if identifier.len() > 1 && identifier.starts_with('0') {
+        return Err(SemverError::LeadingZero {
+            code: SemverErrorCode,
+            identifier: identifier.to_owned(),
+            #[cfg(feature = "message")]
+            message: format!("numeric identifier `{identifier}` must not have leading zeros"),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateSemver for str {
+    fn semver(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateSemver for &str {
+    fn semver(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateSemver for String {
+    fn semver(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateSemver for Cow<'_, str> {
+    fn semver(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateSemver for Option<T>
+where
+    T: ValidateSemver,
+{
+    fn semver(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self {
+            T::semver(s)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateSemver for $type
+        where
+            T: ValidateSemver,
+        {
+            fn semver(&self) -> Option<Cow<'_, str>> {
+                T::semver(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{SemverError, SemverErrorCode, ValidateSemver};
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"1.0.0").validate_semver(), Ok(()));
+        assert_eq!("1.0.0".validate_semver(), Ok(()));
+        assert_eq!("1.0.0".to_owned().validate_semver(), Ok(()));
+        assert_eq!(Cow::<str>::Borrowed("1.0.0").validate_semver(), Ok(()));
+
+        assert_eq!(None::<&str>.validate_semver(), Ok(()));
+        assert_eq!(Some("1.0.0").validate_semver(), Ok(()));
+
+        assert_eq!((&"1.0.0").validate_semver(), Ok(()));
+        assert_eq!(Box::new("1.0.0").validate_semver(), Ok(()));
+        assert_eq!(Arc::new("1.0.0").validate_semver(), Ok(()));
+        assert_eq!(Rc::new("1.0.0").validate_semver(), Ok(()));
+
+        let cell = RefCell::new("1.0.0");
+        assert_eq!(cell.borrow().validate_semver(), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_semver(), Ok(()));
+
+        for value in [
+            "0.0.0",
+            "10.20.30",
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-0.3.7",
+            "1.0.0-x-y-z.--",
+            "1.0.0-alpha+001",
+            "1.0.0+20130313144700",
+            "1.0.0-beta+exp.sha.5114f85",
+        ] {
+            assert_eq!(value.validate_semver(), Ok(()), "{value}");
+        }
+    }
+
+    #[test]
+    fn invalid_core_error() {
+        for value in ["1", "1.2", "1.2.3.4", "v1.2.3", "1..3", "1.2.x", ""] {
+            assert_eq!(
+                value.validate_semver(),
+                Err(SemverError::InvalidCore {
+                    code: SemverErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "version must start with `MAJOR.MINOR.PATCH`".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_identifier_error() {
+        for value in ["1.0.0-", "1.0.0-alpha..1", "1.0.0+", "1.0.0+build."] {
+            assert_eq!(
+                value.validate_semver(),
+                Err(SemverError::EmptyIdentifier {
+                    code: SemverErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "identifiers must not be empty".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_character_error() {
+        assert_eq!(
+            "1.0.0-alpha_1".validate_semver(),
+            Err(SemverError::InvalidCharacter {
+                code: SemverErrorCode,
+                character: '_',
+                #[cfg(feature = "message")]
+                message: "invalid character `_`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "1.0.0+build+1".validate_semver(),
+            Err(SemverError::InvalidCharacter {
+                code: SemverErrorCode,
+                character: '+',
+                #[cfg(feature = "message")]
+                message: "invalid character `+`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn leading_zero_error() {
+        assert_eq!(
+            "01.0.0".validate_semver(),
+            Err(SemverError::LeadingZero {
+                code: SemverErrorCode,
+                identifier: "01".to_owned(),
+                #[cfg(feature = "message")]
+                message: "numeric identifier `01` must not have leading zeros".to_owned(),
+            })
+        );
+        assert_eq!(
+            "1.0.0-alpha.01".validate_semver(),
+            Err(SemverError::LeadingZero {
+                code: SemverErrorCode,
+                identifier: "01".to_owned(),
+                #[cfg(feature = "message")]
+                message: "numeric identifier `01` must not have leading zeros".to_owned(),
+            })
+        );
+        assert_eq!("1.0.0+001".validate_semver(), Ok(()));
+    }
+}
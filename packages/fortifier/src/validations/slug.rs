@@ -0,0 +1,270 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(SlugErrorCode, SLUG_ERROR_CODE, "slug");
+
+/// Slug validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum SlugError {
+    /// Empty slug error.
+    Empty {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SlugErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SlugErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Leading, trailing or consecutive hyphen error.
+    InvalidHyphen {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SlugErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for SlugError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for SlugError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for SlugError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("SlugError", |_| {
+            [
+                error_type(SLUG_ERROR_CODE, Some("empty"), &[]),
+                error_type(
+                    SLUG_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string")],
+                ),
+                error_type(SLUG_ERROR_CODE, Some("invalidHyphen"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "SlugError".to_owned()
+    }
+}
+
+/// Validate a slug.
+pub trait ValidateSlug {
+    /// The slug.
+    fn slug(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate slug.
+    fn validate_slug(&self) -> Result<(), SlugError> {
+        self.slug().map(|slug| validate_slug(&slug)).transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Validate a URL slug.
+///
+/// A slug consists of lowercase ASCII letters and digits, separated by single hyphens (e.g. `hello-world-2`).
+pub fn validate_slug(value: &str) -> Result<(), SlugError> {
+    if value.is_empty() {
+        return Err(SlugError::Empty {
+            code: SlugErrorCode,
+            #[cfg(feature = "message")]
+            message: "slug must not be empty".to_owned(),
+        });
+    }
+
+    if let Some(character) = value
+        .chars()
+        .find(|character| !matches!(character, 'a'..='z' | '0'..='9' | '-'))
+    {
+        return Err(SlugError::InvalidCharacter {
+            code: SlugErrorCode,
+            character,
+            #[cfg(feature = "message")]
+            message: format!("invalid character `{character}`"),
+        });
+    }
+
+    if value.starts_with('-') || value.ends_with('-') || value.contains("--") {
+        return Err(SlugError::InvalidHyphen {
+            code: SlugErrorCode,
+            #[cfg(feature = "message")]
+            message: "slug must not start, end or contain consecutive hyphens".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ValidateSlug for str {
+    fn slug(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateSlug for &str {
+    fn slug(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateSlug for String {
+    fn slug(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateSlug for Cow<'_, str> {
+    fn slug(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateSlug for Option<T>
+where
+    T: ValidateSlug,
+{
+    fn slug(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self { T::slug(s) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateSlug for $type
+        where
+            T: ValidateSlug,
+        {
+            fn slug(&self) -> Option<Cow<'_, str>> {
+                T::slug(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{SlugError, SlugErrorCode, ValidateSlug};
+
+    #[test]
+    fn ok() {
+        assert_eq!((*"hello-world").validate_slug(), Ok(()));
+        assert_eq!("hello-world-2".validate_slug(), Ok(()));
+        assert_eq!("hello-world".to_owned().validate_slug(), Ok(()));
+        assert_eq!(Cow::<str>::Borrowed("hello-world").validate_slug(), Ok(()));
+
+        assert_eq!(None::<&str>.validate_slug(), Ok(()));
+        assert_eq!(Some("hello").validate_slug(), Ok(()));
+
+        assert_eq!((&"hello-world").validate_slug(), Ok(()));
+        assert_eq!(Box::new("hello-world").validate_slug(), Ok(()));
+        assert_eq!(Arc::new("hello-world").validate_slug(), Ok(()));
+        assert_eq!(Rc::new("hello-world").validate_slug(), Ok(()));
+
+        let cell = RefCell::new("hello-world");
+        assert_eq!(cell.borrow().validate_slug(), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_slug(), Ok(()));
+    }
+
+    #[test]
+    fn empty_error() {
+        assert_eq!(
+            "".validate_slug(),
+            Err(SlugError::Empty {
+                code: SlugErrorCode,
+                #[cfg(feature = "message")]
+                message: "slug must not be empty".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_character_error() {
+        assert_eq!(
+            "Hello-world".validate_slug(),
+            Err(SlugError::InvalidCharacter {
+                code: SlugErrorCode,
+                character: 'H',
+                #[cfg(feature = "message")]
+                message: "invalid character `H`".to_owned(),
+            })
+        );
+        assert_eq!(
+            "hello_world".validate_slug(),
+            Err(SlugError::InvalidCharacter {
+                code: SlugErrorCode,
+                character: '_',
+                #[cfg(feature = "message")]
+                message: "invalid character `_`".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_hyphen_error() {
+        for value in ["-hello", "hello-", "hello--world", "-"] {
+            assert_eq!(
+                value.validate_slug(),
+                Err(SlugError::InvalidHyphen {
+                    code: SlugErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "slug must not start, end or contain consecutive hyphens".to_owned(),
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,477 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    net::{IpAddr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+use crate::validations::ip::{IpOptions, IpVersion, IpViolation};
+
+constant_string!(SocketAddrErrorCode, SOCKET_ADDR_ERROR_CODE, "socketAddr");
+
+/// Socket address validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum SocketAddrError {
+    /// Missing port error.
+    MissingPort {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid socket address error.
+    InvalidAddress {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed version error.
+    DisallowedVersion {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// The actual version.
+        value: IpVersion,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Private address error.
+    Private {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Loopback address error.
+    Loopback {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Link-local address error.
+    LinkLocal {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Multicast address error.
+    Multicast {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Unspecified address error.
+    Unspecified {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Broadcast address error.
+    Broadcast {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: SocketAddrErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for SocketAddrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for SocketAddrError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for SocketAddrError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::{validations::ip::IP_VERSION_TYPESCRIPT, zod::error_type};
+
+        generator.define_type("SocketAddrError", |_| {
+            [
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("missingPort"), &[]),
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("invalidAddress"), &[]),
+                error_type(
+                    SOCKET_ADDR_ERROR_CODE,
+                    Some("disallowedVersion"),
+                    &[("value", IP_VERSION_TYPESCRIPT)],
+                ),
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("private"), &[]),
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("loopback"), &[]),
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("linkLocal"), &[]),
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("multicast"), &[]),
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("unspecified"), &[]),
+                error_type(SOCKET_ADDR_ERROR_CODE, Some("broadcast"), &[]),
+            ]
+            .join(" | ")
+        });
+
+        "SocketAddrError".to_owned()
+    }
+}
+
+impl From<IpViolation> for SocketAddrError {
+    fn from(value: IpViolation) -> Self {
+        let code = SocketAddrErrorCode;
+        #[cfg(feature = "message")]
+        let message = value.message("address");
+
+        match value {
+            IpViolation::Version(version) => Self::DisallowedVersion {
+                code,
+                value: version,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Private => Self::Private {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Loopback => Self::Loopback {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::LinkLocal => Self::LinkLocal {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Multicast => Self::Multicast {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Unspecified => Self::Unspecified {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+            IpViolation::Broadcast => Self::Broadcast {
+                code,
+                #[cfg(feature = "message")]
+                message,
+            },
+        }
+    }
+}
+
+/// Validate a socket address.
+pub trait ValidateSocketAddr {
+    /// The socket address.
+    fn socket_addr(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate socket address.
+    fn validate_socket_addr(&self, options: IpOptions) -> Result<(), SocketAddrError> {
+        self.parse_socket_addr(options)?;
+
+        Ok(())
+    }
+
+    /// Validate socket address and return the parsed socket address.
+    fn parse_socket_addr(&self, options: IpOptions) -> Result<Option<SocketAddr>, SocketAddrError> {
+        self.socket_addr()
+            .map(|socket_addr| parse_socket_addr(&socket_addr, options))
+            .transpose()
+    }
+}
+
+/// Parse a socket address (e.g. `10.0.0.1:8080` or `[::1]:8080`).
+///
+/// The options are checked against the IP address.
+pub fn parse_socket_addr(value: &str, options: IpOptions) -> Result<SocketAddr, SocketAddrError> {
+    let socket_addr = value.parse::<SocketAddr>().map_err(|_| {
+        if value.parse::<IpAddr>().is_ok()
+            || value
+                .strip_prefix('[')
+                .and_then(|value| value.strip_suffix(']'))
+                .is_some_and(|value| value.parse::<IpAddr>().is_ok())
+        {
+            SocketAddrError::MissingPort {
+                code: SocketAddrErrorCode,
+                #[cfg(feature = "message")]
+                message: "missing port".to_owned(),
+            }
+        } else {
+            SocketAddrError::InvalidAddress {
+                code: SocketAddrErrorCode,
+                #[cfg(feature = "message")]
+                message: "invalid socket address".to_owned(),
+            }
+        }
+    })?;
+
+    options.check(socket_addr.ip())?;
+
+    Ok(socket_addr)
+}
+
+impl ValidateSocketAddr for str {
+    fn socket_addr(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateSocketAddr for &str {
+    fn socket_addr(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateSocketAddr for String {
+    fn socket_addr(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateSocketAddr for Cow<'_, str> {
+    fn socket_addr(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+macro_rules! validate_socket_addr {
+    ($type:ty) => {
+        impl ValidateSocketAddr for $type {
+            fn socket_addr(&self) -> Option<Cow<'_, str>> {
+                Some(self.to_string().into())
+            }
+
+            fn parse_socket_addr(
+                &self,
+                options: IpOptions,
+            ) -> Result<Option<SocketAddr>, SocketAddrError> {
+                // Socket address has already been parsed, but the options still apply.
+                let socket_addr = SocketAddr::from(*self);
+
+                options.check(socket_addr.ip())?;
+
+                Ok(Some(socket_addr))
+            }
+        }
+    };
+}
+
+validate_socket_addr!(SocketAddr);
+validate_socket_addr!(SocketAddrV4);
+validate_socket_addr!(SocketAddrV6);
+
+impl<T> ValidateSocketAddr for Option<T>
+where
+    T: ValidateSocketAddr,
+{
+    fn socket_addr(&self) -> Option<Cow<'_, str>> {
+        if let Some(socket_addr) = self {
+            T::socket_addr(socket_addr)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateSocketAddr for $type
+        where
+            T: ValidateSocketAddr,
+        {
+            fn socket_addr(&self) -> Option<Cow<'_, str>> {
+                T::socket_addr(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        cell::RefCell,
+        net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use super::{IpOptions, IpVersion, SocketAddrError, SocketAddrErrorCode, ValidateSocketAddr};
+
+    #[test]
+    fn ok() {
+        let options = IpOptions::default();
+
+        assert_eq!((*"127.0.0.1:8080").validate_socket_addr(options), Ok(()));
+        assert_eq!("[::1]:443".validate_socket_addr(options), Ok(()));
+        assert_eq!(
+            "10.0.0.1:80".to_owned().validate_socket_addr(options),
+            Ok(())
+        );
+        assert_eq!(
+            Cow::<str>::Borrowed("[fe80::1]:8080").validate_socket_addr(options),
+            Ok(())
+        );
+        assert_eq!(
+            SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80).validate_socket_addr(options),
+            Ok(())
+        );
+
+        assert_eq!(None::<&str>.validate_socket_addr(options), Ok(()));
+        assert_eq!(Some("8.8.8.8:53").validate_socket_addr(options), Ok(()));
+
+        assert_eq!((&"8.8.8.8:53").validate_socket_addr(options), Ok(()));
+        assert_eq!(Box::new("8.8.8.8:53").validate_socket_addr(options), Ok(()));
+        assert_eq!(Arc::new("8.8.8.8:53").validate_socket_addr(options), Ok(()));
+        assert_eq!(Rc::new("8.8.8.8:53").validate_socket_addr(options), Ok(()));
+
+        let cell = RefCell::new("8.8.8.8:53");
+        assert_eq!(cell.borrow().validate_socket_addr(options), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_socket_addr(options), Ok(()));
+
+        assert_eq!(
+            "8.8.8.8:53".parse_socket_addr(options),
+            Ok(Some(SocketAddr::from(([8, 8, 8, 8], 53))))
+        );
+    }
+
+    #[test]
+    fn invalid_error() {
+        let options = IpOptions::default();
+
+        for value in ["127.0.0.1", "::1", "[::1]"] {
+            assert_eq!(
+                value.validate_socket_addr(options),
+                Err(SocketAddrError::MissingPort {
+                    code: SocketAddrErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "missing port".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+        for value in [
+            "",
+            "localhost:80",
+            "127.0.0.1:65536",
+            "[::1]:http",
+            "127.0.0.1:",
+        ] {
+            assert_eq!(
+                value.validate_socket_addr(options),
+                Err(SocketAddrError::InvalidAddress {
+                    code: SocketAddrErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "invalid socket address".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn options_error() {
+        let options = IpOptions {
+            v6: false,
+            allow_loopback: false,
+            allow_unspecified: false,
+            allow_broadcast: false,
+            ..IpOptions::default()
+        };
+
+        assert_eq!(
+            "[2001:db8::1]:80".validate_socket_addr(options),
+            Err(SocketAddrError::DisallowedVersion {
+                code: SocketAddrErrorCode,
+                value: IpVersion::V6,
+                #[cfg(feature = "message")]
+                message: "IPv6 address is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80).validate_socket_addr(options),
+            Err(SocketAddrError::Loopback {
+                code: SocketAddrErrorCode,
+                #[cfg(feature = "message")]
+                message: "loopback address is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            "0.0.0.0:80".validate_socket_addr(options),
+            Err(SocketAddrError::Unspecified {
+                code: SocketAddrErrorCode,
+                #[cfg(feature = "message")]
+                message: "unspecified address is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            "255.255.255.255:80".validate_socket_addr(options),
+            Err(SocketAddrError::Broadcast {
+                code: SocketAddrErrorCode,
+                #[cfg(feature = "message")]
+                message: "broadcast address is not allowed".to_owned(),
+            })
+        );
+    }
+}
@@ -8,10 +8,166 @@ use std::{
 };
 
 use constant_string::constant_string;
-use url::{ParseError, Url};
+pub use url::Url;
+use url::{Host, ParseError};
 
 constant_string!(UrlErrorCode, URL_ERROR_CODE, "url");
 
+/// URL validation options.
+///
+/// The default options allow any URL.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct UrlOptions<'a> {
+    /// Allowed schemes (e.g. `https`).
+    pub schemes: Option<&'a [&'a str]>,
+    /// Allowed hosts.
+    ///
+    /// A host starting with `*.` allows any subdomain (e.g. `*.example.com` allows `api.example.com`, but not
+    /// `example.com`).
+    pub hosts: Option<&'a [&'a str]>,
+    /// Allowed ports.
+    ///
+    /// URLs without a port are checked using the default port for the scheme.
+    pub ports: Option<&'a [u16]>,
+    /// Deny IP address hosts.
+    pub deny_ip_hosts: bool,
+    /// Deny credentials (username and password).
+    pub deny_credentials: bool,
+    /// Require a domain host with a top-level domain.
+    pub require_tld: bool,
+    /// Maximum length in characters.
+    pub max_length: Option<usize>,
+}
+
+impl UrlOptions<'_> {
+    /// Check a parsed URL against the options.
+    fn check(&self, url: &Url) -> Result<(), UrlError> {
+        if let Some(schemes) = self.schemes
+            && !schemes
+                .iter()
+                .any(|scheme| scheme.eq_ignore_ascii_case(url.scheme()))
+        {
+            return Err(UrlError::DisallowedScheme {
+                code: UrlErrorCode,
+                allowed: schemes.iter().map(|scheme| (*scheme).to_owned()).collect(),
+                value: url.scheme().to_owned(),
+                #[cfg(feature = "message")]
+                message: format!("scheme `{}` is not allowed", url.scheme()),
+            });
+        }
+
+        if self.deny_credentials && (!url.username().is_empty() || url.password().is_some()) {
+            return Err(UrlError::Credentials {
+                code: UrlErrorCode,
+                #[cfg(feature = "message")]
+                message: "credentials are not allowed".to_owned(),
+            });
+        }
+
+        if self.deny_ip_hosts || self.require_tld || self.hosts.is_some() {
+            let Some(host) = url.host() else {
+                return Err(UrlError::MissingHost {
+                    code: UrlErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "missing host".to_owned(),
+                });
+            };
+
+            if self.deny_ip_hosts && matches!(host, Host::Ipv4(_) | Host::Ipv6(_)) {
+                return Err(UrlError::IpHost {
+                    code: UrlErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "IP address host is not allowed".to_owned(),
+                });
+            }
+
+            if self.require_tld && !has_tld(&host) {
+                return Err(UrlError::MissingTld {
+                    code: UrlErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "host must have a top-level domain".to_owned(),
+                });
+            }
+
+            let host = host.to_string();
+
+            if let Some(hosts) = self.hosts
+                && !hosts.iter().any(|allowed| host_matches(allowed, &host))
+            {
+                return Err(UrlError::DisallowedHost {
+                    code: UrlErrorCode,
+                    #[cfg(feature = "message")]
+                    message: format!("host `{host}` is not allowed"),
+                    value: host,
+                });
+            }
+        }
+
+        if let Some(ports) = self.ports {
+            let port = url.port_or_known_default();
+
+            if !port.is_some_and(|port| ports.contains(&port)) {
+                return Err(UrlError::DisallowedPort {
+                    code: UrlErrorCode,
+                    allowed: ports.to_vec(),
+                    value: port,
+                    #[cfg(feature = "message")]
+                    message: match port {
+                        Some(port) => format!("port {port} is not allowed"),
+                        None => "missing port".to_owned(),
+                    },
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_length(&self, value: &str) -> Result<(), UrlError> {
+        // The number of bytes is an upper bound for the number of characters, so only count characters when needed.
+        if let Some(max_length) = self.max_length
+            && value.len() > max_length
+        {
+            let length = value.chars().count();
+
+            if length > max_length {
+                return Err(UrlError::TooLong {
+                    code: UrlErrorCode,
+                    max: max_length,
+                    value: length,
+                    #[cfg(feature = "message")]
+                    message: format!("URL must be at most {max_length} characters"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn has_tld(host: &Host<&str>) -> bool {
+    match host {
+        Host::Domain(domain) => {
+            let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+            domain.rsplit_once('.').is_some_and(|(name, tld)| {
+                !name.is_empty() && !tld.is_empty() && !tld.chars().all(|c| c.is_ascii_digit())
+            })
+        }
+        Host::Ipv4(_) | Host::Ipv6(_) => false,
+    }
+}
+
+fn host_matches(allowed: &str, host: &str) -> bool {
+    match allowed.strip_prefix("*.") {
+        Some(domain) => host
+            .strip_suffix(domain.to_ascii_lowercase().as_str())
+            .and_then(|subdomain| subdomain.strip_suffix('.'))
+            .is_some_and(|subdomain| !subdomain.is_empty()),
+        None => allowed.eq_ignore_ascii_case(host),
+    }
+}
+
 /// URL validation error.
 #[derive(Debug, Eq, PartialEq)]
 #[cfg_attr(
@@ -135,6 +291,117 @@ pub enum UrlError {
         #[cfg(feature = "message")]
         message: String,
     },
+    /// URL too long error.
+    TooLong {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// The maximum length.
+        max: usize,
+
+        /// The actual length.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed scheme error.
+    DisallowedScheme {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// The allowed schemes.
+        allowed: Vec<String>,
+
+        /// The actual scheme.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Credentials error.
+    ///
+    /// The URL contains a username or password.
+    Credentials {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Missing host error.
+    MissingHost {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// IP address host error.
+    IpHost {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Missing top-level domain error.
+    MissingTld {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed host error.
+    DisallowedHost {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// The actual host.
+        value: String,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed port error.
+    DisallowedPort {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UrlErrorCode,
+
+        /// The allowed ports.
+        allowed: Vec<u16>,
+
+        /// The actual port, or the default port for the scheme.
+        value: Option<u16>,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
     /// Unknown error.
     Unknown {
         /// The error code.
@@ -156,6 +423,60 @@ impl fmt::Display for UrlError {
 
 impl Error for UrlError {}
 
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for UrlError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("UrlError", |_| {
+            [
+                error_type(URL_ERROR_CODE, Some("emptyHost"), &[]),
+                error_type(URL_ERROR_CODE, Some("idnaError"), &[]),
+                error_type(URL_ERROR_CODE, Some("invalidPort"), &[]),
+                error_type(URL_ERROR_CODE, Some("invalidIpv4Address"), &[]),
+                error_type(URL_ERROR_CODE, Some("invalidIpv6Address"), &[]),
+                error_type(URL_ERROR_CODE, Some("invalidDomainCharacter"), &[]),
+                error_type(URL_ERROR_CODE, Some("relativeUrlWithoutBase"), &[]),
+                error_type(
+                    URL_ERROR_CODE,
+                    Some("relativeUrlWithCannotBeABaseBase"),
+                    &[],
+                ),
+                error_type(URL_ERROR_CODE, Some("setHostOnCannotBeABaseUrl"), &[]),
+                error_type(URL_ERROR_CODE, Some("overflow"), &[]),
+                error_type(URL_ERROR_CODE, Some("unknown"), &[]),
+                error_type(
+                    URL_ERROR_CODE,
+                    Some("tooLong"),
+                    &[("max", "number"), ("value", "number")],
+                ),
+                error_type(
+                    URL_ERROR_CODE,
+                    Some("disallowedScheme"),
+                    &[("allowed", "Array<string>"), ("value", "string")],
+                ),
+                error_type(URL_ERROR_CODE, Some("credentials"), &[]),
+                error_type(URL_ERROR_CODE, Some("missingHost"), &[]),
+                error_type(URL_ERROR_CODE, Some("ipHost"), &[]),
+                error_type(URL_ERROR_CODE, Some("missingTld"), &[]),
+                error_type(
+                    URL_ERROR_CODE,
+                    Some("disallowedHost"),
+                    &[("value", "string")],
+                ),
+                error_type(
+                    URL_ERROR_CODE,
+                    Some("disallowedPort"),
+                    &[("allowed", "Array<number>"), ("value", "number | null")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "UrlError".to_owned()
+    }
+}
+
 impl From<ParseError> for UrlError {
     fn from(value: ParseError) -> Self {
         let code = UrlErrorCode;
@@ -240,15 +561,28 @@ pub trait ValidateUrl {
     fn url(&self) -> Option<Cow<'_, str>>;
 
     /// Validate URL.
-    fn validate_url(&self) -> Result<(), UrlError> {
-        let Some(url) = self.url() else {
-            return Ok(());
-        };
-
-        Url::parse(&url).map_err(UrlError::from)?;
+    fn validate_url(&self, options: UrlOptions<'_>) -> Result<(), UrlError> {
+        self.parse_url(options)?;
 
         Ok(())
     }
+
+    /// Validate URL and return the parsed URL.
+    fn parse_url(&self, options: UrlOptions<'_>) -> Result<Option<Url>, UrlError> {
+        self.url().map(|url| parse_url(&url, options)).transpose()
+    }
+}
+
+/// Parse a URL.
+pub fn parse_url(value: &str, options: UrlOptions<'_>) -> Result<Url, UrlError> {
+    // Check the length before parsing to avoid parsing long values.
+    options.check_length(value)?;
+
+    let url = Url::parse(value).map_err(UrlError::from)?;
+
+    options.check(&url)?;
+
+    Ok(url)
 }
 
 impl ValidateUrl for str {
@@ -280,9 +614,17 @@ impl ValidateUrl for Url {
         Some(self.as_str().into())
     }
 
-    fn validate_url(&self) -> Result<(), UrlError> {
-        // URL has already been parsed, so it must be valid.
-        Ok(())
+    fn validate_url(&self, options: UrlOptions<'_>) -> Result<(), UrlError> {
+        // URL has already been parsed, but the options still apply.
+        options.check_length(self.as_str())?;
+        options.check(self)
+    }
+
+    fn parse_url(&self, options: UrlOptions<'_>) -> Result<Option<Url>, UrlError> {
+        options.check_length(self.as_str())?;
+        options.check(self)?;
+
+        Ok(Some(self.clone()))
     }
 }
 
@@ -327,75 +669,104 @@ mod tests {
 
     use crate::UrlErrorCode;
 
-    use super::{UrlError, ValidateUrl};
+    use super::{UrlError, UrlOptions, ValidateUrl, parse_url};
 
     #[test]
     fn ok() {
-        assert_eq!((*"http://localhost").validate_url(), Ok(()));
-        assert_eq!("http://localhost".validate_url(), Ok(()));
-        assert_eq!("http://localhost".to_owned().validate_url(), Ok(()));
         assert_eq!(
-            Cow::<str>::Borrowed("http://localhost").validate_url(),
+            (*"http://localhost").validate_url(UrlOptions::default()),
             Ok(())
         );
         assert_eq!(
-            Cow::<str>::Owned("http://localhost".to_owned()).validate_url(),
+            "http://localhost".validate_url(UrlOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            "http://localhost"
+                .to_owned()
+                .validate_url(UrlOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Cow::<str>::Borrowed("http://localhost").validate_url(UrlOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Cow::<str>::Owned("http://localhost".to_owned()).validate_url(UrlOptions::default()),
             Ok(())
         );
         assert_eq!(
             Url::parse("http://localhost")
                 .expect("valid URL")
-                .validate_url(),
+                .validate_url(UrlOptions::default()),
             Ok(())
         );
 
-        assert_eq!(None::<&str>.validate_url(), Ok(()));
-        assert_eq!(Some("http://localhost").validate_url(), Ok(()));
+        assert_eq!(None::<&str>.validate_url(UrlOptions::default()), Ok(()));
+        assert_eq!(
+            Some("http://localhost").validate_url(UrlOptions::default()),
+            Ok(())
+        );
 
-        assert_eq!((&"http://localhost").validate_url(), Ok(()));
-        assert_eq!(Box::new("http://localhost").validate_url(), Ok(()));
-        assert_eq!(Arc::new("http://localhost").validate_url(), Ok(()));
-        assert_eq!(Rc::new("http://localhost").validate_url(), Ok(()));
+        assert_eq!(
+            (&"http://localhost").validate_url(UrlOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Box::new("http://localhost").validate_url(UrlOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Arc::new("http://localhost").validate_url(UrlOptions::default()),
+            Ok(())
+        );
+        assert_eq!(
+            Rc::new("http://localhost").validate_url(UrlOptions::default()),
+            Ok(())
+        );
 
         let cell = RefCell::new("http://localhost");
-        assert_eq!(cell.borrow().validate_url(), Ok(()));
-        assert_eq!(cell.borrow_mut().validate_url(), Ok(()));
+        assert_eq!(cell.borrow().validate_url(UrlOptions::default()), Ok(()));
+        assert_eq!(
+            cell.borrow_mut().validate_url(UrlOptions::default()),
+            Ok(())
+        );
     }
 
     #[test]
     fn parse_error() {
         assert_eq!(
-            (*"http://").validate_url(),
+            (*"http://").validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
         assert_eq!(
-            "http://".validate_url(),
+            "http://".validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
         assert_eq!(
-            "http://".to_owned().validate_url(),
+            "http://".to_owned().validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
         assert_eq!(
-            Cow::<str>::Borrowed("http://").validate_url(),
+            Cow::<str>::Borrowed("http://").validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
         assert_eq!(
-            Cow::<str>::Owned("http://".to_owned()).validate_url(),
+            Cow::<str>::Owned("http://".to_owned()).validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
 
         assert_eq!(
-            Some("http://").validate_url(),
+            Some("http://").validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
 
         assert_eq!(
-            (&"http://").validate_url(),
+            (&"http://").validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
         assert_eq!(
-            Box::new("http://").validate_url(),
+            Box::new("http://").validate_url(UrlOptions::default()),
             Err(UrlError::EmptyHost {
                 code: UrlErrorCode,
                 #[cfg(feature = "message")]
@@ -403,22 +774,239 @@ mod tests {
             })
         );
         assert_eq!(
-            Arc::new("http://").validate_url(),
+            Arc::new("http://").validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
         assert_eq!(
-            Rc::new("http://").validate_url(),
+            Rc::new("http://").validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
 
         let cell = RefCell::new("http://");
         assert_eq!(
-            cell.borrow().validate_url(),
+            cell.borrow().validate_url(UrlOptions::default()),
+            Err(UrlError::from(ParseError::EmptyHost))
+        );
+        assert_eq!(
+            cell.borrow_mut().validate_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
+    }
+
+    #[test]
+    fn parse() {
+        let url = Url::parse("http://localhost/").expect("valid URL");
+
+        assert_eq!(
+            parse_url("HTTP://LOCALHOST", UrlOptions::default()),
+            Ok(url.clone())
+        );
+        assert_eq!(
+            "http://localhost".parse_url(UrlOptions::default()),
+            Ok(Some(url.clone()))
+        );
+        assert_eq!(url.parse_url(UrlOptions::default()), Ok(Some(url)));
+        assert_eq!(None::<&str>.parse_url(UrlOptions::default()), Ok(None));
         assert_eq!(
-            cell.borrow_mut().validate_url(),
+            "http://".parse_url(UrlOptions::default()),
             Err(UrlError::from(ParseError::EmptyHost))
         );
     }
+
+    #[test]
+    fn options_ok() {
+        let options = UrlOptions {
+            schemes: Some(&["https"]),
+            hosts: Some(&["example.com", "*.example.org"]),
+            ports: Some(&[443, 8443]),
+            deny_ip_hosts: true,
+            deny_credentials: true,
+            require_tld: true,
+            max_length: Some(64),
+        };
+
+        assert_eq!("https://example.com/webhook".validate_url(options), Ok(()));
+        assert_eq!("HTTPS://EXAMPLE.COM".validate_url(options), Ok(()));
+        assert_eq!(
+            "https://api.example.org:8443/webhook".validate_url(options),
+            Ok(())
+        );
+        assert_eq!(
+            Url::parse("https://hooks.api.example.org")
+                .expect("valid URL")
+                .validate_url(options),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn options_error() {
+        let options = UrlOptions {
+            schemes: Some(&["https"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            "http://example.com".validate_url(options),
+            Err(UrlError::DisallowedScheme {
+                code: UrlErrorCode,
+                allowed: vec!["https".to_owned()],
+                value: "http".to_owned(),
+                #[cfg(feature = "message")]
+                message: "scheme `http` is not allowed".to_owned(),
+            })
+        );
+
+        let options = UrlOptions {
+            max_length: Some(16),
+            ..Default::default()
+        };
+        assert_eq!(
+            "https://example.com".validate_url(options),
+            Err(UrlError::TooLong {
+                code: UrlErrorCode,
+                max: 16,
+                value: 19,
+                #[cfg(feature = "message")]
+                message: "URL must be at most 16 characters".to_owned(),
+            })
+        );
+        assert_eq!(
+            Url::parse("https://example.com")
+                .expect("valid URL")
+                .validate_url(options),
+            Err(UrlError::TooLong {
+                code: UrlErrorCode,
+                max: 16,
+                value: 20,
+                #[cfg(feature = "message")]
+                message: "URL must be at most 16 characters".to_owned(),
+            })
+        );
+
+        // 19 characters, 21 bytes.
+        let options = UrlOptions {
+            max_length: Some(19),
+            ..Default::default()
+        };
+        assert_eq!("https://exämplé.com".validate_url(options), Ok(()));
+        let options = UrlOptions {
+            max_length: Some(18),
+            ..Default::default()
+        };
+        assert_eq!(
+            "https://exämplé.com".validate_url(options),
+            Err(UrlError::TooLong {
+                code: UrlErrorCode,
+                max: 18,
+                value: 19,
+                #[cfg(feature = "message")]
+                message: "URL must be at most 18 characters".to_owned(),
+            })
+        );
+
+        let options = UrlOptions {
+            deny_credentials: true,
+            ..Default::default()
+        };
+        for value in ["https://user@example.com", "https://:secret@example.com"] {
+            assert_eq!(
+                value.validate_url(options),
+                Err(UrlError::Credentials {
+                    code: UrlErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "credentials are not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        let options = UrlOptions {
+            deny_ip_hosts: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "mailto:user@example.com".validate_url(options),
+            Err(UrlError::MissingHost {
+                code: UrlErrorCode,
+                #[cfg(feature = "message")]
+                message: "missing host".to_owned(),
+            })
+        );
+        for value in ["http://127.0.0.1", "http://[::1]", "http://2130706433"] {
+            assert_eq!(
+                value.validate_url(options),
+                Err(UrlError::IpHost {
+                    code: UrlErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "IP address host is not allowed".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        let options = UrlOptions {
+            require_tld: true,
+            ..Default::default()
+        };
+        assert_eq!("https://example.com.".validate_url(options), Ok(()));
+        for value in ["http://localhost", "http://127.0.0.1", "http://.com"] {
+            assert_eq!(
+                value.validate_url(options),
+                Err(UrlError::MissingTld {
+                    code: UrlErrorCode,
+                    #[cfg(feature = "message")]
+                    message: "host must have a top-level domain".to_owned(),
+                }),
+                "{value}"
+            );
+        }
+
+        let options = UrlOptions {
+            hosts: Some(&["example.com", "*.example.org"]),
+            ..Default::default()
+        };
+        for (value, host) in [
+            ("https://api.example.com", "api.example.com"),
+            ("https://example.org", "example.org"),
+            ("https://example.org.evil.com", "example.org.evil.com"),
+            ("https://evilexample.org", "evilexample.org"),
+        ] {
+            assert_eq!(
+                value.validate_url(options),
+                Err(UrlError::DisallowedHost {
+                    code: UrlErrorCode,
+                    value: host.to_owned(),
+                    #[cfg(feature = "message")]
+                    message: format!("host `{host}` is not allowed"),
+                }),
+                "{value}"
+            );
+        }
+
+        let options = UrlOptions {
+            ports: Some(&[443]),
+            ..Default::default()
+        };
+        assert_eq!("https://example.com:443".validate_url(options), Ok(()));
+        assert_eq!(
+            "http://example.com".validate_url(options),
+            Err(UrlError::DisallowedPort {
+                code: UrlErrorCode,
+                allowed: vec![443],
+                value: Some(80),
+                #[cfg(feature = "message")]
+                message: "port 80 is not allowed".to_owned(),
+            })
+        );
+        assert_eq!(
+            "custom://example.com".validate_url(options),
+            Err(UrlError::DisallowedPort {
+                code: UrlErrorCode,
+                allowed: vec![443],
+                value: None,
+                #[cfg(feature = "message")]
+                message: "missing port".to_owned(),
+            })
+        );
+    }
 }
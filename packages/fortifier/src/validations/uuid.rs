@@ -0,0 +1,330 @@
+use std::{
+    borrow::Cow,
+    cell::{Ref, RefMut},
+    error::Error,
+    fmt,
+    rc::Rc,
+    sync::Arc,
+};
+
+use constant_string::constant_string;
+
+constant_string!(UuidErrorCode, UUID_ERROR_CODE, "uuid");
+
+/// UUID validation error.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        tag = "subcode",
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase"
+    )
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum UuidError {
+    /// Invalid length error.
+    InvalidLength {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UuidErrorCode,
+
+        /// The actual number of characters.
+        value: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Invalid character error.
+    InvalidCharacter {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UuidErrorCode,
+
+        /// The invalid character.
+        #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+        character: char,
+
+        /// The index of the invalid character.
+        index: usize,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+    /// Disallowed version error.
+    DisallowedVersion {
+        /// The error code.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "utoipa", schema(inline, required))]
+        code: UuidErrorCode,
+
+        /// The allowed versions.
+        allowed: Vec<u8>,
+
+        /// The actual version.
+        value: u8,
+
+        /// A human-readable error message.
+        #[cfg(feature = "message")]
+        message: String,
+    },
+}
+
+impl fmt::Display for UuidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for UuidError {}
+
+#[cfg(feature = "zod")]
+impl crate::zod::ToTypeScript for UuidError {
+    fn typescript(generator: &mut crate::zod::ZodGenerator) -> String {
+        use crate::zod::error_type;
+
+        generator.define_type("UuidError", |_| {
+            [
+                error_type(
+                    UUID_ERROR_CODE,
+                    Some("invalidLength"),
+                    &[("value", "number")],
+                ),
+                error_type(
+                    UUID_ERROR_CODE,
+                    Some("invalidCharacter"),
+                    &[("character", "string"), ("index", "number")],
+                ),
+                error_type(
+                    UUID_ERROR_CODE,
+                    Some("disallowedVersion"),
+                    &[("allowed", "number[]"), ("value", "number")],
+                ),
+            ]
+            .join(" | ")
+        });
+
+        "UuidError".to_owned()
+    }
+}
+
+/// Validate a UUID string.
+pub trait ValidateUuid {
+    /// The UUID string.
+    fn uuid(&self) -> Option<Cow<'_, str>>;
+
+    /// Validate UUID string.
+    fn validate_uuid(&self, versions: Option<&[u8]>) -> Result<(), UuidError> {
+        self.uuid()
+            .map(|uuid| validate_uuid(&uuid, versions))
+            .transpose()?;
+
+        Ok(())
+    }
+}
+
+/// Indices of the hyphens in the hyphenated UUID format.
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// Validate a UUID string in the hyphenated format (e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`).
+///
+/// The version is the first hexadecimal digit of the third group.
+pub fn validate_uuid(value: &str, versions: Option<&[u8]>) -> Result<(), UuidError> {
+    let length = value.chars().count();
+
+    if length != 36 {
+        return Err(UuidError::InvalidLength {
+            code: UuidErrorCode,
+            value: length,
+            #[cfg(feature = "message")]
+            message: "UUID must have 36 characters".to_owned(),
+        });
+    }
+
+    for (index, character) in value.chars().enumerate() {
+        let valid = if HYPHENS.contains(&index) {
+            character == '-'
+        } else {
+            character.is_ascii_hexdigit()
+        };
+
+        if !valid {
+            return Err(UuidError::InvalidCharacter {
+                code: UuidErrorCode,
+                character,
+                index,
+                #[cfg(feature = "message")]
+                message: format!("invalid character `{character}` at index {index}"),
+            });
+        }
+    }
+
+    if let Some(versions) = versions {
+        // The length and characters are checked above, so the version is an ASCII hexadecimal digit.
+        let version = value
+            .chars()
+            .nth(14)
+            .and_then(|character| character.to_digit(16))
+            .unwrap_or_default() as u8;
+
+        if !versions.contains(&version) {
+            return Err(UuidError::DisallowedVersion {
+                code: UuidErrorCode,
+                allowed: versions.to_vec(),
+                value: version,
+                #[cfg(feature = "message")]
+                message: format!("UUID version {version} is not allowed"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl ValidateUuid for str {
+    fn uuid(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateUuid for &str {
+    fn uuid(&self) -> Option<Cow<'_, str>> {
+        Some((*self).into())
+    }
+}
+
+impl ValidateUuid for String {
+    fn uuid(&self) -> Option<Cow<'_, str>> {
+        Some(self.into())
+    }
+}
+
+impl ValidateUuid for Cow<'_, str> {
+    fn uuid(&self) -> Option<Cow<'_, str>> {
+        Some(self.clone())
+    }
+}
+
+impl<T> ValidateUuid for Option<T>
+where
+    T: ValidateUuid,
+{
+    fn uuid(&self) -> Option<Cow<'_, str>> {
+        if let Some(s) = self { T::uuid(s) } else { None }
+    }
+}
+
+macro_rules! validate_with_deref {
+    ($type:ty) => {
+        impl<T> ValidateUuid for $type
+        where
+            T: ValidateUuid,
+        {
+            fn uuid(&self) -> Option<Cow<'_, str>> {
+                T::uuid(self)
+            }
+        }
+    };
+}
+
+validate_with_deref!(&T);
+validate_with_deref!(Arc<T>);
+validate_with_deref!(Box<T>);
+validate_with_deref!(Rc<T>);
+validate_with_deref!(Ref<'_, T>);
+validate_with_deref!(RefMut<'_, T>);
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{UuidError, UuidErrorCode, ValidateUuid};
+
+    const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    #[test]
+    fn ok() {
+        assert_eq!((*UUID).validate_uuid(None), Ok(()));
+        assert_eq!(UUID.validate_uuid(None), Ok(()));
+        assert_eq!(UUID.to_owned().validate_uuid(None), Ok(()));
+        assert_eq!(Cow::<str>::Borrowed(UUID).validate_uuid(None), Ok(()));
+
+        assert_eq!(None::<&str>.validate_uuid(None), Ok(()));
+        assert_eq!(Some(UUID).validate_uuid(None), Ok(()));
+
+        assert_eq!((&UUID).validate_uuid(None), Ok(()));
+        assert_eq!(Box::new(UUID).validate_uuid(None), Ok(()));
+        assert_eq!(Arc::new(UUID).validate_uuid(None), Ok(()));
+        assert_eq!(Rc::new(UUID).validate_uuid(None), Ok(()));
+
+        let cell = RefCell::new(UUID);
+        assert_eq!(cell.borrow().validate_uuid(None), Ok(()));
+        assert_eq!(cell.borrow_mut().validate_uuid(None), Ok(()));
+
+        assert_eq!(
+            "67E55044-10B1-426F-9247-BB680E5FE0C8".validate_uuid(None),
+            Ok(())
+        );
+        assert_eq!(
+            "00000000-0000-0000-0000-000000000000".validate_uuid(None),
+            Ok(())
+        );
+        assert_eq!(UUID.validate_uuid(Some(&[4, 7])), Ok(()));
+    }
+
+    #[test]
+    fn invalid_length_error() {
+        assert_eq!(
+            "67e5504410b1426f9247bb680e5fe0c8".validate_uuid(None),
+            Err(UuidError::InvalidLength {
+                code: UuidErrorCode,
+                value: 32,
+                #[cfg(feature = "message")]
+                message: "UUID must have 36 characters".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_character_error() {
+        assert_eq!(
+            "67e55044-10b1-426f-9247-bb680e5fe0cg".validate_uuid(None),
+            Err(UuidError::InvalidCharacter {
+                code: UuidErrorCode,
+                character: 'g',
+                index: 35,
+                #[cfg(feature = "message")]
+                message: "invalid character `g` at index 35".to_owned(),
+            })
+        );
+        assert_eq!(
+            "67e5504410b1-426f-9247-bb680e5fe0c8-".validate_uuid(None),
+            Err(UuidError::InvalidCharacter {
+                code: UuidErrorCode,
+                character: '1',
+                index: 8,
+                #[cfg(feature = "message")]
+                message: "invalid character `1` at index 8".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn disallowed_version_error() {
+        assert_eq!(
+            UUID.validate_uuid(Some(&[7])),
+            Err(UuidError::DisallowedVersion {
+                code: UuidErrorCode,
+                allowed: vec![7],
+                value: 4,
+                #[cfg(feature = "message")]
+                message: "UUID version 4 is not allowed".to_owned(),
+            })
+        );
+    }
+}
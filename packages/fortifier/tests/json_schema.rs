@@ -0,0 +1,170 @@
+#![cfg(all(
+    feature = "email-address",
+    feature = "json-schema",
+    feature = "macros",
+    feature = "regex",
+    feature = "serde",
+    feature = "url"
+))]
+
+use fortifier::ValidateWithContext;
+use pretty_assertions::assert_eq;
+use serde_json::{Value, json};
+
+fortifier::import_json_schema!("tests/json_schema/user.json");
+
+/// Remove messages, which depend on the `message` feature.
+fn without_messages(mut value: Value) -> Value {
+    match &mut value {
+        Value::Array(values) => {
+            for value in values {
+                *value = without_messages(value.take());
+            }
+        }
+        Value::Object(values) => {
+            values.remove("message");
+
+            for value in values.values_mut() {
+                *value = without_messages(value.take());
+            }
+        }
+        _ => {}
+    }
+
+    value
+}
+
+#[test]
+fn types() {
+    let user: User = serde_json::from_value(json!({
+        "name": "John Doe",
+        "emailAddress": "john@example.com",
+        "role": "admin",
+        "tags": ["a", "b"],
+        "addresses": [{ "postalCode": "1234 AB", "type": "home" }],
+        "contact": { "number": "+31612345678" },
+    }))
+    .expect("deserializable value");
+
+    assert_eq!(
+        user,
+        User {
+            name: "John Doe".to_owned(),
+            email_address: "john@example.com".to_owned(),
+            website: None,
+            age: None,
+            score: None,
+            role: UserRole::Admin,
+            tags: Some(vec![
+                UserTagsItem("a".to_owned()),
+                UserTagsItem("b".to_owned())
+            ]),
+            addresses: Some(vec![Address {
+                postal_code: "1234 AB".to_owned(),
+                r#type: Some("home".to_owned()),
+            }]),
+            contact: UserContact::Phone(Phone {
+                number: "+31612345678".to_owned(),
+            }),
+            metadata: None,
+        }
+    );
+    assert_eq!(user.validate_sync_with_context(&()), Ok(()));
+}
+
+#[test]
+fn error() {
+    let user = User {
+        name: "".to_owned(),
+        email_address: "john".to_owned(),
+        website: Some("example".to_owned()),
+        age: Some(150),
+        score: Some(1.5),
+        role: UserRole::User,
+        tags: Some(vec![UserTagsItem("".to_owned())]),
+        addresses: Some(vec![]),
+        contact: UserContact::Address(Address {
+            postal_code: "1234".to_owned(),
+            r#type: None,
+        }),
+        metadata: Some(json!({})),
+    };
+
+    let errors = user
+        .validate_sync_with_context(&())
+        .expect_err("validation errors");
+
+    assert_eq!(
+        without_messages(serde_json::to_value(errors).expect("serializable value")),
+        json!([
+            {
+                "path": "addresses",
+                "code": "length",
+                "subcode": "min",
+                "min": 1,
+                "value": 0,
+            },
+            {
+                "path": "age",
+                "code": "range",
+                "subcode": "exclusiveMax",
+                "exclusiveMax": 150,
+                "value": 150,
+            },
+            {
+                "path": "contact",
+                "code": "nested",
+                "errors": [
+                    {
+                        "path": "f0",
+                        "code": "nested",
+                        "errors": [
+                            {
+                                "path": "postalCode",
+                                "code": "regex",
+                            },
+                        ],
+                    },
+                ],
+            },
+            {
+                "path": "emailAddress",
+                "code": "emailAddress",
+                "subcode": "missingSeparator",
+            },
+            {
+                "path": "name",
+                "code": "length",
+                "subcode": "min",
+                "min": 1,
+                "value": 0,
+            },
+            {
+                "path": "score",
+                "code": "range",
+                "subcode": "max",
+                "max": 1.0,
+                "value": 1.5,
+            },
+            {
+                "path": "tags",
+                "code": "nested",
+                "errors": [
+                    {
+                        "index": 0,
+                        "path": "f0",
+                        "code": "length",
+                        "subcode": "min",
+                        "min": 1,
+                        "value": 0,
+                    },
+                ],
+            },
+            {
+                "path": "website",
+                "code": "url",
+                "subcode": "relativeUrlWithoutBase",
+            },
+        ])
+    );
+}
@@ -0,0 +1,281 @@
+#![cfg(all(feature = "schema", feature = "regex", feature = "url"))]
+
+use fortifier::{Schema, SchemaError, SchemaValidation, ValidationErrors};
+use pretty_assertions::assert_eq;
+use regex::Regex;
+use serde_json::{Value, json};
+
+fn schema() -> Schema {
+    Schema::object()
+        .required_property(
+            "name",
+            Schema::string().validation(SchemaValidation::Length {
+                equal: None,
+                min: Some(1),
+                max: Some(256),
+            }),
+        )
+        .property(
+            "age",
+            Schema::integer().validation(SchemaValidation::Range {
+                min: Some(18.0),
+                max: None,
+                exclusive_min: None,
+                exclusive_max: Some(150.0),
+            }),
+        )
+        .property(
+            "addresses",
+            Schema::array(
+                Schema::object()
+                    .required_property(
+                        "postalCode",
+                        Schema::string().validation(SchemaValidation::Regex {
+                            pattern: Regex::new(r"^\d{4} ?[A-Z]{2}$").expect("valid regex"),
+                        }),
+                    )
+                    .property(
+                        "website",
+                        Schema::string().validation(SchemaValidation::Url),
+                    ),
+            )
+            .validation(SchemaValidation::Length {
+                equal: None,
+                min: None,
+                max: Some(2),
+            }),
+        )
+}
+
+/// Remove messages, which depend on the `message` feature.
+fn without_messages(mut value: Value) -> Value {
+    match &mut value {
+        Value::Array(values) => {
+            for value in values {
+                *value = without_messages(value.take());
+            }
+        }
+        Value::Object(values) => {
+            values.remove("message");
+
+            for value in values.values_mut() {
+                *value = without_messages(value.take());
+            }
+        }
+        _ => {}
+    }
+
+    value
+}
+
+#[test]
+fn serialize() {
+    let schema = schema();
+
+    let value = serde_json::to_value(&schema).expect("serializable value");
+    assert_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "addresses": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "postalCode": {
+                                "type": "string",
+                                "validations": [
+                                    {
+                                        "type": "regex",
+                                        "pattern": r"^\d{4} ?[A-Z]{2}$",
+                                    },
+                                ],
+                                "required": true,
+                            },
+                            "website": {
+                                "type": "string",
+                                "validations": [
+                                    {
+                                        "type": "url",
+                                    },
+                                ],
+                                "required": false,
+                            },
+                        },
+                    },
+                    "validations": [
+                        {
+                            "type": "length",
+                            "equal": null,
+                            "min": null,
+                            "max": 2,
+                        },
+                    ],
+                    "required": false,
+                },
+                "age": {
+                    "type": "integer",
+                    "validations": [
+                        {
+                            "type": "range",
+                            "min": 18.0,
+                            "max": null,
+                            "exclusiveMin": null,
+                            "exclusiveMax": 150.0,
+                        },
+                    ],
+                    "required": false,
+                },
+                "name": {
+                    "type": "string",
+                    "validations": [
+                        {
+                            "type": "length",
+                            "equal": null,
+                            "min": 1,
+                            "max": 256,
+                        },
+                    ],
+                    "required": true,
+                },
+            },
+        })
+    );
+
+    let deserialized: Schema = serde_json::from_value(value.clone()).expect("deserializable value");
+    assert_eq!(
+        serde_json::to_value(&deserialized).expect("serializable value"),
+        value
+    );
+}
+
+#[test]
+fn ok() {
+    let schema = schema();
+
+    assert_eq!(schema.validate(&json!({ "name": "John Doe" })), Ok(()));
+    assert_eq!(
+        schema.validate(&json!({
+            "name": "John Doe",
+            "age": null,
+            "addresses": [
+                {
+                    "postalCode": "1234 AB",
+                    "website": "https://example.com",
+                },
+                {
+                    "postalCode": "5678CD",
+                    "unknown": true,
+                },
+            ],
+        })),
+        Ok(())
+    );
+}
+
+#[test]
+fn error() {
+    let schema = schema();
+
+    let errors = schema
+        .validate(&json!({
+            "name": "",
+            "age": 12,
+            "addresses": [
+                {
+                    "postalCode": "1234",
+                    "website": "example",
+                },
+                {
+                    "postalCode": null,
+                },
+                "address",
+            ],
+        }))
+        .expect_err("validation errors");
+
+    let value = serde_json::to_value(&errors).expect("serializable value");
+    assert_eq!(
+        serde_json::from_value::<ValidationErrors<SchemaError>>(value.clone())
+            .expect("deserializable value"),
+        errors
+    );
+    assert_eq!(
+        without_messages(value),
+        json!([
+            {
+                "path": "addresses",
+                "code": "length",
+                "subcode": "max",
+                "max": 2,
+                "value": 3,
+            },
+            {
+                "path": "addresses",
+                "code": "nested",
+                "errors": [
+                    {
+                        "index": 0,
+                        "code": "nested",
+                        "errors": [
+                            {
+                                "path": "postalCode",
+                                "code": "regex",
+                            },
+                            {
+                                "path": "website",
+                                "code": "url",
+                                "subcode": "relativeUrlWithoutBase",
+                            },
+                        ],
+                    },
+                    {
+                        "index": 1,
+                        "code": "nested",
+                        "errors": [
+                            {
+                                "path": "postalCode",
+                                "code": "required",
+                            },
+                        ],
+                    },
+                    {
+                        "index": 2,
+                        "code": "type",
+                        "expected": "object",
+                        "actual": "string",
+                    },
+                ],
+            },
+            {
+                "path": "age",
+                "code": "range",
+                "subcode": "min",
+                "min": 18.0,
+                "value": 12.0,
+            },
+            {
+                "path": "name",
+                "code": "length",
+                "subcode": "min",
+                "min": 1,
+                "value": 0,
+            },
+        ])
+    );
+
+    assert_eq!(
+        without_messages(
+            serde_json::to_value(schema.validate(&json!([])).expect_err("validation errors"))
+                .expect("serializable value")
+        ),
+        json!([
+            {
+                "code": "type",
+                "expected": "object",
+                "actual": "array",
+            },
+        ])
+    );
+}
@@ -26,6 +26,7 @@ fn setup() -> (ValidationErrors<TestError>, Value) {
         TestError::Length(LengthError::Equal {
             equal: 1,
             value: 2,
+            unit: None,
             code: LengthErrorCode,
             #[cfg(feature = "message")]
             message: "length 2 is not equal to required length 1".to_owned(),
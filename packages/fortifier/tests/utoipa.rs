@@ -0,0 +1,261 @@
+#![cfg(feature = "utoipa")]
+
+use std::{error::Error, fmt};
+
+use fortifier::{IndexedValidationError, KeyedValidationError, utoipa::NestedErrors};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use utoipa::{PartialSchema, ToSchema};
+
+#[derive(Debug, ToSchema)]
+struct TestError {
+    value: usize,
+}
+
+impl fmt::Display for TestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "test error {}", self.value)
+    }
+}
+
+impl Error for TestError {}
+
+#[test]
+fn indexed() {
+    assert_eq!(
+        serde_json::to_value(NestedErrors::<IndexedValidationError<TestError>>::schema())
+            .expect("serializable value"),
+        json!({
+            "type": "object",
+            "description": "Nested validation errors.",
+            "required": ["code", "errors"],
+            "properties": {
+                "code": {
+                    "oneOf": [
+                        {
+                            "type": "string",
+                            "enum": ["nested"]
+                        }
+                    ],
+                    "description": "The error code."
+                },
+                "errors": {
+                    "oneOf": [
+                        {
+                            "type": "array",
+                            "items": {
+                                "allOf": [
+                                    {
+                                        "oneOf": [
+                                            {
+                                                "type": "object",
+                                                "required": ["value"],
+                                                "properties": {
+                                                    "value": {
+                                                        "type": "integer",
+                                                        "minimum": 0
+                                                    }
+                                                }
+                                            }
+                                        ],
+                                        "description": "The error."
+                                    },
+                                    {
+                                        "type": "object",
+                                        "required": ["index"],
+                                        "properties": {
+                                            "index": {
+                                                "type": "integer",
+                                                "description": "The index.",
+                                                "minimum": 0
+                                            }
+                                        }
+                                    }
+                                ],
+                                "description": "Validation error with index."
+                            },
+                            "description": "Validation errors."
+                        }
+                    ],
+                    "description": "The nested errors."
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn keyed() {
+    assert_eq!(
+        serde_json::to_value(KeyedValidationError::<String, TestError>::schema())
+            .expect("serializable value"),
+        json!({
+            "allOf": [
+                {
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "required": ["value"],
+                            "properties": {
+                                "value": {
+                                    "type": "integer",
+                                    "minimum": 0
+                                }
+                            }
+                        }
+                    ],
+                    "description": "The error."
+                },
+                {
+                    "type": "object",
+                    "required": ["key"],
+                    "properties": {
+                        "key": {
+                            "oneOf": [
+                                {
+                                    "type": "string"
+                                }
+                            ],
+                            "description": "The key."
+                        }
+                    }
+                }
+            ],
+            "description": "Validation error with key."
+        })
+    );
+}
+
+#[cfg(all(feature = "macros", feature = "serde"))]
+mod derive {
+    #![allow(dead_code)]
+
+    use std::collections::{BTreeMap, HashMap};
+
+    use fortifier::Validate;
+    use pretty_assertions::assert_eq;
+    use serde_json::{Value, json};
+    use utoipa::{PartialSchema, ToSchema};
+
+    #[derive(Validate)]
+    struct CreateUser {
+        #[validate(length(min = 1, max = 256))]
+        name: String,
+        address: CreateAddress,
+        tags: Vec<CreateTag>,
+        settings: HashMap<String, CreateTag>,
+    }
+
+    #[derive(Validate)]
+    struct CreateAddress {
+        #[validate(length(min = 1))]
+        street: String,
+    }
+
+    #[derive(Validate)]
+    struct CreateTag {
+        #[validate(length(min = 1))]
+        name: String,
+    }
+
+    fn path(schema: &str, path: &str) -> Value {
+        json!({
+            "allOf": [
+                {
+                    "$ref": format!("#/components/schemas/{schema}")
+                },
+                {
+                    "type": "object",
+                    "required": ["path"],
+                    "properties": {
+                        "path": {
+                            "type": "string",
+                            "enum": [path]
+                        }
+                    }
+                }
+            ]
+        })
+    }
+
+    fn schemas() -> BTreeMap<String, Value> {
+        let mut schemas = vec![];
+        CreateUserValidationError::schemas(&mut schemas);
+
+        schemas
+            .into_iter()
+            .map(|(name, schema)| {
+                (
+                    name,
+                    serde_json::to_value(schema).expect("serializable value"),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            serde_json::to_value(CreateUserValidationError::schema()).expect("serializable value"),
+            json!({
+                "oneOf": [
+                    path("LengthError_usize", "name"),
+                    path("NestedErrors_CreateAddressValidationError", "address"),
+                    path(
+                        "NestedErrors_IndexedValidationError_CreateTagValidationError",
+                        "tags"
+                    ),
+                    path(
+                        "NestedErrors_KeyedValidationError_String_CreateTagValidationError",
+                        "settings"
+                    ),
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let schemas = schemas();
+
+        assert_eq!(
+            schemas["NestedErrors_CreateAddressValidationError"]
+                .pointer("/properties/errors/oneOf/0/items"),
+            Some(&json!({
+                "oneOf": [path("LengthError_usize", "street")]
+            }))
+        );
+    }
+
+    #[test]
+    fn indexed() {
+        let schemas = schemas();
+        let items = schemas["NestedErrors_IndexedValidationError_CreateTagValidationError"]
+            .pointer("/properties/errors/oneOf/0/items")
+            .expect("items");
+
+        assert_eq!(
+            items.pointer("/allOf/0/oneOf/0"),
+            Some(&json!({
+                "oneOf": [path("LengthError_usize", "name")]
+            }))
+        );
+        assert_eq!(items.pointer("/allOf/1/required"), Some(&json!(["index"])));
+    }
+
+    #[test]
+    fn keyed() {
+        let schemas = schemas();
+        let items = schemas["NestedErrors_KeyedValidationError_String_CreateTagValidationError"]
+            .pointer("/properties/errors/oneOf/0/items")
+            .expect("items");
+
+        assert_eq!(
+            items.pointer("/allOf/0/oneOf/0"),
+            Some(&json!({
+                "oneOf": [path("LengthError_usize", "name")]
+            }))
+        );
+        assert_eq!(items.pointer("/allOf/1/required"), Some(&json!(["key"])));
+    }
+}
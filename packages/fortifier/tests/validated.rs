@@ -0,0 +1,93 @@
+#![cfg(feature = "macros")]
+
+use fortifier::{LengthError, Validate, Validated, ValidationErrors};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Debug, PartialEq, Validate)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+struct CreateUser {
+    #[validate(length(min = 1, max = 256))]
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Validate)]
+struct CreateUsers {
+    owner: fortifier::Validated<CreateUser>,
+    users: Vec<Validated<CreateUser>>,
+}
+
+#[tokio::test]
+async fn new() {
+    let user = Validated::new(CreateUser {
+        name: "John Doe".to_owned(),
+    })
+    .await
+    .expect("valid value");
+
+    assert_eq!(user.name, "John Doe");
+    assert_eq!(
+        user.into_inner(),
+        CreateUser {
+            name: "John Doe".to_owned()
+        }
+    );
+
+    assert_eq!(
+        Validated::new(CreateUser {
+            name: "".to_owned()
+        })
+        .await,
+        Err(ValidationErrors::from_iter([
+            CreateUserValidationError::Name(LengthError::Min {
+                min: 1,
+                value: 0,
+                unit: None,
+                code: Default::default(),
+                #[cfg(feature = "message")]
+                message: "length 0 is less than minimum length 1".to_owned(),
+            })
+        ]))
+    );
+}
+
+#[test]
+fn new_sync() {
+    let user = Validated::new_sync(
+        CreateUser {
+            name: "John Doe".to_owned(),
+        },
+        &(),
+    )
+    .expect("valid value");
+
+    assert_eq!(user.as_ref().name, "John Doe");
+    assert_eq!(
+        CreateUsers {
+            owner: user.clone(),
+            users: vec![user]
+        }
+        .validate_sync(),
+        Ok(())
+    );
+
+    assert!(
+        Validated::new_sync(
+            CreateUser {
+                name: "".to_owned()
+            },
+            &()
+        )
+        .is_err()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize() {
+    let user: Validated<CreateUser> =
+        serde_json::from_str(r#"{"name":"John Doe"}"#).expect("valid value");
+
+    assert_eq!(user.name, "John Doe");
+
+    assert!(serde_json::from_str::<Validated<CreateUser>>(r#"{"name":""}"#).is_err());
+}
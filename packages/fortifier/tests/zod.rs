@@ -0,0 +1,100 @@
+#![cfg(all(feature = "macros", feature = "zod"))]
+#![allow(dead_code)]
+
+use fortifier::{ToZod, Validate, zod::ZodGenerator};
+use pretty_assertions::assert_eq;
+
+const MESSAGE: &str = if cfg!(feature = "message") {
+    "; message: string"
+} else {
+    ""
+};
+
+const UNIT: &str = if cfg!(feature = "unicode-segmentation") {
+    "\"bytes\" | \"chars\" | \"utf16\" | \"graphemes\""
+} else {
+    "\"bytes\" | \"chars\" | \"utf16\""
+};
+
+#[derive(ToZod, Validate)]
+struct CreateUser {
+    #[validate(length(min = 1, max = 256))]
+    name: String,
+    #[validate(range(min = 18, exclusive_max = 150))]
+    age: Option<u8>,
+    addresses: Vec<CreateAddress>,
+}
+
+#[derive(ToZod, Validate)]
+#[serde(rename_all = "camelCase")]
+struct CreateAddress {
+    #[validate(length(min = 1))]
+    street_name: String,
+    #[serde(default)]
+    primary: bool,
+}
+
+#[derive(ToZod, Validate)]
+#[serde(rename_all = "lowercase")]
+enum Action {
+    Create {
+        #[validate(length(equal = 4))]
+        code: String,
+    },
+    Delete,
+}
+
+#[test]
+fn r#struct() {
+    let mut generator = ZodGenerator::new();
+    generator.add::<CreateUser>();
+
+    assert_eq!(
+        generator.to_string(),
+        format!(
+            r#"import {{ z }} from "zod";
+
+export type LengthError<T> = {{ code: "length"; subcode: "equal"; equal: T; value: T; unit?: {UNIT}{MESSAGE} }} | {{ code: "length"; subcode: "min"; min: T; value: T; unit?: {UNIT}{MESSAGE} }} | {{ code: "length"; subcode: "max"; max: T; value: T; unit?: {UNIT}{MESSAGE} }};
+
+export type RangeError<T> = {{ code: "range"; subcode: "min"; min: T; value: T{MESSAGE} }} | {{ code: "range"; subcode: "max"; max: T; value: T{MESSAGE} }} | {{ code: "range"; subcode: "exclusiveMin"; exclusiveMin: T; value: T{MESSAGE} }} | {{ code: "range"; subcode: "exclusiveMax"; exclusiveMax: T; value: T{MESSAGE} }};
+
+export type NestedErrors<E> = {{ code: "nested"; errors: Array<E> }};
+
+export type IndexedValidationError<E> = {{ index: number }} & E;
+
+export type CreateAddressValidationError = {{ path: "streetName" }} & (LengthError<number>);
+
+export type CreateUserValidationError = {{ path: "name" }} & (LengthError<number>) | {{ path: "age" }} & (RangeError<number>) | {{ path: "addresses" }} & (NestedErrors<IndexedValidationError<CreateAddressValidationError>>);
+
+export const CreateAddressSchema = z.object({{ streetName: z.string().min(1), primary: z.boolean().optional() }});
+export type CreateAddress = z.infer<typeof CreateAddressSchema>;
+
+export const CreateUserSchema = z.object({{ name: z.string().min(1).max(256), age: z.number().int().nonnegative().gte(18).lt(150).nullish(), addresses: z.array(CreateAddressSchema) }});
+export type CreateUser = z.infer<typeof CreateUserSchema>;
+"#
+        )
+    );
+}
+
+#[test]
+fn r#enum() {
+    let mut generator = ZodGenerator::new();
+    generator.add::<Action>();
+
+    assert_eq!(
+        generator.to_string(),
+        format!(
+            r#"import {{ z }} from "zod";
+
+export type LengthError<T> = {{ code: "length"; subcode: "equal"; equal: T; value: T; unit?: {UNIT}{MESSAGE} }} | {{ code: "length"; subcode: "min"; min: T; value: T; unit?: {UNIT}{MESSAGE} }} | {{ code: "length"; subcode: "max"; max: T; value: T; unit?: {UNIT}{MESSAGE} }};
+
+export type ActionCreateValidationError = {{ path: "code" }} & (LengthError<number>);
+
+export type ActionValidationError = {{ path: "create" }} & (ActionCreateValidationError);
+
+export const ActionSchema = z.union([z.object({{ create: z.object({{ code: z.string().length(4) }}) }}), z.literal("delete")]);
+export type Action = z.infer<typeof ActionSchema>;
+"#
+        )
+    );
+}